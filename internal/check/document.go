@@ -2,6 +2,8 @@ package check
 
 import (
 	"fmt"
+	"sort"
+	"strings"
 
 	"github.com/aidanlsb/raven/internal/parser"
 	"github.com/aidanlsb/raven/internal/schema"
@@ -43,6 +45,36 @@ func (v *Validator) ValidateDocument(doc *parser.ParsedDocument) []Issue {
 		issues = append(issues, v.validateRef(doc.FilePath, ref)...)
 	}
 
+	issues = append(issues, headingLevelSkipIssues(doc)...)
+
+	return issues
+}
+
+// headingLevelSkipIssues warns when a heading is more than one level deeper
+// than the heading before it (an H2 followed directly by an H4), which leaves
+// the section hierarchy without the intermediate parent.
+func headingLevelSkipIssues(doc *parser.ParsedDocument) []Issue {
+	sections := append([]*parser.ParsedSection(nil), doc.Sections...)
+	sort.SliceStable(sections, func(i, j int) bool {
+		return sections[i].LineStart < sections[j].LineStart
+	})
+
+	var issues []Issue
+	for i := 1; i < len(sections); i++ {
+		prev, cur := sections[i-1], sections[i]
+		if cur.Level <= prev.Level+1 {
+			continue
+		}
+		issues = append(issues, Issue{
+			Level:    LevelWarning,
+			Type:     IssueHeadingLevelSkip,
+			FilePath: doc.FilePath,
+			Line:     cur.LineStart,
+			Message:  fmt.Sprintf("Heading level jumped from %d to %d at '%s'", prev.Level, cur.Level, cur.Title),
+			Value:    cur.ID,
+			FixHint:  fmt.Sprintf("Use a level-%d heading here or add the missing intermediate heading", prev.Level+1),
+		})
+	}
 	return issues
 }
 
@@ -90,6 +122,8 @@ func (v *Validator) validateObject(filePath string, obj *parser.ParsedObject) []
 			})
 		}
 
+		issues = append(issues, missingDefaultedFieldIssues(filePath, obj, typeDef)...)
+
 		// Validate ref fields with type context for missing ref tracking
 		for fieldName, fieldDef := range typeDef.Fields {
 			if fieldDef == nil {
@@ -134,9 +168,10 @@ func (v *Validator) validateObject(filePath string, obj *parser.ParsedObject) []
 		// Check for unknown frontmatter keys (not a defined field)
 		// Reserved keys that are always allowed
 		reservedKeys := map[string]bool{
-			"type":  true, // Object type declaration
-			"id":    true, // Optional file object ID override
-			"alias": true, // Alias for reference resolution
+			"type":    true, // Object type declaration
+			"id":      true, // Optional file object ID override
+			"alias":   true, // Alias for reference resolution
+			"aliases": true, // Additional aliases for reference resolution
 		}
 
 		for fieldName := range obj.Fields {
@@ -174,7 +209,7 @@ func (v *Validator) validateTrait(filePath string, trait *parser.ParsedTrait) []
 	// Check if trait is defined
 	traitDef, exists := v.schema.Traits[trait.TraitType]
 	if !exists {
-		issues = append(issues, Issue{
+		issue := Issue{
 			Level:      LevelWarning,
 			Type:       IssueUndefinedTrait,
 			FilePath:   filePath,
@@ -183,7 +218,13 @@ func (v *Validator) validateTrait(filePath string, trait *parser.ParsedTrait) []
 			Value:      trait.TraitType,
 			FixCommand: fmt.Sprintf("rvn schema add trait %s", trait.TraitType),
 			FixHint:    fmt.Sprintf("Add trait '%s' to schema", trait.TraitType),
-		})
+		}
+		if canonical, ok := v.schema.TraitCaseVariant(trait.TraitType); ok {
+			issue.Message = fmt.Sprintf("Undefined trait '@%s' (schema declares '@%s')", trait.TraitType, canonical)
+			issue.FixCommand = "rvn check fix --confirm"
+			issue.FixHint = fmt.Sprintf("Trait names are case-sensitive; rename to '@%s'", canonical)
+		}
+		issues = append(issues, issue)
 		// Track this undefined trait
 		v.trackUndefinedTrait(trait.TraitType, filePath, trait.Line, trait.HasValue())
 		return issues
@@ -201,6 +242,18 @@ func (v *Validator) validateTrait(filePath string, trait *parser.ParsedTrait) []
 		return issues
 	}
 
+	if traitDef.RequireContent && strings.TrimSpace(trait.Content) == "" {
+		issues = append(issues, Issue{
+			Level:    LevelWarning,
+			Type:     IssueEmptyTraitContent,
+			FilePath: filePath,
+			Line:     trait.Line,
+			Message:  fmt.Sprintf("Trait '@%s' has no content", trait.TraitType),
+			Value:    trait.TraitType,
+			FixHint:  fmt.Sprintf("Describe what @%s applies to on the same line", trait.TraitType),
+		})
+	}
+
 	// Validate value based on trait type
 	if !traitDef.IsBoolean() && !trait.HasValue() && traitDef.Default == nil {
 		issues = append(issues, Issue{
@@ -271,3 +324,35 @@ func normalizedTraitFieldType(def *schema.TraitDefinition) schema.FieldType {
 	}
 	return def.Type
 }
+
+// missingDefaultedFieldIssues reports required fields an object omits but the
+// schema supplies a default for. ValidateFields accepts these, so they surface
+// as warnings that 'rvn check fix' can resolve by writing the default.
+func missingDefaultedFieldIssues(filePath string, obj *parser.ParsedObject, typeDef *schema.TypeDefinition) []Issue {
+	var names []string
+	for name, fieldDef := range typeDef.Fields {
+		if fieldDef == nil || !fieldDef.Required || fieldDef.Default == nil {
+			continue
+		}
+		if value, ok := obj.Fields[name]; ok && !value.IsNull() {
+			continue
+		}
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	issues := make([]Issue, 0, len(names))
+	for _, name := range names {
+		issues = append(issues, Issue{
+			Level:      LevelWarning,
+			Type:       IssueMissingRequiredField,
+			FilePath:   filePath,
+			Line:       obj.LineStart,
+			Message:    fmt.Sprintf("Field '%s': Required field is missing (schema default: %v)", name, typeDef.Fields[name].Default),
+			Value:      name,
+			FixCommand: "rvn check fix --confirm",
+			FixHint:    "Write the schema default into the file's frontmatter",
+		})
+	}
+	return issues
+}
@@ -25,6 +25,7 @@ const (
 	IssueMissingTargetType       IssueType = "missing_target_type"
 	IssueSelfReferentialRequired IssueType = "self_referential_required"
 	IssueUnknownFieldType        IssueType = "unknown_field_type"
+	IssueInvalidFieldDefinition  IssueType = "invalid_field_definition"
 	IssueIDCollision             IssueType = "id_collision"
 	IssueDuplicateAlias          IssueType = "duplicate_alias"
 	IssueAliasCollision          IssueType = "alias_collision"
@@ -35,6 +36,8 @@ const (
 	IssueDirectoryTypeMismatch   IssueType = "directory_type_mismatch"
 	IssueMissingAsset            IssueType = "missing_asset"
 	IssueOrphanedAsset           IssueType = "orphaned_asset"
+	IssueHeadingLevelSkip        IssueType = "heading_level_skip"
+	IssueEmptyTraitContent       IssueType = "empty_trait_content"
 )
 
 // AllIssueTypes returns the stable issue type strings emitted by check.
@@ -61,6 +64,7 @@ func AllIssueTypes() []IssueType {
 		IssueMissingTargetType,
 		IssueSelfReferentialRequired,
 		IssueUnknownFieldType,
+		IssueInvalidFieldDefinition,
 		IssueIDCollision,
 		IssueDuplicateAlias,
 		IssueAliasCollision,
@@ -71,6 +75,8 @@ func AllIssueTypes() []IssueType {
 		IssueDirectoryTypeMismatch,
 		IssueMissingAsset,
 		IssueOrphanedAsset,
+		IssueHeadingLevelSkip,
+		IssueEmptyTraitContent,
 	}
 }
 
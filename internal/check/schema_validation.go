@@ -97,6 +97,15 @@ func (v *Validator) ValidateSchema() []SchemaIssue {
 					FixHint: fmt.Sprintf("Use one of: %s", schema.ValidFieldTypes()),
 				})
 			}
+			for _, problem := range schema.FieldDefinitionIssues(fieldDef) {
+				issues = append(issues, SchemaIssue{
+					Level:   LevelError,
+					Type:    IssueInvalidFieldDefinition,
+					Message: fmt.Sprintf("Field '%s.%s' %s", typeName, fieldName, problem),
+					Value:   typeName + "." + fieldName,
+					FixHint: fmt.Sprintf("Fix the '%s' field definition under type '%s' in schema.yaml", fieldName, typeName),
+				})
+			}
 			// Check ref and ref[] fields with target constraints
 			if (fieldDef.Type == schema.FieldTypeRef || fieldDef.Type == schema.FieldTypeRefArray) && fieldDef.Target != "" {
 				// Check if target type exists
@@ -171,6 +171,42 @@ func TestValidatorUnknownFrontmatterKey(t *testing.T) {
 	}
 }
 
+func TestValidatorMissingRequiredFieldWithDefault(t *testing.T) {
+	t.Parallel()
+	s := &schema.Schema{
+		Types: map[string]*schema.TypeDefinition{
+			"task": {
+				Fields: map[string]*schema.FieldDefinition{
+					"priority": {Type: schema.FieldTypeString, Required: true, Default: "medium"},
+					"owner":    {Type: schema.FieldTypeString, Required: true},
+				},
+			},
+		},
+		Traits: map[string]*schema.TraitDefinition{},
+	}
+
+	v := NewValidator(s, []string{"tasks/ship"})
+	issues := v.ValidateDocument(&parser.ParsedDocument{
+		FilePath: "tasks/ship.md",
+		Objects: []*parser.ParsedObject{
+			{ID: "tasks/ship", ObjectType: "task", Fields: map[string]schema.FieldValue{}, LineStart: 1},
+		},
+	})
+
+	levels := make(map[string]IssueLevel)
+	for _, issue := range issues {
+		if issue.Type == IssueMissingRequiredField {
+			levels[issue.Message] = issue.Level
+		}
+	}
+	if level, ok := levels["Field 'owner': Required field is missing"]; !ok || level != LevelError {
+		t.Errorf("expected missing owner error, got %v", issues)
+	}
+	if level, ok := levels["Field 'priority': Required field is missing (schema default: medium)"]; !ok || level != LevelWarning {
+		t.Errorf("expected missing priority warning, got %v", issues)
+	}
+}
+
 func TestValidatorTraitValidation(t *testing.T) {
 	t.Parallel()
 	s := &schema.Schema{
@@ -696,6 +732,36 @@ func TestValidatorSchemaIntegrity(t *testing.T) {
 		}
 	})
 
+	t.Run("invalid field definition", func(t *testing.T) {
+		s := &schema.Schema{
+			Types: map[string]*schema.TypeDefinition{
+				"book": {
+					Fields: map[string]*schema.FieldDefinition{
+						"status": {Type: schema.FieldTypeEnum},
+						"rating": {Type: schema.FieldTypeNumber, Default: "high"},
+					},
+				},
+			},
+			Traits: map[string]*schema.TraitDefinition{},
+		}
+
+		v := NewValidatorWithTypes(s, []ObjectInfo{})
+		schemaIssues := v.ValidateSchema()
+
+		found := map[string]bool{}
+		for _, issue := range schemaIssues {
+			if issue.Type == IssueInvalidFieldDefinition {
+				if issue.Level != LevelError {
+					t.Errorf("expected invalid field definition to be an error, got %v", issue.Level)
+				}
+				found[issue.Value] = true
+			}
+		}
+		if !found["book.status"] || !found["book.rating"] {
+			t.Errorf("Expected invalid field definition errors for book.status and book.rating, got: %v", schemaIssues)
+		}
+	})
+
 	t.Run("self-referential required field", func(t *testing.T) {
 		s := &schema.Schema{
 			Types: map[string]*schema.TypeDefinition{
@@ -1107,3 +1173,68 @@ func TestValidatorStaleFragment(t *testing.T) {
 		t.Fatalf("expected local fragment issue, got %v", issues)
 	})
 }
+
+func TestValidatorHeadingLevelSkips(t *testing.T) {
+	t.Parallel()
+	v := NewValidator(schema.New(), []string{"notes/plan"})
+
+	parse := func(t *testing.T, content string) *parser.ParsedDocument {
+		t.Helper()
+		doc, err := parser.ParseDocument(content, "/vault/notes/plan.md", "/vault")
+		if err != nil {
+			t.Fatalf("parse: %v", err)
+		}
+		return doc
+	}
+
+	t.Run("skipped level warns", func(t *testing.T) {
+		issues := v.ValidateDocument(parse(t, "# Plan\n\n## Goals\n\n#### Detail\n\n## Risks\n"))
+		var skips []Issue
+		for _, issue := range issues {
+			if issue.Type == IssueHeadingLevelSkip {
+				skips = append(skips, issue)
+			}
+		}
+		if len(skips) != 1 {
+			t.Fatalf("expected 1 heading_level_skip issue, got %v", issues)
+		}
+		if skips[0].Level != LevelWarning || skips[0].Line != 5 || !strings.Contains(skips[0].Message, "from 2 to 4") {
+			t.Errorf("unexpected issue: %+v", skips[0])
+		}
+	})
+
+	t.Run("contiguous levels are clean", func(t *testing.T) {
+		issues := v.ValidateDocument(parse(t, "# Plan\n\n## Goals\n\n### Detail\n\n# Appendix\n\n## Notes\n"))
+		for _, issue := range issues {
+			if issue.Type == IssueHeadingLevelSkip {
+				t.Fatalf("unexpected heading_level_skip issue: %+v", issue)
+			}
+		}
+	})
+}
+
+func TestValidatorEmptyTraitContent(t *testing.T) {
+	t.Parallel()
+	sch := schema.New()
+	sch.Traits["todo"] = &schema.TraitDefinition{Type: schema.FieldTypeBool, RequireContent: true}
+	sch.Traits["highlight"] = &schema.TraitDefinition{Type: schema.FieldTypeBool}
+	v := NewValidator(sch, []string{"notes/plan"})
+
+	doc, err := parser.ParseDocument("# Plan\n\n- @todo\n- @todo Write the intro\n- @highlight\n", "/vault/notes/plan.md", "/vault")
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	var empty []Issue
+	for _, issue := range v.ValidateDocument(doc) {
+		if issue.Type == IssueEmptyTraitContent {
+			empty = append(empty, issue)
+		}
+	}
+	if len(empty) != 1 {
+		t.Fatalf("expected 1 empty_trait_content issue, got %+v", empty)
+	}
+	if empty[0].Level != LevelWarning || empty[0].Line != 3 || empty[0].Value != "todo" {
+		t.Errorf("unexpected issue: %+v", empty[0])
+	}
+}
@@ -36,16 +36,17 @@ type Scope struct {
 }
 
 type RunResult struct {
-	Scope             Scope
-	FileCount         int
-	ErrorCount        int
-	WarningCount      int
-	Issues            []check.Issue
-	SchemaIssues      []check.SchemaIssue
-	StaleWarningShown bool
-	MissingRefs       []*check.MissingRef
-	UndefinedTraits   []*check.UndefinedTrait
-	ShortRefs         map[string]string
+	Scope              Scope
+	FileCount          int
+	ErrorCount         int
+	WarningCount       int
+	HiddenWarningCount int
+	Issues             []check.Issue
+	SchemaIssues       []check.SchemaIssue
+	StaleWarningShown  bool
+	MissingRefs        []*check.MissingRef
+	UndefinedTraits    []*check.UndefinedTrait
+	ShortRefs          map[string]string
 }
 
 type CheckIssueJSON struct {
@@ -79,6 +80,7 @@ type CheckResultJSON struct {
 	FileCount  int                `json:"file_count"`
 	ErrorCount int                `json:"error_count"`
 	WarnCount  int                `json:"warning_count"`
+	HiddenWarn int                `json:"hidden_warning_count,omitempty"`
 	Issues     []CheckIssueJSON   `json:"issues"`
 	Summary    []CheckSummaryJSON `json:"summary"`
 }
@@ -118,6 +120,7 @@ func Run(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, opt
 	var allIssues []check.Issue
 	var parseErrors []check.Issue
 	var schemaIssues []check.SchemaIssue
+	idFiles := objectIDFiles{}
 
 	// Check staleness + pull aliases from index when available.
 	var aliases map[string]string
@@ -140,7 +143,7 @@ func Run(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, opt
 					FixCommand: "rvn reindex",
 					FixHint:    "Run 'rvn reindex' to update the index",
 				}
-				if shouldIncludeIssue(staleIssue, includeIssues, excludeIssues, opts.ErrorsOnly) {
+				if shouldIncludeIssue(staleIssue, includeIssues, excludeIssues) {
 					allIssues = append(allIssues, staleIssue)
 					result.WarningCount++
 				}
@@ -168,11 +171,9 @@ func Run(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, opt
 	}
 
 	walkOpts := &vault.WalkOptions{
-		ParseOptions: &parser.ParseOptions{
-			ObjectsRoot: vaultCfg.GetObjectsRoot(),
-			PagesRoot:   vaultCfg.GetPagesRoot(),
-		},
+		ParseOptions:   vault.ParseOptionsFromConfig(vaultCfg),
 		ExcludeMatcher: excludeMatcher,
+		FollowSymlinks: vaultCfg.FollowSymlinks,
 	}
 	walkErr := vault.WalkMarkdownFilesWithOptions(vaultPath, walkOpts, func(walkResult vault.WalkResult) error {
 		if walkResult.Error != nil {
@@ -193,6 +194,7 @@ func Run(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, opt
 		for _, obj := range walkResult.Document.Objects {
 			allObjectInfos = append(allObjectInfos, check.ObjectInfo{ID: obj.ID, Type: obj.ObjectType})
 		}
+		idFiles.add(walkResult.Document)
 
 		if isFileInScope(walkResult.Path, scope, walkPath, targetFileSet) {
 			result.FileCount++
@@ -205,54 +207,27 @@ func Run(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, opt
 		return nil, fmt.Errorf("error walking vault: %w", walkErr)
 	}
 
-	validator := check.NewValidatorWithTypesAliasesAndResolver(sch, allObjectInfos, aliases, canonicalResolver)
-	validator.SetDuplicateAliases(duplicateAliases)
-	validator.SetDailyDirectoryForInference(vaultCfg.GetDailyDirectory())
-	if vaultCfg.HasDirectoriesConfig() {
-		validator.SetDirectoryRoots(vaultCfg.GetObjectsRoot(), vaultCfg.GetPagesRoot())
-	}
-	if canonicalResolver == nil {
-		validator.SetDailyDirectory(vaultCfg.GetDailyDirectory())
-	}
+	validator := newValidator(sch, vaultCfg, allObjectInfos, aliases, duplicateAliases, canonicalResolver)
 
-	for _, doc := range allDocs {
-		issues := validator.ValidateDocument(doc)
-		for _, issue := range issues {
-			if !isIssueInScope(issue, doc, scope) {
-				continue
-			}
-			if !shouldIncludeIssue(issue, includeIssues, excludeIssues, opts.ErrorsOnly) {
-				continue
-			}
-
-			allIssues = append(allIssues, issue)
-			if issue.Level == check.LevelWarning {
-				result.WarningCount++
-			} else {
-				result.ErrorCount++
-			}
-		}
+	for _, issue := range validateDocuments(validator, allDocs, sch, vaultCfg, scope, includeIssues, excludeIssues) {
+		allIssues = append(allIssues, issue)
+		result.countIssue(issue)
 	}
 
-	for _, issue := range detectNonCanonicalIssues(allDocs, sch, vaultCfg) {
-		doc := docByPath(allDocs, issue.FilePath)
-		if doc != nil && !isIssueInScope(issue, doc, scope) {
+	for _, issue := range detectCrossFileDuplicateIDs(allDocs, idFiles) {
+		if doc := docByPath(allDocs, issue.FilePath); doc != nil && !isIssueInScope(issue, doc, scope) {
 			continue
 		}
-		if !shouldIncludeIssue(issue, includeIssues, excludeIssues, opts.ErrorsOnly) {
+		if !shouldIncludeIssue(issue, includeIssues, excludeIssues) {
 			continue
 		}
 		allIssues = append(allIssues, issue)
-		if issue.Level == check.LevelWarning {
-			result.WarningCount++
-		} else {
-			result.ErrorCount++
-		}
+		result.countIssue(issue)
 	}
 
 	if db != nil && (scope.Type == "full" || scope.Type == "directory") {
 		for _, issue := range detectAssetIssues(db, vaultPath, excludeMatcher, scope, walkPath, targetFileSet) {
-			if !shouldIncludeIssue(issue, includeIssues, excludeIssues, opts.ErrorsOnly) {
+			if !shouldIncludeIssue(issue, includeIssues, excludeIssues) {
 				continue
 			}
 			allIssues = append(allIssues, issue)
@@ -265,7 +240,7 @@ func Run(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, opt
 	}
 
 	for _, pe := range parseErrors {
-		if shouldIncludeIssue(pe, includeIssues, excludeIssues, opts.ErrorsOnly) {
+		if shouldIncludeIssue(pe, includeIssues, excludeIssues) {
 			allIssues = append([]check.Issue{pe}, allIssues...)
 			result.ErrorCount++
 		}
@@ -282,7 +257,7 @@ func Run(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, opt
 			if scope.Type == "trait_filter" && issue.Value != scope.Value {
 				continue
 			}
-			if !shouldIncludeSchemaIssue(issue, includeIssues, excludeIssues, opts.ErrorsOnly) {
+			if !shouldIncludeSchemaIssue(issue, includeIssues, excludeIssues) {
 				continue
 			}
 
@@ -297,18 +272,16 @@ func Run(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, opt
 
 	result.Issues = allIssues
 	result.SchemaIssues = schemaIssues
+	if opts.ErrorsOnly {
+		result.hideWarnings()
+	}
 	result.MissingRefs = validator.MissingRefs()
 	result.UndefinedTraits = validator.UndefinedTraits()
 	result.ShortRefs = validator.ShortRefs()
-	sort.Slice(result.Issues, func(i, j int) bool {
-		a := result.Issues[i]
-		b := result.Issues[j]
-		if a.FilePath != b.FilePath {
-			return a.FilePath < b.FilePath
-		}
-		if a.Line != b.Line {
-			return a.Line < b.Line
-		}
+	sortIssues(result.Issues)
+	sort.Slice(result.SchemaIssues, func(i, j int) bool {
+		a := result.SchemaIssues[i]
+		b := result.SchemaIssues[j]
 		if a.Level != b.Level {
 			return a.Level.String() < b.Level.String()
 		}
@@ -320,9 +293,110 @@ func Run(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, opt
 		}
 		return a.Message < b.Message
 	})
-	sort.Slice(result.SchemaIssues, func(i, j int) bool {
-		a := result.SchemaIssues[i]
-		b := result.SchemaIssues[j]
+	return result, nil
+}
+
+// newValidator builds a check validator with the vault's daily-note and
+// directory settings applied.
+func newValidator(
+	sch *schema.Schema,
+	vaultCfg *config.VaultConfig,
+	objectInfos []check.ObjectInfo,
+	aliases map[string]string,
+	duplicateAliases []index.DuplicateAlias,
+	canonicalResolver *resolver.Resolver,
+) *check.Validator {
+	validator := check.NewValidatorWithTypesAliasesAndResolver(sch, objectInfos, aliases, canonicalResolver)
+	validator.SetDuplicateAliases(duplicateAliases)
+	validator.SetDailyDirectoryForInference(vaultCfg.GetDailyDirectory())
+	if vaultCfg.HasDirectoriesConfig() {
+		validator.SetDirectoryRoots(vaultCfg.GetObjectsRoot(), vaultCfg.GetPagesRoot())
+	}
+	if canonicalResolver == nil {
+		validator.SetDailyDirectory(vaultCfg.GetDailyDirectory())
+	}
+	return validator
+}
+
+// validateDocuments runs the per-file checks on docs and returns the issues
+// that are in scope and pass the issue filters.
+func validateDocuments(
+	validator *check.Validator,
+	docs []*parser.ParsedDocument,
+	sch *schema.Schema,
+	vaultCfg *config.VaultConfig,
+	scope *Scope,
+	includeIssues, excludeIssues map[check.IssueType]bool,
+) []check.Issue {
+	var issues []check.Issue
+	for _, doc := range docs {
+		for _, issue := range validator.ValidateDocument(doc) {
+			if !isIssueInScope(issue, doc, scope) {
+				continue
+			}
+			if !shouldIncludeIssue(issue, includeIssues, excludeIssues) {
+				continue
+			}
+			issues = append(issues, issue)
+		}
+	}
+
+	for _, issue := range detectNonCanonicalIssues(docs, sch, vaultCfg) {
+		doc := docByPath(docs, issue.FilePath)
+		if doc != nil && !isIssueInScope(issue, doc, scope) {
+			continue
+		}
+		if !shouldIncludeIssue(issue, includeIssues, excludeIssues) {
+			continue
+		}
+		issues = append(issues, issue)
+	}
+	return issues
+}
+
+func (r *RunResult) countIssue(issue check.Issue) {
+	if issue.Level == check.LevelWarning {
+		r.WarningCount++
+	} else {
+		r.ErrorCount++
+	}
+}
+
+// hideWarnings drops warnings from the result, keeping only their count so
+// callers can mention what --errors-only suppressed.
+func (r *RunResult) hideWarnings() {
+	issues := r.Issues[:0]
+	for _, issue := range r.Issues {
+		if issue.Level == check.LevelWarning {
+			r.HiddenWarningCount++
+			continue
+		}
+		issues = append(issues, issue)
+	}
+	r.Issues = issues
+
+	schemaIssues := r.SchemaIssues[:0]
+	for _, issue := range r.SchemaIssues {
+		if issue.Level == check.LevelWarning {
+			r.HiddenWarningCount++
+			continue
+		}
+		schemaIssues = append(schemaIssues, issue)
+	}
+	r.SchemaIssues = schemaIssues
+	r.WarningCount = 0
+}
+
+func sortIssues(issues []check.Issue) {
+	sort.Slice(issues, func(i, j int) bool {
+		a := issues[i]
+		b := issues[j]
+		if a.FilePath != b.FilePath {
+			return a.FilePath < b.FilePath
+		}
+		if a.Line != b.Line {
+			return a.Line < b.Line
+		}
 		if a.Level != b.Level {
 			return a.Level.String() < b.Level.String()
 		}
@@ -334,7 +408,6 @@ func Run(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, opt
 		}
 		return a.Message < b.Message
 	})
-	return result, nil
 }
 
 func BuildJSON(vaultPath string, result *RunResult) CheckResultJSON {
@@ -343,6 +416,7 @@ func BuildJSON(vaultPath string, result *RunResult) CheckResultJSON {
 		FileCount:  result.FileCount,
 		ErrorCount: result.ErrorCount,
 		WarnCount:  result.WarningCount,
+		HiddenWarn: result.HiddenWarningCount,
 		Issues:     make([]CheckIssueJSON, 0, len(result.Issues)+len(result.SchemaIssues)),
 	}
 	if result.Scope.Type != "" && result.Scope.Type != "full" {
@@ -617,10 +691,7 @@ func parseIssueFilter(opts Options) (include map[check.IssueType]bool, exclude m
 	return include, exclude
 }
 
-func shouldIncludeIssue(issue check.Issue, include, exclude map[check.IssueType]bool, errorsOnly bool) bool {
-	if errorsOnly && issue.Level == check.LevelWarning {
-		return false
-	}
+func shouldIncludeIssue(issue check.Issue, include, exclude map[check.IssueType]bool) bool {
 	if len(include) > 0 && !include[issue.Type] {
 		return false
 	}
@@ -630,10 +701,7 @@ func shouldIncludeIssue(issue check.Issue, include, exclude map[check.IssueType]
 	return true
 }
 
-func shouldIncludeSchemaIssue(issue check.SchemaIssue, include, exclude map[check.IssueType]bool, errorsOnly bool) bool {
-	if errorsOnly && issue.Level == check.LevelWarning {
-		return false
-	}
+func shouldIncludeSchemaIssue(issue check.SchemaIssue, include, exclude map[check.IssueType]bool) bool {
 	if len(include) > 0 && !include[issue.Type] {
 		return false
 	}
@@ -1,6 +1,7 @@
 package checksvc
 
 import (
+	"encoding/json"
 	"os"
 	"path/filepath"
 	"strings"
@@ -100,6 +101,97 @@ func TestBuildJSON_MissingReferenceSummarySuggestsCreateMissing(t *testing.T) {
 	}
 }
 
+func TestBuildJSON_ReportsErrorsAndWarningsWithLocations(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("note.md", "# Notes\n\n### Deep\n\nSee [paper](assets/pdfs/missing.pdf).\n").
+		WithFile("other.md", "# Other\n").
+		Build()
+	sch, err := schema.Load(vault.Path)
+	if err != nil {
+		t.Fatalf("load schema: %v", err)
+	}
+
+	result, err := Run(vault.Path, config.DefaultVaultConfig(), sch, Options{})
+	if err != nil {
+		t.Fatalf("Run returned error: %v", err)
+	}
+
+	encoded, err := json.Marshal(BuildJSON(vault.Path, result))
+	if err != nil {
+		t.Fatalf("marshal check result: %v", err)
+	}
+	var report struct {
+		FileCount  int `json:"file_count"`
+		ErrorCount int `json:"error_count"`
+		WarnCount  int `json:"warning_count"`
+		Issues     []struct {
+			Type     string `json:"type"`
+			Level    string `json:"level"`
+			FilePath string `json:"file_path"`
+			Line     int    `json:"line"`
+			Message  string `json:"message"`
+		} `json:"issues"`
+	}
+	if err := json.Unmarshal(encoded, &report); err != nil {
+		t.Fatalf("decode check result: %v\n%s", err, encoded)
+	}
+
+	if report.FileCount != 2 || report.ErrorCount != 1 || report.WarnCount != 1 {
+		t.Fatalf("counts = files %d, errors %d, warnings %d; want 2, 1, 1\n%s", report.FileCount, report.ErrorCount, report.WarnCount, encoded)
+	}
+	want := map[string]struct {
+		level string
+		line  int
+	}{
+		string(check.IssueMissingAsset):     {level: "ERROR", line: 5},
+		string(check.IssueHeadingLevelSkip): {level: "WARN", line: 3},
+	}
+	if len(report.Issues) != len(want) {
+		t.Fatalf("issues = %+v, want %d", report.Issues, len(want))
+	}
+	for _, issue := range report.Issues {
+		expected, ok := want[issue.Type]
+		if !ok {
+			t.Fatalf("unexpected issue %+v", issue)
+		}
+		if issue.Level != expected.level || issue.Line != expected.line || issue.FilePath != "note.md" || issue.Message == "" {
+			t.Errorf("issue %s = %+v, want level %s at note.md:%d with a message", issue.Type, issue, expected.level, expected.line)
+		}
+	}
+}
+
+func TestRun_ErrorsOnlyHidesWarningsButCountsThem(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("note.md", "# Notes\n\n### Deep\n\nSee [paper](assets/pdfs/missing.pdf).\n").
+		Build()
+	sch, err := schema.Load(vault.Path)
+	if err != nil {
+		t.Fatalf("load schema: %v", err)
+	}
+
+	result, err := Run(vault.Path, config.DefaultVaultConfig(), sch, Options{ErrorsOnly: true})
+	if err != nil {
+		t.Fatalf("Run returned error: %v", err)
+	}
+	if len(result.Issues) != 1 || result.Issues[0].Type != check.IssueMissingAsset {
+		t.Fatalf("issues = %#v, want only the missing_asset error", result.Issues)
+	}
+	if result.ErrorCount != 1 || result.WarningCount != 0 || result.HiddenWarningCount != 1 {
+		t.Fatalf("counts = errors %d, warnings %d, hidden %d; want 1, 0, 1", result.ErrorCount, result.WarningCount, result.HiddenWarningCount)
+	}
+
+	jsonResult := BuildJSON(vault.Path, result)
+	if jsonResult.HiddenWarn != 1 || jsonResult.WarnCount != 0 {
+		t.Fatalf("json warning_count = %d, hidden_warning_count = %d; want 0, 1", jsonResult.WarnCount, jsonResult.HiddenWarn)
+	}
+}
+
 func TestCreateMissingRefsNonInteractive_ReportsFailures(t *testing.T) {
 	t.Parallel()
 
@@ -193,6 +285,70 @@ func TestRun_ReportsOrphanedAsset(t *testing.T) {
 	}
 }
 
+func TestRun_ReportsDuplicateObjectIDsAcrossFiles(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("page/ideas.md", "# Ideas\n").
+		WithFile("ideas.md", "# More ideas\n").
+		WithFile("page/other.md", "# Other\n").
+		Build()
+
+	sch, err := schema.Load(vault.Path)
+	if err != nil {
+		t.Fatalf("load schema: %v", err)
+	}
+	cfg := &config.VaultConfig{Directories: &config.DirectoriesConfig{Page: "page/"}}
+
+	result, err := Run(vault.Path, cfg, sch, Options{Issues: string(check.IssueDuplicateID)})
+	if err != nil {
+		t.Fatalf("Run returned error: %v", err)
+	}
+
+	var files []string
+	for _, issue := range result.Issues {
+		if issue.Type != check.IssueDuplicateID {
+			continue
+		}
+		if issue.Level != check.LevelError || issue.Value != "ideas" {
+			t.Fatalf("unexpected duplicate issue: %#v", issue)
+		}
+		files = append(files, issue.FilePath)
+	}
+	if len(files) != 2 || files[0] != "ideas.md" || files[1] != "page/ideas.md" {
+		t.Fatalf("duplicate_object_id files = %v, want [ideas.md page/ideas.md]", files)
+	}
+	if result.ErrorCount != 2 {
+		t.Fatalf("error count = %d, want 2", result.ErrorCount)
+	}
+}
+
+func TestRun_DuplicateObjectIDsRespectTypeFilter(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("page/ideas.md", "# Ideas\n").
+		WithFile("ideas.md", "# More ideas\n").
+		WithFile("people/freya.md", "---\ntype: person\nname: Freya\n---\n").
+		Build()
+
+	sch, err := schema.Load(vault.Path)
+	if err != nil {
+		t.Fatalf("load schema: %v", err)
+	}
+	cfg := &config.VaultConfig{Directories: &config.DirectoriesConfig{Page: "page/"}}
+
+	result, err := Run(vault.Path, cfg, sch, Options{TypeFilter: "person"})
+	if err != nil {
+		t.Fatalf("Run returned error: %v", err)
+	}
+	if hasIssue(result.Issues, check.IssueDuplicateID) {
+		t.Fatalf("duplicate pages are outside the person scope: %#v", result.Issues)
+	}
+}
+
 func TestRun_IgnoresExcludedMarkdownAndAssetIssues(t *testing.T) {
 	t.Parallel()
 
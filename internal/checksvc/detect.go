@@ -10,6 +10,7 @@ import (
 	"github.com/aidanlsb/raven/internal/index"
 	"github.com/aidanlsb/raven/internal/parser"
 	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/vault"
 )
 
 // DetectMissingRefs returns the page-style missing references found in the given
@@ -57,10 +58,7 @@ func DetectMissingRefs(vaultPath string, vaultCfg *config.VaultConfig, sch *sche
 		validator.SetDirectoryRoots(vaultCfg.GetObjectsRoot(), vaultCfg.GetPagesRoot())
 	}
 
-	parseOpts := &parser.ParseOptions{
-		ObjectsRoot: vaultCfg.GetObjectsRoot(),
-		PagesRoot:   vaultCfg.GetPagesRoot(),
-	}
+	parseOpts := vault.ParseOptionsFromConfig(vaultCfg)
 
 	seen := make(map[string]struct{}, len(relPaths))
 	for _, relPath := range relPaths {
@@ -0,0 +1,68 @@
+package checksvc
+
+import (
+	"fmt"
+	"slices"
+	"sort"
+	"strings"
+
+	"github.com/aidanlsb/raven/internal/check"
+	"github.com/aidanlsb/raven/internal/parser"
+)
+
+// objectIDFiles maps object IDs to the vault-relative files that produce them.
+type objectIDFiles map[string][]string
+
+func (m objectIDFiles) add(doc *parser.ParsedDocument) {
+	if doc == nil {
+		return
+	}
+	for _, obj := range doc.Objects {
+		if obj == nil || obj.ID == "" {
+			continue
+		}
+		if slices.Contains(m[obj.ID], doc.FilePath) {
+			continue
+		}
+		m[obj.ID] = append(m[obj.ID], doc.FilePath)
+	}
+}
+
+// detectCrossFileDuplicateIDs reports objects whose ID is also produced by a
+// different file. This happens when directory roots are stripped from IDs, e.g.
+// "page/ideas.md" and "ideas.md" both map to "ideas". References to such IDs
+// cannot be resolved reliably, so each occurrence is an error.
+func detectCrossFileDuplicateIDs(docs []*parser.ParsedDocument, idFiles objectIDFiles) []check.Issue {
+	var issues []check.Issue
+	for _, doc := range docs {
+		if doc == nil {
+			continue
+		}
+		for _, obj := range doc.Objects {
+			if obj == nil {
+				continue
+			}
+			files := idFiles[obj.ID]
+			if len(files) < 2 {
+				continue
+			}
+			others := make([]string, 0, len(files)-1)
+			for _, file := range files {
+				if file != doc.FilePath {
+					others = append(others, file)
+				}
+			}
+			sort.Strings(others)
+			issues = append(issues, check.Issue{
+				Level:    check.LevelError,
+				Type:     check.IssueDuplicateID,
+				FilePath: doc.FilePath,
+				Line:     obj.LineStart,
+				Message:  fmt.Sprintf("Duplicate object ID '%s' (also produced by %s)", obj.ID, strings.Join(others, ", ")),
+				Value:    obj.ID,
+				FixHint:  "Rename or move one of the files so each object ID is unique",
+			})
+		}
+	}
+	return issues
+}
@@ -7,12 +7,16 @@ import (
 	"sort"
 	"strings"
 
+	"gopkg.in/yaml.v3"
+
 	"github.com/aidanlsb/raven/internal/check"
 	"github.com/aidanlsb/raven/internal/config"
+	"github.com/aidanlsb/raven/internal/frontmatter"
 	"github.com/aidanlsb/raven/internal/objectsvc"
 	"github.com/aidanlsb/raven/internal/parser"
 	"github.com/aidanlsb/raven/internal/paths"
 	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/vault"
 )
 
 type FixType string
@@ -21,6 +25,11 @@ const (
 	FixTypeWikilink FixType = "wikilink"
 	FixTypeTrait    FixType = "trait"
 	FixTypeMoveFile FixType = "move_file"
+	// FixTypeDefaultField writes a schema default for a missing required
+	// frontmatter field.
+	FixTypeDefaultField FixType = "default_field"
+	// FixTypeTraitName renames a trait annotation to the declared spelling.
+	FixTypeTraitName FixType = "trait_name"
 )
 
 type FixableIssue struct {
@@ -37,6 +46,9 @@ type FixableIssue struct {
 	NewFilePath    string
 	SourceObjectID string
 	DestObjectID   string
+
+	// Default-only field (FixType == FixTypeDefaultField).
+	FieldName string
 }
 
 type FixResult struct {
@@ -62,6 +74,9 @@ type FileFixes struct {
 // Only truly unambiguous fixes are included.
 func CollectFixableIssues(issues []check.Issue, shortRefMap map[string]string, sch *schema.Schema, vaultCfg *config.VaultConfig) []FixableIssue {
 	var fixable []FixableIssue
+	// A trait rename rewrites every matching annotation on its line, so one
+	// fix per (file, line, name) is enough.
+	traitRenames := make(map[string]struct{})
 
 	for _, issue := range issues {
 		switch issue.Type {
@@ -89,6 +104,19 @@ func CollectFixableIssues(issues []check.Issue, shortRefMap map[string]string, s
 			if fix := tryFixNonCanonicalPath(issue, vaultCfg); fix != nil {
 				fixable = append(fixable, *fix)
 			}
+		case check.IssueUndefinedTrait:
+			if fix := tryFixTraitNameCase(issue, sch); fix != nil {
+				key := fmt.Sprintf("%s:%d:%s", fix.FilePath, fix.Line, fix.OldValue)
+				if _, seen := traitRenames[key]; seen {
+					continue
+				}
+				traitRenames[key] = struct{}{}
+				fixable = append(fixable, *fix)
+			}
+		case check.IssueMissingRequiredField:
+			if fix := tryFixMissingDefault(issue); fix != nil {
+				fixable = append(fixable, *fix)
+			}
 		}
 	}
 
@@ -122,7 +150,8 @@ func GroupFixesByFile(fixes []FixableIssue) []FileFixes {
 }
 
 // ApplyFixes applies the given fixes to the vault. Text fixes (wikilink,
-// trait) are batched per file and replaced in place. File moves are applied
+// trait, trait name) are batched per file and replaced in place. Missing defaults are
+// written into each file's frontmatter. File moves are applied
 // one at a time via objectsvc.MoveFile with reference updates and a per-file
 // re-index. Failures are collected as Skipped entries and processing continues
 // past them; an error is returned only for unrecoverable I/O issues against
@@ -131,13 +160,17 @@ func ApplyFixes(vaultPath string, fixes []FixableIssue, vaultCfg *config.VaultCo
 	result := FixResult{}
 
 	textFixes := make([]FixableIssue, 0, len(fixes))
+	defaultFixes := make([]FixableIssue, 0)
 	moveFixes := make([]FixableIssue, 0)
 	for _, fix := range fixes {
-		if fix.FixType == FixTypeMoveFile {
+		switch fix.FixType {
+		case FixTypeMoveFile:
 			moveFixes = append(moveFixes, fix)
-			continue
+		case FixTypeDefaultField:
+			defaultFixes = append(defaultFixes, fix)
+		default:
+			textFixes = append(textFixes, fix)
 		}
-		textFixes = append(textFixes, fix)
 	}
 
 	textResult, err := applyTextFixes(vaultPath, textFixes)
@@ -148,6 +181,14 @@ func ApplyFixes(vaultPath string, fixes []FixableIssue, vaultCfg *config.VaultCo
 	result.IssueCount += textResult.IssueCount
 	result.Skipped = append(result.Skipped, textResult.Skipped...)
 
+	defaultResult, err := applyDefaultFieldFixes(vaultPath, sch, defaultFixes)
+	if err != nil {
+		return result, err
+	}
+	result.FileCount += defaultResult.FileCount
+	result.IssueCount += defaultResult.IssueCount
+	result.Skipped = append(result.Skipped, defaultResult.Skipped...)
+
 	moveResult := applyMoveFixes(vaultPath, vaultCfg, sch, moveFixes)
 	result.FileCount += moveResult.FileCount
 	result.IssueCount += moveResult.IssueCount
@@ -190,6 +231,17 @@ func applyTextFixes(vaultPath string, fixes []FixableIssue) (FixResult, error) {
 		})
 
 		for _, fix := range fileFixes {
+			if fix.FixType == FixTypeTraitName {
+				updated, ok := renameTraitOnLine(newContent, fix.Line, fix.OldValue, fix.NewValue)
+				if !ok {
+					result.Skipped = append(result.Skipped, skippedFix(fix, "expected content no longer present in file"))
+					continue
+				}
+				newContent = updated
+				fixedCount++
+				continue
+			}
+
 			var oldPattern, newPattern string
 			switch fix.FixType {
 			case FixTypeWikilink:
@@ -222,13 +274,130 @@ func applyTextFixes(vaultPath string, fixes []FixableIssue) (FixResult, error) {
 	return result, nil
 }
 
+// applyDefaultFieldFixes writes schema defaults into file frontmatter. The
+// default is resolved from the current schema at apply time, and each field is
+// added as its own line so existing key order and comments are kept.
+func applyDefaultFieldFixes(vaultPath string, sch *schema.Schema, fixes []FixableIssue) (FixResult, error) {
+	result := FixResult{}
+	if len(fixes) == 0 {
+		return result, nil
+	}
+
+	grouped := make(map[string][]FixableIssue)
+	for _, fix := range fixes {
+		grouped[fix.FilePath] = append(grouped[fix.FilePath], fix)
+	}
+
+	var files []string
+	for fp := range grouped {
+		files = append(files, fp)
+	}
+	sort.Strings(files)
+
+	for _, filePath := range files {
+		fileFixes := grouped[filePath]
+		fullPath := filepath.Join(vaultPath, filePath)
+
+		content, err := os.ReadFile(fullPath)
+		if err != nil {
+			return result, fmt.Errorf("failed to read %s: %w", filePath, err)
+		}
+
+		fm, err := parser.ParseFrontmatter(string(content))
+		if err != nil || fm == nil {
+			for _, fix := range fileFixes {
+				result.Skipped = append(result.Skipped, skippedFix(fix, "file has no readable frontmatter"))
+			}
+			continue
+		}
+		var typeDef *schema.TypeDefinition
+		if sch != nil {
+			typeDef = sch.Types[fm.ObjectType]
+		}
+
+		newContent := string(content)
+		fixedCount := 0
+		for _, fix := range fileFixes {
+			var fieldDef *schema.FieldDefinition
+			if typeDef != nil {
+				fieldDef = typeDef.Fields[fix.FieldName]
+			}
+			defaultValue, ok := fieldDef.DefaultValue()
+			if !ok {
+				result.Skipped = append(result.Skipped, skippedFix(fix, "schema no longer defines a default for this field"))
+				continue
+			}
+			if value, exists := fm.Fields[fix.FieldName]; exists && !value.IsNull() {
+				result.Skipped = append(result.Skipped, skippedFix(fix, "field is already set"))
+				continue
+			}
+			updated, err := setFrontmatterFieldLine(newContent, fix.FieldName, frontmatter.FieldValueToYAMLValue(defaultValue))
+			if err != nil {
+				result.Skipped = append(result.Skipped, skippedFix(fix, err.Error()))
+				continue
+			}
+			newContent = updated
+			fixedCount++
+		}
+
+		if fixedCount > 0 {
+			if err := os.WriteFile(fullPath, []byte(newContent), 0o644); err != nil {
+				return result, fmt.Errorf("failed to write %s: %w", filePath, err)
+			}
+			result.FileCount++
+			result.IssueCount += fixedCount
+		}
+	}
+
+	return result, nil
+}
+
+// setFrontmatterFieldLine writes key: value into the frontmatter block. An
+// existing empty or null entry for the key is replaced in place; otherwise the
+// field is appended just before the closing delimiter.
+func setFrontmatterFieldLine(content, key string, value interface{}) (string, error) {
+	lines := strings.Split(content, "\n")
+	_, endLine, ok := parser.FrontmatterBounds(lines)
+	if !ok || endLine == -1 {
+		return "", fmt.Errorf("no frontmatter found")
+	}
+
+	rendered, err := yaml.Marshal(map[string]interface{}{key: value})
+	if err != nil {
+		return "", fmt.Errorf("failed to render default: %w", err)
+	}
+	fieldLines := strings.Split(strings.TrimRight(string(rendered), "\n"), "\n")
+
+	insertAt := endLine
+	replace := 0
+	for i := 1; i < endLine; i++ {
+		rest, found := strings.CutPrefix(lines[i], key+":")
+		if !found {
+			continue
+		}
+		switch strings.TrimSpace(rest) {
+		case "", "null", "~":
+			insertAt, replace = i, 1
+		default:
+			return "", fmt.Errorf("field is already set")
+		}
+		break
+	}
+
+	out := make([]string, 0, len(lines)+len(fieldLines))
+	out = append(out, lines[:insertAt]...)
+	out = append(out, fieldLines...)
+	out = append(out, lines[insertAt+replace:]...)
+	return strings.Join(out, "\n"), nil
+}
+
 func applyMoveFixes(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, fixes []FixableIssue) FixResult {
 	result := FixResult{}
 	if len(fixes) == 0 {
 		return result
 	}
 
-	parseOpts := parserOptionsFor(vaultCfg)
+	parseOpts := vault.ParseOptionsFromConfig(vaultCfg)
 
 	sort.Slice(fixes, func(i, j int) bool {
 		return fixes[i].FilePath < fixes[j].FilePath
@@ -276,16 +445,6 @@ func applyMoveFixes(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.
 	return result
 }
 
-func parserOptionsFor(vaultCfg *config.VaultConfig) *parser.ParseOptions {
-	if vaultCfg == nil {
-		return &parser.ParseOptions{}
-	}
-	return &parser.ParseOptions{
-		ObjectsRoot: vaultCfg.GetObjectsRoot(),
-		PagesRoot:   vaultCfg.GetPagesRoot(),
-	}
-}
-
 // tryFixNonCanonicalRef builds a wikilink text fix that strips the configured
 // root prefix from a ref target. Returns nil if no configured root matches the
 // ref value (defensive — detection should have already filtered).
@@ -345,6 +504,79 @@ func tryFixNonCanonicalPath(issue check.Issue, vaultCfg *config.VaultConfig) *Fi
 	}
 }
 
+// tryFixMissingDefault plans writing the schema default for a required field
+// the file's frontmatter omits. Check only reports these as warnings when a
+// default exists; the value itself is resolved from the schema when applied.
+func tryFixMissingDefault(issue check.Issue) *FixableIssue {
+	if issue.Level != check.LevelWarning || issue.Value == "" || issue.Line != 1 {
+		return nil
+	}
+	return &FixableIssue{
+		FilePath:    issue.FilePath,
+		Line:        issue.Line,
+		IssueType:   issue.Type,
+		FixType:     FixTypeDefaultField,
+		FieldName:   issue.Value,
+		Description: fmt.Sprintf("set %s to its schema default", issue.Value),
+	}
+}
+
+// tryFixTraitNameCase plans renaming a trait annotation whose name matches a
+// declared trait in everything but letter case, e.g. @Todo -> @todo. Names
+// that match several declared traits are left for manual review, and variants
+// of an undeclared trait (@Productivity vs @productivity) are not fixable
+// because there is no declared spelling to pick.
+func tryFixTraitNameCase(issue check.Issue, sch *schema.Schema) *FixableIssue {
+	canonical, ok := sch.TraitCaseVariant(issue.Value)
+	if !ok || issue.Line < 1 {
+		return nil
+	}
+	return &FixableIssue{
+		FilePath:    issue.FilePath,
+		Line:        issue.Line,
+		IssueType:   issue.Type,
+		FixType:     FixTypeTraitName,
+		OldValue:    issue.Value,
+		NewValue:    canonical,
+		TraitName:   canonical,
+		Description: fmt.Sprintf("@%s -> @%s", issue.Value, canonical),
+	}
+}
+
+// renameTraitOnLine rewrites every @oldName annotation on the given 1-based
+// line to @newName. Only annotations the parser recognizes are renamed, so
+// longer names (@Todos), escaped annotations (\@Todo), inline code and text
+// such as x.@Todo are left alone.
+func renameTraitOnLine(content string, line int, oldName, newName string) (string, bool) {
+	lines := strings.Split(content, "\n")
+	if line < 1 || line > len(lines) {
+		return content, false
+	}
+
+	target := lines[line-1]
+	annotations := parser.ParseTraitAnnotations(target, line)
+	renamed := false
+	// Rewrite from the end of the line so earlier offsets stay valid.
+	for i := len(annotations) - 1; i >= 0; i-- {
+		annotation := annotations[i]
+		if annotation.TraitName != oldName {
+			continue
+		}
+		at := strings.IndexByte(target[annotation.StartOffset:annotation.EndOffset], '@')
+		if at == -1 {
+			continue
+		}
+		nameStart := annotation.StartOffset + at + 1
+		target = target[:nameStart] + newName + target[nameStart+len(oldName):]
+		renamed = true
+	}
+	if !renamed {
+		return content, false
+	}
+	lines[line-1] = target
+	return strings.Join(lines, "\n"), true
+}
+
 func splitMoveValue(value string) (source, dest string, ok bool) {
 	const sep = " -> "
 	idx := strings.Index(value, sep)
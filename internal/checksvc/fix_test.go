@@ -1,9 +1,11 @@
 package checksvc
 
 import (
+	"strings"
 	"testing"
 
 	"github.com/aidanlsb/raven/internal/check"
+	"github.com/aidanlsb/raven/internal/config"
 	"github.com/aidanlsb/raven/internal/schema"
 	"github.com/aidanlsb/raven/internal/testutil"
 )
@@ -67,3 +69,123 @@ func TestCollectFixableIssues_IgnoresNilTraitDefinition(t *testing.T) {
 		t.Fatalf("expected no fixes for nil trait definition, got %#v", fixes)
 	}
 }
+
+func TestApplyFixes_WritesMissingRequiredDefaults(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(`version: 1
+types:
+  task:
+    fields:
+      title:
+        type: string
+      priority:
+        type: enum
+        values: [low, medium, high]
+        required: true
+        default: medium
+`).
+		WithFile("tasks/ship.md", "---\ntype: task\n# imported from tracker\ntitle: Ship it\n---\nBody\n").
+		WithFile("tasks/blank.md", "---\ntype: task\npriority:\ntitle: Blank\n---\n").
+		WithFile("tasks/done.md", "---\ntype: task\npriority: high\n---\n").
+		Build()
+	sch, err := schema.Load(vault.Path)
+	if err != nil {
+		t.Fatalf("load schema: %v", err)
+	}
+	cfg := config.DefaultVaultConfig()
+
+	result, err := Run(vault.Path, cfg, sch, Options{})
+	if err != nil {
+		t.Fatalf("Run returned error: %v", err)
+	}
+	fixes := CollectFixableIssues(result.Issues, result.ShortRefs, sch, cfg)
+	if len(fixes) != 2 {
+		t.Fatalf("fixes = %#v, want 2 default fixes", fixes)
+	}
+	for _, fix := range fixes {
+		if fix.FixType != FixTypeDefaultField || fix.FieldName != "priority" {
+			t.Fatalf("unexpected fix %#v", fix)
+		}
+	}
+
+	applied, err := ApplyFixes(vault.Path, fixes, cfg, sch)
+	if err != nil {
+		t.Fatalf("ApplyFixes returned error: %v", err)
+	}
+	if applied.IssueCount != 2 || applied.FileCount != 2 || len(applied.Skipped) != 0 {
+		t.Fatalf("applied = %+v, want 2 fixes in 2 files", applied)
+	}
+	if got, want := vault.ReadFile("tasks/ship.md"), "---\ntype: task\n# imported from tracker\ntitle: Ship it\npriority: medium\n---\nBody\n"; got != want {
+		t.Fatalf("tasks/ship.md = %q, want %q", got, want)
+	}
+	if got, want := vault.ReadFile("tasks/blank.md"), "---\ntype: task\npriority: medium\ntitle: Blank\n---\n"; got != want {
+		t.Fatalf("tasks/blank.md = %q, want %q", got, want)
+	}
+
+	result, err = Run(vault.Path, cfg, sch, Options{})
+	if err != nil {
+		t.Fatalf("Run returned error: %v", err)
+	}
+	if hasIssue(result.Issues, check.IssueMissingRequiredField) {
+		t.Fatalf("issues after fix = %#v, want no missing_required_field", result.Issues)
+	}
+}
+
+func TestApplyFixes_RenamesTraitCaseVariants(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(`version: 1
+traits:
+  todo:
+    type: boolean
+  due:
+    type: date
+`).
+		WithFile("notes/inbox.md", "# Inbox\n\n- @Todo buy milk\n- @TODO call Freya @Due(2025-02-01)\n- @Todos is a different trait\n- \\@Todo stays escaped\n- @Todo twice @Todo\n- @Todo here, `@Todo` in code, x.@Todo in text\n").
+		Build()
+	sch, err := schema.Load(vault.Path)
+	if err != nil {
+		t.Fatalf("load schema: %v", err)
+	}
+	cfg := config.DefaultVaultConfig()
+
+	result, err := Run(vault.Path, cfg, sch, Options{})
+	if err != nil {
+		t.Fatalf("Run returned error: %v", err)
+	}
+	variants := 0
+	for _, issue := range result.Issues {
+		if issue.Type == check.IssueUndefinedTrait && strings.Contains(issue.Message, "schema declares") {
+			variants++
+		}
+	}
+	if variants != 6 {
+		t.Fatalf("issues = %#v, want 6 case-variant undefined_trait warnings", result.Issues)
+	}
+
+	// The two @Todo annotations on one line share a single fix.
+	fixes := CollectFixableIssues(result.Issues, result.ShortRefs, sch, cfg)
+	if len(fixes) != 5 {
+		t.Fatalf("fixes = %#v, want 5 trait name fixes", fixes)
+	}
+	for _, fix := range fixes {
+		if fix.FixType != FixTypeTraitName {
+			t.Fatalf("unexpected fix %#v", fix)
+		}
+	}
+
+	applied, err := ApplyFixes(vault.Path, fixes, cfg, sch)
+	if err != nil {
+		t.Fatalf("ApplyFixes returned error: %v", err)
+	}
+	if applied.IssueCount != 5 || applied.FileCount != 1 || len(applied.Skipped) != 0 {
+		t.Fatalf("applied = %+v, want 5 fixes in 1 file", applied)
+	}
+	want := "# Inbox\n\n- @todo buy milk\n- @todo call Freya @due(2025-02-01)\n- @Todos is a different trait\n- \\@Todo stays escaped\n- @todo twice @todo\n- @todo here, `@Todo` in code, x.@Todo in text\n"
+	if got := vault.ReadFile("notes/inbox.md"); got != want {
+		t.Fatalf("notes/inbox.md = %q, want %q", got, want)
+	}
+}
@@ -0,0 +1,163 @@
+package checksvc
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/aidanlsb/raven/internal/check"
+	"github.com/aidanlsb/raven/internal/config"
+	ravenignore "github.com/aidanlsb/raven/internal/ignore"
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/parser"
+	"github.com/aidanlsb/raven/internal/paths"
+	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/vault"
+)
+
+// FilePathError reports a ValidateFile target that cannot be validated: it
+// does not exist, or is not a managed markdown file inside the vault.
+type FilePathError struct {
+	Message  string
+	NotFound bool
+}
+
+func (e *FilePathError) Error() string {
+	return e.Message
+}
+
+// ValidateFile parses and validates a single markdown file against the schema.
+//
+// Unlike Run with a file scope, it does not walk the vault: the set of known
+// objects, aliases, and reference resolution come from the index, with the
+// file's own objects taken from the fresh parse. Cross-file checks such as
+// duplicate IDs and orphaned assets are left to Run.
+func ValidateFile(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, filePath string, opts Options) (*RunResult, error) {
+	absPath, relPath, err := resolveValidateFilePath(vaultPath, filePath, vaultCfg != nil && vaultCfg.FollowSymlinks)
+	if err != nil {
+		return nil, err
+	}
+
+	excludeMatcher, err := ravenignore.NewMatcher(vaultCfg.GetExcludePatterns())
+	if err != nil {
+		return nil, fmt.Errorf("invalid exclude config: %w", err)
+	}
+	if excludeMatcher.Match(relPath, false) {
+		return nil, &FilePathError{Message: fmt.Sprintf("'%s' is excluded from Raven by exclude patterns", relPath)}
+	}
+
+	includeIssues, excludeIssues := parseIssueFilter(opts)
+	scope := &Scope{Type: "file", Value: relPath, targetFiles: []string{absPath}}
+	result := &RunResult{
+		Scope:     Scope{Type: scope.Type, Value: scope.Value},
+		FileCount: 1,
+	}
+
+	content, err := os.ReadFile(absPath)
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", relPath, err)
+	}
+	doc, err := parser.ParseDocumentWithOptions(string(content), absPath, vaultPath, vault.ParseOptionsFromConfig(vaultCfg))
+	if err != nil {
+		parseError := check.Issue{
+			Level:    check.LevelError,
+			Type:     check.IssueParseError,
+			FilePath: relPath,
+			Line:     1,
+			Message:  err.Error(),
+			FixHint:  "Fix the YAML frontmatter or markdown syntax",
+		}
+		if shouldIncludeIssue(parseError, includeIssues, excludeIssues) {
+			result.Issues = []check.Issue{parseError}
+			result.ErrorCount++
+		}
+		return result, nil
+	}
+
+	db, err := index.Open(vaultPath)
+	if err != nil {
+		return nil, fmt.Errorf("failed to open index: %w (run 'rvn reindex' first)", err)
+	}
+	defer db.Close()
+
+	indexed, err := db.AllObjects()
+	if err != nil {
+		return nil, fmt.Errorf("failed to load indexed objects: %w", err)
+	}
+	objectInfos := make([]check.ObjectInfo, 0, len(indexed)+len(doc.Objects))
+	for _, obj := range indexed {
+		// The file's own objects come from the fresh parse below.
+		if obj.FilePath == relPath {
+			continue
+		}
+		objectInfos = append(objectInfos, check.ObjectInfo{ID: obj.ID, Type: obj.Type})
+	}
+	for _, obj := range doc.Objects {
+		objectInfos = append(objectInfos, check.ObjectInfo{ID: obj.ID, Type: obj.ObjectType})
+	}
+
+	aliases, err := db.AllAliases()
+	if err != nil {
+		return nil, fmt.Errorf("failed to load indexed aliases: %w", err)
+	}
+	duplicateAliases, err := db.FindDuplicateAliases()
+	if err != nil {
+		return nil, fmt.Errorf("failed to load duplicate aliases: %w", err)
+	}
+	canonicalResolver, err := db.Resolver(index.ResolverOptions{
+		DailyDirectory: vaultCfg.GetDailyDirectory(),
+		Schema:         sch,
+	})
+	if err != nil {
+		return nil, fmt.Errorf("failed to create resolver: %w", err)
+	}
+
+	validator := newValidator(sch, vaultCfg, objectInfos, aliases, duplicateAliases, canonicalResolver)
+	for _, issue := range validateDocuments(validator, []*parser.ParsedDocument{doc}, sch, vaultCfg, scope, includeIssues, excludeIssues) {
+		result.Issues = append(result.Issues, issue)
+		result.countIssue(issue)
+	}
+
+	if opts.ErrorsOnly {
+		result.hideWarnings()
+	}
+	result.MissingRefs = validator.MissingRefs()
+	result.UndefinedTraits = validator.UndefinedTraits()
+	result.ShortRefs = validator.ShortRefs()
+	sortIssues(result.Issues)
+	return result, nil
+}
+
+// resolveValidateFilePath accepts a vault-relative or absolute path to a
+// markdown file and returns its absolute and vault-relative forms.
+func resolveValidateFilePath(vaultPath, filePath string, followSymlinks bool) (string, string, error) {
+	filePath = strings.TrimSpace(filePath)
+	if filePath == "" {
+		return "", "", &FilePathError{Message: "file path is required"}
+	}
+
+	absPath := filePath
+	if !filepath.IsAbs(absPath) {
+		absPath = filepath.Join(vaultPath, filePath)
+	}
+	if !strings.HasSuffix(absPath, ".md") {
+		return "", "", &FilePathError{Message: fmt.Sprintf("'%s' is not a markdown file", filePath)}
+	}
+	if err := paths.ValidateNotePath(vaultPath, absPath, followSymlinks); err != nil {
+		return "", "", &FilePathError{Message: fmt.Sprintf("'%s' is not inside the vault", filePath)}
+	}
+	info, err := os.Stat(absPath)
+	if err != nil {
+		return "", "", &FilePathError{Message: fmt.Sprintf("file not found: %s", filePath), NotFound: true}
+	}
+	if info.IsDir() {
+		return "", "", &FilePathError{Message: fmt.Sprintf("'%s' is a directory; use 'rvn check' for directories", filePath)}
+	}
+
+	relPath, err := filepath.Rel(vaultPath, absPath)
+	if err != nil {
+		return "", "", &FilePathError{Message: fmt.Sprintf("'%s' is not inside the vault", filePath)}
+	}
+	return absPath, filepath.ToSlash(relPath), nil
+}
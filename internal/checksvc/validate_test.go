@@ -0,0 +1,107 @@
+package checksvc
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/check"
+	"github.com/aidanlsb/raven/internal/config"
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/parser"
+	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/testutil"
+)
+
+func TestValidateFile_ReportsIssuesForSingleFile(t *testing.T) {
+	t.Parallel()
+
+	freya := "---\ntype: person\nname: Freya\n---\n# Freya\n"
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("people/freya.md", freya).
+		WithFile("projects/website.md", "---\ntype: project\nstatus: someday\nowner: \"[[people/freya]]\"\n---\nSee [[people/loki]].\n").
+		WithFile("projects/other.md", "---\ntype: project\n---\nAlso broken, but not validated.\n").
+		Build()
+
+	sch, err := schema.Load(vault.Path)
+	if err != nil {
+		t.Fatalf("load schema: %v", err)
+	}
+
+	db, err := index.Open(vault.Path)
+	if err != nil {
+		t.Fatalf("open index: %v", err)
+	}
+	doc, err := parser.ParseDocument(freya, filepath.Join(vault.Path, "people", "freya.md"), vault.Path)
+	if err != nil {
+		t.Fatalf("parse freya: %v", err)
+	}
+	if err := db.IndexDocument(doc, sch); err != nil {
+		t.Fatalf("index freya: %v", err)
+	}
+	db.Close()
+
+	result, err := ValidateFile(vault.Path, config.DefaultVaultConfig(), sch, "projects/website.md", Options{})
+	if err != nil {
+		t.Fatalf("ValidateFile returned error: %v", err)
+	}
+
+	if result.FileCount != 1 || result.Scope.Type != "file" || result.Scope.Value != "projects/website.md" {
+		t.Fatalf("file count/scope = %d %+v, want 1 file scoped to projects/website.md", result.FileCount, result.Scope)
+	}
+	for _, want := range []check.IssueType{check.IssueMissingRequiredField, check.IssueInvalidEnumValue, check.IssueMissingReference} {
+		if !hasIssue(result.Issues, want) {
+			t.Errorf("issues = %#v, want %s", result.Issues, want)
+		}
+	}
+	for _, issue := range result.Issues {
+		if issue.FilePath != "projects/website.md" {
+			t.Errorf("issue from %s, want only projects/website.md: %#v", issue.FilePath, issue)
+		}
+		if issue.Type == check.IssueMissingReference && issue.Value != "people/loki" {
+			t.Errorf("missing reference = %q, want only people/loki", issue.Value)
+		}
+	}
+	if result.ErrorCount == 0 {
+		t.Fatalf("error count = 0, want errors")
+	}
+}
+
+func TestValidateFile_ParseErrorAndBadPaths(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("broken.md", "---\ntype: person\nname: [\n---\nbody\n").
+		WithFile("notes.txt", "plain text\n").
+		Build()
+	sch, err := schema.Load(vault.Path)
+	if err != nil {
+		t.Fatalf("load schema: %v", err)
+	}
+
+	result, err := ValidateFile(vault.Path, config.DefaultVaultConfig(), sch, "broken.md", Options{})
+	if err != nil {
+		t.Fatalf("ValidateFile returned error: %v", err)
+	}
+	if result.ErrorCount != 1 || !hasIssue(result.Issues, check.IssueParseError) {
+		t.Fatalf("result = %+v, want a single parse_error", result)
+	}
+
+	if err := os.MkdirAll(filepath.Join(vault.Path, "people"), 0o755); err != nil {
+		t.Fatalf("mkdir people: %v", err)
+	}
+	for path, wantNotFound := range map[string]bool{"missing.md": true, "notes.txt": false, "people": false, "../outside.md": false} {
+		_, err := ValidateFile(vault.Path, config.DefaultVaultConfig(), sch, path, Options{})
+		var pathErr *FilePathError
+		if !errors.As(err, &pathErr) {
+			t.Errorf("ValidateFile(%q) error = %v, want *FilePathError", path, err)
+			continue
+		}
+		if pathErr.NotFound != wantNotFound {
+			t.Errorf("ValidateFile(%q) NotFound = %v, want %v", path, pathErr.NotFound, wantNotFound)
+		}
+	}
+}
@@ -42,6 +42,15 @@ func prepareBacklinksArgs(cmd *cobra.Command, args []string) ([]string, bool, er
 }
 
 func buildBacklinksArgs(cmd *cobra.Command, args []string) (map[string]interface{}, error) {
+	argsMap := map[string]interface{}{}
+	if cmd.Flags().Changed("depth") {
+		depth, _ := cmd.Flags().GetInt("depth")
+		argsMap["depth"] = depth
+	}
+	if sourceType, _ := cmd.Flags().GetString("type"); sourceType != "" {
+		argsMap["type"] = sourceType
+	}
+
 	stdin, _ := cmd.Flags().GetBool("stdin")
 	if stdin {
 		targets, err := ReadReferencesFromStdin()
@@ -51,14 +60,12 @@ func buildBacklinksArgs(cmd *cobra.Command, args []string) (map[string]interface
 		if len(targets) == 0 {
 			return nil, fmt.Errorf("no targets provided on stdin")
 		}
-		return map[string]interface{}{
-			"stdin":   true,
-			"targets": targets,
-		}, nil
+		argsMap["stdin"] = true
+		argsMap["targets"] = targets
+		return argsMap, nil
 	}
-	return map[string]interface{}{
-		"target": args[0],
-	}, nil
+	argsMap["target"] = args[0]
+	return argsMap, nil
 }
 
 func handleBacklinksFailure(cmd *cobra.Command, result commandexec.Result) error {
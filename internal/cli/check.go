@@ -321,36 +321,45 @@ func renderCanonicalCheckValidate(result commandexec.Result) {
 	if checkByFile {
 		printIssuesByFileFromJSON(decoded.Issues)
 		fmt.Println()
-		if decoded.ErrorCount == 0 && decoded.WarnCount == 0 {
-			fmt.Println(ui.Starf("No issues found in %d files.", decoded.FileCount))
-		} else {
-			fmt.Printf("Found %d error(s), %d warning(s) in %d files.\n", decoded.ErrorCount, decoded.WarnCount, decoded.FileCount)
-		}
+		printCheckTotals(decoded)
 		return
 	}
 
 	if checkVerbose {
 		printIssuesVerboseFromJSON(decoded.Issues)
 		fmt.Println()
-		if decoded.ErrorCount == 0 && decoded.WarnCount == 0 {
-			fmt.Println(ui.Starf("No issues found in %d files.", decoded.FileCount))
-		} else {
-			fmt.Printf("Found %d error(s), %d warning(s) in %d files.\n", decoded.ErrorCount, decoded.WarnCount, decoded.FileCount)
-		}
+		printCheckTotals(decoded)
 		return
 	}
 
 	fmt.Println()
 	if decoded.ErrorCount == 0 && decoded.WarnCount == 0 {
-		fmt.Println(ui.Starf("No issues found in %d files.", decoded.FileCount))
+		printCheckTotals(decoded)
 		return
 	}
 	printIssueSummaryFromJSON(decoded.Summary, decoded.Issues)
 	fmt.Println()
-	fmt.Printf("Found %d error(s), %d warning(s) in %d files.\n", decoded.ErrorCount, decoded.WarnCount, decoded.FileCount)
+	printCheckTotals(decoded)
 	fmt.Println(ui.Hint("Use --verbose to see all issues, or --by-file to group by file."))
 }
 
+// printCheckTotals prints the closing error/warning tally, noting warnings
+// that --errors-only left out.
+func printCheckTotals(decoded CheckResultJSON) {
+	hidden := ""
+	if decoded.HiddenWarn > 0 {
+		hidden = fmt.Sprintf(" (%d warning(s) hidden by --errors-only)", decoded.HiddenWarn)
+	}
+	switch {
+	case decoded.ErrorCount > 0 || decoded.WarnCount > 0:
+		fmt.Printf("Found %d error(s), %d warning(s) in %d files.%s\n", decoded.ErrorCount, decoded.WarnCount, decoded.FileCount, hidden)
+	case decoded.HiddenWarn > 0:
+		fmt.Println(ui.Starf("No errors found in %d files.%s", decoded.FileCount, hidden))
+	default:
+		fmt.Println(ui.Starf("No issues found in %d files.", decoded.FileCount))
+	}
+}
+
 func renderCanonicalCheckFix(result commandexec.Result) {
 	data := canonicalDataMap(result)
 	fixableIssues := intValue(data["fixable_issues"])
@@ -8,6 +8,7 @@ import (
 	"testing"
 
 	"github.com/aidanlsb/raven/internal/check"
+	"github.com/aidanlsb/raven/internal/commandexec"
 )
 
 type fakeCheckInteraction struct {
@@ -99,6 +100,40 @@ func TestCheckJSONUsesStandardEnvelope(t *testing.T) {
 	}
 }
 
+func TestCheckErrorsOnlyTotalsAndExit(t *testing.T) {
+	prevStrict := checkStrict
+	t.Cleanup(func() { checkStrict = prevStrict })
+
+	hidden := commandexec.Success(map[string]interface{}{
+		"file_count":           3,
+		"error_count":          1,
+		"warning_count":        0,
+		"hidden_warning_count": 2,
+	}, nil)
+	decoded, ok := decodeCanonicalCheckJSON(hidden)
+	if !ok {
+		t.Fatal("failed to decode check result")
+	}
+	out := captureStdout(t, func() { printCheckTotals(decoded) })
+	if !strings.Contains(out, "Found 1 error(s), 0 warning(s) in 3 files. (2 warning(s) hidden by --errors-only)") {
+		t.Fatalf("totals = %q, want errors shown and hidden warnings noted", out)
+	}
+
+	checkStrict = true
+	if !checkShouldExit(hidden) {
+		t.Fatal("expected errors to fail the check")
+	}
+	onlyHidden := commandexec.Success(map[string]interface{}{
+		"file_count":           3,
+		"error_count":          0,
+		"warning_count":        0,
+		"hidden_warning_count": 2,
+	}, nil)
+	if checkShouldExit(onlyHidden) {
+		t.Fatal("hidden warnings should not fail the check, even with --strict")
+	}
+}
+
 func TestPromptTraitTypeAcceptsNumber(t *testing.T) {
 	trait := &check.UndefinedTrait{
 		TraitName: "estimate",
@@ -69,6 +69,9 @@ func renderConfigShow(_ *cobra.Command, result commandexec.Result) error {
 	if v := strings.TrimSpace(stringValue(data["editor_mode"])); v != "" {
 		fmt.Printf("%s %s\n", ui.Hint("editor_mode:"), v)
 	}
+	if v := strings.TrimSpace(stringValue(data["editor_profile"])); v != "" {
+		fmt.Printf("%s %s\n", ui.Hint("editor_profile:"), v)
+	}
 	uiConfig, _ := data["ui"].(map[string]interface{})
 	if v := strings.TrimSpace(stringValue(uiConfig["accent"])); v != "" {
 		fmt.Printf("ui.accent: %s\n", v)
@@ -14,6 +14,7 @@ import (
 func resetConfigSetFlagsForTest() {
 	resetStringFlag(configSetCmd, "editor")
 	resetStringFlag(configSetCmd, "editor-mode")
+	resetStringFlag(configSetCmd, "editor-profile")
 	resetStringFlag(configSetCmd, "state-file")
 	resetStringFlag(configSetCmd, "default-vault")
 	resetStringFlag(configSetCmd, "ui-accent")
@@ -24,6 +25,7 @@ func resetConfigSetFlagsForTest() {
 func resetConfigUnsetFlagsForTest() {
 	resetBoolFlag(configUnsetCmd, "editor")
 	resetBoolFlag(configUnsetCmd, "editor-mode")
+	resetBoolFlag(configUnsetCmd, "editor-profile")
 	resetBoolFlag(configUnsetCmd, "state-file")
 	resetBoolFlag(configUnsetCmd, "default-vault")
 	resetBoolFlag(configUnsetCmd, "ui-accent")
@@ -111,6 +113,9 @@ work = "/vault/work"
 	if err := configSetCmd.Flags().Set("editor-mode", "terminal"); err != nil {
 		t.Fatalf("set editor-mode: %v", err)
 	}
+	if err := configSetCmd.Flags().Set("editor-profile", "Vim"); err != nil {
+		t.Fatalf("set editor-profile: %v", err)
+	}
 	if err := configSetCmd.Flags().Set("default-vault", "work"); err != nil {
 		t.Fatalf("set default-vault: %v", err)
 	}
@@ -138,6 +143,9 @@ work = "/vault/work"
 	if cfg.EditorMode != "terminal" {
 		t.Fatalf("expected editor_mode=terminal, got %q", cfg.EditorMode)
 	}
+	if cfg.EditorProfile != "vim" {
+		t.Fatalf("expected editor_profile=vim, got %q", cfg.EditorProfile)
+	}
 	if cfg.DefaultVault != "work" {
 		t.Fatalf("expected default_vault=work, got %q", cfg.DefaultVault)
 	}
@@ -159,6 +167,7 @@ func TestConfigUnsetClearsFields(t *testing.T) {
 	content := `default_vault = "work"
 editor = "code"
 editor_mode = "gui"
+editor_profile = "vscode"
 
 [vaults]
 work = "/vault/work"
@@ -193,6 +202,9 @@ markdown_style = "dark"
 	if err := configUnsetCmd.Flags().Set("editor-mode", "true"); err != nil {
 		t.Fatalf("set editor-mode: %v", err)
 	}
+	if err := configUnsetCmd.Flags().Set("editor-profile", "true"); err != nil {
+		t.Fatalf("set editor-profile: %v", err)
+	}
 	if err := configUnsetCmd.Flags().Set("default-vault", "true"); err != nil {
 		t.Fatalf("set default-vault: %v", err)
 	}
@@ -220,6 +232,9 @@ markdown_style = "dark"
 	if cfg.EditorMode != "" {
 		t.Fatalf("expected editor_mode to be cleared, got %q", cfg.EditorMode)
 	}
+	if cfg.EditorProfile != "" {
+		t.Fatalf("expected editor_profile to be cleared, got %q", cfg.EditorProfile)
+	}
 	if cfg.DefaultVault != "" {
 		t.Fatalf("expected default_vault to be cleared, got %q", cfg.DefaultVault)
 	}
@@ -51,13 +51,22 @@ func handleDailyResult(cmd *cobra.Command, result commandexec.Result) error {
 		return nil
 	}
 
+	if noEdit, _ := cmd.Flags().GetBool("no-edit"); noEdit {
+		if !created {
+			fmt.Println(ui.Bullet(ui.FilePath(relativePath)))
+		}
+		return nil
+	}
+
 	openFileInEditor(filePath, relativePath, created)
 	return nil
 }
 
 func init() {
-	// `--edit` is a CLI-only affordance and is intentionally excluded from the
-	// shared canonical command contract exposed to MCP callers.
+	// `--edit` and `--no-edit` are CLI-only affordances and are intentionally
+	// excluded from the shared canonical command contract exposed to MCP callers.
 	dailyCmd.Flags().BoolP("edit", "e", false, "Open the note in the configured editor (CLI only)")
+	dailyCmd.Flags().Bool("no-edit", false, "Create the note without opening an editor (CLI only)")
+	dailyCmd.MarkFlagsMutuallyExclusive("edit", "no-edit")
 	rootCmd.AddCommand(dailyCmd)
 }
@@ -231,6 +231,50 @@ func TestDailyHumanModeOpensEditorByDefault(t *testing.T) {
 	}
 }
 
+func TestDailyHumanModeNoEditSkipsEditor(t *testing.T) {
+	vaultPath := t.TempDir()
+	const date = "2026-02-17"
+	absPath := filepath.Join(vaultPath, "daily", date+".md")
+
+	markerPath := filepath.Join(vaultPath, "editor-called.marker")
+	editorPath := writeFakeEditor(t, vaultPath, markerPath)
+
+	prevVault := resolvedVaultPath
+	prevJSON := jsonOutput
+	prevCfg := cfg
+	t.Cleanup(func() {
+		resolvedVaultPath = prevVault
+		jsonOutput = prevJSON
+		cfg = prevCfg
+		if err := dailyCmd.Flags().Set("no-edit", "false"); err != nil {
+			t.Fatalf("reset daily --no-edit: %v", err)
+		}
+	})
+
+	resolvedVaultPath = vaultPath
+	jsonOutput = false
+	cfg = &config.Config{
+		Editor:     editorPath,
+		EditorMode: "terminal",
+	}
+	if err := dailyCmd.Flags().Set("no-edit", "true"); err != nil {
+		t.Fatalf("set daily --no-edit: %v", err)
+	}
+
+	captureStdout(t, func() {
+		if err := dailyCmd.RunE(dailyCmd, []string{date}); err != nil {
+			t.Fatalf("dailyCmd.RunE: %v", err)
+		}
+	})
+
+	if _, err := os.Stat(absPath); err != nil {
+		t.Fatalf("daily note does not exist at %s: %v", absPath, err)
+	}
+	if _, err := os.Stat(markerPath); !os.IsNotExist(err) {
+		t.Fatalf("expected editor not to be invoked with --no-edit, stat err=%v", err)
+	}
+}
+
 func writeFakeEditor(t *testing.T, dir, markerPath string) string {
 	t.Helper()
 
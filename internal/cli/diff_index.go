@@ -0,0 +1,50 @@
+package cli
+
+import (
+	"fmt"
+
+	"github.com/spf13/cobra"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/ui"
+)
+
+var diffIndexCmd = newCanonicalLeafCommand("diff_index", canonicalLeafOptions{
+	VaultPath:   getVaultPath,
+	RenderHuman: renderDiffIndex,
+})
+
+func renderDiffIndex(_ *cobra.Command, result commandexec.Result) error {
+	data := canonicalDataMap(result)
+	newFiles := stringSliceFromAny(data["new"])
+	modified := stringSliceFromAny(data["modified"])
+	deleted := stringSliceFromAny(data["deleted"])
+
+	if len(newFiles)+len(modified)+len(deleted) == 0 {
+		fmt.Println(ui.Check("Index is up to date"))
+		return nil
+	}
+	if indexed, _ := data["indexed"].(bool); !indexed {
+		fmt.Println(ui.Warning("Vault has not been indexed yet"))
+	}
+
+	printDiffIndexSection("New", newFiles)
+	printDiffIndexSection("Modified", modified)
+	printDiffIndexSection("Deleted", deleted)
+	fmt.Printf("\n%s\n", ui.Hint("Run 'rvn reindex' to update the index"))
+	return nil
+}
+
+func printDiffIndexSection(title string, files []string) {
+	if len(files) == 0 {
+		return
+	}
+	fmt.Println(ui.SectionHeader(fmt.Sprintf("%s (%d)", title, len(files))))
+	for _, file := range files {
+		fmt.Println(ui.Bullet(ui.FilePath(file)))
+	}
+}
+
+func init() {
+	rootCmd.AddCommand(diffIndexCmd)
+}
@@ -0,0 +1,41 @@
+package cli
+
+import (
+	"encoding/json"
+	"fmt"
+
+	"github.com/spf13/cobra"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/ui"
+)
+
+var exportCmd = newCanonicalLeafCommand("export", canonicalLeafOptions{
+	VaultPath:   getVaultPath,
+	HandleError: handleCanonicalMaintSvcFailure,
+	RenderHuman: renderExport,
+})
+
+func renderExport(_ *cobra.Command, result commandexec.Result) error {
+	data := canonicalDataMap(result)
+	if out := stringValue(data["out"]); out != "" {
+		fmt.Println(ui.Checkf("Exported index to %s", ui.FilePath(out)))
+		fmt.Println(ui.Bullet(ui.Muted.Render("Objects: ") + ui.Bold.Render(fmt.Sprintf("%v", data["object_count"]))))
+		fmt.Println(ui.Bullet(ui.Muted.Render("Traits: ") + ui.Bold.Render(fmt.Sprintf("%v", data["trait_count"]))))
+		fmt.Println(ui.Bullet(ui.Muted.Render("References: ") + ui.Bold.Render(fmt.Sprintf("%v", data["ref_count"]))))
+		fmt.Println(ui.Bullet(ui.Muted.Render("Assets: ") + ui.Bold.Render(fmt.Sprintf("%v", data["asset_count"]))))
+		return nil
+	}
+
+	// Plain JSON (no envelope) so `rvn export > vault.json` is directly usable.
+	encoded, err := json.MarshalIndent(data["export"], "", "  ")
+	if err != nil {
+		return handleError(ErrInternal, err, "")
+	}
+	fmt.Println(string(encoded))
+	return nil
+}
+
+func init() {
+	rootCmd.AddCommand(exportCmd)
+}
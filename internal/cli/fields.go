@@ -0,0 +1,42 @@
+package cli
+
+import (
+	"fmt"
+
+	"github.com/spf13/cobra"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/schemasvc"
+	"github.com/aidanlsb/raven/internal/ui"
+)
+
+var fieldsCmd = newCanonicalLeafCommand("fields", canonicalLeafOptions{
+	VaultPath:   getVaultPath,
+	RenderHuman: renderFields,
+})
+
+func renderFields(_ *cobra.Command, result commandexec.Result) error {
+	data := canonicalDataMap(result)
+	var fields []schemasvc.ObservedField
+	_ = decodeResultData(data["fields"], &fields)
+
+	typeName := stringValue(data["type"])
+	if len(fields) == 0 {
+		fmt.Println(ui.Star(fmt.Sprintf("No fields set on %s objects", typeName)))
+		return nil
+	}
+
+	fmt.Println(ui.SectionHeader(fmt.Sprintf("Fields on %s (%d)", typeName, len(fields))))
+	for _, field := range fields {
+		line := fmt.Sprintf("%s %s", ui.Bold.Render(field.Name), ui.Hint(fmt.Sprintf("(%d)", field.Count)))
+		if !field.InSchema {
+			line += " " + ui.Muted.Render("not in schema")
+		}
+		fmt.Println(ui.Bullet(line))
+	}
+	return nil
+}
+
+func init() {
+	rootCmd.AddCommand(fieldsCmd)
+}
@@ -8,6 +8,7 @@ import (
 	"os"
 	"os/exec"
 	"path/filepath"
+	"regexp"
 	"strconv"
 	"strings"
 	"testing"
@@ -1655,6 +1656,11 @@ See [[nonexistent/page]] for details.
 	if !strings.Contains(result.RawJSON, "missing_reference") {
 		t.Errorf("expected check output to include 'missing_reference' issue\nRaw: %s", result.RawJSON)
 	}
+
+	// Dangling references are errors, so check fails without --strict.
+	if result.ExitCode == 0 {
+		t.Errorf("expected non-zero exit code for dangling reference\nRaw: %s", result.RawJSON)
+	}
 }
 
 func TestIntegration_CheckFixSubcommandAppliesShortRefFixes(t *testing.T) {
@@ -2392,6 +2398,37 @@ type: page
 	v.AssertFileContains("inbox.md", "New task for today")
 }
 
+func TestIntegration_AddTimestampedCapturesUnderHeading(t *testing.T) {
+	t.Parallel()
+	v := testutil.NewTestVault(t).
+		WithSchema(testutil.MinimalSchema()).
+		WithRavenYAML("capture:\n  destination: inbox.md\n  heading: \"## Log\"\n  timestamp: true\n").
+		WithFile("inbox.md", `---
+type: page
+---
+# Inbox
+`).
+		Build()
+
+	v.RunCLI("add", "first thought").MustSucceed(t)
+	v.RunCLI("add", "second thought").MustSucceed(t)
+
+	content := v.ReadFile("inbox.md")
+	if got := strings.Count(content, "## Log"); got != 1 {
+		t.Fatalf("expected one capture heading, got %d:\n%s", got, content)
+	}
+	bullets := regexp.MustCompile(`(?m)^- \d{2}:\d{2} (first|second) thought$`).FindAllString(content, -1)
+	if len(bullets) != 2 {
+		t.Fatalf("expected two timestamped bullets, got %v:\n%s", bullets, content)
+	}
+	if strings.Index(content, "## Log") > strings.Index(content, bullets[0]) {
+		t.Fatalf("expected bullets under the capture heading:\n%s", content)
+	}
+	if !strings.HasSuffix(bullets[0], "first thought") || !strings.HasSuffix(bullets[1], "second thought") {
+		t.Fatalf("expected captures in write order, got %v", bullets)
+	}
+}
+
 func TestIntegration_AddToSectionBySlug(t *testing.T) {
 	t.Parallel()
 	v := testutil.NewTestVault(t).
@@ -2771,6 +2808,9 @@ type: person
 name: Freya
 ---
 # Freya
+
+## Notes
+- @due(2026-01-05) Call Odin
 `).
 		WithFile("people/thor.md", `---
 type: person
@@ -2778,6 +2818,7 @@ name: Thor
 ---
 # Thor
 `).
+		WithFile("assets/pdfs/paper.pdf", "%PDF-1.7\nhello").
 		Build()
 
 	v.RunCLI("reindex").MustSucceed(t)
@@ -2832,6 +2873,155 @@ name: Thor
 			t.Errorf("expected object_id 'people/freya', got %q", result.DataString("object_id"))
 		}
 	})
+	t.Run("resolve includes object fields", func(t *testing.T) {
+		result := v.RunCLI("resolve", "freya", "--body")
+		result.MustSucceed(t)
+
+		fields, ok := result.Data["fields"].(map[string]interface{})
+		if !ok {
+			t.Fatalf("expected fields map in resolve result, got %#v", result.Data["fields"])
+		}
+		if fields["name"] != "Freya" {
+			t.Errorf("expected fields.name 'Freya', got %#v", fields["name"])
+		}
+		if result.Data["line_start"] != float64(1) {
+			t.Errorf("expected line_start 1, got %#v", result.Data["line_start"])
+		}
+		if _, ok := result.Data["parent"]; ok {
+			t.Errorf("expected no parent for a file object, got %#v", result.Data["parent"])
+		}
+		if body := result.DataString("body"); !strings.Contains(body, "## Notes") || strings.Contains(body, "name: Freya") {
+			t.Errorf("expected body without frontmatter, got %q", body)
+		}
+	})
+
+	t.Run("resolve embedded section includes parent, traits, and body", func(t *testing.T) {
+		result := v.RunCLI("resolve", "people/freya#notes", "--body")
+		result.MustSucceed(t)
+
+		if result.Data["resolved"] != true {
+			t.Fatalf("expected resolved=true, got %#v", result.Data)
+		}
+		if result.DataString("parent") != "people/freya#freya" {
+			t.Errorf("expected enclosing section as parent, got %q", result.DataString("parent"))
+		}
+		traits := result.DataList("traits")
+		if len(traits) != 1 {
+			t.Fatalf("expected one trait, got %#v", traits)
+		}
+		if trait, _ := traits[0].(map[string]interface{}); trait["trait_type"] != "due" || trait["value"] != "2026-01-05" {
+			t.Errorf("expected @due(2026-01-05), got %#v", traits[0])
+		}
+		if body := result.DataString("body"); body != "## Notes\n- @due(2026-01-05) Call Odin\n" {
+			t.Errorf("expected section body, got %q", body)
+		}
+	})
+
+	t.Run("resolve omits body unless requested", func(t *testing.T) {
+		result := v.RunCLI("resolve", "freya")
+		result.MustSucceed(t)
+
+		if _, ok := result.Data["body"]; ok {
+			t.Errorf("expected no body without --body, got %#v", result.Data["body"])
+		}
+	})
+
+	t.Run("resolve asset never includes body", func(t *testing.T) {
+		result := v.RunCLI("resolve", "assets/pdfs/paper.pdf", "--body")
+		result.MustSucceed(t)
+
+		if result.Data["resolved"] != true {
+			t.Fatalf("expected asset to resolve, got %#v", result.Data)
+		}
+		if _, ok := result.Data["body"]; ok {
+			t.Errorf("expected no body for an asset, got %#v", result.Data["body"])
+		}
+	})
+
+	t.Run("resolve missing embedded id", func(t *testing.T) {
+		result := v.RunCLI("resolve", "people/freya#missing")
+		result.MustSucceed(t)
+
+		if result.Data["resolved"] != false {
+			t.Fatalf("expected resolved=false for a missing embedded id, got %#v", result.Data)
+		}
+		for _, key := range []string{"object_id", "fields", "traits", "body"} {
+			if _, ok := result.Data[key]; ok {
+				t.Errorf("expected no %s for a missing id, got %#v", key, result.Data[key])
+			}
+		}
+	})
+}
+
+func TestIntegration_ResolveAmbiguousWikilinkListsCandidates(t *testing.T) {
+	t.Parallel()
+	v := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("people/alice.md", `---
+type: person
+name: Alice
+---
+`).
+		WithFile("projects/alice.md", `---
+type: project
+---
+`).
+		Build()
+
+	v.RunCLI("reindex").MustSucceed(t)
+
+	result := v.RunCLI("resolve", "[[alice]]")
+	result.MustSucceed(t)
+
+	if result.Data["ambiguous"] != true {
+		t.Fatalf("expected ambiguous=true, got %#v", result.Data["ambiguous"])
+	}
+	if result.DataString("reference") != "[[alice]]" {
+		t.Errorf("expected reference to echo input, got %q", result.DataString("reference"))
+	}
+
+	sources := map[string]string{}
+	for _, raw := range result.DataList("matches") {
+		match, ok := raw.(map[string]interface{})
+		if !ok {
+			t.Fatalf("unexpected match entry: %#v", raw)
+		}
+		id, _ := match["object_id"].(string)
+		source, _ := match["match_source"].(string)
+		sources[id] = source
+	}
+	for _, want := range []string{"people/alice", "projects/alice"} {
+		if _, ok := sources[want]; !ok {
+			t.Errorf("expected candidate %q in matches, got %#v", want, sources)
+		}
+	}
+	if sources["projects/alice"] != "short_name" {
+		t.Errorf("expected projects/alice via short_name, got %q", sources["projects/alice"])
+	}
+	if _, ok := result.Data["trace"]; ok {
+		t.Errorf("expected no trace without --explain, got %#v", result.Data["trace"])
+	}
+
+	explained := v.RunCLI("resolve", "[[alice]]", "--explain")
+	explained.MustSucceed(t)
+
+	var shortNameStep map[string]interface{}
+	for _, raw := range explained.DataList("trace") {
+		step, _ := raw.(map[string]interface{})
+		if step["rule"] == "short_name" {
+			shortNameStep = step
+		}
+	}
+	if shortNameStep == nil {
+		t.Fatalf("expected a short_name step in the trace, got %#v", explained.Data["trace"])
+	}
+	candidates := map[string]bool{}
+	for _, raw := range shortNameStep["candidates"].([]interface{}) {
+		candidates[raw.(string)] = true
+	}
+	if len(candidates) != 2 || !candidates["people/alice"] || !candidates["projects/alice"] {
+		t.Errorf("expected short_name step to list both candidates, got %#v", shortNameStep["candidates"])
+	}
 }
 
 // TestIntegration_SchemaTemplateLifecycle tests schema template lifecycle commands.
@@ -23,6 +23,8 @@ var (
 	newFieldJSON  string
 	newPathFlag   string
 	newTemplate   string
+	newUnique     bool
+	newNoEdit     bool
 )
 
 var newCmd = newCanonicalLeafCommand("new", canonicalLeafOptions{
@@ -80,7 +82,11 @@ func buildNewArgs(_ *cobra.Command, args []string) (map[string]interface{}, erro
 			return nil, handleErrorMsg(ErrInvalidInput, err.Error(), "Use --path with an explicit object path like note/raven-friction")
 		}
 	}
-	return buildNewCommandArgs(typeName, title, targetPath, newTemplate, fieldValues, fieldJSONRaw), nil
+	commandArgs := buildNewCommandArgs(typeName, title, targetPath, newTemplate, fieldValues, fieldJSONRaw)
+	if newUnique {
+		commandArgs["unique"] = true
+	}
+	return commandArgs, nil
 }
 
 func invokeNew(_ *cobra.Command, commandID, vaultPath string, args map[string]interface{}) commandexec.Result {
@@ -186,6 +192,9 @@ func renderNewResult(_ *cobra.Command, result commandexec.Result) error {
 	relativePath, _ := data["file"].(string)
 	fmt.Println(ui.Checkf("Created %s", ui.FilePath(relativePath)))
 	promptCreateMissingRefsFromResult(getVaultPath(), result)
+	if newNoEdit {
+		return nil
+	}
 	vault.OpenInEditorOrPrintPath(getConfig(), filepath.Join(getVaultPath(), filepath.FromSlash(relativePath)))
 	return nil
 }
@@ -304,6 +313,9 @@ func init() {
 	newCmd.Flags().StringVar(&newFieldJSON, "field-json", "", "Set frontmatter fields via JSON object (typed values)")
 	newCmd.Flags().StringVar(&newPathFlag, "path", "", "Explicit target path (overrides title-derived path)")
 	newCmd.Flags().StringVar(&newTemplate, "template", "", "Type template ID to use for object creation")
+	// `--no-edit` is CLI-only; JSON/MCP callers never open an editor.
+	newCmd.Flags().BoolVar(&newNoEdit, "no-edit", false, "Create the object without opening an editor (CLI only)")
+	newCmd.Flags().BoolVar(&newUnique, "unique", false, "If the target file exists, create a -2, -3, ... suffixed file instead of failing")
 	newCmd.ValidArgsFunction = completeTypes
 	rootCmd.AddCommand(newCmd)
 }
@@ -10,6 +10,8 @@ import (
 	"strings"
 	"sync"
 	"testing"
+
+	"github.com/aidanlsb/raven/internal/config"
 )
 
 var captureStdoutMu sync.Mutex
@@ -733,3 +735,55 @@ types:
 		t.Fatalf("did not expect template content when default_template is unset, got:\n%s", content)
 	}
 }
+
+func TestNewHumanModeNoEditSkipsEditor(t *testing.T) {
+	vaultPath := t.TempDir()
+	schemaYAML := "version: 2\ntypes:\n  note:\n    default_path: notes/\n"
+	if err := os.WriteFile(filepath.Join(vaultPath, "schema.yaml"), []byte(schemaYAML), 0o644); err != nil {
+		t.Fatalf("write schema.yaml: %v", err)
+	}
+
+	markerPath := filepath.Join(vaultPath, "editor-called.marker")
+	editorPath := writeFakeEditor(t, vaultPath, markerPath)
+
+	prevVault := resolvedVaultPath
+	prevJSON := jsonOutput
+	prevCfg := cfg
+	prevFields := newFieldFlags
+	prevPath := newPathFlag
+	prevTemplate := newTemplate
+	prevNoEdit := newNoEdit
+	t.Cleanup(func() {
+		resolvedVaultPath = prevVault
+		jsonOutput = prevJSON
+		cfg = prevCfg
+		newFieldFlags = prevFields
+		newPathFlag = prevPath
+		newTemplate = prevTemplate
+		newNoEdit = prevNoEdit
+	})
+
+	resolvedVaultPath = vaultPath
+	jsonOutput = false
+	cfg = &config.Config{
+		Editor:     editorPath,
+		EditorMode: "terminal",
+	}
+	newFieldFlags = nil
+	newPathFlag = ""
+	newTemplate = ""
+	newNoEdit = true
+
+	captureStdout(t, func() {
+		if err := newCmd.RunE(newCmd, []string{"note", "Scripted Note"}); err != nil {
+			t.Fatalf("newCmd.RunE: %v", err)
+		}
+	})
+
+	if _, err := os.Stat(filepath.Join(vaultPath, "notes", "scripted-note.md")); err != nil {
+		t.Fatalf("expected note to be created: %v", err)
+	}
+	if _, err := os.Stat(markerPath); !os.IsNotExist(err) {
+		t.Fatalf("expected editor not to be invoked with --no-edit, stat err=%v", err)
+	}
+}
@@ -25,6 +25,9 @@ func validateOpenArgs(cmd *cobra.Command, args []string) error {
 		if len(args) > 0 {
 			return fmt.Errorf("cannot specify reference when using --stdin")
 		}
+		if backlink, _ := cmd.Flags().GetBool("backlink"); backlink {
+			return fmt.Errorf("cannot use --backlink with --stdin")
+		}
 		return nil
 	}
 	if len(args) > 1 {
@@ -86,9 +89,13 @@ func buildOpenArgs(cmd *cobra.Command, args []string) (map[string]interface{}, e
 		}, nil
 	}
 
-	return map[string]interface{}{
+	out := map[string]interface{}{
 		"reference": args[0],
-	}, nil
+	}
+	if backlink, _ := cmd.Flags().GetBool("backlink"); backlink {
+		out["backlink"] = true
+	}
+	return out, nil
 }
 
 func handleCanonicalOpenFailure(cmd *cobra.Command, result commandexec.Result) error {
@@ -160,6 +167,10 @@ func handleOpenResult(cmd *cobra.Command, result commandexec.Result) error {
 		return nil
 	}
 
+	if backlink, _ := cmd.Flags().GetBool("backlink"); backlink {
+		return renderBacklinkOpenResult(data)
+	}
+
 	file := stringValue(data["file"])
 	line := intFromAny(data["line_start"])
 	if boolValue(data["opened"]) {
@@ -173,6 +184,23 @@ func handleOpenResult(cmd *cobra.Command, result commandexec.Result) error {
 	return renderSingleOpenResult(data)
 }
 
+func renderBacklinkOpenResult(data map[string]interface{}) error {
+	target := stringValue(data["target_object_id"])
+	count := intFromAny(data["backlink_count"])
+	if count == 0 {
+		fmt.Println(ui.Starf("No notes link to %s", ui.FilePath(target)))
+		return nil
+	}
+
+	file := stringValue(data["file"])
+	position := intFromAny(data["position"])
+	if boolValue(data["opened"]) {
+		fmt.Println(ui.Checkf("Opening %s %s", ui.FilePath(file), ui.Hint(fmt.Sprintf("(backlink %d of %d to %s)", position, count, target))))
+		return nil
+	}
+	return renderSingleOpenResult(data)
+}
+
 func ambiguousReferenceDetails(raw interface{}) (string, []string, map[string]string) {
 	details, ok := raw.(map[string]interface{})
 	if !ok {
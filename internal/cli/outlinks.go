@@ -42,6 +42,11 @@ func prepareOutlinksArgs(cmd *cobra.Command, args []string) ([]string, bool, err
 }
 
 func buildOutlinksArgs(cmd *cobra.Command, args []string) (map[string]interface{}, error) {
+	argsMap := map[string]interface{}{}
+	if direct, _ := cmd.Flags().GetBool("direct"); direct {
+		argsMap["direct"] = true
+	}
+
 	stdin, _ := cmd.Flags().GetBool("stdin")
 	if stdin {
 		sources, err := ReadReferencesFromStdin()
@@ -51,14 +56,12 @@ func buildOutlinksArgs(cmd *cobra.Command, args []string) (map[string]interface{
 		if len(sources) == 0 {
 			return nil, fmt.Errorf("no sources provided on stdin")
 		}
-		return map[string]interface{}{
-			"stdin":   true,
-			"sources": sources,
-		}, nil
+		argsMap["stdin"] = true
+		argsMap["sources"] = sources
+		return argsMap, nil
 	}
-	return map[string]interface{}{
-		"source": args[0],
-	}, nil
+	argsMap["source"] = args[0]
+	return argsMap, nil
 }
 
 func handleOutlinksFailure(cmd *cobra.Command, result commandexec.Result) error {
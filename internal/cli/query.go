@@ -207,6 +207,16 @@ Boolean operators:
 Saved query inputs must be declared with args: in raven.yaml when using {{args.<name>}}.
 You can then pass inputs either by position (following args order) or as key=value pairs.
 
+Use --sample N to return a random subset of matches. Add --seed to make the
+selection reproducible (useful for review sessions you want to resume).
+
+Use --group-by dir to group results by top-level directory. Files at the
+vault root are listed under "(root)".
+
+Use --select title,rating to print just those fields as aligned columns, and
+--sort rating (or --sort=-rating for descending) to order type query results
+by a field.
+
 Use --browse to open an interactive Raven picker with filtering, preview, and
 editor handoff for the selected result.
 
@@ -214,6 +224,7 @@ editor handoff for the selected result.
 Examples:
   rvn query "type:project .status==active"
   rvn query "type:meeting has(trait:due)"
+  rvn query "type:book" --select title,rating --sort=-rating
   rvn query "section .title==Tasks"
   rvn query "trait:due .value<today"
   rvn query "asset .extension==pdf"
@@ -299,12 +310,14 @@ Examples:
 
 		// If --apply is set, route through the canonical query handler.
 		if len(applyArgs) > 0 {
-			return runCanonicalQuery(queryStr, map[string]interface{}{
+			applyQueryArgs := map[string]interface{}{
 				"query_string": joinQueryArgs(args),
 				"refresh":      refresh,
 				"apply":        applyArgs,
 				"confirm":      confirmApply,
-			})
+			}
+			addOptionalQueryArgs(cmd, applyQueryArgs)
+			return runCanonicalQuery(queryStr, applyQueryArgs)
 		}
 
 		if !strings.HasPrefix(queryStr, "type:") && !strings.HasPrefix(queryStr, "trait:") && !isAssetQueryString(queryStr) && !isSectionQueryString(queryStr) {
@@ -330,7 +343,7 @@ Examples:
 				suggestion)
 		}
 
-		return runCanonicalQuery(queryStr, map[string]interface{}{
+		canonicalArgs := map[string]interface{}{
 			"query_string": joinQueryArgs(args),
 			"refresh":      refresh,
 			"ids":          idsOnly,
@@ -338,10 +351,36 @@ Examples:
 			"offset":       offset,
 			"count-only":   countOnly,
 			"browse":       browse,
-		})
+		}
+		addOptionalQueryArgs(cmd, canonicalArgs)
+		return runCanonicalQuery(queryStr, canonicalArgs)
 	},
 }
 
+// addOptionalQueryArgs forwards the optional output flags (--sample, --seed,
+// --group-by, --select, --sort) only when set. Leaving --seed out lets the
+// canonical handler pick a fresh seed for unseeded samples.
+func addOptionalQueryArgs(cmd *cobra.Command, argsMap map[string]interface{}) {
+	if cmd.Flags().Changed("sample") {
+		value, _ := cmd.Flags().GetInt("sample")
+		argsMap["sample"] = value
+	}
+	if cmd.Flags().Changed("seed") {
+		value, _ := cmd.Flags().GetInt("seed")
+		argsMap["seed"] = value
+	}
+	if cmd.Flags().Changed("group-by") {
+		value, _ := cmd.Flags().GetString("group-by")
+		argsMap["group-by"] = value
+	}
+	for _, name := range []string{"select", "sort"} {
+		if cmd.Flags().Changed(name) {
+			value, _ := cmd.Flags().GetString(name)
+			argsMap[name] = value
+		}
+	}
+}
+
 func runCanonicalQuery(queryStr string, args map[string]interface{}) error {
 	result := executeCanonicalQuery(args)
 	if hasQueryApply(args) {
@@ -386,9 +425,17 @@ func runCanonicalQuery(queryStr string, args map[string]interface{}) error {
 
 	queryKind, _ := data["query_kind"].(string)
 	browse := boolValue(args["browse"])
+	if rawGroups, ok := data["groups"]; ok && !browse && !ShouldUsePipeFormat() {
+		printQueryGroups(queryStr, rawGroups)
+		return nil
+	}
 	switch queryKind {
 	case "type", "object":
 		objects := objectResultsFromAny(data["items"])
+		if selectFields := stringSliceFromAny(data["select"]); len(selectFields) > 0 && !browse {
+			printSelectedFieldsTable(os.Stdout, objects, selectFields)
+			return nil
+		}
 		if browse {
 			if len(objects) == 0 {
 				sch, _ := schema.Load(getVaultPath())
@@ -1399,6 +1446,11 @@ func init() {
 	queryCmd.Flags().Int("limit", 0, "Maximum number of query results to return (0 means no limit)")
 	queryCmd.Flags().Int("offset", 0, "Zero-based offset for query results")
 	queryCmd.Flags().Bool("count-only", false, "Return only the total count of matches (no items or IDs)")
+	queryCmd.Flags().Int("sample", 0, "Return a random subset of N matches (in query order)")
+	queryCmd.Flags().Int("seed", 0, "Seed for --sample (requires --sample); the same seed returns the same subset")
+	queryCmd.Flags().String("group-by", "", "Group results by a derived key (supported: dir)")
+	queryCmd.Flags().String("select", "", "Comma-separated frontmatter fields to output as table columns (type queries only)")
+	queryCmd.Flags().String("sort", "", "Sort type query results by a frontmatter field; prefix with '-' for descending")
 	queryCmd.Flags().StringArray("apply", nil, "Apply a bulk operation to query results (format: command args...)")
 	queryCmd.Flags().Bool("confirm", false, "Apply changes (without this flag, shows preview only)")
 	queryCmd.Flags().Bool("pipe", false, "Force pipe-friendly output for shell pipelines (jq, head, sort)")
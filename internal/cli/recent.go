@@ -0,0 +1,70 @@
+package cli
+
+import (
+	"fmt"
+	"time"
+
+	"github.com/spf13/cobra"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/ui"
+)
+
+var recentCmd = newCanonicalLeafCommand("recent", canonicalLeafOptions{
+	VaultPath:   getVaultPath,
+	RenderHuman: renderRecent,
+})
+
+func renderRecent(_ *cobra.Command, result commandexec.Result) error {
+	data := canonicalDataMap(result)
+	var items []index.RecentObject
+	_ = decodeResultData(data["items"], &items)
+	if len(items) == 0 {
+		fmt.Println(ui.Star("No indexed objects yet"))
+		return nil
+	}
+
+	now := time.Now()
+	for _, item := range items {
+		line := fmt.Sprintf("%s %s", ui.Bold.Render(item.ID), ui.Hint("("+item.Type+")"))
+		fmt.Println(ui.Bullet(line))
+		fmt.Println(ui.Indent(2, ui.Hint(fmt.Sprintf("%s · %s", item.FilePath, formatRelativeTime(item.FileMtime, now)))))
+	}
+	return nil
+}
+
+// formatRelativeTime renders a Unix timestamp as a coarse "N units ago" label,
+// falling back to the date once it is more than a month old.
+func formatRelativeTime(unix int64, now time.Time) string {
+	if unix <= 0 {
+		return "unknown"
+	}
+	then := time.Unix(unix, 0)
+	elapsed := now.Sub(then)
+	switch {
+	case elapsed < time.Minute:
+		return "just now"
+	case elapsed < time.Hour:
+		return pluralAgo(int(elapsed/time.Minute), "minute")
+	case elapsed < 24*time.Hour:
+		return pluralAgo(int(elapsed/time.Hour), "hour")
+	case elapsed < 48*time.Hour:
+		return "yesterday"
+	case elapsed < 30*24*time.Hour:
+		return pluralAgo(int(elapsed/(24*time.Hour)), "day")
+	default:
+		return then.Format("2006-01-02")
+	}
+}
+
+func pluralAgo(n int, unit string) string {
+	if n == 1 {
+		return fmt.Sprintf("1 %s ago", unit)
+	}
+	return fmt.Sprintf("%d %ss ago", n, unit)
+}
+
+func init() {
+	rootCmd.AddCommand(recentCmd)
+}
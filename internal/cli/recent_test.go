@@ -0,0 +1,33 @@
+package cli
+
+import (
+	"testing"
+	"time"
+)
+
+func TestFormatRelativeTime(t *testing.T) {
+	t.Parallel()
+
+	now := time.Date(2025, 3, 10, 12, 0, 0, 0, time.Local)
+	tests := []struct {
+		name string
+		then time.Time
+		want string
+	}{
+		{name: "seconds", then: now.Add(-20 * time.Second), want: "just now"},
+		{name: "one minute", then: now.Add(-time.Minute), want: "1 minute ago"},
+		{name: "minutes", then: now.Add(-42 * time.Minute), want: "42 minutes ago"},
+		{name: "hours", then: now.Add(-5 * time.Hour), want: "5 hours ago"},
+		{name: "yesterday", then: now.Add(-30 * time.Hour), want: "yesterday"},
+		{name: "days", then: now.Add(-6 * 24 * time.Hour), want: "6 days ago"},
+		{name: "old", then: time.Date(2024, 11, 2, 9, 0, 0, 0, time.Local), want: "2024-11-02"},
+	}
+	for _, tt := range tests {
+		if got := formatRelativeTime(tt.then.Unix(), now); got != tt.want {
+			t.Errorf("%s: formatRelativeTime = %q, want %q", tt.name, got, tt.want)
+		}
+	}
+	if got := formatRelativeTime(0, now); got != "unknown" {
+		t.Errorf("zero mtime = %q, want unknown", got)
+	}
+}
@@ -1,6 +1,7 @@
 package cli
 
 import (
+	"slices"
 	"strings"
 	"testing"
 
@@ -137,6 +138,33 @@ func TestRegistryBackedCommandsMatchRegistryVaultPolicy(t *testing.T) {
 	}
 }
 
+func TestRegistryBackedCommandsMatchRegistryAliases(t *testing.T) {
+	syncRegistryMetadata(rootCmd)
+
+	paths := commandPaths(rootCmd)
+	for _, path := range paths {
+		if path == "" {
+			continue
+		}
+
+		cmd, ok := findCommandByPath(rootCmd, path)
+		if !ok {
+			t.Errorf("failed to locate command for path %q", path)
+			continue
+		}
+
+		commandID, ok := registryCommandIDForCommand(cmd)
+		if !ok {
+			continue
+		}
+
+		meta, _ := commands.EffectiveMeta(commandID)
+		if !slices.Equal(cmd.Aliases, meta.Aliases) {
+			t.Errorf("command %q (registry id %q) aliases=%v, want %v", path, commandID, cmd.Aliases, meta.Aliases)
+		}
+	}
+}
+
 func TestExplicitNoVaultRuntimeCommands(t *testing.T) {
 	cases := make([]*cobra.Command, 0, 2)
 	for _, path := range []string{"mcp", "skill"} {
@@ -23,7 +23,9 @@ func prepareReindexArgs(cmd *cobra.Command, args []string) ([]string, bool, erro
 	fullReindex, _ := cmd.Flags().GetBool("full")
 	dryRun, _ := cmd.Flags().GetBool("dry-run")
 	if !jsonOutput && !dryRun {
-		if fullReindex {
+		if len(args) > 0 {
+			fmt.Printf("Reindexing %s\n", ui.FilePath(args[0]))
+		} else if fullReindex {
 			fmt.Printf("Full reindexing vault: %s\n", ui.FilePath(getVaultPath()))
 		} else {
 			fmt.Printf("Reindexing vault: %s\n", ui.FilePath(getVaultPath()))
@@ -32,13 +34,17 @@ func prepareReindexArgs(cmd *cobra.Command, args []string) ([]string, bool, erro
 	return args, false, nil
 }
 
-func buildReindexArgs(cmd *cobra.Command, _ []string) (map[string]interface{}, error) {
+func buildReindexArgs(cmd *cobra.Command, args []string) (map[string]interface{}, error) {
 	fullReindex, _ := cmd.Flags().GetBool("full")
 	dryRun, _ := cmd.Flags().GetBool("dry-run")
-	return map[string]interface{}{
+	argsMap := map[string]interface{}{
 		"full":    fullReindex,
 		"dry-run": dryRun,
-	}, nil
+	}
+	if len(args) > 0 {
+		argsMap["path"] = args[0]
+	}
+	return argsMap, nil
 }
 
 func invokeReindex(cmd *cobra.Command, commandID, vaultPath string, args map[string]interface{}) commandexec.Result {
@@ -2,10 +2,14 @@ package cli
 
 import (
 	"fmt"
+	"sort"
+	"strings"
 
 	"github.com/spf13/cobra"
 
 	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/model"
+	"github.com/aidanlsb/raven/internal/resolver"
 	"github.com/aidanlsb/raven/internal/ui"
 )
 
@@ -27,9 +31,11 @@ func prepareResolveArgs(_ *cobra.Command, args []string) ([]string, bool, error)
 	return prepareInteractiveReferenceArgs(args, "resolve", "reference", "resolve> ", "Select a reference to resolve (Esc to cancel)", interactiveReferencePickerOptions{IncludeAssets: true})
 }
 
-func buildResolveArgs(_ *cobra.Command, args []string) (map[string]interface{}, error) {
+func buildResolveArgs(cmd *cobra.Command, args []string) (map[string]interface{}, error) {
+	explain, _ := cmd.Flags().GetBool("explain")
 	return map[string]interface{}{
 		"reference": args[0],
+		"explain":   explain,
 	}, nil
 }
 
@@ -48,19 +54,20 @@ func renderResolve(_ *cobra.Command, result commandexec.Result) error {
 				fmt.Println(ui.Bullet(fmt.Sprintf("%s%s", match["object_id"], src)))
 			}
 		}
+		printResolveTrace(data["trace"])
 		return nil
 	}
 
 	if resolved, _ := data["resolved"].(bool); !resolved {
 		fmt.Println(ui.Starf("Reference '%s' not found.", reference))
+		printResolveTrace(data["trace"])
 		return nil
 	}
 
 	objectID, _ := data["object_id"].(string)
 	objectType, _ := data["type"].(string)
 	relFilePath, _ := data["file_path"].(string)
-	isSection, _ := data["is_section"].(bool)
-	fileObjectID, _ := data["file_object_id"].(string)
+	parentID, _ := data["parent"].(string)
 	matchSource, _ := data["match_source"].(string)
 
 	fmt.Printf("%s %s\n", ui.SectionHeader("Resolved"), ui.Bold.Render(objectID))
@@ -68,15 +75,72 @@ func renderResolve(_ *cobra.Command, result commandexec.Result) error {
 		fmt.Printf("  %s %s\n", ui.Hint("Type:"), objectType)
 	}
 	fmt.Printf("  %s %s\n", ui.Hint("File:"), ui.FilePath(relFilePath))
-	if isSection {
-		fmt.Printf("  %s %s\n", ui.Hint("Parent:"), fileObjectID)
+	if parentID != "" {
+		fmt.Printf("  %s %s\n", ui.Hint("Parent:"), parentID)
 	}
 	if matchSource != "" {
 		fmt.Printf("  %s %s\n", ui.Hint("Matched via:"), matchSource)
 	}
+	printResolveTrace(data["trace"])
+	if fields, ok := data["fields"].(map[string]interface{}); ok && len(fields) > 0 {
+		fmt.Printf("  %s\n", ui.Hint("Fields:"))
+		for _, key := range orderedFieldKeys(fields, stringSliceFromAny(data["field_order"])) {
+			fmt.Printf("    %s %s\n", ui.Hint(key+":"), formatFieldValueSimple(fields[key]))
+		}
+	}
+	if traits, ok := data["traits"].([]model.Trait); ok && len(traits) > 0 {
+		fmt.Printf("  %s\n", ui.Hint("Traits:"))
+		for _, trait := range traits {
+			label := "@" + trait.TraitType
+			if trait.Value != nil {
+				label += "(" + *trait.Value + ")"
+			}
+			fmt.Printf("    %s %s %s\n", label, trait.Content, ui.Hint(fmt.Sprintf("(line %d)", trait.Line)))
+		}
+	}
+	if body := strings.TrimSpace(stringValue(data["body"])); body != "" {
+		fmt.Printf("\n%s\n", body)
+	}
 	return nil
 }
 
+// printResolveTrace prints the rules tried by --explain and what each matched.
+func printResolveTrace(raw interface{}) {
+	trace, ok := raw.([]resolver.TraceStep)
+	if !ok {
+		return
+	}
+	fmt.Printf("  %s\n", ui.Hint("Trace:"))
+	for _, step := range trace {
+		candidates := ui.Hint("no match")
+		if len(step.Candidates) > 0 {
+			candidates = strings.Join(step.Candidates, ", ")
+		}
+		fmt.Printf("    %s %s\n", ui.Hint(step.Rule+":"), candidates)
+	}
+}
+
+// orderedFieldKeys returns the keys of fields in frontmatter order, falling
+// back to sorted order for any keys the order does not cover.
+func orderedFieldKeys(fields map[string]interface{}, order []string) []string {
+	keys := make([]string, 0, len(fields))
+	seen := make(map[string]bool, len(fields))
+	for _, key := range order {
+		if _, ok := fields[key]; ok && !seen[key] {
+			seen[key] = true
+			keys = append(keys, key)
+		}
+	}
+	rest := make([]string, 0, len(fields)-len(keys))
+	for key := range fields {
+		if !seen[key] {
+			rest = append(rest, key)
+		}
+	}
+	sort.Strings(rest)
+	return append(keys, rest...)
+}
+
 func init() {
 	resolveCmd.ValidArgsFunction = completeReferenceArgAt(0, referenceCompletionOptions{
 		IncludeDynamicDates: true,
@@ -0,0 +1,34 @@
+package cli
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+)
+
+func TestRenderResolvePrintsFieldsInFrontmatterOrder(t *testing.T) {
+	data := map[string]interface{}{
+		"resolved":    true,
+		"object_id":   "books/dune",
+		"type":        "book",
+		"file_path":   "books/dune.md",
+		"fields":      map[string]interface{}{"title": "Dune", "author": "Frank", "pages": 412, "rating": 5},
+		"field_order": []interface{}{"title", "author", "pages"},
+	}
+
+	out := captureStdout(t, func() {
+		if err := renderResolve(nil, commandexec.Success(data, nil)); err != nil {
+			t.Fatalf("renderResolve: %v", err)
+		}
+	})
+
+	last := -1
+	for _, key := range []string{"title:", "author:", "pages:", "rating:"} {
+		idx := strings.Index(out, key)
+		if idx <= last {
+			t.Fatalf("expected %s after previous field in output:\n%s", key, out)
+		}
+		last = idx
+	}
+}
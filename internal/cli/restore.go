@@ -0,0 +1,31 @@
+package cli
+
+import (
+	"fmt"
+
+	"github.com/spf13/cobra"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/ui"
+)
+
+var restoreCmd = newCanonicalLeafCommand("restore", canonicalLeafOptions{
+	VaultPath:   getVaultPath,
+	HandleError: handleCanonicalMaintSvcFailure,
+	RenderHuman: renderRestore,
+})
+
+func renderRestore(_ *cobra.Command, result commandexec.Result) error {
+	data := canonicalDataMap(result)
+	fmt.Println(ui.Check("Restored index from export"))
+	fmt.Println(ui.Bullet(ui.Muted.Render("Files: ") + ui.Bold.Render(fmt.Sprintf("%v", data["file_count"]))))
+	fmt.Println(ui.Bullet(ui.Muted.Render("Objects: ") + ui.Bold.Render(fmt.Sprintf("%v", data["object_count"]))))
+	fmt.Println(ui.Bullet(ui.Muted.Render("Traits: ") + ui.Bold.Render(fmt.Sprintf("%v", data["trait_count"]))))
+	fmt.Println(ui.Bullet(ui.Muted.Render("References: ") + ui.Bold.Render(fmt.Sprintf("%v", data["ref_count"]))))
+	fmt.Println(ui.Hint("Run 'rvn reindex' to rebuild sections, dates, and search content."))
+	return nil
+}
+
+func init() {
+	rootCmd.AddCommand(restoreCmd)
+}
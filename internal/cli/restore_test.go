@@ -0,0 +1,34 @@
+package cli_test
+
+import (
+	"path/filepath"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/testutil"
+)
+
+func TestRestore_NextReindexRebuildsSectionsAndSearch(t *testing.T) {
+	v := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("people/freya.md", "---\ntype: person\nname: Freya\n---\n# Freya\n\n## Notes\nDiscussed the longship repairs.\n").
+		Build()
+	v.RunCLI("reindex").MustSucceed(t)
+
+	exportPath := filepath.Join(t.TempDir(), "vault.json")
+	v.RunCLI("export", "--out", exportPath).MustSucceed(t)
+	v.RunCLI("restore", exportPath).MustSucceed(t)
+
+	diff := v.RunCLI("diff-index").MustSucceed(t)
+	if modified := diff.DataList("modified"); len(modified) != 1 || modified[0] != "people/freya.md" {
+		t.Fatalf("expected restored file to be stale, got modified = %#v", modified)
+	}
+
+	v.RunCLI("reindex").MustSucceed(t)
+
+	if results := v.RunCLI("search", "longship").MustSucceed(t).DataList("results"); len(results) == 0 {
+		t.Fatal("expected search to find restored content after reindex")
+	}
+	if section := v.RunCLI("resolve", "people/freya#notes").MustSucceed(t); section.Data["is_section"] != true || section.Data["line_start"] == nil {
+		t.Fatalf("expected indexed section after reindex, got %#v", section.Data)
+	}
+}
@@ -2,8 +2,10 @@ package cli
 
 import (
 	"fmt"
+	"io"
 	"path/filepath"
 	"strings"
+	"text/tabwriter"
 
 	"github.com/aidanlsb/raven/internal/model"
 	"github.com/aidanlsb/raven/internal/schema"
@@ -20,6 +22,52 @@ func printQueryObjectResults(queryStr, typeName string, results []model.Object,
 	printObjectTable(results, sch)
 }
 
+// printSelectedFieldsTable prints --select output as plain aligned columns:
+// the object ID followed by each selected field. Missing values print as "-".
+func printSelectedFieldsTable(w io.Writer, results []model.Object, fields []string) {
+	tw := tabwriter.NewWriter(w, 0, 0, 2, ' ', 0)
+	fmt.Fprintln(tw, "id\t"+strings.Join(fields, "\t"))
+	for _, obj := range results {
+		cells := make([]string, 0, len(fields)+1)
+		cells = append(cells, obj.ID)
+		for _, field := range fields {
+			value := formatFieldValueSimple(obj.Fields[field])
+			if value == "" {
+				value = "-"
+			}
+			cells = append(cells, value)
+		}
+		fmt.Fprintln(tw, strings.Join(cells, "\t"))
+	}
+	_ = tw.Flush()
+}
+
+func printQueryGroups(queryStr string, rawGroups interface{}) {
+	var groups []map[string]interface{}
+	switch typed := rawGroups.(type) {
+	case []map[string]interface{}:
+		groups = typed
+	case []interface{}:
+		for _, raw := range typed {
+			groups = append(groups, mapValue(raw))
+		}
+	}
+	if len(groups) == 0 {
+		fmt.Println(ui.Starf("No results found for: %s", queryStr))
+		return
+	}
+
+	for i, group := range groups {
+		if i > 0 {
+			fmt.Println()
+		}
+		fmt.Printf("%s %s\n", ui.SectionHeader(stringValue(group["dir"])), ui.Badge(fmt.Sprintf("%d", intFromAny(group["count"]))))
+		for _, id := range stringSliceFromAny(group["ids"]) {
+			fmt.Println(ui.Bullet(id))
+		}
+	}
+}
+
 func printQueryTraitResults(queryStr, traitName string, results []model.Trait) {
 	if len(results) == 0 {
 		fmt.Println(ui.Starf("No traits found for: %s", queryStr))
@@ -497,6 +545,12 @@ func printBacklinksResults(target string, links []model.Reference) {
 			if link.DisplayText != nil {
 				displayText = *link.DisplayText
 			}
+			if link.Depth > 1 {
+				displayText = fmt.Sprintf("%s %s", ui.Muted.Render(fmt.Sprintf("[%d]", link.Depth)), displayText)
+			}
+			if link.Context != "" {
+				displayText += "  " + ui.Muted.Render(link.Context)
+			}
 			return displayText
 		},
 	)
@@ -522,6 +576,9 @@ func printOutlinksResults(source string, links []model.Reference) {
 			if link.DisplayText != nil && *link.DisplayText != "" && *link.DisplayText != link.TargetRaw {
 				target = fmt.Sprintf("%s (%s)", *link.DisplayText, link.TargetRaw)
 			}
+			if link.TargetID != nil && *link.TargetID != link.TargetRaw {
+				target += " → " + *link.TargetID
+			}
 			return target
 		},
 	)
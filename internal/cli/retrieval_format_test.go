@@ -1,6 +1,7 @@
 package cli
 
 import (
+	"bytes"
 	"strings"
 	"testing"
 
@@ -58,6 +59,7 @@ func TestPrintBacklinksAndOutlinksUseQueryStyleLocations(t *testing.T) {
 				FilePath:    "note/planning.md",
 				Line:        &backlinkLine,
 				DisplayText: &backlinkLabel,
+				Context:     "Kickoff with [[project/raven]] team",
 			},
 		})
 	})
@@ -65,6 +67,9 @@ func TestPrintBacklinksAndOutlinksUseQueryStyleLocations(t *testing.T) {
 	if !strings.Contains(backlinksOut, "planning note") {
 		t.Fatalf("expected backlinks output to include display text, got: %q", backlinksOut)
 	}
+	if !strings.Contains(backlinksOut, "Kickoff with") {
+		t.Fatalf("expected backlinks output to include source line context, got: %q", backlinksOut)
+	}
 	if !strings.Contains(backlinksOut, "note/planning.md:12") {
 		t.Fatalf("expected backlinks output to include query-style location, got: %q", backlinksOut)
 	}
@@ -177,3 +182,21 @@ func TestPrintObjectTableIncludesHeadersNameFieldDynamicFieldsAndLocation(t *tes
 		}
 	}
 }
+
+func TestPrintSelectedFieldsTableAlignsColumns(t *testing.T) {
+	t.Parallel()
+
+	var buf bytes.Buffer
+	printSelectedFieldsTable(&buf, []model.Object{
+		{ID: "book/ulysses", Fields: map[string]interface{}{"title": "Ulysses", "rating": 10}},
+		{ID: "book/notes", Fields: map[string]interface{}{"title": "Notes"}},
+	}, []string{"title", "rating"})
+
+	want := "" +
+		"id            title    rating\n" +
+		"book/ulysses  Ulysses  10\n" +
+		"book/notes    Notes    -\n"
+	if buf.String() != want {
+		t.Fatalf("table =\n%s\nwant\n%s", buf.String(), want)
+	}
+}
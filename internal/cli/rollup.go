@@ -0,0 +1,68 @@
+package cli
+
+import (
+	"fmt"
+
+	"github.com/spf13/cobra"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/datesvc"
+	"github.com/aidanlsb/raven/internal/model"
+	"github.com/aidanlsb/raven/internal/ui"
+)
+
+var rollupCmd = newCanonicalLeafCommand("rollup", canonicalLeafOptions{
+	VaultPath:   getVaultPath,
+	RenderHuman: renderRollup,
+})
+
+func renderRollup(_ *cobra.Command, result commandexec.Result) error {
+	data := canonicalDataMap(result)
+	display := ui.NewDisplayContext()
+
+	var notes []datesvc.RollupNote
+	var traits []model.Trait
+	var mentions []datesvc.RollupMention
+	_ = decodeResultData(data["notes"], &notes)
+	_ = decodeResultData(data["traits"], &traits)
+	_ = decodeResultData(data["mentions"], &mentions)
+
+	fmt.Printf("%s %s\n\n", ui.SectionHeader(stringValue(data["period"])),
+		ui.Hint(fmt.Sprintf("(%s to %s)", stringValue(data["from"]), stringValue(data["to"]))))
+
+	if len(notes) == 0 {
+		fmt.Println(ui.Star("No daily notes in this period"))
+		return nil
+	}
+
+	fmt.Println(ui.Divider(fmt.Sprintf("Daily Notes (%d)", len(notes)), display.TermWidth))
+	for _, note := range notes {
+		fmt.Println(ui.Bullet(ui.FilePath(note.FilePath)))
+	}
+	fmt.Println()
+
+	if len(traits) > 0 {
+		fmt.Println(ui.Divider(fmt.Sprintf("Traits (%d)", len(traits)), display.TermWidth))
+		for _, trait := range traits {
+			value := ""
+			if trait.Value != nil {
+				value = *trait.Value
+			}
+			fmt.Println(ui.Bullet(fmt.Sprintf("%s %s", ui.Trait(trait.TraitType, value), trait.Content)))
+			fmt.Println(ui.Indent(2, ui.Hint(fmt.Sprintf("%s:%d", trait.FilePath, trait.Line))))
+		}
+		fmt.Println()
+	}
+
+	if len(mentions) > 0 {
+		fmt.Println(ui.Divider(fmt.Sprintf("Mentions (%d)", len(mentions)), display.TermWidth))
+		for _, mention := range mentions {
+			fmt.Println(ui.Bullet(fmt.Sprintf("%s %s", mention.Target, ui.Hint(fmt.Sprintf("(%d)", mention.Count)))))
+		}
+	}
+	return nil
+}
+
+func init() {
+	rootCmd.AddCommand(rollupCmd)
+}
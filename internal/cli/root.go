@@ -23,6 +23,7 @@ var (
 	vaultPathFlag string // Explicit path (rare)
 	configPath    string
 	statePathFlag string
+	colorFlag     string
 
 	// Resolved values
 	resolvedVaultPath  string
@@ -53,6 +54,9 @@ who gathered knowledge from across the world.`,
 			cfg = &config.Config{}
 		}
 		resolvedStatePath = config.ResolveStatePath(statePathFlag, resolvedConfigPath, cfg)
+		if !ui.SetColorMode(colorFlag) {
+			return handleStartupError(ErrInvalidInput, fmt.Sprintf("invalid --color value '%s'", colorFlag), "Use auto, always, or never")
+		}
 		ui.ConfigureTheme(cfg.UI.Accent)
 		ui.ConfigureMarkdownCodeTheme(cfg.UI.CodeTheme)
 		ui.ConfigureMarkdownStyle(cfg.UI.MarkdownStyle)
@@ -158,6 +162,7 @@ func init() {
 	rootCmd.PersistentFlags().StringVar(&configPath, "config", "", "Path to config file")
 	rootCmd.PersistentFlags().StringVar(&statePathFlag, "state", "", "Path to state file (overrides state_file in config)")
 	rootCmd.PersistentFlags().BoolVar(&jsonOutput, "json", false, "Output in JSON format (for agent/script use)")
+	rootCmd.PersistentFlags().StringVar(&colorFlag, "color", ui.ColorAuto, "When to use colored output: auto, always, or never")
 }
 
 // getVaultPath returns the resolved vault path.
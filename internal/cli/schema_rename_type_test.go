@@ -194,3 +194,34 @@ title: Kickoff
 	v.AssertFileContains("schema.yaml", "description: Meetings and calls")
 	v.AssertFileNotContains("schema.yaml", "description: Calendar events")
 }
+
+func TestSchemaRenameType_ConfirmRewritesExtends(t *testing.T) {
+	v := testutil.NewTestVault(t).
+		WithSchema(`version: 2
+types:
+  person:
+    fields:
+      name: { type: string, required: true }
+  employee:
+    extends: person
+    fields:
+      team: { type: string }
+traits: {}
+`).
+		WithFile("people/freya.md", `---
+type: employee
+name: Freya
+---
+# Freya
+`).
+		Build()
+
+	res := v.RunCLI("schema", "rename", "type", "person", "contact", "--confirm")
+	res.MustSucceed(t)
+
+	v.AssertFileContains("schema.yaml", "extends: contact")
+	v.AssertFileNotContains("schema.yaml", "extends: person")
+
+	// The renamed schema must still load for follow-up commands.
+	v.RunCLI("schema", "type", "employee").MustSucceed(t)
+}
@@ -8,6 +8,7 @@ import (
 	"github.com/spf13/cobra"
 
 	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/schema"
 	"github.com/aidanlsb/raven/internal/ui"
 )
 
@@ -202,6 +203,12 @@ func interfaceMapFromAny(raw interface{}) map[string]interface{} {
 			out[key] = value
 		}
 		return out
+	case map[string]schema.FieldValue:
+		out := make(map[string]interface{}, len(values))
+		for key, value := range values {
+			out[key] = value.DisplayString()
+		}
+		return out
 	default:
 		return map[string]interface{}{}
 	}
@@ -0,0 +1,30 @@
+package cli_test
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/aidanlsb/raven/internal/testutil"
+)
+
+func TestSet_RefTargetTypeUsesFileWhenIndexIsStale(t *testing.T) {
+	v := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("people/freya.md", "---\ntype: person\nname: Freya\n---\n# Freya\n").
+		WithFile("projects/site.md", "---\ntype: project\ntitle: Site\n---\n# Site\n").
+		Build()
+
+	v.RunCLI("reindex").MustSucceed(t)
+
+	// Reclassify Freya on disk without reindexing; the index still says person.
+	v.WriteFile("people/freya.md", "---\ntype: project\ntitle: Freya\n---\n# Freya\n")
+	later := time.Now().Add(time.Hour)
+	if err := os.Chtimes(filepath.Join(v.Path, "people/freya.md"), later, later); err != nil {
+		t.Fatalf("chtimes people/freya.md: %v", err)
+	}
+
+	v.RunCLI("set", "projects/site", "owner=[[people/freya]]").
+		MustFailWithMessage(t, "resolves to type 'project', expected 'person'")
+}
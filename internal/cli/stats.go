@@ -7,6 +7,7 @@ import (
 
 	"github.com/aidanlsb/raven/internal/codes"
 	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/index"
 	"github.com/aidanlsb/raven/internal/maintsvc"
 	"github.com/aidanlsb/raven/internal/ui"
 )
@@ -17,6 +18,12 @@ var vaultStatsCmd = newCanonicalLeafCommand("vault_stats", canonicalLeafOptions{
 	RenderHuman: renderVaultStats,
 })
 
+var vaultCompactCmd = newCanonicalLeafCommand("vault_compact", canonicalLeafOptions{
+	VaultPath:   getVaultPath,
+	HandleError: handleCanonicalMaintSvcFailure,
+	RenderHuman: renderVaultCompact,
+})
+
 func handleCanonicalMaintSvcFailure(result commandexec.Result) error {
 	if result.Error == nil {
 		return nil
@@ -31,6 +38,37 @@ func renderVaultStats(_ *cobra.Command, result commandexec.Result) error {
 	fmt.Println(ui.Bullet(ui.Muted.Render("Objects: ") + ui.Bold.Render(fmt.Sprintf("%v", data["object_count"]))))
 	fmt.Println(ui.Bullet(ui.Muted.Render("Traits: ") + ui.Bold.Render(fmt.Sprintf("%v", data["trait_count"]))))
 	fmt.Println(ui.Bullet(ui.Muted.Render("References: ") + ui.Bold.Render(fmt.Sprintf("%v", data["ref_count"]))))
+	fmt.Println(ui.Bullet(ui.Muted.Render("Words: ") + ui.Bold.Render(fmt.Sprintf("%v", data["word_count"]))))
+	printStatsBreakdown("Objects by Type", data["objects_by_type"])
+	printStatsBreakdown("Annotations by Trait", data["traits_by_type"])
+	return nil
+}
+
+// printStatsBreakdown prints a --by-type/--by-trait section. It prints nothing
+// when the breakdown was not requested.
+func printStatsBreakdown(title string, raw interface{}) {
+	if raw == nil {
+		return
+	}
+	var counts []index.TypeCount
+	_ = decodeResultData(raw, &counts)
+	fmt.Println()
+	fmt.Println(ui.SectionHeader(title))
+	if len(counts) == 0 {
+		fmt.Println(ui.Bullet(ui.Muted.Render("none")))
+		return
+	}
+	for _, count := range counts {
+		fmt.Println(ui.Bullet(ui.Muted.Render(count.Type+": ") + ui.Bold.Render(fmt.Sprintf("%d", count.Count))))
+	}
+}
+
+func renderVaultCompact(_ *cobra.Command, result commandexec.Result) error {
+	data := canonicalDataMap(result)
+	fmt.Println(ui.SectionHeader("Index Compacted"))
+	fmt.Println(ui.Bullet(ui.Muted.Render("Before: ") + ui.Bold.Render(fmt.Sprintf("%v bytes", data["size_before"]))))
+	fmt.Println(ui.Bullet(ui.Muted.Render("After: ") + ui.Bold.Render(fmt.Sprintf("%v bytes", data["size_after"]))))
+	fmt.Println(ui.Bullet(ui.Muted.Render("Reclaimed: ") + ui.Bold.Render(fmt.Sprintf("%v bytes", data["bytes_reclaimed"]))))
 	return nil
 }
 
@@ -40,6 +78,10 @@ func mapMaintSvcCode(code codes.ErrorCode) codes.ErrorCode {
 		return ErrInvalidInput
 	case maintsvc.CodeDatabaseError:
 		return ErrDatabaseError
+	case maintsvc.CodeFileReadError:
+		return ErrFileReadError
+	case maintsvc.CodeFileWriteError:
+		return ErrFileWriteError
 	default:
 		return ErrInternal
 	}
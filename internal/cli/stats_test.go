@@ -0,0 +1,46 @@
+package cli
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/index"
+)
+
+func TestRenderVaultStatsBreakdownsOnlyWhenRequested(t *testing.T) {
+	base := map[string]interface{}{
+		"file_count":   2,
+		"object_count": 3,
+		"trait_count":  1,
+		"ref_count":    0,
+		"word_count":   120,
+	}
+
+	out := captureStdout(t, func() {
+		if err := renderVaultStats(nil, commandexec.Success(base, nil)); err != nil {
+			t.Fatalf("renderVaultStats: %v", err)
+		}
+	})
+	if strings.Contains(out, "Objects by Type") || strings.Contains(out, "Annotations by Trait") {
+		t.Fatalf("expected no breakdown sections without flags:\n%s", out)
+	}
+
+	flagged := map[string]interface{}{
+		"objects_by_type": []index.TypeCount{{Type: "meeting", Count: 2}, {Type: "page", Count: 1}},
+		"traits_by_type":  []index.TypeCount{{Type: "todo", Count: 1}},
+	}
+	for key, value := range base {
+		flagged[key] = value
+	}
+	out = captureStdout(t, func() {
+		if err := renderVaultStats(nil, commandexec.Success(flagged, nil)); err != nil {
+			t.Fatalf("renderVaultStats: %v", err)
+		}
+	})
+	for _, want := range []string{"Objects by Type", "meeting: ", "Annotations by Trait", "todo: "} {
+		if !strings.Contains(out, want) {
+			t.Fatalf("output missing %q:\n%s", want, out)
+		}
+	}
+}
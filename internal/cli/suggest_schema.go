@@ -0,0 +1,32 @@
+package cli
+
+import (
+	"fmt"
+
+	"github.com/spf13/cobra"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/ui"
+)
+
+var suggestSchemaCmd = newCanonicalLeafCommand("suggest_schema", canonicalLeafOptions{
+	VaultPath:   getVaultPath,
+	RenderHuman: renderSuggestSchema,
+})
+
+func renderSuggestSchema(_ *cobra.Command, result commandexec.Result) error {
+	data := canonicalDataMap(result)
+	snippet := stringValue(data["yaml"])
+	if snippet == "" {
+		fmt.Println(ui.Star("Schema already declares every field in use"))
+		return nil
+	}
+
+	// Plain YAML (no decoration) so the output can be pasted or redirected as-is.
+	fmt.Print(snippet)
+	return nil
+}
+
+func init() {
+	rootCmd.AddCommand(suggestSchemaCmd)
+}
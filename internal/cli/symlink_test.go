@@ -0,0 +1,49 @@
+package cli_test
+
+import (
+	"os"
+	"path/filepath"
+	"runtime"
+	"strings"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/testutil"
+)
+
+func TestFollowSymlinks_LinkedNoteCanBeReadValidatedAndMoved(t *testing.T) {
+	if runtime.GOOS == "windows" {
+		t.Skip("symlink tests are not reliable on windows")
+	}
+
+	v := testutil.NewTestVault(t).
+		WithSchema(testutil.MinimalSchema()).
+		WithRavenYAML("follow_symlinks: true\n").
+		WithFile("notes/index.md", "# Index\n").
+		Build()
+
+	outside := filepath.Join(t.TempDir(), "linked.md")
+	if err := os.WriteFile(outside, []byte("# Linked\n\nShared from elsewhere.\n"), 0o644); err != nil {
+		t.Fatalf("write linked note: %v", err)
+	}
+	if err := os.Symlink(outside, filepath.Join(v.Path, "notes", "linked.md")); err != nil {
+		t.Skipf("symlink not supported: %v", err)
+	}
+	v.RunCLI("reindex").MustSucceed(t)
+
+	read := v.RunCLI("read", "notes/linked.md").MustSucceed(t)
+	if content := read.DataString("content"); !strings.Contains(content, "Shared from elsewhere.") {
+		t.Fatalf("expected linked note content, got %q", content)
+	}
+	v.RunCLI("validate", "notes/linked.md").MustSucceed(t)
+	v.RunCLI("reindex", "notes/linked.md").MustSucceed(t)
+
+	v.RunCLI("move", "notes/linked.md", "archive/linked.md").MustSucceed(t)
+	info, err := os.Lstat(filepath.Join(v.Path, "archive", "linked.md"))
+	if err != nil {
+		t.Fatalf("expected moved link: %v", err)
+	}
+	if info.Mode()&os.ModeSymlink == 0 {
+		t.Fatalf("expected archive/linked.md to still be a symlink, mode %v", info.Mode())
+	}
+	v.AssertFileNotExists("notes/linked.md")
+}
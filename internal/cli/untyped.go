@@ -0,0 +1,31 @@
+package cli
+
+import (
+	"fmt"
+
+	"github.com/spf13/cobra"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/ui"
+)
+
+var untypedCmd = newCanonicalLeafCommand("untyped", canonicalLeafOptions{
+	VaultPath:   getVaultPath,
+	RenderHuman: renderUntyped,
+})
+
+func renderUntyped(_ *cobra.Command, result commandexec.Result) error {
+	files := stringSliceFromAny(canonicalDataMap(result)["files"])
+	if len(files) == 0 {
+		fmt.Println(ui.Star("No untyped pages"))
+		return nil
+	}
+	for _, file := range files {
+		fmt.Println(ui.Bullet(ui.FilePath(file)))
+	}
+	return nil
+}
+
+func init() {
+	rootCmd.AddCommand(untypedCmd)
+}
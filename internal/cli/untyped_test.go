@@ -0,0 +1,28 @@
+package cli_test
+
+import (
+	"reflect"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/testutil"
+)
+
+func TestUntyped_ImplicitOnlySkipsDeclaredPages(t *testing.T) {
+	v := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("loose.md", "# Loose\n").
+		WithFile("declared.md", "---\ntype: page\n---\n# Declared\n").
+		WithFile("people/freya.md", "---\ntype: person\nname: Freya\n---\n").
+		Build()
+	v.RunCLI("reindex").MustSucceed(t)
+
+	all := v.RunCLI("untyped").MustSucceed(t)
+	if got, want := all.DataList("files"), []interface{}{"declared.md", "loose.md"}; !reflect.DeepEqual(got, want) {
+		t.Fatalf("untyped files = %#v, want %#v", got, want)
+	}
+
+	implicit := v.RunCLI("untyped", "--implicit-only").MustSucceed(t)
+	if got, want := implicit.DataList("files"), []interface{}{"loose.md"}; !reflect.DeepEqual(got, want) {
+		t.Fatalf("untyped --implicit-only files = %#v, want %#v", got, want)
+	}
+}
@@ -0,0 +1,52 @@
+package cli
+
+import (
+	"fmt"
+
+	"github.com/spf13/cobra"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/datesvc"
+	"github.com/aidanlsb/raven/internal/ui"
+)
+
+var upcomingCmd = newCanonicalLeafCommand("upcoming", canonicalLeafOptions{
+	VaultPath:   getVaultPath,
+	RenderHuman: renderUpcoming,
+})
+
+func renderUpcoming(_ *cobra.Command, result commandexec.Result) error {
+	data := canonicalDataMap(result)
+	display := ui.NewDisplayContext()
+	from := stringValue(data["from"])
+	to := stringValue(data["to"])
+
+	var days []datesvc.UpcomingDay
+	_ = decodeResultData(data["days"], &days)
+	if len(days) == 0 {
+		fmt.Println(ui.Starf("Nothing scheduled from %s to %s", from, to))
+		return nil
+	}
+
+	for _, day := range days {
+		label := fmt.Sprintf("%s %s (%d)", day.DayOfWeek, day.Date, len(day.Items))
+		fmt.Println(ui.Divider(label, display.TermWidth))
+		for _, item := range day.Items {
+			line := fmt.Sprintf("%s %s", ui.Trait(item.TraitType, item.Value), item.Content)
+			if item.Time != "" {
+				line = fmt.Sprintf("%s %s", item.Time, line)
+			}
+			if item.Every != "" {
+				line = fmt.Sprintf("%s %s", line, ui.Hint("("+item.Every+")"))
+			}
+			fmt.Println(ui.Bullet(line))
+			fmt.Println(ui.Indent(2, ui.Hint(fmt.Sprintf("%s:%d", item.FilePath, item.Line))))
+		}
+		fmt.Println()
+	}
+	return nil
+}
+
+func init() {
+	rootCmd.AddCommand(upcomingCmd)
+}
@@ -0,0 +1,47 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+
+	"github.com/spf13/cobra"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/ui"
+)
+
+var validateCmd = newCanonicalLeafCommand("validate", canonicalLeafOptions{
+	VaultPath:    getVaultPath,
+	HandleError:  handleCheckLeafFailure,
+	HandleResult: handleValidateResult,
+})
+
+func handleValidateResult(cmd *cobra.Command, result commandexec.Result) error {
+	strict, _ := cmd.Flags().GetBool("strict")
+	decoded, ok := decodeCanonicalCheckJSON(result)
+	if !ok {
+		return handleErrorMsg(ErrInternal, "failed to decode validate results", "")
+	}
+	failed := decoded.ErrorCount > 0 || (strict && decoded.WarnCount > 0)
+
+	if jsonOutput {
+		outputJSON(result)
+	} else {
+		printCheckScopeHeader(getVaultPath(), checkScopeFromResult(result))
+		printIssuesVerboseFromJSON(decoded.Issues)
+		if decoded.ErrorCount == 0 && decoded.WarnCount == 0 {
+			fmt.Println(ui.Star("No issues found."))
+		} else {
+			fmt.Printf("Found %d error(s), %d warning(s).\n", decoded.ErrorCount, decoded.WarnCount)
+		}
+	}
+
+	if failed {
+		os.Exit(1)
+	}
+	return nil
+}
+
+func init() {
+	rootCmd.AddCommand(validateCmd)
+}
@@ -0,0 +1,18 @@
+package cli_test
+
+import (
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/testutil"
+)
+
+func TestValidate_PathErrorCodes(t *testing.T) {
+	v := testutil.NewTestVault(t).
+		WithSchema(testutil.MinimalSchema()).
+		WithFile("notes.txt", "plain text\n").
+		Build()
+
+	v.RunCLI("validate", "missing.md").MustFail(t, "FILE_NOT_FOUND")
+	v.RunCLI("validate", "notes.txt").MustFail(t, "INVALID_INPUT")
+	v.RunCLI("validate", "../outside.md").MustFail(t, "INVALID_INPUT")
+}
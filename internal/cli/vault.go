@@ -80,6 +80,7 @@ func init() {
 	vaultCmd.AddCommand(vaultCurrentCmd)
 	vaultCmd.AddCommand(vaultPathCmd)
 	vaultCmd.AddCommand(vaultStatsCmd)
+	vaultCmd.AddCommand(vaultCompactCmd)
 	vaultCmd.AddCommand(vaultUseCmd)
 	vaultCmd.AddCommand(vaultPinCmd)
 	vaultCmd.AddCommand(vaultClearCmd)
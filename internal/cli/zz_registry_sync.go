@@ -37,6 +37,10 @@ func applyRegistryMetadata(cmd *cobra.Command, path string) {
 		cmd.Use = meta.Use
 	}
 
+	if len(meta.Aliases) > 0 {
+		cmd.Aliases = meta.Aliases
+	}
+
 	if meta.Description != "" {
 		cmd.Short = meta.Description
 	}
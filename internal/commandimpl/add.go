@@ -16,6 +16,7 @@ import (
 	"github.com/aidanlsb/raven/internal/paths"
 	"github.com/aidanlsb/raven/internal/readsvc"
 	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/vault"
 )
 
 // HandleAdd executes the canonical `add` command.
@@ -62,7 +63,7 @@ func runAddBulk(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Sche
 		ObjectIDs:    fileIDs,
 		Line:         text,
 		HeadingSpec:  headingSpec,
-		ParseOptions: buildParseOptions(vaultCfg),
+		ParseOptions: vault.ParseOptionsFromConfig(vaultCfg),
 	}
 
 	if !confirm {
@@ -111,7 +112,7 @@ func runAddBulk(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Sche
 
 func runAddSingle(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, text, toRef, headingSpec string) commandexec.Result {
 	captureCfg := vaultCfg.GetCaptureConfig()
-	parseOpts := buildParseOptions(vaultCfg)
+	parseOpts := vault.ParseOptionsFromConfig(vaultCfg)
 
 	var destPath string
 	var isDailyNote bool
@@ -170,7 +171,7 @@ func runAddSingle(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Sc
 	if err := os.MkdirAll(filepath.Dir(destPath), 0o755); err != nil {
 		return commandexec.Failure("FILE_WRITE_ERROR", err.Error(), nil, "")
 	}
-	line, err := objectsvc.AppendToFile(vaultPath, destPath, text, captureCfg, vaultCfg, isDailyNote, targetObjectID, parseOpts)
+	line, err := objectsvc.AppendToFile(vaultPath, destPath, formatCaptureLine(text, captureCfg, time.Now()), captureCfg, vaultCfg, isDailyNote, targetObjectID, parseOpts)
 	if err != nil {
 		return mapContentMutationError(err)
 	}
@@ -188,6 +189,14 @@ func runAddSingle(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Sc
 	return commandexec.SuccessWithWarnings(data, warnings, nil)
 }
 
+// formatCaptureLine applies capture.timestamp formatting to captured text.
+func formatCaptureLine(text string, captureCfg *config.CaptureConfig, now time.Time) string {
+	if captureCfg == nil || !captureCfg.Timestamp {
+		return text
+	}
+	return fmt.Sprintf("- %s %s", now.Format("15:04"), text)
+}
+
 func isDailyNoteObjectID(objectID string, vaultCfg *config.VaultConfig) bool {
 	if objectID == "" {
 		return false
@@ -3,6 +3,7 @@ package commandimpl
 import (
 	"context"
 	"encoding/json"
+	"errors"
 	"fmt"
 	"strings"
 
@@ -73,6 +74,48 @@ func HandleCheckCreateMissing(ctx context.Context, req commandexec.Request) comm
 	return HandleCheck(ctx, req)
 }
 
+// HandleValidate executes the canonical `validate` command.
+func HandleValidate(_ context.Context, req commandexec.Request) commandexec.Result {
+	vaultPath := strings.TrimSpace(req.VaultPath)
+	if vaultPath == "" {
+		return commandexec.Failure("INVALID_INPUT", "vault path is required", nil, "Resolve a vault before invoking the command")
+	}
+	file := strings.TrimSpace(stringArg(req.Args, "file"))
+	if file == "" {
+		return commandexec.Failure("MISSING_ARGUMENT", "file is required", nil, "Usage: rvn validate <file>")
+	}
+
+	vaultCfg, err := config.LoadVaultConfig(vaultPath)
+	if err != nil {
+		return commandexec.Failure("CONFIG_INVALID", "failed to load raven.yaml", nil, "Fix raven.yaml and try again")
+	}
+
+	sch, err := schema.Load(vaultPath)
+	if err != nil {
+		return commandexec.Failure("SCHEMA_INVALID", "failed to load schema", nil, "Fix schema.yaml and try again")
+	}
+
+	result, err := checksvc.ValidateFile(vaultPath, vaultCfg, sch, file, checksvc.Options{
+		ErrorsOnly: boolArg(req.Args, "errors-only"),
+	})
+	if err != nil {
+		var pathErr *checksvc.FilePathError
+		if errors.As(err, &pathErr) {
+			if pathErr.NotFound {
+				return commandexec.Failure("FILE_NOT_FOUND", pathErr.Error(), nil, "Check the path and try again")
+			}
+			return commandexec.Failure("INVALID_INPUT", pathErr.Error(), nil, "Pass a markdown file inside the vault")
+		}
+		return commandexec.Failure("VALIDATION_FAILED", err.Error(), nil, "")
+	}
+
+	data, convErr := structToMap(checksvc.BuildJSON(vaultPath, result))
+	if convErr != nil {
+		return commandexec.Failure("INTERNAL_ERROR", "failed to build validate response", nil, "")
+	}
+	return commandexec.Success(data, nil)
+}
+
 func handleCheckFix(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Schema, result *checksvc.RunResult, confirm bool) commandexec.Result {
 	fixes := checksvc.CollectFixableIssues(result.Issues, result.ShortRefs, sch, vaultCfg)
 	grouped := checksvc.GroupFixesByFile(fixes)
@@ -45,6 +45,10 @@ func HandleConfigSet(_ context.Context, req commandexec.Request) commandexec.Res
 		setReq.EditorMode = &value
 		_ = raw
 	}
+	if _, ok := req.Args["editor-profile"]; ok {
+		value := stringArg(req.Args, "editor-profile")
+		setReq.EditorProfile = &value
+	}
 	if raw, ok := req.Args["state-file"]; ok {
 		value := stringArg(req.Args, "state-file")
 		setReq.StateFile = &value
@@ -91,6 +95,7 @@ func HandleConfigUnset(_ context.Context, req commandexec.Request) commandexec.R
 		ContextOptions:  configContextOptions(req),
 		Editor:          boolArg(req.Args, "editor"),
 		EditorMode:      boolArg(req.Args, "editor-mode"),
+		EditorProfile:   boolArg(req.Args, "editor-profile"),
 		StateFile:       boolArg(req.Args, "state-file"),
 		DefaultVault:    boolArg(req.Args, "default-vault"),
 		UIAccent:        boolArg(req.Args, "ui-accent"),
@@ -12,7 +12,93 @@ import (
 func HandleVaultStats(_ context.Context, req commandexec.Request) commandexec.Result {
 	start := time.Now()
 
-	stats, err := maintsvc.Stats(req.VaultPath)
+	stats, err := maintsvc.Stats(maintsvc.StatsRequest{
+		VaultPath: req.VaultPath,
+		ByType:    boolArg(req.Args, "by-type"),
+		ByTrait:   boolArg(req.Args, "by-trait"),
+	})
+	if err != nil {
+		svcErr, ok := maintsvc.AsError(err)
+		if !ok {
+			return commandexec.Failure("INTERNAL_ERROR", err.Error(), nil, "")
+		}
+		return commandexec.Failure(svcErr.Code, svcErr.Message, nil, svcErr.Suggestion)
+	}
+
+	data := map[string]interface{}{
+		"file_count":   stats.FileCount,
+		"object_count": stats.ObjectCount,
+		"trait_count":  stats.TraitCount,
+		"ref_count":    stats.RefCount,
+		"word_count":   stats.WordCount,
+	}
+	if stats.ObjectsByType != nil {
+		data["objects_by_type"] = stats.ObjectsByType
+	}
+	if stats.TraitsByType != nil {
+		data["traits_by_type"] = stats.TraitsByType
+	}
+	return commandexec.Success(data, &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
+}
+
+// HandleVaultCompact executes the canonical `vault_compact` command.
+func HandleVaultCompact(_ context.Context, req commandexec.Request) commandexec.Result {
+	start := time.Now()
+
+	result, err := maintsvc.Compact(req.VaultPath)
+	if err != nil {
+		svcErr, ok := maintsvc.AsError(err)
+		if !ok {
+			return commandexec.Failure("INTERNAL_ERROR", err.Error(), nil, "")
+		}
+		return commandexec.Failure(svcErr.Code, svcErr.Message, nil, svcErr.Suggestion)
+	}
+
+	return commandexec.Success(map[string]interface{}{
+		"size_before":     result.SizeBefore,
+		"size_after":      result.SizeAfter,
+		"bytes_reclaimed": result.SizeBefore - result.SizeAfter,
+	}, &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
+}
+
+// HandleExport executes the canonical `export` command.
+func HandleExport(_ context.Context, req commandexec.Request) commandexec.Result {
+	start := time.Now()
+
+	result, err := maintsvc.Export(maintsvc.ExportRequest{
+		VaultPath: req.VaultPath,
+		OutPath:   stringArg(req.Args, "out"),
+	})
+	if err != nil {
+		svcErr, ok := maintsvc.AsError(err)
+		if !ok {
+			return commandexec.Failure("INTERNAL_ERROR", err.Error(), nil, "")
+		}
+		return commandexec.Failure(svcErr.Code, svcErr.Message, nil, svcErr.Suggestion)
+	}
+
+	data := map[string]interface{}{
+		"object_count": result.ObjectCount,
+		"trait_count":  result.TraitCount,
+		"ref_count":    result.RefCount,
+		"asset_count":  result.AssetCount,
+	}
+	if result.OutPath != "" {
+		data["out"] = result.OutPath
+	} else {
+		data["export"] = result.Export
+	}
+	return commandexec.Success(data, &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
+}
+
+// HandleRestore executes the canonical `restore` command.
+func HandleRestore(_ context.Context, req commandexec.Request) commandexec.Result {
+	start := time.Now()
+
+	stats, err := maintsvc.Restore(maintsvc.RestoreRequest{
+		VaultPath: req.VaultPath,
+		InPath:    stringArg(req.Args, "file"),
+	})
 	if err != nil {
 		svcErr, ok := maintsvc.AsError(err)
 		if !ok {
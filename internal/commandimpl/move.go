@@ -10,6 +10,7 @@ import (
 	"github.com/aidanlsb/raven/internal/config"
 	"github.com/aidanlsb/raven/internal/objectsvc"
 	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/vault"
 )
 
 // HandleMove executes the canonical `move` command.
@@ -57,7 +58,7 @@ func HandleMove(_ context.Context, req commandexec.Request) commandexec.Result {
 		UpdateRefs:     boolArgDefault(req.Args, "update-refs", true),
 		SkipTypeCheck:  boolArg(req.Args, "skip-type-check"),
 		Preview:        req.Preview,
-		ParseOptions:   buildParseOptions(vaultCfg),
+		ParseOptions:   vault.ParseOptionsFromConfig(vaultCfg),
 		FailOnIndexErr: true,
 	})
 	if err != nil {
@@ -113,7 +114,7 @@ func runMoveBulk(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Sch
 		ObjectIDs:      fileIDs,
 		DestinationDir: destination,
 		UpdateRefs:     updateRefs,
-		ParseOptions:   buildParseOptions(vaultCfg),
+		ParseOptions:   vault.ParseOptionsFromConfig(vaultCfg),
 	}
 
 	if !confirm {
@@ -64,6 +64,7 @@ func HandleNew(_ context.Context, req commandexec.Request) commandexec.Result {
 		PagesRoot:   vaultCfg.GetPagesRoot(),
 		TemplateDir: vaultCfg.GetTemplateDirectory(),
 		TemplateID:  stringArg(req.Args, "template"),
+		Unique:      boolArg(req.Args, "unique"),
 	})
 	if err != nil {
 		return mapContentMutationError(err)
@@ -4,6 +4,8 @@ import (
 	"context"
 	"errors"
 	"fmt"
+	"path/filepath"
+	"sort"
 	"strings"
 	"time"
 
@@ -85,6 +87,11 @@ func HandleQuery(ctx context.Context, req commandexec.Request) commandexec.Resul
 	offset, _ := intArg(req.Args, "offset")
 	idsOnly := boolArg(req.Args, "ids")
 	countOnly := boolArg(req.Args, "count-only")
+	sample, _ := intArg(req.Args, "sample")
+	seed, hasSeed := intArg(req.Args, "seed")
+	if !hasSeed {
+		seed = int(time.Now().UnixNano())
+	}
 
 	if limit < 0 {
 		return commandexec.Failure("INVALID_INPUT", "--limit must be >= 0", nil, "Use --limit 0 for no limit")
@@ -92,12 +99,68 @@ func HandleQuery(ctx context.Context, req commandexec.Request) commandexec.Resul
 	if offset < 0 {
 		return commandexec.Failure("INVALID_INPUT", "--offset must be >= 0", nil, "Use --offset 0 for no offset")
 	}
-	if len(applyArgs) > 0 && (limit > 0 || offset > 0 || countOnly) {
+	if len(applyArgs) > 0 && (limit > 0 || offset > 0 || countOnly || sample > 0) {
 		return commandexec.Failure(
 			"INVALID_INPUT",
-			"--limit, --offset, and --count-only cannot be used with --apply",
+			"--limit, --offset, --count-only, and --sample cannot be used with --apply",
 			nil,
-			"Remove pagination/count-only flags when using --apply",
+			"Remove pagination/count-only/sample flags when using --apply",
+		)
+	}
+	if sample < 0 {
+		return commandexec.Failure("INVALID_INPUT", "--sample must be >= 0", nil, "Use --sample 0 to return all matches")
+	}
+	if hasSeed && sample == 0 {
+		return commandexec.Failure("INVALID_INPUT", "--seed requires --sample", nil, "Add --sample N to draw a seeded random subset")
+	}
+	groupBy := strings.TrimSpace(stringArg(req.Args, "group-by"))
+	if groupBy != "" && groupBy != queryGroupByDir {
+		return commandexec.Failure("INVALID_INPUT", fmt.Sprintf("unknown --group-by value %q", groupBy), nil, "Supported values: dir")
+	}
+	if groupBy != "" && (idsOnly || countOnly || len(applyArgs) > 0) {
+		return commandexec.Failure(
+			"INVALID_INPUT",
+			"--group-by cannot be used with --ids, --count-only, or --apply",
+			nil,
+			"Use --group-by with regular query output",
+		)
+	}
+	sortField := strings.TrimSpace(stringArg(req.Args, "sort"))
+	if sortField != "" && !query.IsPlainFieldName(strings.TrimPrefix(sortField, "-")) {
+		return commandexec.Failure(
+			"INVALID_INPUT",
+			fmt.Sprintf("invalid --sort field %q", sortField),
+			nil,
+			"Use a frontmatter field name made of letters, digits, and underscores; prefix it with '-' to sort descending",
+		)
+	}
+	selectFields, err := parseQuerySelect(stringArg(req.Args, "select"))
+	if err != nil {
+		return commandexec.Failure("INVALID_INPUT", err.Error(), nil, "Pass comma-separated frontmatter field names, e.g. --select title,rating")
+	}
+	if len(selectFields) > 0 && (idsOnly || countOnly || len(applyArgs) > 0 || groupBy != "") {
+		return commandexec.Failure(
+			"INVALID_INPUT",
+			"--select cannot be used with --ids, --count-only, --apply, or --group-by",
+			nil,
+			"Use --select with regular query output",
+		)
+	}
+	if sortField != "" || len(selectFields) > 0 {
+		parsed, err := query.Parse(resolvedQuery)
+		if err != nil {
+			return commandexec.Failure("QUERY_INVALID", fmt.Sprintf("parse error: %v", err), nil, "")
+		}
+		if parsed.Type != query.QueryTypeObject {
+			return commandexec.Failure("INVALID_INPUT", "--select and --sort are only supported for type queries", nil, "Use a query starting with 'type:'")
+		}
+	}
+	if sample > 0 && (limit > 0 || offset > 0 || countOnly) {
+		return commandexec.Failure(
+			"INVALID_INPUT",
+			"--sample cannot be used with --limit, --offset, or --count-only",
+			nil,
+			"Use --sample on its own to pick a random subset of matches",
 		)
 	}
 
@@ -107,6 +170,9 @@ func HandleQuery(ctx context.Context, req commandexec.Request) commandexec.Resul
 		Limit:       limit,
 		Offset:      offset,
 		CountOnly:   countOnly,
+		Sample:      sample,
+		Seed:        int64(seed),
+		Sort:        sortField,
 	})
 	if err != nil {
 		return mapExecuteQueryFailure(resolvedQuery, err)
@@ -161,6 +227,10 @@ func HandleQuery(ctx context.Context, req commandexec.Request) commandexec.Resul
 		} else {
 			data["type"] = result.TypeName
 		}
+		if len(selectFields) > 0 {
+			selectQueryFields(data, selectFields)
+		}
+		addQueryGroups(data, groupBy)
 		return commandexec.Success(data, meta)
 	}
 
@@ -177,6 +247,7 @@ func HandleQuery(ctx context.Context, req commandexec.Request) commandexec.Resul
 		if isSavedQuery && queryName != "" {
 			data["saved_query"] = queryName
 		}
+		addQueryGroups(data, groupBy)
 		return commandexec.Success(data, meta)
 	}
 
@@ -193,6 +264,7 @@ func HandleQuery(ctx context.Context, req commandexec.Request) commandexec.Resul
 		if isSavedQuery && queryName != "" {
 			data["saved_query"] = queryName
 		}
+		addQueryGroups(data, groupBy)
 		return commandexec.Success(data, meta)
 	}
 
@@ -210,6 +282,7 @@ func HandleQuery(ctx context.Context, req commandexec.Request) commandexec.Resul
 	} else {
 		data["trait"] = result.TypeName
 	}
+	addQueryGroups(data, groupBy)
 	return commandexec.Success(data, meta)
 }
 
@@ -395,6 +468,94 @@ func resolveQueryString(queryString string, rawInputs interface{}, vaultCfg *con
 	return resolvedQuery, name, true, nil
 }
 
+const (
+	queryGroupByDir   = "dir"
+	queryRootDirGroup = "(root)"
+)
+
+// addQueryGroups attaches a `groups` summary to query data when grouping is
+// requested. Items keep their query order; groups list item IDs per group.
+func addQueryGroups(data map[string]interface{}, groupBy string) {
+	if groupBy != queryGroupByDir {
+		return
+	}
+	items, _ := data["items"].([]map[string]interface{})
+	data["group_by"] = groupBy
+	data["groups"] = queryDirGroups(items)
+}
+
+// queryDirGroups groups query items by the top-level directory of their
+// file_path. Files at the vault root are grouped under "(root)".
+func queryDirGroups(items []map[string]interface{}) []map[string]interface{} {
+	idsByDir := make(map[string][]string)
+	for _, item := range items {
+		filePath, _ := item["file_path"].(string)
+		dir := topLevelDir(filePath)
+		id, _ := item["id"].(string)
+		idsByDir[dir] = append(idsByDir[dir], id)
+	}
+
+	dirs := make([]string, 0, len(idsByDir))
+	for dir := range idsByDir {
+		dirs = append(dirs, dir)
+	}
+	sort.Strings(dirs)
+
+	groups := make([]map[string]interface{}, 0, len(dirs))
+	for _, dir := range dirs {
+		groups = append(groups, map[string]interface{}{
+			"dir":   dir,
+			"count": len(idsByDir[dir]),
+			"ids":   idsByDir[dir],
+		})
+	}
+	return groups
+}
+
+func topLevelDir(filePath string) string {
+	filePath = strings.TrimPrefix(filepath.ToSlash(filePath), "./")
+	dir, _, found := strings.Cut(filePath, "/")
+	if !found || dir == "" {
+		return queryRootDirGroup
+	}
+	return dir
+}
+
+// parseQuerySelect splits a comma-separated --select value into field names.
+func parseQuerySelect(raw string) ([]string, error) {
+	if strings.TrimSpace(raw) == "" {
+		return nil, nil
+	}
+	var fields []string
+	seen := make(map[string]bool)
+	for _, part := range strings.Split(raw, ",") {
+		field := strings.TrimSpace(part)
+		if !query.IsPlainFieldName(field) {
+			return nil, fmt.Errorf("invalid --select field %q", field)
+		}
+		if !seen[field] {
+			seen[field] = true
+			fields = append(fields, field)
+		}
+	}
+	return fields, nil
+}
+
+// selectQueryFields narrows each object item's fields to the selected names.
+// Missing fields are reported as null so every item has the same keys.
+func selectQueryFields(data map[string]interface{}, selectFields []string) {
+	data["select"] = selectFields
+	items, _ := data["items"].([]map[string]interface{})
+	for _, item := range items {
+		fields, _ := item["fields"].(map[string]interface{})
+		selected := make(map[string]interface{}, len(selectFields))
+		for _, name := range selectFields {
+			selected[name] = fields[name]
+		}
+		item["fields"] = selected
+	}
+}
+
 func objectQueryItems(result *readsvc.ExecuteQueryResult) []map[string]interface{} {
 	items := make([]map[string]interface{}, len(result.Objects))
 	for i, row := range result.Objects {
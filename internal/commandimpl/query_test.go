@@ -0,0 +1,116 @@
+package commandimpl
+
+import (
+	"context"
+	"reflect"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/commandexec"
+	"github.com/aidanlsb/raven/internal/testutil"
+)
+
+func TestQueryDirGroupsGroupsByTopLevelDirectory(t *testing.T) {
+	t.Parallel()
+
+	items := []map[string]interface{}{
+		{"id": "people/freya", "file_path": "people/freya.md"},
+		{"id": "daily/2026-01-02", "file_path": "daily/2026-01-02.md"},
+		{"id": "inbox", "file_path": "inbox.md"},
+		{"id": "people/team/thor", "file_path": "people/team/thor.md"},
+	}
+
+	got := queryDirGroups(items)
+	want := []map[string]interface{}{
+		{"dir": "(root)", "count": 1, "ids": []string{"inbox"}},
+		{"dir": "daily", "count": 1, "ids": []string{"daily/2026-01-02"}},
+		{"dir": "people", "count": 2, "ids": []string{"people/freya", "people/team/thor"}},
+	}
+	if !reflect.DeepEqual(got, want) {
+		t.Fatalf("queryDirGroups() = %#v, want %#v", got, want)
+	}
+}
+
+func TestParseQuerySelectAndSelectQueryFields(t *testing.T) {
+	t.Parallel()
+
+	fields, err := parseQuerySelect(" title, rating ,title")
+	if err != nil {
+		t.Fatalf("parseQuerySelect returned error: %v", err)
+	}
+	if !reflect.DeepEqual(fields, []string{"title", "rating"}) {
+		t.Fatalf("parseQuerySelect() = %v, want [title rating]", fields)
+	}
+	for _, raw := range []string{"title,", "rating desc", "fields.title"} {
+		if _, err := parseQuerySelect(raw); err == nil {
+			t.Errorf("parseQuerySelect(%q) returned nil error", raw)
+		}
+	}
+
+	data := map[string]interface{}{
+		"items": []map[string]interface{}{
+			{"id": "book/dune", "fields": map[string]interface{}{"title": "Dune", "rating": 5, "isbn": "x"}},
+			{"id": "book/notes", "fields": map[string]interface{}{"title": "Notes"}},
+		},
+	}
+	selectQueryFields(data, fields)
+	items := data["items"].([]map[string]interface{})
+	if got, want := items[0]["fields"], map[string]interface{}{"title": "Dune", "rating": 5}; !reflect.DeepEqual(got, want) {
+		t.Fatalf("first item fields = %#v, want %#v", got, want)
+	}
+	if got, want := items[1]["fields"], map[string]interface{}{"title": "Notes", "rating": nil}; !reflect.DeepEqual(got, want) {
+		t.Fatalf("second item fields = %#v, want %#v", got, want)
+	}
+}
+
+func TestHandleQueryRejectsSeedWithoutSample(t *testing.T) {
+	t.Parallel()
+
+	v := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("people/freya.md", "---\ntype: person\nname: Freya\n---\n").
+		Build()
+	reindexForEditTest(t, v.Path)
+
+	result := HandleQuery(context.Background(), commandexec.Request{
+		VaultPath: v.Path,
+		Args:      map[string]any{"query_string": "type:person", "seed": 7},
+	})
+	if result.OK || result.Error == nil || result.Error.Code != "INVALID_INPUT" {
+		t.Fatalf("HandleQuery() = %#v, want INVALID_INPUT for --seed without --sample", result)
+	}
+
+	result = HandleQuery(context.Background(), commandexec.Request{
+		VaultPath: v.Path,
+		Args:      map[string]any{"query_string": "type:person", "seed": 7, "sample": 1},
+	})
+	if !result.OK {
+		t.Fatalf("HandleQuery() with --sample failed: %#v", result.Error)
+	}
+}
+
+func TestHandleQuerySortAndSelectUseParsedQueryKind(t *testing.T) {
+	t.Parallel()
+
+	v := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithRavenYAML("queries:\n  people:\n    query: 'type:person'\n").
+		WithFile("people/freya.md", "---\ntype: person\nname: Freya\n---\n# Notes\n").
+		Build()
+	reindexForEditTest(t, v.Path)
+
+	result := HandleQuery(context.Background(), commandexec.Request{
+		VaultPath: v.Path,
+		Args:      map[string]any{"query_string": "type:section", "select": "title"},
+	})
+	if result.OK || result.Error == nil || result.Error.Code != "INVALID_INPUT" {
+		t.Fatalf("HandleQuery() = %#v, want INVALID_INPUT for --select on a section query", result)
+	}
+
+	result = HandleQuery(context.Background(), commandexec.Request{
+		VaultPath: v.Path,
+		Args:      map[string]any{"query_string": "people", "sort": "name"},
+	})
+	if !result.OK {
+		t.Fatalf("HandleQuery() with --sort on a saved type query failed: %#v", result.Error)
+	}
+}
@@ -11,9 +11,11 @@ import (
 
 	"github.com/aidanlsb/raven/internal/commandexec"
 	"github.com/aidanlsb/raven/internal/configsvc"
+	"github.com/aidanlsb/raven/internal/index"
 	"github.com/aidanlsb/raven/internal/model"
 	"github.com/aidanlsb/raven/internal/readsvc"
 	"github.com/aidanlsb/raven/internal/vault"
+	"github.com/aidanlsb/raven/internal/wikilink"
 )
 
 // HandleSearch executes the canonical `search` command.
@@ -80,8 +82,20 @@ func HandleBacklinks(_ context.Context, req commandexec.Request) commandexec.Res
 	}
 	defer rt.Close()
 
+	depth := 1
+	if value, ok := intArg(req.Args, "depth"); ok {
+		if value < 1 || value > index.MaxBacklinkDepth {
+			return commandexec.Failure("INVALID_INPUT", fmt.Sprintf("--depth must be between 1 and %d", index.MaxBacklinkDepth), nil, "")
+		}
+		depth = value
+	}
+	sourceType := strings.TrimSpace(stringArg(req.Args, "type"))
+	if sourceType != "" && depth > 1 {
+		return commandexec.Failure("INVALID_INPUT", "--type cannot be combined with --depth greater than 1", nil, "")
+	}
+
 	if backlinksStdinMode(req.Args) {
-		return handleBacklinksStdin(rt, req, start)
+		return handleBacklinksStdin(rt, req, depth, sourceType, start)
 	}
 
 	reference := stringArg(req.Args, "target")
@@ -93,7 +107,7 @@ func HandleBacklinks(_ context.Context, req commandexec.Request) commandexec.Res
 		return mapResolveFailure(err, reference)
 	}
 
-	links, err := readsvc.Backlinks(rt, resolved.ObjectID)
+	links, err := readsvc.BacklinksWithContext(rt, resolved.ObjectID, depth, sourceType)
 	if err != nil {
 		return commandexec.Failure("DATABASE_ERROR", fmt.Sprintf("failed to read backlinks: %v", err), nil, "")
 	}
@@ -127,7 +141,7 @@ func HandleOutlinks(_ context.Context, req commandexec.Request) commandexec.Resu
 		return mapResolveFailure(err, reference)
 	}
 
-	links, err := readsvc.Outlinks(rt, resolved.ObjectID)
+	links, err := readsvc.Outlinks(rt, resolved.ObjectID, boolArg(req.Args, "direct"))
 	if err != nil {
 		return commandexec.Failure("DATABASE_ERROR", fmt.Sprintf("failed to read outlinks: %v", err), nil, "")
 	}
@@ -138,7 +152,58 @@ func HandleOutlinks(_ context.Context, req commandexec.Request) commandexec.Resu
 	}, &commandexec.Meta{Count: len(links), QueryTimeMs: time.Since(start).Milliseconds()})
 }
 
-func handleBacklinksStdin(rt *readsvc.Runtime, req commandexec.Request, start time.Time) commandexec.Result {
+// HandleRecent executes the canonical `recent` command.
+func HandleRecent(_ context.Context, req commandexec.Request) commandexec.Result {
+	start := time.Now()
+
+	limit, ok := intArg(req.Args, "limit")
+	if !ok {
+		limit = 10
+	}
+	if limit < 0 {
+		return commandexec.Failure("INVALID_INPUT", "--limit must be >= 0", nil, "Use --limit 0 for no limit")
+	}
+
+	rt, failure := newReadRuntime(req.VaultPath, readsvc.RuntimeOptions{OpenDB: true})
+	if failure.Error != nil {
+		return failure
+	}
+	defer rt.Close()
+
+	items, err := rt.DB.RecentlyModified(limit)
+	if err != nil {
+		return commandexec.Failure("DATABASE_ERROR", fmt.Sprintf("failed to read recent objects: %v", err), nil, "")
+	}
+
+	return commandexec.Success(map[string]interface{}{
+		"items": items,
+	}, &commandexec.Meta{Count: len(items), QueryTimeMs: time.Since(start).Milliseconds()})
+}
+
+// HandleUntyped executes the canonical `untyped` command.
+func HandleUntyped(_ context.Context, req commandexec.Request) commandexec.Result {
+	start := time.Now()
+
+	rt, failure := newReadRuntime(req.VaultPath, readsvc.RuntimeOptions{OpenDB: true})
+	if failure.Error != nil {
+		return failure
+	}
+	defer rt.Close()
+
+	files, err := rt.DB.UntypedPages(boolArg(req.Args, "implicit-only"))
+	if err != nil {
+		return commandexec.Failure("DATABASE_ERROR", fmt.Sprintf("failed to read untyped pages: %v", err), nil, "")
+	}
+	if files == nil {
+		files = []string{}
+	}
+
+	return commandexec.Success(map[string]interface{}{
+		"files": files,
+	}, &commandexec.Meta{Count: len(files), QueryTimeMs: time.Since(start).Milliseconds()})
+}
+
+func handleBacklinksStdin(rt *readsvc.Runtime, req commandexec.Request, depth int, sourceType string, start time.Time) commandexec.Result {
 	targets := stringSliceArg(req.Args["targets"])
 	if len(targets) == 0 {
 		return commandexec.Failure("MISSING_ARGUMENT", "no targets provided via stdin", nil, "Pipe targets to stdin, one per line")
@@ -153,7 +218,7 @@ func handleBacklinksStdin(rt *readsvc.Runtime, req commandexec.Request, start ti
 			errors = append(errors, referenceInputError(target, mapResolveFailure(err, target)))
 			continue
 		}
-		links, err := readsvc.Backlinks(rt, resolved.ObjectID)
+		links, err := readsvc.BacklinksWithContext(rt, resolved.ObjectID, depth, sourceType)
 		if err != nil {
 			errors = append(errors, referenceInputError(target, commandexec.Failure("DATABASE_ERROR", fmt.Sprintf("failed to read backlinks: %v", err), nil, "")))
 			continue
@@ -195,7 +260,7 @@ func handleOutlinksStdin(rt *readsvc.Runtime, req commandexec.Request, start tim
 			errors = append(errors, referenceInputError(source, mapResolveFailure(err, source)))
 			continue
 		}
-		links, err := readsvc.Outlinks(rt, resolved.ObjectID)
+		links, err := readsvc.Outlinks(rt, resolved.ObjectID, boolArg(req.Args, "direct"))
 		if err != nil {
 			errors = append(errors, referenceInputError(source, commandexec.Failure("DATABASE_ERROR", fmt.Sprintf("failed to read outlinks: %v", err), nil, "")))
 			continue
@@ -249,7 +314,18 @@ func HandleResolve(_ context.Context, req commandexec.Request) commandexec.Resul
 	}
 	defer rt.Close()
 
-	resolved, err := readsvc.ResolveReferenceWithDynamicDates(reference, rt, true)
+	// Accept a pasted wikilink literal ([[target]] or [[target|display]]).
+	target := reference
+	if linkTarget, _, ok := wikilink.ParseExact(reference); ok {
+		target = linkTarget
+	}
+
+	explain := boolArg(req.Args, "explain")
+	resolve := readsvc.ResolveReferenceWithDynamicDates
+	if explain {
+		resolve = readsvc.ExplainReferenceWithDynamicDates
+	}
+	resolved, err := resolve(target, rt, true)
 
 	var ambiguousErr *readsvc.AmbiguousRefError
 	if errors.As(err, &ambiguousErr) {
@@ -264,19 +340,28 @@ func HandleResolve(_ context.Context, req commandexec.Request) commandexec.Resul
 			matches = append(matches, entry)
 		}
 
-		return commandexec.Success(map[string]interface{}{
+		data := map[string]interface{}{
 			"resolved":  false,
 			"ambiguous": true,
 			"reference": reference,
 			"matches":   matches,
-		}, &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
+		}
+		if explain {
+			data["trace"] = ambiguousErr.Trace
+		}
+		return commandexec.Success(data, &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
 	}
 
 	if err != nil {
-		return commandexec.Success(map[string]interface{}{
+		data := map[string]interface{}{
 			"resolved":  false,
 			"reference": reference,
-		}, &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
+		}
+		var notFoundErr *readsvc.RefNotFoundError
+		if explain && errors.As(err, &notFoundErr) {
+			data["trace"] = notFoundErr.Trace
+		}
+		return commandexec.Success(data, &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
 	}
 
 	relPath := resolved.FilePath
@@ -285,9 +370,15 @@ func HandleResolve(_ context.Context, req commandexec.Request) commandexec.Resul
 	}
 
 	objectType := ""
+	var objectFields map[string]interface{}
+	var fieldOrder []string
+	objectLine := 0
 	if rt.DB != nil {
 		if obj, objErr := rt.DB.GetObject(resolved.ObjectID); objErr == nil && obj != nil {
 			objectType = obj.Type
+			objectFields = obj.Fields
+			fieldOrder = obj.FieldOrder
+			objectLine = obj.LineStart
 		}
 	}
 
@@ -300,9 +391,40 @@ func HandleResolve(_ context.Context, req commandexec.Request) commandexec.Resul
 	if objectType != "" {
 		data["type"] = objectType
 	}
+	if objectFields != nil {
+		data["fields"] = objectFields
+		if len(fieldOrder) > 0 {
+			data["field_order"] = fieldOrder
+		}
+	}
 	if resolved.MatchSource != "" {
 		data["match_source"] = resolved.MatchSource
 	}
+	if explain {
+		data["trace"] = resolved.Trace
+	}
+	if !resolved.IsSection && objectLine > 0 {
+		data["line_start"] = objectLine
+	}
+	if parentID := resolvedParentID(rt.DB, resolved); parentID != "" {
+		data["parent"] = parentID
+	}
+	if rt.DB != nil {
+		if traits, traitErr := rt.DB.TraitsInFiles([]string{relPath}); traitErr == nil {
+			objectTraits := make([]model.Trait, 0, len(traits))
+			for _, trait := range traits {
+				if trait.ParentObjectID == resolved.ObjectID {
+					objectTraits = append(objectTraits, trait)
+				}
+			}
+			data["traits"] = objectTraits
+		}
+	}
+	if boolArg(req.Args, "body") && resolvedHasBody(resolved) {
+		if body, bodyErr := readsvc.ResolvedBody(resolved); bodyErr == nil {
+			data["body"] = body
+		}
+	}
 	if resolved.IsSection {
 		data["file_object_id"] = resolved.FileObjectID
 		if resolved.LineStart > 0 {
@@ -320,6 +442,36 @@ func HandleResolve(_ context.Context, req commandexec.Request) commandexec.Resul
 	return commandexec.Success(data, &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
 }
 
+// resolvedHasBody reports whether a resolved reference has markdown body
+// content: assets never do, and sections need an indexed line range.
+func resolvedHasBody(resolved *readsvc.ResolveResult) bool {
+	if resolved.IsAsset {
+		return false
+	}
+	return !resolved.IsSection || resolved.LineStart > 0
+}
+
+// resolvedParentID returns the object a resolved reference is embedded in: the
+// enclosing section for nested headings, otherwise the file object. File
+// objects have no parent.
+func resolvedParentID(db *index.Database, resolved *readsvc.ResolveResult) string {
+	if db != nil {
+		if section, err := db.GetSection(resolved.ObjectID); err == nil && section != nil {
+			if section.ParentSectionID != nil && *section.ParentSectionID != "" {
+				return *section.ParentSectionID
+			}
+			return section.FileObjectID
+		}
+	}
+	if resolved.IsSection {
+		return resolved.FileObjectID
+	}
+	if fileID, _, ok := strings.Cut(resolved.ObjectID, "#"); ok {
+		return fileID
+	}
+	return ""
+}
+
 // HandleRead executes the canonical `read` command.
 func HandleRead(_ context.Context, req commandexec.Request) commandexec.Result {
 	start := time.Now()
@@ -431,6 +583,42 @@ func HandleOpen(_ context.Context, req commandexec.Request) commandexec.Result {
 		return commandexec.Failure("MISSING_ARGUMENT", "requires reference argument", nil, "Usage: rvn open <reference>")
 	}
 
+	if boolArg(req.Args, "backlink") {
+		picked, target, err := readsvc.ResolveBacklinkOpenTarget(rt, reference)
+		if err != nil {
+			if target != nil {
+				return commandexec.Failure("DATABASE_ERROR", fmt.Sprintf("failed to read backlinks: %v", err), nil, "Run 'rvn reindex' to rebuild the database")
+			}
+			return mapOpenFailure(err)
+		}
+		if picked == nil {
+			return commandexec.Success(map[string]interface{}{
+				"target_object_id": target.ObjectID,
+				"backlink_count":   0,
+				"opened":           false,
+			}, &commandexec.Meta{Count: 0})
+		}
+		opened := vault.OpenInEditorAtLine(cfg, picked.FilePath, picked.LineStart)
+		if opened {
+			if err := readsvc.SaveBacklinkOpenCursor(rt, picked); err != nil {
+				return commandexec.Failure("FILE_WRITE_ERROR", fmt.Sprintf("failed to save backlink cursor: %v", err), nil, "")
+			}
+		}
+		data := map[string]interface{}{
+			"object_id":        picked.ObjectID,
+			"file":             picked.RelativePath,
+			"target_object_id": picked.TargetObjectID,
+			"position":         picked.Position,
+			"backlink_count":   picked.BacklinkCount,
+			"opened":           opened,
+			"editor":           editor,
+		}
+		if picked.LineStart > 0 {
+			data["line_number"] = picked.LineStart
+		}
+		return commandexec.Success(data, nil)
+	}
+
 	target, err := readsvc.ResolveOpenTarget(rt, reference)
 	if err != nil {
 		return mapOpenFailure(err)
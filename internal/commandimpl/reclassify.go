@@ -9,6 +9,7 @@ import (
 	"github.com/aidanlsb/raven/internal/config"
 	"github.com/aidanlsb/raven/internal/objectsvc"
 	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/vault"
 )
 
 // HandleReclassify executes the canonical `reclassify` command.
@@ -49,7 +50,7 @@ func HandleReclassify(_ context.Context, req commandexec.Request) commandexec.Re
 		NoMove:       boolArg(req.Args, "no-move"),
 		UpdateRefs:   boolArgDefault(req.Args, "update-refs", true),
 		Force:        boolArg(req.Args, "force"),
-		ParseOptions: buildParseOptions(vaultCfg),
+		ParseOptions: vault.ParseOptionsFromConfig(vaultCfg),
 	})
 	if err != nil {
 		return mapContentMutationError(err)
@@ -21,11 +21,15 @@ func RegisterAll(registry *commandexec.HandlerRegistry) {
 	registry.Register("import", HandleImport)
 	registry.Register("init", HandleInit)
 	registry.Register("reindex", HandleReindex)
+	registry.Register("diff_index", HandleDiffIndex)
 	registry.Register("check", HandleCheck)
 	registry.Register("check_fix", HandleCheckFix)
 	registry.Register("check create-missing", HandleCheckCreateMissing)
+	registry.Register("validate", HandleValidate)
 	registry.Register("daily", HandleDaily)
 	registry.Register("date", HandleDate)
+	registry.Register("upcoming", HandleUpcoming)
+	registry.Register("rollup", HandleRollup)
 	registry.Register("version", HandleVersion)
 	registry.Register("config_show", HandleConfigShow)
 	registry.Register("config_init", HandleConfigInit)
@@ -58,6 +62,9 @@ func RegisterAll(registry *commandexec.HandlerRegistry) {
 	registry.Register("vault_config_deletion_set", HandleVaultConfigDeletionSet)
 	registry.Register("vault_config_deletion_unset", HandleVaultConfigDeletionUnset)
 	registry.Register("vault_stats", HandleVaultStats)
+	registry.Register("vault_compact", HandleVaultCompact)
+	registry.Register("export", HandleExport)
+	registry.Register("restore", HandleRestore)
 	registry.Register("search", HandleSearch)
 	registry.Register("read", HandleRead)
 	registry.Register("open", HandleOpen)
@@ -76,8 +83,12 @@ func RegisterAll(registry *commandexec.HandlerRegistry) {
 	registry.Register("skill_doctor", HandleSkillDoctor)
 	registry.Register("backlinks", HandleBacklinks)
 	registry.Register("outlinks", HandleOutlinks)
+	registry.Register("recent", HandleRecent)
+	registry.Register("untyped", HandleUntyped)
 	registry.Register("resolve", HandleResolve)
 	registry.Register("schema", HandleSchema)
+	registry.Register("fields", HandleFields)
+	registry.Register("suggest_schema", HandleSuggestSchema)
 	registry.Register("schema_validate", HandleSchemaValidate)
 	registry.Register("schema_add_type", HandleSchemaAddType)
 	registry.Register("schema_add_trait", HandleSchemaAddTrait)
@@ -15,22 +15,13 @@ import (
 	"github.com/aidanlsb/raven/internal/index"
 	"github.com/aidanlsb/raven/internal/parser"
 	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/vault"
 )
 
 const indexUpdateFailedWarningCode = codes.WarnIndexUpdateFailed
 
 const indexUpdateFailedWarningRef = "The write succeeded, but the derived index may be stale. Run 'rvn reindex' to refresh it."
 
-func buildParseOptions(vaultCfg *config.VaultConfig) *parser.ParseOptions {
-	if vaultCfg == nil {
-		return nil
-	}
-	return &parser.ParseOptions{
-		ObjectsRoot: vaultCfg.GetObjectsRoot(),
-		PagesRoot:   vaultCfg.GetPagesRoot(),
-	}
-}
-
 func autoReindexWarnings(vaultPath string, vaultCfg *config.VaultConfig, filePaths ...string) []commandexec.Warning {
 	if vaultCfg == nil || !vaultCfg.IsAutoReindexEnabled() {
 		return nil
@@ -66,7 +57,7 @@ func autoReindexWarning(vaultPath, filePath string, vaultCfg *config.VaultConfig
 		return indexUpdateWarning(vaultPath, filePath, "failed to read file", err), true
 	}
 
-	doc, err := parser.ParseDocumentWithOptions(string(content), filePath, vaultPath, buildParseOptions(vaultCfg))
+	doc, err := parser.ParseDocumentWithOptions(string(content), filePath, vaultPath, vault.ParseOptionsFromConfig(vaultCfg))
 	if err != nil {
 		return indexUpdateWarning(vaultPath, filePath, "failed to parse file", err), true
 	}
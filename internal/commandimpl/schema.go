@@ -581,6 +581,30 @@ func HandleTemplateDelete(_ context.Context, req commandexec.Request) commandexe
 	return commandexec.Success(data, &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
 }
 
+// HandleFields executes the canonical `fields` command.
+func HandleFields(_ context.Context, req commandexec.Request) commandexec.Result {
+	result, err := schemasvc.ObservedFields(req.VaultPath, stringArg(req.Args, "type"))
+	if err != nil {
+		return mapSchemaFailure(err)
+	}
+	return commandexec.Success(map[string]interface{}{
+		"type":   result.Type,
+		"fields": result.Fields,
+	}, &commandexec.Meta{Count: len(result.Fields)})
+}
+
+// HandleSuggestSchema executes the canonical `suggest_schema` command.
+func HandleSuggestSchema(_ context.Context, req commandexec.Request) commandexec.Result {
+	result, err := schemasvc.SuggestSchema(req.VaultPath)
+	if err != nil {
+		return mapSchemaFailure(err)
+	}
+	return commandexec.Success(map[string]interface{}{
+		"types": result.Types,
+		"yaml":  result.YAML,
+	}, &commandexec.Meta{Count: len(result.Types)})
+}
+
 func mapSchemaFailure(err error) commandexec.Result {
 	var svcErr *schemasvc.Error
 	if errors.As(err, &svcErr) {
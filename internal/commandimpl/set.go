@@ -11,6 +11,7 @@ import (
 	"github.com/aidanlsb/raven/internal/fieldmutation"
 	"github.com/aidanlsb/raven/internal/objectsvc"
 	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/vault"
 )
 
 func setFieldsJSONHint(caller commandexec.Caller) string {
@@ -103,7 +104,7 @@ func HandleSet(_ context.Context, req commandexec.Request) commandexec.Result {
 		Schema:       sch,
 		Reference:    reference,
 		TypedUpdates: allUpdates,
-		ParseOptions: buildParseOptions(vaultCfg),
+		ParseOptions: vault.ParseOptionsFromConfig(vaultCfg),
 		Preview:      req.Preview,
 	})
 	if err != nil {
@@ -178,7 +179,7 @@ func HandleUnset(_ context.Context, req commandexec.Request) commandexec.Result
 		Schema:       sch,
 		Reference:    reference,
 		Fields:       fields,
-		ParseOptions: buildParseOptions(vaultCfg),
+		ParseOptions: vault.ParseOptionsFromConfig(vaultCfg),
 	})
 	if err != nil {
 		return mapContentMutationError(err)
@@ -207,7 +208,7 @@ func runSetBulk(vaultPath string, vaultCfg *config.VaultConfig, sch *schema.Sche
 		Schema:       sch,
 		ObjectIDs:    ids,
 		TypedUpdates: updates,
-		ParseOptions: buildParseOptions(vaultCfg),
+		ParseOptions: vault.ParseOptionsFromConfig(vaultCfg),
 	}
 	serializedUpdates := fieldmutation.SerializeFieldValueMap(updates)
 
@@ -68,6 +68,7 @@ func HandleReindex(ctx context.Context, req commandexec.Request) commandexec.Res
 	start := time.Now()
 	result, err := reindexsvc.Run(reindexsvc.RunRequest{
 		VaultPath: vaultPath,
+		Path:      strings.TrimSpace(stringArg(req.Args, "path")),
 		Full:      boolArg(req.Args, "full"),
 		DryRun:    boolArg(req.Args, "dry-run"),
 		Context:   ctx,
@@ -91,6 +92,26 @@ func HandleReindex(ctx context.Context, req commandexec.Request) commandexec.Res
 	return commandexec.SuccessWithWarnings(result.Data(), warnings, &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
 }
 
+// HandleDiffIndex executes the canonical `diff_index` command.
+func HandleDiffIndex(_ context.Context, req commandexec.Request) commandexec.Result {
+	vaultPath := strings.TrimSpace(req.VaultPath)
+	if vaultPath == "" {
+		return commandexec.Failure("INVALID_INPUT", "vault path is required", nil, "Resolve a vault before invoking the command")
+	}
+
+	start := time.Now()
+	result, err := reindexsvc.Diff(reindexsvc.DiffRequest{VaultPath: vaultPath})
+	if err != nil {
+		svcErr, ok := reindexsvc.AsError(err)
+		if !ok {
+			return commandexec.Failure("INTERNAL_ERROR", err.Error(), nil, "")
+		}
+		return commandexec.Failure(svcErr.Code, svcErr.Message, nil, svcErr.Suggestion)
+	}
+
+	return commandexec.Success(result.Data(), &commandexec.Meta{QueryTimeMs: time.Since(start).Milliseconds()})
+}
+
 // HandleDaily executes the canonical `daily` command.
 func HandleDaily(_ context.Context, req commandexec.Request) commandexec.Result {
 	vaultPath := strings.TrimSpace(req.VaultPath)
@@ -146,6 +167,56 @@ func HandleDate(_ context.Context, req commandexec.Request) commandexec.Result {
 	return commandexec.Success(data, &commandexec.Meta{Count: len(result.Items)})
 }
 
+// HandleUpcoming executes the canonical `upcoming` command.
+func HandleUpcoming(_ context.Context, req commandexec.Request) commandexec.Result {
+	vaultPath := strings.TrimSpace(req.VaultPath)
+	if vaultPath == "" {
+		return commandexec.Failure("INVALID_INPUT", "vault path is required", nil, "Resolve a vault before invoking the command")
+	}
+
+	days, _ := intArg(req.Args, "days")
+	result, err := datesvc.Upcoming(datesvc.UpcomingRequest{
+		VaultPath:   vaultPath,
+		Days:        days,
+		IncludeDone: boolArg(req.Args, "include-done"),
+	})
+	if err != nil {
+		return mapDateServiceError(err)
+	}
+
+	return commandexec.Success(map[string]interface{}{
+		"from": result.From,
+		"to":   result.To,
+		"days": result.Days,
+	}, &commandexec.Meta{Count: result.Count})
+}
+
+// HandleRollup executes the canonical `rollup` command.
+func HandleRollup(_ context.Context, req commandexec.Request) commandexec.Result {
+	vaultPath := strings.TrimSpace(req.VaultPath)
+	if vaultPath == "" {
+		return commandexec.Failure("INVALID_INPUT", "vault path is required", nil, "Resolve a vault before invoking the command")
+	}
+
+	result, err := datesvc.Rollup(datesvc.RollupRequest{
+		VaultPath: vaultPath,
+		Week:      stringArg(req.Args, "week"),
+		Month:     stringArg(req.Args, "month"),
+	})
+	if err != nil {
+		return mapDateServiceError(err)
+	}
+
+	return commandexec.Success(map[string]interface{}{
+		"period":   result.Period,
+		"from":     result.From,
+		"to":       result.To,
+		"notes":    result.Notes,
+		"traits":   result.Traits,
+		"mentions": result.Mentions,
+	}, &commandexec.Meta{Count: len(result.Notes)})
+}
+
 // HandleVersion executes the canonical `version` command.
 func HandleVersion(_ context.Context, req commandexec.Request) commandexec.Result {
 	info := versioninfo.Current()
@@ -7,6 +7,7 @@ package commands
 // generate both Cobra commands and MCP tool schemas.
 type Meta struct {
 	Name                string     // Command name (e.g., "trait", "add", "new")
+	Aliases             []string   // Alternate CLI names for the command (e.g., "links" for "outlinks")
 	Use                 string     // Cobra-style usage string for this command (local, not full invocation)
 	Description         string     // Short description
 	LongDesc            string     // Long description (for --help)
@@ -111,6 +112,10 @@ providing arrays/nulls explicitly.
 Use --path to explicitly control the object path. Titles are treated as display
 names and must not include path separators.
 
+If the target file already exists, new fails with FILE_EXISTS. Pass --unique to
+create the next free suffixed path instead (alice-chen-2.md, alice-chen-3.md, ...);
+the title and name_field keep the unsuffixed value.
+
 If the type has a name_field configured (e.g., name_field: name), the title
 argument automatically populates that field. This means for a person type with
 name_field: name, you can just call: rvn new person "Freya" --json
@@ -135,6 +140,7 @@ CLI offers to create the missing pages; agents can run 'rvn check create-missing
 			{Name: "field-json", Description: "Set/update frontmatter fields as a JSON object with exact typed values", Type: FlagTypeJSON},
 			{Name: "path", Description: "Explicit target path (overrides title-derived path)", Type: FlagTypeString, Examples: []string{"people/freya-2026", "note/raven-friction"}},
 			{Name: "template", Description: "Type template ID to use for object creation", Type: FlagTypeString, Examples: []string{"interview_technical", "interview_screen"}},
+			{Name: "unique", Description: "If the target file exists, create a -2, -3, ... suffixed file instead of failing", Type: FlagTypeBool},
 		},
 		Examples: []string{
 			"rvn new person \"Freya\" --json",
@@ -623,6 +629,11 @@ Single-object move:
 Applies immediately when invoked (CLI JSON and MCP). Pass --dry-run to preview the
 move and the references it would update without applying.
 
+Section move:
+A section ID (file#slug) as the source extracts that heading and its subsections
+into the destination file, creating it or appending to it if it exists. References
+to the moved sections are rewritten to their new location.
+
 Bulk operations:
 Use --stdin to read object IDs from stdin (one per line).
 Destination must be a directory (ending with /).
@@ -645,12 +656,14 @@ IMPORTANT: Bulk operations return preview by default. Changes are NOT applied un
 			"rvn move inbox/task.md projects/website/task.md --json",
 			"rvn move drafts/person.md people/freya.md --update-refs --json",
 			"rvn move assets/pdfs/paper.pdf assets/pdfs/archive/paper.pdf --json",
+			"rvn move daily/2026-01-10#standup meetings/standup --json",
 		},
 		UseCases: []string{
 			"Rename a file in place (NEVER use 'mv' shell command)",
 			"Move file to different directory with reference updates",
 			"Reorganize vault structure while keeping links intact",
 			"Archive old content without breaking references",
+			"Extract a section into its own file",
 		},
 	},
 	"reclassify": {
@@ -742,8 +755,10 @@ Common agent patterns:
 - Open todos in a daily-note range: trait:todo .value==todo within(type:date .date>=2026-05-01 .date<=2026-05-31)
 - Path + structure together: type:page matches(.path, "^pages/work/") has(trait:todo .value==todo)
 
-Special date values for trait and type:date .date comparisons:
+Special date values for trait, date field, and type:date .date comparisons:
 - today, tomorrow, yesterday
+- Ranges: this-week, last-week, next-week, this-month, last-month, next-month,
+  this-year, last-year, next-year, last-N-days, next-N-days (e.g. .date==this-month)
 
 Saved query inputs must be declared in the saved query definition when using {{args.<name>}}.
 You can then pass inputs by position (in args order) or as key=value pairs.
@@ -751,6 +766,10 @@ You can then pass inputs by position (in args order) or as key=value pairs.
 Use --ids to output just IDs (one per line) for piping to other commands.
 Use --limit/--offset for paginated result windows.
 Use --count-only to return only the total match count without items.
+Use --sample N to return a random subset of matches; add --seed to make the
+selection reproducible across runs.
+Use --group-by dir to group results by top-level directory ("(root)" for
+files at the vault root).
 Use --browse to open an interactive Raven picker with filtering and editor
 handoff for the selected result.
 Use --apply to run a bulk operation directly on query results.
@@ -773,6 +792,11 @@ For trait queries (trait:...):
 			{Name: "limit", Description: "Maximum number of query results to return (0 means no limit)", Type: FlagTypeInt},
 			{Name: "offset", Description: "Zero-based offset for query results", Type: FlagTypeInt},
 			{Name: "count-only", Description: "Return only the total count of matches (no items or IDs)", Type: FlagTypeBool},
+			{Name: "sample", Description: "Return a random subset of N matches (in query order)", Type: FlagTypeInt},
+			{Name: "seed", Description: "Seed for --sample (requires --sample); the same seed returns the same subset", Type: FlagTypeInt},
+			{Name: "group-by", Description: "Group results by a derived key (supported: dir, the top-level directory of each file)", Type: FlagTypeString},
+			{Name: "select", Description: "Comma-separated frontmatter fields to output as table columns (type queries only)", Type: FlagTypeString},
+			{Name: "sort", Description: "Sort type query results by a frontmatter field; prefix with '-' for descending", Type: FlagTypeString},
 			{Name: "apply", Description: "Apply bulk operation to results (e.g., 'set status=done', 'delete', 'add @reviewed', 'update done')", Type: FlagTypeStringSlice},
 			{Name: "confirm", Description: "Apply bulk changes (without this flag, shows preview only)", Type: FlagTypeBool},
 			{Name: "pipe", Description: "Force pipe-friendly output for shell pipelines (jq, head, sort)", Type: FlagTypeBool},
@@ -783,6 +807,7 @@ For trait queries (trait:...):
 		Examples: []string{
 			"rvn query 'type:project .status==active' --json",
 			"rvn query 'type:meeting has(trait:due)' --json",
+			"rvn query 'type:book' --select title,rating --sort=-rating --json",
 			"rvn query 'trait:due .value<today' --json",
 			"rvn query 'asset .extension==pdf' --json",
 			"rvn query 'asset startswith(.media_type, \"image/\")' --json",
@@ -869,6 +894,11 @@ For trait queries (trait:...):
 
 In an interactive terminal, bare 'rvn backlinks' launches Raven's picker
 over indexed object, section, and asset references.
+Each backlink includes the trimmed source line it appears on as context.
+Use --depth 2 (up to 5) to include notes that link to the backlinking notes;
+each result carries its hop distance as depth.
+Use --type to keep only links coming from objects of one type; links from a
+section count as coming from the type of the note that contains it.
 When an interactive backlinks target is ambiguous, Raven prompts you to choose the target.
 Use --browse to browse incoming references interactively and open the selected reference location.
 Use --stdin to read targets from stdin and return grouped results for each target.
@@ -879,11 +909,15 @@ Non-interactive use requires either a target or --stdin input.`,
 		Flags: []FlagMeta{
 			{Name: "browse", Description: "Interactively browse backlinks in Raven's picker and open the selected reference", Type: FlagTypeBool},
 			{Name: "stdin", Description: "Read targets from stdin and return grouped backlinks", Type: FlagTypeBool},
+			{Name: "depth", Description: "Also follow backlinks of backlinking notes up to this many hops (max 5)", Type: FlagTypeInt, Default: "1"},
+			{Name: "type", Description: "Only include links from objects of this type (cannot be combined with --depth)", Type: FlagTypeString},
 		},
 		BulkStdinArgName: "targets",
 		Examples: []string{
 			"rvn backlinks people/freya --json",
 			"rvn backlinks people/freya --browse",
+			"rvn backlinks projects/website --depth 2",
+			"rvn backlinks people/freya --type meeting",
 			"rvn backlinks assets/pdfs/paper.pdf --json",
 			"rvn query 'type:project .status==active' --ids | rvn backlinks --stdin --json",
 		},
@@ -898,6 +932,7 @@ Non-interactive use requires either a target or --stdin input.`,
 	},
 	"outlinks": {
 		Name:        "outlinks",
+		Aliases:     []string{"links"},
 		Use:         "outlinks [source]",
 		Description: "Find object and asset links referenced by an object",
 		LongDesc: `Find object and asset links referenced by an object.
@@ -907,17 +942,23 @@ over indexed object and section references.
 When an interactive outlinks source is ambiguous, Raven prompts you to choose the source.
 Use --browse to browse outgoing references interactively and open the selected reference location.
 Use --stdin to read sources from stdin and return grouped results for each source.
-Non-interactive use requires either a source or --stdin input.`,
+Non-interactive use requires either a source or --stdin input.
+
+Links written under the source's headings are included by default; use --direct
+to list only references attributed to the object itself. Each item reports the
+raw target, the object ID it resolved to (target_id), and the line.`,
 		Args: []ArgMeta{
 			{Name: "source", Description: "Source object ID (e.g., projects/bifrost)", Required: false, CLIOptional: true},
 		},
 		Flags: []FlagMeta{
+			{Name: "direct", Description: "Only include references attributed to the object itself, not its sections", Type: FlagTypeBool},
 			{Name: "browse", Description: "Interactively browse outlinks in Raven's picker and open the selected reference", Type: FlagTypeBool},
 			{Name: "stdin", Description: "Read sources from stdin and return grouped outlinks", Type: FlagTypeBool},
 		},
 		BulkStdinArgName: "sources",
 		Examples: []string{
 			"rvn outlinks projects/bifrost --json",
+			"rvn outlinks projects/bifrost --direct --json",
 			"rvn outlinks projects/bifrost --browse",
 			"rvn query 'type:project .status==active' --ids | rvn outlinks --stdin --json",
 		},
@@ -941,6 +982,89 @@ Non-interactive use requires either a source or --stdin input.`,
 			"rvn date 2025-02-01 --json",
 		},
 	},
+	"rollup": {
+		Name:        "rollup",
+		Description: "Summarize daily notes for an ISO week or month",
+		LongDesc: `Collects the daily notes in an ISO week (--week 2025-W06) or calendar
+month (--month 2025-02): which notes exist, every trait they contain, and the
+references they make, ranked by how often each target is mentioned.
+
+ISO weeks run Monday to Sunday; week 1 is the week containing the year's first
+Thursday, so it can start in late December.`,
+		Flags: []FlagMeta{
+			{Name: "week", Description: "ISO week (YYYY-Www)", Type: FlagTypeString},
+			{Name: "month", Description: "Calendar month (YYYY-MM)", Type: FlagTypeString},
+		},
+		Examples: []string{
+			"rvn rollup --week 2025-W06 --json",
+			"rvn rollup --month 2025-02 --json",
+		},
+	},
+	"upcoming": {
+		Name:        "upcoming",
+		Description: "Agenda of dated traits (due dates, reminders) for the coming days",
+		LongDesc: `Lists every trait whose schema type is date or datetime (for example
+@due and @remind) that falls within the next --days days, starting today,
+grouped by day in chronological order.
+
+A trait with an @every(daily|weekly|monthly) trait on the same line recurs:
+each occurrence inside the window is listed. Define 'every' as an enum trait
+in schema.yaml so it is indexed.
+
+Completed items, on a line with a checked "- [x]" box or an enum trait set
+to done (e.g. @todo(done)), are left out unless --include-done is given.`,
+		Flags: []FlagMeta{
+			{Name: "days", Description: "Number of days to include, starting today", Type: FlagTypeInt, Default: "7"},
+			{Name: "include-done", Description: "Include completed items (@todo(done), checked boxes)", Type: FlagTypeBool},
+		},
+		Examples: []string{
+			"rvn upcoming --json",
+			"rvn upcoming --days 14 --json",
+			"rvn upcoming --include-done --json",
+		},
+	},
+	"recent": {
+		Name:        "recent",
+		Description: "List recently modified objects",
+		LongDesc: `Lists objects whose files changed most recently, newest first, with
+their type, file, and how long ago they were modified.
+
+Modification times come from the index; run 'rvn reindex' first if files
+changed. Raven does not track creation times, so there is no --created mode.`,
+		Flags: []FlagMeta{
+			{Name: "limit", Short: "n", Description: "Maximum number of objects to list (0 means no limit)", Type: FlagTypeInt, Default: "10"},
+		},
+		Examples: []string{
+			"rvn recent",
+			"rvn recent --limit 25 --json",
+		},
+		UseCases: []string{
+			"Pick up where you left off",
+			"See what an agent or sync just changed",
+		},
+	},
+	"untyped": {
+		Name:        "untyped",
+		Description: "List notes that use the fallback page type",
+		LongDesc: `Lists the files whose object has the built-in 'page' type, sorted by path.
+
+By default this includes files that declare 'type: page' in frontmatter. Use
+--implicit-only to list only files with no type at all, which fell back to
+'page'.
+
+Results come from the index; run 'rvn reindex' first if files changed.`,
+		Flags: []FlagMeta{
+			{Name: "implicit-only", Description: "Only list files with no declared type", Type: FlagTypeBool},
+		},
+		Examples: []string{
+			"rvn untyped",
+			"rvn untyped --implicit-only --json",
+		},
+		UseCases: []string{
+			"Find notes that still need a type",
+			"Review loose notes before adding schema types",
+		},
+	},
 	"read": {
 		Name:        "read",
 		Use:         "read [reference]",
@@ -1022,17 +1146,49 @@ resolves Markdown links/images to those assets.
 Paths matched by raven.yaml exclude patterns are skipped and removed from the
 index during incremental reindexing.
 
-Use --full to force a complete rebuild of the entire index.`,
+Use --full to force a complete rebuild of the entire index.
+
+Pass a file or directory to reindex only that path. Every file under it is
+reparsed regardless of modification time, and indexed files under it that no
+longer exist are removed. Assets are not scanned in this mode.`,
+		Args: []ArgMeta{
+			{Name: "path", Description: "File or directory to reindex (optional, defaults to the whole vault)", Required: false},
+		},
 		Examples: []string{
 			"rvn reindex",
 			"rvn reindex --dry-run",
 			"rvn reindex --full",
+			"rvn reindex people/freya.md",
+			"rvn reindex projects/",
 		},
 		Flags: []FlagMeta{
 			{Name: "full", Description: "Force full reindex of all files (default is incremental)", Type: FlagTypeBool},
 			{Name: "dry-run", Description: "Show what would be reindexed without doing it", Type: FlagTypeBool},
 		},
 	},
+	"diff_index": {
+		Name:        "diff-index",
+		Use:         "diff-index",
+		Description: "List files that changed since the last index",
+		LongDesc: `Compares managed files on disk against the SQLite index and lists the
+files that are new, modified, or deleted since the last index, without
+reindexing anything.
+
+A file is modified when its modification time is newer than the one recorded
+when it was indexed, the same check incremental 'rvn reindex' uses. Indexed
+files that are now excluded by raven.yaml are listed as deleted, since the next
+reindex removes them.
+
+If the vault has never been indexed, every managed file is listed as new.`,
+		Examples: []string{
+			"rvn diff-index",
+			"rvn diff-index --json",
+		},
+		UseCases: []string{
+			"Check whether the index is stale before running queries",
+			"See which files an incremental reindex would pick up",
+		},
+	},
 	"check": {
 		Name:        "check",
 		Description: "Validate managed vault files against schema",
@@ -1151,6 +1307,75 @@ JSON mode previews by default. Add --confirm to create only deterministic typed
 			"Run interactive missing-reference creation in terminal mode",
 		},
 	},
+	"validate": {
+		Name:        "validate",
+		Description: "Validate a single file against the schema",
+		LongDesc: `Parses and validates one markdown file without walking the vault.
+
+Known objects, aliases, and reference resolution come from the index, so this is
+much faster than 'rvn check' after editing a single note. Issues use the same
+format as check. Cross-file checks (duplicate IDs, orphaned assets, schema-level
+issues) are only reported by 'rvn check'.
+
+Exits with status 1 when errors are found (or warnings with --strict).`,
+		Args: []ArgMeta{
+			{Name: "file", Description: "Markdown file path (vault-relative or absolute)", Required: true},
+		},
+		Flags: []FlagMeta{
+			{Name: "strict", Description: "Treat warnings as errors", Type: FlagTypeBool},
+			{Name: "errors-only", Description: "Only report errors, skip warnings", Type: FlagTypeBool},
+		},
+		Examples: []string{
+			"rvn validate people/freya.md",
+			"rvn validate projects/website.md --json",
+		},
+		UseCases: []string{
+			"Check a note right after editing it",
+			"Validate a file from an editor save hook",
+		},
+	},
+	"fields": {
+		Name:        "fields",
+		Description: "Show which fields objects of a type actually use",
+		LongDesc: `Lists every frontmatter field key set on indexed objects of a type, with
+how many objects use it. Keys the schema does not declare yet are marked, which
+helps when defining or refining a type.
+
+Results come from the index; run 'rvn reindex' first if files changed.`,
+		Args: []ArgMeta{
+			{Name: "type", Description: "Type name", Required: true},
+		},
+		Examples: []string{
+			"rvn fields person",
+			"rvn fields project --json",
+		},
+		UseCases: []string{
+			"Discover fields in use before adding them to the schema",
+			"Find rarely used or misspelled field keys",
+		},
+	},
+	"suggest_schema": {
+		Name:        "suggest-schema",
+		Use:         "suggest-schema",
+		Description: "Suggest schema types and fields from existing notes",
+		LongDesc: `Reads the index and infers a field type (string, number, date, datetime,
+bool, ref, or an array of one of these) for each frontmatter field used by
+each type, including untyped 'page' notes. Field counts match 'rvn fields';
+run 'rvn reindex' first if notes changed since the last index.
+
+Prints a candidate schema.yaml snippet to review and paste. Fields the schema
+already declares are left out, and nothing is written to disk. Values of mixed
+kinds fall back to string.`,
+		Examples: []string{
+			"rvn suggest-schema",
+			"rvn suggest-schema > schema-suggestion.yaml",
+			"rvn suggest-schema --json",
+		},
+		UseCases: []string{
+			"Bootstrap a schema for an existing untyped vault",
+			"Find fields to declare after importing notes",
+		},
+	},
 	"schema": {
 		Name:        "schema",
 		Use:         "schema [types|traits|type <name>|trait <name>|core [name]|template ...]",
@@ -1879,6 +2104,7 @@ Use 'config unset' to clear fields.`,
 		Flags: []FlagMeta{
 			{Name: "editor", Description: "Set editor command", Type: FlagTypeString},
 			{Name: "editor-mode", Description: "Set editor mode (auto|terminal|gui)", Type: FlagTypeString, Examples: []string{"auto", "terminal", "gui"}},
+			{Name: "editor-profile", Description: "Set how a line number is passed to the editor (vscode|vim|helix|emacs|none)", Type: FlagTypeString, Examples: []string{"vscode", "vim", "helix", "emacs", "none"}},
 			{Name: "state-file", Description: "Set state.toml path (absolute or relative to config directory)", Type: FlagTypeString},
 			{Name: "default-vault", Description: "Set default_vault to a configured vault name", Type: FlagTypeString},
 			{Name: "ui-accent", Description: "Set UI accent color (ANSI 0-255 or #RRGGBB)", Type: FlagTypeString},
@@ -1888,6 +2114,7 @@ Use 'config unset' to clear fields.`,
 		Examples: []string{
 			"rvn config set --editor code --json",
 			"rvn config set --editor-mode terminal --json",
+			"rvn config set --editor \"open -a Cursor\" --editor-profile vscode --json",
 			"rvn config set --state-file state.toml --json",
 			"rvn config set --default-vault work --json",
 			"rvn config set --ui-accent 39 --ui-code-theme monokai --ui-markdown-style auto --json",
@@ -1905,6 +2132,7 @@ Use 'config unset' to clear fields.`,
 		Flags: []FlagMeta{
 			{Name: "editor", Description: "Clear editor", Type: FlagTypeBool},
 			{Name: "editor-mode", Description: "Clear editor_mode", Type: FlagTypeBool},
+			{Name: "editor-profile", Description: "Clear editor_profile", Type: FlagTypeBool},
 			{Name: "state-file", Description: "Clear state_file", Type: FlagTypeBool},
 			{Name: "default-vault", Description: "Clear default_vault", Type: FlagTypeBool},
 			{Name: "ui-accent", Description: "Clear ui.accent", Type: FlagTypeBool},
@@ -1973,8 +2201,31 @@ The default vault is stored in config.toml and used as fallback.`,
 	"vault_stats": {
 		Name:        "vault stats",
 		Description: "Show vault statistics",
+		LongDesc: `Shows file, object, trait, and reference counts from the index, plus the
+total word count of note bodies. Words exclude frontmatter, fenced code,
+images, trait annotations, and link targets.
+
+Use --by-type to add the number of objects per type, and --by-trait to add
+the number of trait annotations per trait. Both are most common first.`,
+		Flags: []FlagMeta{
+			{Name: "by-type", Description: "Also count objects per type", Type: FlagTypeBool},
+			{Name: "by-trait", Description: "Also count trait annotations per trait", Type: FlagTypeBool},
+		},
 		Examples: []string{
 			"rvn vault stats --json",
+			"rvn vault stats --by-type --by-trait",
+		},
+	},
+	"vault_compact": {
+		Name:        "vault compact",
+		Description: "Compact the vault index database",
+		LongDesc: `Checkpoints the index write-ahead log, runs VACUUM to reclaim space left
+by deleted rows, and refreshes query planner statistics with ANALYZE.
+
+Reports the index size before and after compaction. Vault files are not touched.`,
+		Examples: []string{
+			"rvn vault compact",
+			"rvn vault compact --json",
 		},
 	},
 	"vault_use": {
@@ -2282,16 +2533,23 @@ target. Non-interactive and JSON output still return REF_AMBIGUOUS with the
 candidate matches.
 
 Use --stdin to read object IDs from stdin (one per line) and open them all.
-This is useful for piping query results to open multiple files at once.`,
+This is useful for piping query results to open multiple files at once.
+
+Use --backlink to open a note that links to the reference instead of the
+reference itself, at the first line that links to it. Repeated calls cycle
+through the backlinking notes in path order; the position is remembered per
+target in .raven/open-backlinks.json and only advances when the editor opens.`,
 		Args: []ArgMeta{
 			{Name: "reference", Description: "Reference to the file (short name, partial path, or full path)", Required: false},
 		},
 		Flags: []FlagMeta{
 			{Name: "stdin", Description: "Read object IDs from stdin for bulk open", Type: FlagTypeBool},
+			{Name: "backlink", Description: "Open the next note that links to the reference (cycles on repeated calls)", Type: FlagTypeBool},
 		},
 		Examples: []string{
 			"rvn open cursor --json",
 			"rvn open companies/cursor --json",
+			"rvn open people/freya --backlink",
 			"rvn query 'type:project .status==active' --ids | rvn open --stdin --json",
 		},
 		UseCases: []string{
@@ -2300,6 +2558,7 @@ This is useful for piping query results to open multiple files at once.`,
 			"Interactively disambiguate open references in Raven's picker",
 			"Open files using references without knowing full paths",
 			"Open multiple files from query results",
+			"Review the notes that link to an object one at a time",
 		},
 	},
 	"skill_list": {
@@ -2410,6 +2669,10 @@ information about the target object.
 
 This is a pure query — it does not modify anything. The result always returns
 "resolved": true/false to indicate whether the reference was successfully resolved.
+Resolved objects also include their type, indexed fields, starting line, parent
+object (for embedded sections), and traits. Pass --body to add the object's body
+content (the section's lines for embedded sections), so you can inspect an
+object without reading the whole file. Assets never include a body.
 
 In an interactive terminal, bare 'rvn resolve' launches Raven's picker
 over indexed object, section, and asset references.
@@ -2423,14 +2686,25 @@ Supports all reference formats:
 - Date references: "2025-02-01" → daily/2025-02-01
 - Dynamic dates: "today", "yesterday", "tomorrow"
 - Section references: "projects/website#tasks"
+- Wikilink literals: "[[freya]]" or "[[freya|Freya]]"
 
 If the reference is ambiguous (matches multiple objects), returns all matches
-with their match sources.`,
+with their match sources (alias, name_field, object_id, short_name, date) so
+you can see why the reference could not be resolved to a single target.
+
+Use --explain to include the decision trace: each rule tried, in order, and the
+candidates it matched, whether the reference resolved or not.`,
 		Args: []ArgMeta{
 			{Name: "reference", Description: "Reference to resolve (short name, path, alias, date, etc.)", Required: true, CLIOptional: true},
 		},
+		Flags: []FlagMeta{
+			{Name: "explain", Description: "Include the rules tried and the candidates each matched", Type: FlagTypeBool},
+			{Name: "body", Description: "Include the object's body content", Type: FlagTypeBool},
+		},
 		Examples: []string{
 			"rvn resolve freya --json",
+			"rvn resolve people/freya#notes --body",
+			"rvn resolve \"[[alice]]\" --explain",
 			"rvn resolve people/freya --json",
 			"rvn resolve today --json",
 			"rvn resolve \"The Prose Edda\" --json",
@@ -2443,6 +2717,52 @@ with their match sources.`,
 			"Validate references without side effects",
 		},
 	},
+	"export": {
+		Name:        "export",
+		Description: "Export the index as JSON",
+		LongDesc: `Dumps every indexed object, trait, reference, and asset as one JSON document.
+
+Object fields are emitted as JSON values. Rows are ordered by file path so
+exports diff cleanly. Without --out the export is written to stdout; with
+--out it is written to that file and only counts are reported.
+
+Vault files are not read; run 'rvn reindex' first if the index may be stale.`,
+		Flags: []FlagMeta{
+			{Name: "out", Description: "Write the export to this file instead of stdout", Type: FlagTypeString},
+		},
+		Examples: []string{
+			"rvn export > vault.json",
+			"rvn export --out backup/vault.json",
+		},
+		UseCases: []string{
+			"Back up the index",
+			"Feed vault structure to external tools",
+		},
+	},
+	"restore": {
+		Name:        "restore",
+		Description: "Replace the index with a previous export",
+		LongDesc: `Clears the index and reinserts the objects, traits, references, and assets
+from a file written by 'rvn export', in a single transaction. Vault files are
+not parsed, which makes this useful for moving an index between machines.
+
+The export must come from the same index version, with unique IDs and object
+fields stored as JSON objects; otherwise nothing is changed.
+
+Sections, dates, and full-text search content are not part of an export, so
+restored files are marked stale. The next 'rvn reindex' reparses them and
+rebuilds those tables from the vault files.`,
+		Args: []ArgMeta{
+			{Name: "file", Description: "Export file written by 'rvn export'", Required: true},
+		},
+		Examples: []string{
+			"rvn restore vault.json",
+		},
+		UseCases: []string{
+			"Move an index to another machine without reparsing",
+			"Roll back the index to a known-good export",
+		},
+	},
 	"import": {
 		Name:        "import",
 		Description: "Import objects from JSON data",
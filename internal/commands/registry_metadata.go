@@ -63,11 +63,12 @@ func defaultCategoryForCommandID(commandID string) Category {
 		commandID == "delete" || commandID == "move" || commandID == "reclassify" || commandID == "import" ||
 		commandID == "edit" || commandID == "update":
 		return CategoryContent
-	case commandID == "schema" || strings.HasPrefix(commandID, "schema_") || commandID == "template" || strings.HasPrefix(commandID, "template_"):
+	case commandID == "schema" || strings.HasPrefix(commandID, "schema_") || commandID == "fields" || commandID == "suggest_schema" || commandID == "template" || strings.HasPrefix(commandID, "template_"):
 		return CategorySchema
-	case commandID == "read" || commandID == "open" || commandID == "daily" || commandID == "date":
+	case commandID == "read" || commandID == "open" || commandID == "daily" || commandID == "date" || commandID == "upcoming" || commandID == "rollup" || commandID == "recent" ||
+		commandID == "untyped":
 		return CategoryNavigation
-	case commandID == "check" || commandID == "reindex" || commandID == "version":
+	case commandID == "check" || commandID == "validate" || commandID == "reindex" || commandID == "diff_index" || commandID == "version" || commandID == "export" || commandID == "restore":
 		return CategoryMaintenance
 	default:
 		return CategoryVault
@@ -77,8 +78,9 @@ func defaultCategoryForCommandID(commandID string) Category {
 func defaultAccessForCommandID(commandID string) AccessMode {
 	commandID = strings.ReplaceAll(commandID, " ", "_")
 	switch commandID {
-	case "read", "search", "backlinks", "outlinks", "resolve", "query", "query_saved_list", "query_saved_get",
-		"schema", "schema_validate", "schema_template_list", "schema_template_get",
+	case "read", "search", "backlinks", "outlinks", "resolve", "query", "query_saved_list", "query_saved_get", "upcoming", "rollup", "recent", "untyped",
+		"validate", "export", "diff_index",
+		"schema", "schema_validate", "schema_template_list", "schema_template_get", "fields", "suggest_schema",
 		"docs", "docs_list", "docs_search",
 		"version",
 		"vault", "vault_list", "vault_current", "vault_path", "vault_stats",
@@ -32,6 +32,10 @@ type Config struct {
 	// EditorMode controls how the editor is launched: auto, terminal, or gui.
 	EditorMode string `toml:"editor_mode"`
 
+	// EditorProfile selects how a target line is passed to the editor:
+	// vscode, vim, helix, emacs, or none. Empty detects it from Editor.
+	EditorProfile string `toml:"editor_profile"`
+
 	// UI controls optional CLI theming preferences.
 	UI UIConfig `toml:"ui"`
 }
@@ -200,6 +204,15 @@ func CreateDefaultAt(path string) (string, error) {
 #   gui      - always run in the background (non-blocking)
 # editor_mode = "auto"
 #
+# How to pass a line number when opening at a specific line (detected from
+# the editor command when unset; set it for wrappers like "open -a Cursor"):
+#   vscode - editor -g file:line
+#   vim    - editor +line file
+#   helix  - editor file:line
+#   emacs  - editor +line file
+#   none   - editor file
+# editor_profile = "vscode"
+#
 # Optional UI accent color for headers/links in terminal output.
 # Supports ANSI color codes (0-255) or hex (#RRGGBB).
 # [ui]
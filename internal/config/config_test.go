@@ -180,6 +180,18 @@ func TestConfigGetEditor(t *testing.T) {
 		}
 	})
 
+	t.Run("configured editor takes precedence over EDITOR env", func(t *testing.T) {
+		cfg := &Config{Editor: "code -w"}
+
+		oldEditor := os.Getenv("EDITOR")
+		os.Setenv("EDITOR", "nano")
+		defer os.Setenv("EDITOR", oldEditor)
+
+		if cfg.GetEditor() != "code -w" {
+			t.Errorf("expected 'code -w', got %q", cfg.GetEditor())
+		}
+	})
+
 	t.Run("falls back to EDITOR env", func(t *testing.T) {
 		cfg := &Config{}
 
@@ -13,13 +13,14 @@ import (
 )
 
 type persistedConfig struct {
-	DefaultVault *string              `toml:"default_vault,omitempty"`
-	StateFile    *string              `toml:"state_file,omitempty"`
-	Vault        *string              `toml:"vault,omitempty"`
-	Vaults       map[string]string    `toml:"vaults,omitempty"`
-	Editor       *string              `toml:"editor,omitempty"`
-	EditorMode   *string              `toml:"editor_mode,omitempty"`
-	UI           *persistedUISettings `toml:"ui,omitempty"`
+	DefaultVault  *string              `toml:"default_vault,omitempty"`
+	StateFile     *string              `toml:"state_file,omitempty"`
+	Vault         *string              `toml:"vault,omitempty"`
+	Vaults        map[string]string    `toml:"vaults,omitempty"`
+	Editor        *string              `toml:"editor,omitempty"`
+	EditorMode    *string              `toml:"editor_mode,omitempty"`
+	EditorProfile *string              `toml:"editor_profile,omitempty"`
+	UI            *persistedUISettings `toml:"ui,omitempty"`
 }
 
 type persistedUISettings struct {
@@ -46,11 +47,12 @@ func SaveTo(path string, cfg *Config) error {
 	}
 
 	out := persistedConfig{
-		DefaultVault: nonEmptyPtr(cfg.DefaultVault),
-		StateFile:    nonEmptyPtr(cfg.StateFile),
-		Vault:        nonEmptyPtr(cfg.Vault),
-		Editor:       nonEmptyPtr(cfg.Editor),
-		EditorMode:   nonEmptyPtr(cfg.EditorMode),
+		DefaultVault:  nonEmptyPtr(cfg.DefaultVault),
+		StateFile:     nonEmptyPtr(cfg.StateFile),
+		Vault:         nonEmptyPtr(cfg.Vault),
+		Editor:        nonEmptyPtr(cfg.Editor),
+		EditorMode:    nonEmptyPtr(cfg.EditorMode),
+		EditorProfile: nonEmptyPtr(cfg.EditorProfile),
 	}
 	if len(cfg.Vaults) > 0 {
 		out.Vaults = cfg.Vaults
@@ -47,11 +47,31 @@ type VaultConfig struct {
 	// Exclude contains gitignore-style patterns for paths that are not managed by Raven.
 	Exclude []string `yaml:"exclude,omitempty"`
 
+	// IgnoreFilePatterns holds patterns read from the vault's .ravenignore file.
+	// They are applied after Exclude and are never written back to raven.yaml.
+	IgnoreFilePatterns []string `yaml:"-"`
+
 	// Capture configures quick capture behavior
 	Capture *CaptureConfig `yaml:"capture,omitempty"`
 
 	// Deletion configures file deletion behavior
 	Deletion *DeletionConfig `yaml:"deletion,omitempty"`
+
+	// SectionIDStyle controls how section IDs are derived from headings:
+	// "slug" (default) - slugified heading text, e.g. "team-sync"
+	// "index" - sequential IDs in document order, e.g. "sec-1"
+	// "heading-path" - slugs of the full heading ancestry, e.g. "intro/background"
+	SectionIDStyle string `yaml:"section_id_style,omitempty"`
+
+	// CheckboxTasks indexes markdown checkbox items ("- [ ]", "- [x]") as
+	// task traits with a todo/done value (default: false).
+	CheckboxTasks bool `yaml:"checkbox_tasks,omitempty"`
+
+	// FollowSymlinks makes check and reindex follow symlinked directories and
+	// symlinks pointing outside the vault (default: false, only symlinked
+	// files inside the vault are indexed). The same policy applies when a
+	// linked note is addressed by path (validate, reindex, move, resolve).
+	FollowSymlinks bool `yaml:"follow_symlinks,omitempty"`
 }
 
 func (vc *VaultConfig) UnmarshalYAML(value *yaml.Node) error {
@@ -76,6 +96,11 @@ func (vc *VaultConfig) UnmarshalYAML(value *yaml.Node) error {
 			return fmt.Errorf("assets is no longer supported; use directories.assets instead")
 		}
 	}
+	switch vc.SectionIDStyle {
+	case "", "slug", "index", "heading-path":
+	default:
+		return fmt.Errorf("invalid section_id_style %q: must be one of slug, index, heading-path", vc.SectionIDStyle)
+	}
 	vc.DailyDirectory = vc.GetDailyDirectory()
 	return nil
 }
@@ -225,12 +250,24 @@ func (vc *VaultConfig) IsAutoReindexEnabled() bool {
 	return *vc.AutoReindex
 }
 
-// GetExcludePatterns returns normalized Raven exclude patterns.
+// GetSectionIDStyle returns the configured section ID style (default: "slug").
+func (vc *VaultConfig) GetSectionIDStyle() string {
+	if vc == nil || vc.SectionIDStyle == "" {
+		return "slug"
+	}
+	return vc.SectionIDStyle
+}
+
+// GetExcludePatterns returns normalized Raven exclude patterns from raven.yaml
+// followed by any patterns from .ravenignore.
 func (vc *VaultConfig) GetExcludePatterns() []string {
 	if vc == nil {
 		return nil
 	}
-	return ravenignore.NormalizePatterns(vc.Exclude)
+	patterns := make([]string, 0, len(vc.Exclude)+len(vc.IgnoreFilePatterns))
+	patterns = append(patterns, vc.Exclude...)
+	patterns = append(patterns, vc.IgnoreFilePatterns...)
+	return ravenignore.NormalizePatterns(patterns)
 }
 
 // CaptureConfig defines settings for quick capture via `rvn add`.
@@ -244,6 +281,9 @@ type CaptureConfig struct {
 	// If empty, appends to end of file.
 	// The heading is created if it doesn't exist.
 	Heading string `yaml:"heading,omitempty"`
+
+	// Timestamp writes each capture as a "- HH:MM text" bullet.
+	Timestamp bool `yaml:"timestamp,omitempty"`
 }
 
 // GetCaptureConfig returns the capture config with defaults applied.
@@ -393,8 +433,15 @@ func DefaultVaultConfig() *VaultConfig {
 func LoadVaultConfig(vaultPath string) (*VaultConfig, error) {
 	configPath := filepath.Join(vaultPath, "raven.yaml")
 
+	ignorePatterns, err := loadRavenIgnore(vaultPath)
+	if err != nil {
+		return nil, err
+	}
+
 	if _, err := os.Stat(configPath); os.IsNotExist(err) {
-		return DefaultVaultConfig(), nil
+		config := DefaultVaultConfig()
+		config.IgnoreFilePatterns = ignorePatterns
+		return config, nil
 	}
 
 	data, err := os.ReadFile(configPath)
@@ -407,10 +454,37 @@ func LoadVaultConfig(vaultPath string) (*VaultConfig, error) {
 		return nil, fmt.Errorf("failed to parse vault config %s: %w", configPath, err)
 	}
 	config.DailyDirectory = config.GetDailyDirectory()
+	config.IgnoreFilePatterns = ignorePatterns
 
 	return &config, nil
 }
 
+// RavenIgnoreFile is the vault-root file holding extra gitignore-style exclude patterns.
+const RavenIgnoreFile = ".ravenignore"
+
+// loadRavenIgnore reads .ravenignore from the vault root. Blank lines and
+// lines starting with '#' are skipped; a missing file yields no patterns.
+func loadRavenIgnore(vaultPath string) ([]string, error) {
+	ignorePath := filepath.Join(vaultPath, RavenIgnoreFile)
+	data, err := os.ReadFile(ignorePath)
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read %s: %w", ignorePath, err)
+	}
+
+	var patterns []string
+	for _, line := range strings.Split(string(data), "\n") {
+		line = strings.TrimSpace(line)
+		if line == "" || strings.HasPrefix(line, "#") {
+			continue
+		}
+		patterns = append(patterns, line)
+	}
+	return patterns, nil
+}
+
 // CreateDefaultVaultConfig creates a default raven.yaml file in the vault.
 // Returns true if a new file was created, false if one already existed.
 func CreateDefaultVaultConfig(vaultPath string) (bool, error) {
@@ -167,6 +167,85 @@ func TestLoadVaultConfig(t *testing.T) {
 			t.Fatal("expected error for legacy top-level assets, got nil")
 		}
 	})
+
+	t.Run("loads section_id_style", func(t *testing.T) {
+		tmpDir := t.TempDir()
+		configPath := filepath.Join(tmpDir, "raven.yaml")
+
+		content := "section_id_style: heading-path\n"
+		if err := os.WriteFile(configPath, []byte(content), 0644); err != nil {
+			t.Fatalf("failed to write config: %v", err)
+		}
+
+		cfg, err := LoadVaultConfig(tmpDir)
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+		if cfg.GetSectionIDStyle() != "heading-path" {
+			t.Errorf("expected section_id_style 'heading-path', got %q", cfg.GetSectionIDStyle())
+		}
+		if DefaultVaultConfig().GetSectionIDStyle() != "slug" {
+			t.Errorf("expected default section_id_style 'slug', got %q", DefaultVaultConfig().GetSectionIDStyle())
+		}
+	})
+
+	t.Run("rejects unknown section_id_style", func(t *testing.T) {
+		tmpDir := t.TempDir()
+		configPath := filepath.Join(tmpDir, "raven.yaml")
+
+		content := "section_id_style: numbered\n"
+		if err := os.WriteFile(configPath, []byte(content), 0644); err != nil {
+			t.Fatalf("failed to write config: %v", err)
+		}
+
+		if _, err := LoadVaultConfig(tmpDir); err == nil {
+			t.Fatal("expected error for unknown section_id_style, got nil")
+		}
+	})
+
+	t.Run("merges .ravenignore patterns after exclude", func(t *testing.T) {
+		tmpDir := t.TempDir()
+		if err := os.WriteFile(filepath.Join(tmpDir, "raven.yaml"), []byte("exclude:\n  - AGENTS.md\n"), 0644); err != nil {
+			t.Fatalf("failed to write config: %v", err)
+		}
+		ignore := "# archived notes\narchive/\n\ntemplates/\n!templates/keep.md\n"
+		if err := os.WriteFile(filepath.Join(tmpDir, RavenIgnoreFile), []byte(ignore), 0644); err != nil {
+			t.Fatalf("failed to write .ravenignore: %v", err)
+		}
+
+		cfg, err := LoadVaultConfig(tmpDir)
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+		got := cfg.GetExcludePatterns()
+		want := []string{"AGENTS.md", "archive/", "templates/", "!templates/keep.md"}
+		if len(got) != len(want) {
+			t.Fatalf("exclude patterns = %#v, want %#v", got, want)
+		}
+		for i := range want {
+			if got[i] != want[i] {
+				t.Fatalf("exclude patterns = %#v, want %#v", got, want)
+			}
+		}
+		if len(cfg.Exclude) != 1 {
+			t.Errorf("raven.yaml exclude list should be unchanged, got %#v", cfg.Exclude)
+		}
+	})
+
+	t.Run("reads .ravenignore without raven.yaml", func(t *testing.T) {
+		tmpDir := t.TempDir()
+		if err := os.WriteFile(filepath.Join(tmpDir, RavenIgnoreFile), []byte("archive/\n"), 0644); err != nil {
+			t.Fatalf("failed to write .ravenignore: %v", err)
+		}
+
+		cfg, err := LoadVaultConfig(tmpDir)
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+		if got := cfg.GetExcludePatterns(); len(got) != 1 || got[0] != "archive/" {
+			t.Errorf("exclude patterns = %#v, want [archive/]", got)
+		}
+	})
 }
 
 func TestAssetRootConfig(t *testing.T) {
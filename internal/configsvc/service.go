@@ -95,15 +95,16 @@ func (ctx *GlobalConfigContext) Data() map[string]interface{} {
 	}
 
 	return map[string]interface{}{
-		"config_path":   ctx.ConfigPath,
-		"state_path":    ctx.StatePath,
-		"exists":        ctx.ConfigExists,
-		"default_vault": strings.TrimSpace(ctx.Cfg.DefaultVault),
-		"state_file":    strings.TrimSpace(ctx.Cfg.StateFile),
-		"vault":         strings.TrimSpace(ctx.Cfg.Vault),
-		"vaults":        vaults,
-		"editor":        strings.TrimSpace(ctx.Cfg.Editor),
-		"editor_mode":   strings.TrimSpace(ctx.Cfg.EditorMode),
+		"config_path":    ctx.ConfigPath,
+		"state_path":     ctx.StatePath,
+		"exists":         ctx.ConfigExists,
+		"default_vault":  strings.TrimSpace(ctx.Cfg.DefaultVault),
+		"state_file":     strings.TrimSpace(ctx.Cfg.StateFile),
+		"vault":          strings.TrimSpace(ctx.Cfg.Vault),
+		"vaults":         vaults,
+		"editor":         strings.TrimSpace(ctx.Cfg.Editor),
+		"editor_mode":    strings.TrimSpace(ctx.Cfg.EditorMode),
+		"editor_profile": strings.TrimSpace(ctx.Cfg.EditorProfile),
 		"ui": map[string]interface{}{
 			"accent":         strings.TrimSpace(ctx.Cfg.UI.Accent),
 			"code_theme":     strings.TrimSpace(ctx.Cfg.UI.CodeTheme),
@@ -154,10 +155,21 @@ func NormalizeEditorMode(raw string) (string, bool) {
 	}
 }
 
+func NormalizeEditorProfile(raw string) (string, bool) {
+	profile := strings.ToLower(strings.TrimSpace(raw))
+	switch profile {
+	case "vscode", "vim", "helix", "emacs", "none":
+		return profile, true
+	default:
+		return "", false
+	}
+}
+
 type SetRequest struct {
 	ContextOptions
 	Editor          *string
 	EditorMode      *string
+	EditorProfile   *string
 	StateFile       *string
 	DefaultVault    *string
 	UIAccent        *string
@@ -196,6 +208,15 @@ func Set(req SetRequest) (*SetResult, error) {
 		changed = append(changed, "editor_mode")
 	}
 
+	if req.EditorProfile != nil {
+		value, ok := NormalizeEditorProfile(*req.EditorProfile)
+		if !ok {
+			return nil, newError(CodeInvalidInput, "editor-profile must be one of: vscode, vim, helix, emacs, none", nil)
+		}
+		ctx.Cfg.EditorProfile = value
+		changed = append(changed, "editor_profile")
+	}
+
 	if req.StateFile != nil {
 		value := strings.TrimSpace(*req.StateFile)
 		if value == "" {
@@ -245,7 +266,7 @@ func Set(req SetRequest) (*SetResult, error) {
 	}
 
 	if len(changed) == 0 {
-		return nil, newError(CodeMissingArgument, "no fields provided; set at least one --editor/--editor-mode/--state-file/--default-vault/--ui-accent/--ui-code-theme/--ui-markdown-style", nil)
+		return nil, newError(CodeMissingArgument, "no fields provided; set at least one --editor/--editor-mode/--editor-profile/--state-file/--default-vault/--ui-accent/--ui-code-theme/--ui-markdown-style", nil)
 	}
 
 	if err := config.SaveTo(ctx.ConfigPath, ctx.Cfg); err != nil {
@@ -263,6 +284,7 @@ type UnsetRequest struct {
 	ContextOptions
 	Editor          bool
 	EditorMode      bool
+	EditorProfile   bool
 	StateFile       bool
 	DefaultVault    bool
 	UIAccent        bool
@@ -293,6 +315,10 @@ func Unset(req UnsetRequest) (*UnsetResult, error) {
 		ctx.Cfg.EditorMode = ""
 		changed = append(changed, "editor_mode")
 	}
+	if req.EditorProfile {
+		ctx.Cfg.EditorProfile = ""
+		changed = append(changed, "editor_profile")
+	}
 	if req.StateFile {
 		ctx.Cfg.StateFile = ""
 		changed = append(changed, "state_file")
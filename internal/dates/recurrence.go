@@ -0,0 +1,70 @@
+package dates
+
+import (
+	"fmt"
+	"strings"
+	"time"
+)
+
+// Recurrence intervals accepted by NextOccurrences.
+const (
+	RecurDaily   = "daily"
+	RecurWeekly  = "weekly"
+	RecurMonthly = "monthly"
+)
+
+// NormalizeRecurrence normalizes and validates a recurrence interval.
+// Returns the canonical interval and true when valid.
+func NormalizeRecurrence(every string) (string, bool) {
+	normalized := strings.ToLower(strings.TrimSpace(every))
+	switch normalized {
+	case RecurDaily, RecurWeekly, RecurMonthly:
+		return normalized, true
+	default:
+		return "", false
+	}
+}
+
+// NextOccurrences returns the occurrences of an event first happening at base
+// and repeating every interval that fall within [from, to], in order.
+//
+// Monthly recurrence keeps the base day of month, clamped to the last day of
+// shorter months (Jan 31 -> Feb 28 -> Mar 31). The time of day is preserved.
+func NextOccurrences(base time.Time, every string, from, to time.Time) ([]time.Time, error) {
+	interval, ok := NormalizeRecurrence(every)
+	if !ok {
+		return nil, fmt.Errorf("invalid recurrence %q: use daily, weekly, or monthly", every)
+	}
+	if to.Before(from) {
+		return nil, nil
+	}
+
+	var occurrences []time.Time
+	for n := 0; ; n++ {
+		occ := nthOccurrence(base, interval, n)
+		if occ.After(to) {
+			break
+		}
+		if !occ.Before(from) {
+			occurrences = append(occurrences, occ)
+		}
+	}
+	return occurrences, nil
+}
+
+func nthOccurrence(base time.Time, interval string, n int) time.Time {
+	switch interval {
+	case RecurDaily:
+		return base.AddDate(0, 0, n)
+	case RecurWeekly:
+		return base.AddDate(0, 0, 7*n)
+	default:
+		year, month, day := base.Date()
+		firstOfMonth := time.Date(year, month+time.Month(n), 1, base.Hour(), base.Minute(), base.Second(), base.Nanosecond(), base.Location())
+		lastDay := firstOfMonth.AddDate(0, 1, -1).Day()
+		if day > lastDay {
+			day = lastDay
+		}
+		return firstOfMonth.AddDate(0, 0, day-1)
+	}
+}
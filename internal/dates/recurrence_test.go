@@ -0,0 +1,92 @@
+package dates
+
+import (
+	"testing"
+	"time"
+)
+
+func TestNextOccurrences(t *testing.T) {
+	t.Parallel()
+
+	base := time.Date(2025, 2, 1, 9, 0, 0, 0, time.UTC)
+	day := func(month time.Month, d int) time.Time {
+		return time.Date(2025, month, d, 9, 0, 0, 0, time.UTC)
+	}
+
+	tests := []struct {
+		name  string
+		base  time.Time
+		every string
+		from  time.Time
+		to    time.Time
+		want  []time.Time
+	}{
+		{
+			name:  "weekly across a month",
+			base:  base,
+			every: "weekly",
+			from:  time.Date(2025, 2, 1, 0, 0, 0, 0, time.UTC),
+			to:    time.Date(2025, 2, 28, 23, 59, 0, 0, time.UTC),
+			want:  []time.Time{day(2, 1), day(2, 8), day(2, 15), day(2, 22)},
+		},
+		{
+			name:  "weekly window starting after base",
+			base:  base,
+			every: "Weekly",
+			from:  time.Date(2025, 2, 20, 0, 0, 0, 0, time.UTC),
+			to:    time.Date(2025, 3, 10, 0, 0, 0, 0, time.UTC),
+			want:  []time.Time{day(2, 22), day(3, 1), day(3, 8)},
+		},
+		{
+			name:  "daily",
+			base:  base,
+			every: "daily",
+			from:  day(2, 3),
+			to:    day(2, 5),
+			want:  []time.Time{day(2, 3), day(2, 4), day(2, 5)},
+		},
+		{
+			name:  "monthly clamps to short months",
+			base:  day(1, 31),
+			every: "monthly",
+			from:  day(1, 1),
+			to:    day(4, 30),
+			want:  []time.Time{day(1, 31), day(2, 28), day(3, 31), day(4, 30)},
+		},
+		{
+			name:  "window before base",
+			base:  base,
+			every: "weekly",
+			from:  day(1, 1),
+			to:    day(1, 31),
+			want:  nil,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			got, err := NextOccurrences(tt.base, tt.every, tt.from, tt.to)
+			if err != nil {
+				t.Fatalf("unexpected error: %v", err)
+			}
+			if len(got) != len(tt.want) {
+				t.Fatalf("got %v, want %v", got, tt.want)
+			}
+			for i := range tt.want {
+				if !got[i].Equal(tt.want[i]) {
+					t.Fatalf("occurrence %d = %v, want %v", i, got[i], tt.want[i])
+				}
+			}
+		})
+	}
+
+	t.Run("invalid interval", func(t *testing.T) {
+		t.Parallel()
+
+		if _, err := NextOccurrences(base, "fortnightly", base, base.AddDate(0, 1, 0)); err == nil {
+			t.Fatal("expected error for unsupported interval")
+		}
+	})
+}
@@ -1,39 +1,61 @@
 package dates
 
 import (
+	"regexp"
+	"strconv"
 	"strings"
 	"time"
 )
 
-// RelativeDateKind describes whether a relative date keyword resolves to a single date.
+// RelativeDateKind describes whether a relative date keyword resolves to a
+// single date or to a range of dates.
 type RelativeDateKind int
 
 const (
 	RelativeDateUnknown RelativeDateKind = iota
 	RelativeDateInstant
+	RelativeDateRange
 )
 
 // RelativeDateResolution is the resolved representation of a relative date keyword.
+// Instants set Date; ranges set Start and End (both inclusive).
 type RelativeDateResolution struct {
 	Keyword string
 	Kind    RelativeDateKind
 	Date    time.Time
+	Start   time.Time
+	End     time.Time
 }
 
 var relativeDateKeywords = map[string]struct{}{
-	"today":     {},
-	"tomorrow":  {},
-	"yesterday": {},
+	"today":      {},
+	"tomorrow":   {},
+	"yesterday":  {},
+	"this-week":  {},
+	"last-week":  {},
+	"next-week":  {},
+	"this-month": {},
+	"last-month": {},
+	"next-month": {},
+	"this-year":  {},
+	"last-year":  {},
+	"next-year":  {},
 }
 
+// relativeDaysPattern matches rolling ranges such as last-7-days and next-30-days.
+var relativeDaysPattern = regexp.MustCompile(`^(last|next)-([1-9][0-9]{0,3})-days$`)
+
 // NormalizeRelativeDateKeyword normalizes and validates a relative date keyword.
 // Returns the canonical keyword and true when valid.
 func NormalizeRelativeDateKeyword(value string) (string, bool) {
 	normalized := strings.ToLower(strings.TrimSpace(value))
-	if _, ok := relativeDateKeywords[normalized]; !ok {
-		return "", false
+	if _, ok := relativeDateKeywords[normalized]; ok {
+		return normalized, true
+	}
+	if relativeDaysPattern.MatchString(normalized) {
+		return normalized, true
 	}
-	return normalized, true
+	return "", false
 }
 
 // IsRelativeDateKeyword reports whether value is a supported relative date keyword.
@@ -43,7 +65,9 @@ func IsRelativeDateKeyword(value string) bool {
 }
 
 // ResolveRelativeDateKeyword resolves a relative date keyword using the provided "now".
-func ResolveRelativeDateKeyword(value string, now time.Time, _ time.Weekday) (RelativeDateResolution, bool) {
+// Week ranges start on weekStart. Rolling ranges include today: last-7-days is
+// the six days before today plus today, next-7-days is today plus the six days after.
+func ResolveRelativeDateKeyword(value string, now time.Time, weekStart time.Weekday) (RelativeDateResolution, bool) {
 	keyword, ok := NormalizeRelativeDateKeyword(value)
 	if !ok {
 		return RelativeDateResolution{}, false
@@ -57,8 +81,40 @@ func ResolveRelativeDateKeyword(value string, now time.Time, _ time.Weekday) (Re
 		return instantResolution(keyword, anchor.AddDate(0, 0, 1)), true
 	case "yesterday":
 		return instantResolution(keyword, anchor.AddDate(0, 0, -1)), true
+	case "this-week", "last-week", "next-week":
+		offset := (int(anchor.Weekday()) - int(weekStart) + 7) % 7
+		start := anchor.AddDate(0, 0, -offset+7*relativeStep(keyword))
+		return rangeResolution(keyword, start, start.AddDate(0, 0, 6)), true
+	case "this-month", "last-month", "next-month":
+		start := time.Date(anchor.Year(), anchor.Month(), 1, 0, 0, 0, 0, anchor.Location()).AddDate(0, relativeStep(keyword), 0)
+		return rangeResolution(keyword, start, start.AddDate(0, 1, -1)), true
+	case "this-year", "last-year", "next-year":
+		start := time.Date(anchor.Year()+relativeStep(keyword), time.January, 1, 0, 0, 0, 0, anchor.Location())
+		return rangeResolution(keyword, start, start.AddDate(1, 0, -1)), true
+	}
+
+	if m := relativeDaysPattern.FindStringSubmatch(keyword); m != nil {
+		days, err := strconv.Atoi(m[2])
+		if err != nil {
+			return RelativeDateResolution{}, false
+		}
+		if m[1] == "last" {
+			return rangeResolution(keyword, anchor.AddDate(0, 0, -(days-1)), anchor), true
+		}
+		return rangeResolution(keyword, anchor, anchor.AddDate(0, 0, days-1)), true
+	}
+	return RelativeDateResolution{}, false
+}
+
+// relativeStep maps the this-/last-/next- prefix of a range keyword to -1, 0, or 1.
+func relativeStep(keyword string) int {
+	switch {
+	case strings.HasPrefix(keyword, "last-"):
+		return -1
+	case strings.HasPrefix(keyword, "next-"):
+		return 1
 	default:
-		return RelativeDateResolution{}, false
+		return 0
 	}
 }
 
@@ -73,3 +129,12 @@ func instantResolution(keyword string, date time.Time) RelativeDateResolution {
 		Date:    startOfDay(date),
 	}
 }
+
+func rangeResolution(keyword string, start, end time.Time) RelativeDateResolution {
+	return RelativeDateResolution{
+		Keyword: keyword,
+		Kind:    RelativeDateRange,
+		Start:   startOfDay(start),
+		End:     startOfDay(end),
+	}
+}
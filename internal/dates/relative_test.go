@@ -10,8 +10,13 @@ func TestNormalizeRelativeDateKeyword(t *testing.T) {
 	if got, ok := NormalizeRelativeDateKeyword(" today "); !ok || got != "today" {
 		t.Fatalf("NormalizeRelativeDateKeyword(today) = %q, %v", got, ok)
 	}
-	if _, ok := NormalizeRelativeDateKeyword("this-week"); ok {
-		t.Fatalf("expected this-week to be rejected")
+	if got, ok := NormalizeRelativeDateKeyword("Last-7-Days"); !ok || got != "last-7-days" {
+		t.Fatalf("NormalizeRelativeDateKeyword(Last-7-Days) = %q, %v", got, ok)
+	}
+	for _, value := range []string{"this-fortnight", "last-0-days", "last-days", "next-7-weeks"} {
+		if _, ok := NormalizeRelativeDateKeyword(value); ok {
+			t.Fatalf("expected %q to be rejected", value)
+		}
 	}
 }
 
@@ -43,3 +48,36 @@ func TestResolveRelativeDateKeyword_Instants(t *testing.T) {
 		t.Fatalf("unexpected yesterday: %s", yesterday.Date.Format(DateLayout))
 	}
 }
+
+func TestResolveRelativeDateKeyword_Ranges(t *testing.T) {
+	t.Parallel()
+	now := time.Date(2026, time.March, 4, 14, 30, 0, 0, time.UTC) // Wednesday
+
+	tests := []struct {
+		keyword   string
+		weekStart time.Weekday
+		start     string
+		end       string
+	}{
+		{"this-week", time.Monday, "2026-03-02", "2026-03-08"},
+		{"this-week", time.Sunday, "2026-03-01", "2026-03-07"},
+		{"last-week", time.Monday, "2026-02-23", "2026-03-01"},
+		{"next-week", time.Monday, "2026-03-09", "2026-03-15"},
+		{"this-month", time.Monday, "2026-03-01", "2026-03-31"},
+		{"last-month", time.Monday, "2026-02-01", "2026-02-28"},
+		{"next-month", time.Monday, "2026-04-01", "2026-04-30"},
+		{"this-year", time.Monday, "2026-01-01", "2026-12-31"},
+		{"last-7-days", time.Monday, "2026-02-26", "2026-03-04"},
+		{"next-30-days", time.Monday, "2026-03-04", "2026-04-02"},
+	}
+	for _, tt := range tests {
+		got, ok := ResolveRelativeDateKeyword(tt.keyword, now, tt.weekStart)
+		if !ok || got.Kind != RelativeDateRange {
+			t.Fatalf("%s: expected a range, got %+v (ok=%v)", tt.keyword, got, ok)
+		}
+		if got.Start.Format(DateLayout) != tt.start || got.End.Format(DateLayout) != tt.end {
+			t.Errorf("%s (week starts %s) = %s..%s, want %s..%s", tt.keyword, tt.weekStart,
+				got.Start.Format(DateLayout), got.End.Format(DateLayout), tt.start, tt.end)
+		}
+	}
+}
@@ -0,0 +1,184 @@
+package datesvc
+
+import (
+	"fmt"
+	"regexp"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/aidanlsb/raven/internal/config"
+	"github.com/aidanlsb/raven/internal/dates"
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/model"
+)
+
+var (
+	isoWeekRegex = regexp.MustCompile(`^(\d{4})-[Ww](\d{1,2})$`)
+	monthRegex   = regexp.MustCompile(`^(\d{4})-(\d{2})$`)
+)
+
+// ISOWeekRange returns the Monday and Sunday of an ISO 8601 week such as
+// "2025-W06". Week 1 is the week containing the year's first Thursday, so a
+// week can start in the previous year or end in the next one.
+func ISOWeekRange(week string) (time.Time, time.Time, error) {
+	m := isoWeekRegex.FindStringSubmatch(strings.TrimSpace(week))
+	if m == nil {
+		return time.Time{}, time.Time{}, fmt.Errorf("invalid ISO week %q: use YYYY-Www (e.g. 2025-W06)", week)
+	}
+	year, _ := strconv.Atoi(m[1])
+	num, _ := strconv.Atoi(m[2])
+
+	// January 4th is always in week 1.
+	jan4 := time.Date(year, time.January, 4, 0, 0, 0, 0, time.UTC)
+	daysSinceMonday := (int(jan4.Weekday()) + 6) % 7
+	start := jan4.AddDate(0, 0, -daysSinceMonday+7*(num-1))
+	if y, w := start.ISOWeek(); num < 1 || y != year || w != num {
+		return time.Time{}, time.Time{}, fmt.Errorf("invalid ISO week %q: %d has no week %d", week, year, num)
+	}
+	return start, start.AddDate(0, 0, 6), nil
+}
+
+// MonthRange returns the first and last day of a month such as "2025-02".
+func MonthRange(month string) (time.Time, time.Time, error) {
+	m := monthRegex.FindStringSubmatch(strings.TrimSpace(month))
+	if m == nil {
+		return time.Time{}, time.Time{}, fmt.Errorf("invalid month %q: use YYYY-MM (e.g. 2025-02)", month)
+	}
+	year, _ := strconv.Atoi(m[1])
+	num, _ := strconv.Atoi(m[2])
+	if num < 1 || num > 12 {
+		return time.Time{}, time.Time{}, fmt.Errorf("invalid month %q: month must be 01-12", month)
+	}
+	start := time.Date(year, time.Month(num), 1, 0, 0, 0, 0, time.UTC)
+	return start, start.AddDate(0, 1, -1), nil
+}
+
+type RollupRequest struct {
+	VaultPath string
+	Week      string
+	Month     string
+}
+
+// RollupNote is a daily note that exists within the rollup period.
+type RollupNote struct {
+	Date     string `json:"date"`
+	ObjectID string `json:"object_id"`
+	FilePath string `json:"file_path"`
+}
+
+// RollupMention is a reference target mentioned in the period's daily notes.
+// Target is the resolved object ID, or the raw link text when the reference
+// did not resolve.
+type RollupMention struct {
+	Target string `json:"target"`
+	Count  int    `json:"count"`
+}
+
+type RollupResult struct {
+	Period   string          `json:"period"`
+	From     string          `json:"from"`
+	To       string          `json:"to"`
+	Notes    []RollupNote    `json:"notes"`
+	Traits   []model.Trait   `json:"traits"`
+	Mentions []RollupMention `json:"mentions"`
+}
+
+// Rollup collects the daily notes in an ISO week or calendar month, the
+// traits they contain, and the references they make.
+func Rollup(req RollupRequest) (*RollupResult, error) {
+	if strings.TrimSpace(req.VaultPath) == "" {
+		return nil, newError(CodeInvalidInput, "vault path is required", "", nil)
+	}
+
+	week, month := strings.TrimSpace(req.Week), strings.TrimSpace(req.Month)
+	var (
+		period     string
+		start, end time.Time
+		err        error
+	)
+	switch {
+	case week != "" && month != "":
+		return nil, newError(CodeInvalidInput, "specify either --week or --month, not both", "", nil)
+	case week != "":
+		period = strings.ToUpper(week)
+		start, end, err = ISOWeekRange(week)
+	case month != "":
+		period = month
+		start, end, err = MonthRange(month)
+	default:
+		return nil, newError(CodeInvalidInput, "a period is required", "Use --week YYYY-Www or --month YYYY-MM", nil)
+	}
+	if err != nil {
+		return nil, newError(CodeInvalidInput, err.Error(), "Use --week YYYY-Www or --month YYYY-MM", err)
+	}
+
+	vaultCfg, err := config.LoadVaultConfig(req.VaultPath)
+	if err != nil {
+		return nil, newError(CodeConfigInvalid, "failed to load vault config", "Fix raven.yaml and try again", err)
+	}
+
+	db, err := index.Open(req.VaultPath)
+	if err != nil {
+		return nil, newError(CodeDatabaseError, "failed to open database", "Run 'rvn reindex' to rebuild the database", err)
+	}
+	defer db.Close()
+
+	result := &RollupResult{
+		Period:   period,
+		From:     start.Format(dates.DateLayout),
+		To:       end.Format(dates.DateLayout),
+		Notes:    []RollupNote{},
+		Traits:   []model.Trait{},
+		Mentions: []RollupMention{},
+	}
+
+	var filePaths []string
+	mentionCounts := make(map[string]int)
+	for day := start; !day.After(end); day = day.AddDate(0, 0, 1) {
+		dateStr := day.Format(dates.DateLayout)
+		obj, err := db.GetObject(vaultCfg.DailyNoteID(dateStr))
+		if err != nil {
+			return nil, newError(CodeQueryFailed, fmt.Sprintf("failed to query daily note for %s", dateStr), "", err)
+		}
+		if obj == nil {
+			continue
+		}
+		result.Notes = append(result.Notes, RollupNote{Date: dateStr, ObjectID: obj.ID, FilePath: obj.FilePath})
+		filePaths = append(filePaths, obj.FilePath)
+
+		outlinks, err := db.Outlinks(obj.ID)
+		if err != nil {
+			return nil, newError(CodeQueryFailed, fmt.Sprintf("failed to query references from %s", obj.ID), "", err)
+		}
+		for _, ref := range outlinks {
+			// Count aliases and alternate spellings of the same object together.
+			target := ref.TargetRaw
+			if ref.TargetID != nil && *ref.TargetID != "" {
+				target = *ref.TargetID
+			}
+			mentionCounts[target]++
+		}
+	}
+
+	traits, err := db.TraitsInFiles(filePaths)
+	if err != nil {
+		return nil, newError(CodeQueryFailed, "failed to query traits", "", err)
+	}
+	if traits != nil {
+		result.Traits = traits
+	}
+
+	for target, count := range mentionCounts {
+		result.Mentions = append(result.Mentions, RollupMention{Target: target, Count: count})
+	}
+	sort.Slice(result.Mentions, func(i, j int) bool {
+		if result.Mentions[i].Count != result.Mentions[j].Count {
+			return result.Mentions[i].Count > result.Mentions[j].Count
+		}
+		return result.Mentions[i].Target < result.Mentions[j].Target
+	})
+
+	return result, nil
+}
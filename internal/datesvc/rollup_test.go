@@ -0,0 +1,122 @@
+package datesvc
+
+import (
+	"reflect"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/dates"
+	"github.com/aidanlsb/raven/internal/testutil"
+)
+
+func TestISOWeekRange(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		week      string
+		wantStart string
+		wantEnd   string
+	}{
+		{week: "2025-W06", wantStart: "2025-02-03", wantEnd: "2025-02-09"},
+		{week: "2025-w6", wantStart: "2025-02-03", wantEnd: "2025-02-09"},
+		// Week 1 starting in the previous year.
+		{week: "2025-W01", wantStart: "2024-12-30", wantEnd: "2025-01-05"},
+		// 53-week year ending in the next year.
+		{week: "2020-W53", wantStart: "2020-12-28", wantEnd: "2021-01-03"},
+		// Week 1 starting after January 1st.
+		{week: "2021-W01", wantStart: "2021-01-04", wantEnd: "2021-01-10"},
+		{week: "2026-W52", wantStart: "2026-12-21", wantEnd: "2026-12-27"},
+	}
+	for _, tt := range tests {
+		start, end, err := ISOWeekRange(tt.week)
+		if err != nil {
+			t.Fatalf("ISOWeekRange(%q) error: %v", tt.week, err)
+		}
+		if got := start.Format(dates.DateLayout); got != tt.wantStart {
+			t.Errorf("ISOWeekRange(%q) start = %s, want %s", tt.week, got, tt.wantStart)
+		}
+		if got := end.Format(dates.DateLayout); got != tt.wantEnd {
+			t.Errorf("ISOWeekRange(%q) end = %s, want %s", tt.week, got, tt.wantEnd)
+		}
+	}
+
+	for _, invalid := range []string{"2021-W53", "2025-W00", "2025-06", "W06", ""} {
+		if _, _, err := ISOWeekRange(invalid); err == nil {
+			t.Errorf("ISOWeekRange(%q) expected error", invalid)
+		}
+	}
+}
+
+func TestMonthRange(t *testing.T) {
+	t.Parallel()
+
+	start, end, err := MonthRange("2024-02")
+	if err != nil {
+		t.Fatalf("MonthRange error: %v", err)
+	}
+	if start.Format(dates.DateLayout) != "2024-02-01" || end.Format(dates.DateLayout) != "2024-02-29" {
+		t.Fatalf("MonthRange(2024-02) = %s..%s, want 2024-02-01..2024-02-29", start.Format(dates.DateLayout), end.Format(dates.DateLayout))
+	}
+
+	for _, invalid := range []string{"2024-13", "2024-2", "2024-W06"} {
+		if _, _, err := MonthRange(invalid); err == nil {
+			t.Errorf("MonthRange(%q) expected error", invalid)
+		}
+	}
+}
+
+func TestRollup_CollectsDailyNotesInWeek(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(`version: 1
+types: {}
+traits:
+  todo:
+    type: boolean
+`).
+		WithFile("daily/2025-02-03.md", "# Monday\n\n- @todo Follow up with [[people/freya]]\n").
+		WithFile("daily/2025-02-09.md", "# Sunday\n\nLunch with [[people/freya]] and [[people/thor]].\n").
+		WithFile("daily/2025-02-10.md", "# Next Monday\n\n- @todo Not in this week\n").
+		Build()
+
+	vault.RunCLI("reindex").MustSucceed(t)
+
+	result, err := Rollup(RollupRequest{VaultPath: vault.Path, Week: "2025-W06"})
+	if err != nil {
+		t.Fatalf("Rollup returned error: %v", err)
+	}
+	if len(result.Notes) != 2 || result.Notes[0].Date != "2025-02-03" || result.Notes[1].Date != "2025-02-09" {
+		t.Fatalf("notes = %+v, want 2025-02-03 and 2025-02-09", result.Notes)
+	}
+	if len(result.Traits) != 1 || result.Traits[0].Content != "Follow up with [[people/freya]]" {
+		t.Fatalf("traits = %+v, want the single todo from 2025-02-03", result.Traits)
+	}
+	if len(result.Mentions) != 2 || result.Mentions[0].Target != "people/freya" || result.Mentions[0].Count != 2 {
+		t.Fatalf("mentions = %+v, want people/freya (2) first", result.Mentions)
+	}
+}
+
+func TestRollup_CountsMentionsByResolvedTarget(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.MinimalSchema()).
+		WithFile("people/freya.md", "---\nalias: The Queen\n---\n# Freya\n").
+		WithFile("daily/2025-02-03.md", "# Monday\n\nMet [[people/freya]].\n").
+		WithFile("daily/2025-02-04.md", "# Tuesday\n\nCalled [[The Queen]] and [[freya]] about [[someone-new]].\n").
+		Build()
+
+	vault.RunCLI("reindex").MustSucceed(t)
+
+	result, err := Rollup(RollupRequest{VaultPath: vault.Path, Week: "2025-W06"})
+	if err != nil {
+		t.Fatalf("Rollup returned error: %v", err)
+	}
+	want := []RollupMention{
+		{Target: "people/freya", Count: 3},
+		{Target: "someone-new", Count: 1},
+	}
+	if !reflect.DeepEqual(result.Mentions, want) {
+		t.Fatalf("mentions = %+v, want %+v", result.Mentions, want)
+	}
+}
@@ -37,3 +37,96 @@ func TestDateHub_BacklinksUseDailyNoteObjectID(t *testing.T) {
 		t.Fatalf("backlink target raw = %q, want %q", got, want)
 	}
 }
+
+func TestEnsureDaily_ExplicitDateCreatesMatchingNote(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.MinimalSchema()).
+		Build()
+
+	result, err := EnsureDaily(EnsureDailyRequest{
+		VaultPath: vault.Path,
+		DateArg:   "2025-01-10",
+	})
+	if err != nil {
+		t.Fatalf("EnsureDaily returned error: %v", err)
+	}
+	if !result.Created {
+		t.Fatal("expected daily note to be created")
+	}
+	if got, want := result.RelativePath, "daily/2025-01-10.md"; got != want {
+		t.Fatalf("relative path = %q, want %q", got, want)
+	}
+	if got, want := result.Date, "2025-01-10"; got != want {
+		t.Fatalf("date = %q, want %q", got, want)
+	}
+	vault.AssertFileContains("daily/2025-01-10.md", "type: date")
+}
+
+func TestEnsureDaily_UsesConfiguredDailyDirectory(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.MinimalSchema()).
+		WithRavenYAML("directories:\n  daily: journal/\n").
+		Build()
+
+	result, err := EnsureDaily(EnsureDailyRequest{
+		VaultPath: vault.Path,
+		DateArg:   "2025-01-10",
+	})
+	if err != nil {
+		t.Fatalf("EnsureDaily returned error: %v", err)
+	}
+	if got, want := result.RelativePath, "journal/2025-01-10.md"; got != want {
+		t.Fatalf("relative path = %q, want %q", got, want)
+	}
+	vault.AssertFileExists("journal/2025-01-10.md")
+	vault.AssertFileNotExists("daily/2025-01-10.md")
+}
+
+func TestEnsureDaily_InvalidDateIsRejected(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.MinimalSchema()).
+		Build()
+
+	_, err := EnsureDaily(EnsureDailyRequest{
+		VaultPath: vault.Path,
+		DateArg:   "2025-13-40",
+	})
+	svcErr, ok := AsError(err)
+	if !ok {
+		t.Fatalf("expected service error, got %v", err)
+	}
+	if svcErr.Code != CodeInvalidInput {
+		t.Fatalf("error code = %q, want %q", svcErr.Code, CodeInvalidInput)
+	}
+}
+
+func TestEnsureDaily_DefaultDateTemplateSubstitutesPlaceholders(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(`version: 2
+types:
+  date:
+    templates: [daily_default]
+    default_template: daily_default
+templates:
+  daily_default:
+    file: templates/daily.md
+`).
+		WithFile("templates/daily.md", "# {{title}}\n\n## Agenda\n\nPlanning for {{date}}\n\n## Gratitude\n").
+		Build()
+
+	if _, err := EnsureDaily(EnsureDailyRequest{VaultPath: vault.Path, DateArg: "2025-01-10"}); err != nil {
+		t.Fatalf("EnsureDaily returned error: %v", err)
+	}
+	vault.AssertFileContains("daily/2025-01-10.md", "# 2025-01-10")
+	vault.AssertFileContains("daily/2025-01-10.md", "Planning for 2025-01-10")
+	vault.AssertFileContains("daily/2025-01-10.md", "## Gratitude")
+	vault.AssertFileNotContains("daily/2025-01-10.md", "{{date}}")
+}
@@ -0,0 +1,217 @@
+package datesvc
+
+import (
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/aidanlsb/raven/internal/dates"
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/parser"
+	"github.com/aidanlsb/raven/internal/schema"
+)
+
+// DefaultUpcomingDays is the agenda window used when no day count is given.
+const DefaultUpcomingDays = 7
+
+type UpcomingRequest struct {
+	VaultPath string
+	// Days is the number of days in the window, starting today (default 7).
+	Days int
+	// IncludeDone keeps items on completed lines (@todo(done), "- [x]").
+	IncludeDone bool
+	// Now overrides the current time (used by tests).
+	Now time.Time
+}
+
+// UpcomingItem is one occurrence of a dated trait in the agenda window.
+type UpcomingItem struct {
+	Date           string `json:"date"`
+	Time           string `json:"time,omitempty"`
+	TraitID        string `json:"trait_id"`
+	TraitType      string `json:"trait_type"`
+	Value          string `json:"value"`
+	Every          string `json:"every,omitempty"`
+	Content        string `json:"content"`
+	FilePath       string `json:"file_path"`
+	Line           int    `json:"line"`
+	ParentObjectID string `json:"parent_object_id"`
+}
+
+type UpcomingDay struct {
+	Date      string         `json:"date"`
+	DayOfWeek string         `json:"day_of_week"`
+	Items     []UpcomingItem `json:"items"`
+}
+
+type UpcomingResult struct {
+	From  string        `json:"from"`
+	To    string        `json:"to"`
+	Days  []UpcomingDay `json:"days"`
+	Count int           `json:"count"`
+}
+
+// Upcoming builds a chronological agenda of every date- or datetime-typed
+// trait (e.g. @due, @remind) falling within the next req.Days days.
+// Traits with an @every(daily|weekly|monthly) trait on the same line are
+// expanded into each occurrence in the window. Completed items are left out
+// unless req.IncludeDone is set.
+func Upcoming(req UpcomingRequest) (*UpcomingResult, error) {
+	if strings.TrimSpace(req.VaultPath) == "" {
+		return nil, newError(CodeInvalidInput, "vault path is required", "", nil)
+	}
+	days := req.Days
+	if days == 0 {
+		days = DefaultUpcomingDays
+	}
+	if days < 1 {
+		return nil, newError(CodeInvalidInput, "days must be at least 1", "Use --days with a positive number", nil)
+	}
+	now := req.Now
+	if now.IsZero() {
+		now = time.Now()
+	}
+
+	sch, err := schema.Load(req.VaultPath)
+	if err != nil {
+		return nil, newError(CodeSchemaInvalid, "failed to load schema", "Fix schema.yaml and try again", err)
+	}
+	traitTypes := datedTraitTypes(sch)
+
+	windowStart, _ := time.Parse(dates.DateLayout, now.Format(dates.DateLayout))
+	windowEnd := windowStart.AddDate(0, 0, days).Add(-time.Nanosecond)
+	result := &UpcomingResult{
+		From: windowStart.Format(dates.DateLayout),
+		To:   windowEnd.Format(dates.DateLayout),
+		Days: []UpcomingDay{},
+	}
+
+	db, err := index.Open(req.VaultPath)
+	if err != nil {
+		return nil, newError(CodeDatabaseError, "failed to open database", "Run 'rvn reindex' to rebuild the database", err)
+	}
+	defer db.Close()
+
+	var excluded []index.TraitValue
+	if !req.IncludeDone {
+		excluded = completedTraitValues(sch)
+	}
+	traits, err := db.QueryDatedTraits(traitTypes, result.From, result.To, excluded)
+	if err != nil {
+		return nil, newError(CodeQueryFailed, "failed to query dated traits", "", err)
+	}
+
+	var items []UpcomingItem
+	for _, trait := range traits {
+		items = append(items, expandUpcoming(trait, windowStart, windowEnd)...)
+	}
+	sort.SliceStable(items, func(i, j int) bool {
+		if items[i].Date != items[j].Date {
+			return items[i].Date < items[j].Date
+		}
+		// All-day items (no time) come before timed items on the same day.
+		if items[i].Time != items[j].Time {
+			return items[i].Time < items[j].Time
+		}
+		if items[i].FilePath != items[j].FilePath {
+			return items[i].FilePath < items[j].FilePath
+		}
+		return items[i].Line < items[j].Line
+	})
+
+	for _, item := range items {
+		last := len(result.Days) - 1
+		if last < 0 || result.Days[last].Date != item.Date {
+			day, _ := time.Parse(dates.DateLayout, item.Date)
+			result.Days = append(result.Days, UpcomingDay{Date: item.Date, DayOfWeek: day.Format("Monday")})
+			last++
+		}
+		result.Days[last].Items = append(result.Days[last].Items, item)
+	}
+	result.Count = len(items)
+	return result, nil
+}
+
+// datedTraitTypes returns the schema traits whose values are dates or datetimes.
+func datedTraitTypes(sch *schema.Schema) []string {
+	var names []string
+	for name, def := range sch.Traits {
+		if def == nil {
+			continue
+		}
+		if def.Type == schema.FieldTypeDate || def.Type == schema.FieldTypeDatetime {
+			names = append(names, name)
+		}
+	}
+	sort.Strings(names)
+	return names
+}
+
+// completedTraitValues returns the trait values that mark a line as done:
+// "done" on any schema enum trait that allows it (e.g. @todo(done)), and
+// checked checkbox items.
+func completedTraitValues(sch *schema.Schema) []index.TraitValue {
+	values := []index.TraitValue{{TraitType: parser.CheckboxTaskTrait, Value: parser.CheckboxStatusDone}}
+	for name, def := range sch.Traits {
+		if def == nil || def.Type != schema.FieldTypeEnum || name == parser.CheckboxTaskTrait {
+			continue
+		}
+		for _, value := range def.Values {
+			if value == parser.CheckboxStatusDone {
+				values = append(values, index.TraitValue{TraitType: name, Value: value})
+				break
+			}
+		}
+	}
+	sort.Slice(values, func(i, j int) bool { return values[i].TraitType < values[j].TraitType })
+	return values
+}
+
+func expandUpcoming(trait index.DatedTrait, windowStart, windowEnd time.Time) []UpcomingItem {
+	if trait.Value == nil {
+		return nil
+	}
+	value := strings.TrimSpace(*trait.Value)
+
+	base, timed := time.Time{}, false
+	if parsed, err := dates.ParseDate(value); err == nil {
+		base = parsed
+	} else if parsed, err := dates.ParseDatetime(value); err == nil {
+		base, timed = parsed, true
+	} else {
+		return nil
+	}
+
+	every := ""
+	occurrences := []time.Time{base}
+	if trait.Every != nil {
+		if interval, ok := dates.NormalizeRecurrence(*trait.Every); ok {
+			every = interval
+			occurrences, _ = dates.NextOccurrences(base, interval, windowStart, windowEnd)
+		}
+	}
+
+	var items []UpcomingItem
+	for _, occ := range occurrences {
+		day := occ.Format(dates.DateLayout)
+		if day < windowStart.Format(dates.DateLayout) || day > windowEnd.Format(dates.DateLayout) {
+			continue
+		}
+		item := UpcomingItem{
+			Date:           day,
+			TraitID:        trait.ID,
+			TraitType:      trait.TraitType,
+			Value:          value,
+			Every:          every,
+			Content:        trait.Content,
+			FilePath:       trait.FilePath,
+			Line:           trait.Line,
+			ParentObjectID: trait.ParentObjectID,
+		}
+		if timed {
+			item.Time = occ.Format("15:04")
+		}
+		items = append(items, item)
+	}
+	return items
+}
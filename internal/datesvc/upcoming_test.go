@@ -0,0 +1,138 @@
+package datesvc
+
+import (
+	"reflect"
+	"testing"
+	"time"
+
+	"github.com/aidanlsb/raven/internal/testutil"
+)
+
+func TestUpcoming_OrdersDueDatesAndRemindersByDate(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(`version: 1
+types: {}
+traits:
+  due:
+    type: date
+  remind:
+    type: datetime
+  every:
+    type: enum
+    values: [daily, weekly, monthly]
+  todo:
+    type: boolean
+`).
+		WithFile("tasks.md", "- @todo @due(2026-03-05) Ship it\n- @todo @due(2026-02-01) Overdue item\n").
+		WithFile("meetings.md", "- @remind(2026-03-03T09:00) Call Freya\n- @remind(2026-02-23T10:00) @every(weekly) Standup\n").
+		Build()
+
+	vault.RunCLI("reindex").MustSucceed(t)
+
+	result, err := Upcoming(UpcomingRequest{
+		VaultPath: vault.Path,
+		Now:       time.Date(2026, 3, 2, 8, 0, 0, 0, time.UTC),
+	})
+	if err != nil {
+		t.Fatalf("Upcoming returned error: %v", err)
+	}
+	if result.From != "2026-03-02" || result.To != "2026-03-08" {
+		t.Fatalf("window = %s..%s, want 2026-03-02..2026-03-08", result.From, result.To)
+	}
+
+	type entry struct{ date, time, content string }
+	var got []entry
+	for _, day := range result.Days {
+		for _, item := range day.Items {
+			if item.Date != day.Date {
+				t.Fatalf("item %q grouped under %s, has date %s", item.Content, day.Date, item.Date)
+			}
+			got = append(got, entry{item.Date, item.Time, item.Content})
+		}
+	}
+	want := []entry{
+		{"2026-03-02", "10:00", "Standup"},
+		{"2026-03-03", "09:00", "Call Freya"},
+		{"2026-03-05", "", "Ship it"},
+	}
+	if len(got) != len(want) {
+		t.Fatalf("got %+v, want %+v", got, want)
+	}
+	for i := range want {
+		if got[i] != want[i] {
+			t.Fatalf("item %d = %+v, want %+v", i, got[i], want[i])
+		}
+	}
+	if result.Count != 3 || len(result.Days) != 3 {
+		t.Fatalf("count = %d, days = %d, want 3 and 3", result.Count, len(result.Days))
+	}
+	if result.Days[0].Items[0].Every != "weekly" {
+		t.Fatalf("expected standup to carry its recurrence, got %+v", result.Days[0].Items[0])
+	}
+}
+
+func TestUpcoming_ExcludesCompletedItemsUnlessRequested(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(`version: 1
+types: {}
+traits:
+  due:
+    type: date
+  todo:
+    type: enum
+    values: [todo, done]
+  task:
+    type: enum
+    values: [todo, done]
+`).
+		WithRavenYAML("checkbox_tasks: true\n").
+		WithFile("tasks.md", "- @todo(todo) @due(2026-03-03) Open item\n- @todo(done) @due(2026-03-04) Finished item\n- [x] Checked item @due(2026-03-05)\n- [ ] Unchecked item @due(2026-03-06)\n").
+		Build()
+
+	vault.RunCLI("reindex").MustSucceed(t)
+
+	now := time.Date(2026, 3, 2, 8, 0, 0, 0, time.UTC)
+	lines := func(result *UpcomingResult) []int {
+		var out []int
+		for _, day := range result.Days {
+			for _, item := range day.Items {
+				out = append(out, item.Line)
+			}
+		}
+		return out
+	}
+
+	result, err := Upcoming(UpcomingRequest{VaultPath: vault.Path, Now: now})
+	if err != nil {
+		t.Fatalf("Upcoming returned error: %v", err)
+	}
+	if got, want := lines(result), []int{1, 4}; !reflect.DeepEqual(got, want) {
+		t.Fatalf("default lines = %#v, want %#v", got, want)
+	}
+
+	result, err = Upcoming(UpcomingRequest{VaultPath: vault.Path, Now: now, IncludeDone: true})
+	if err != nil {
+		t.Fatalf("Upcoming with IncludeDone returned error: %v", err)
+	}
+	if got, want := lines(result), []int{1, 2, 3, 4}; !reflect.DeepEqual(got, want) {
+		t.Fatalf("lines with IncludeDone = %#v, want %#v", got, want)
+	}
+}
+
+func TestUpcoming_RejectsNonPositiveDays(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.MinimalSchema()).
+		Build()
+
+	_, err := Upcoming(UpcomingRequest{VaultPath: vault.Path, Days: -1})
+	svcErr, ok := AsError(err)
+	if !ok || svcErr.Code != CodeInvalidInput {
+		t.Fatalf("expected invalid input error, got %v", err)
+	}
+}
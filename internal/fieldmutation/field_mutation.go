@@ -4,6 +4,7 @@ import (
 	"encoding/json"
 	"fmt"
 	"os"
+	"path/filepath"
 	"sort"
 	"strconv"
 	"strings"
@@ -18,6 +19,7 @@ import (
 	"github.com/aidanlsb/raven/internal/parser"
 	"github.com/aidanlsb/raven/internal/readsvc"
 	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/vault"
 )
 
 type ValidationError struct {
@@ -535,19 +537,20 @@ func validateRefTargets(
 		Schema:    sch,
 	}
 
+	// Target types come from the index when available; the file is parsed for
+	// objects the index does not know yet or whose file changed since indexing.
+	var objectTypes map[string]string
 	db, err := index.Open(refCtx.VaultPath)
 	if err == nil {
 		db.SetDailyDirectory(refCtx.VaultConfig.GetDailyDirectory())
 		rt.DB = db
 		defer db.Close()
+		objectTypes, _ = db.ObjectTypesByID()
 	}
 
 	parseOpts := refCtx.ParseOptions
 	if parseOpts == nil {
-		parseOpts = &parser.ParseOptions{
-			ObjectsRoot: refCtx.VaultConfig.GetObjectsRoot(),
-			PagesRoot:   refCtx.VaultConfig.GetPagesRoot(),
-		}
+		parseOpts = vault.ParseOptionsFromConfig(refCtx.VaultConfig)
 	}
 
 	var issues []schema.ValidationError
@@ -568,7 +571,7 @@ func validateRefTargets(
 			AllowBareStrings: true,
 		})
 		for _, ref := range refs {
-			actualType, resolveErr := resolveReferenceType(rt, parseOpts, ref.TargetRaw)
+			actualType, resolveErr := resolveReferenceType(rt, objectTypes, parseOpts, ref.TargetRaw)
 			if resolveErr != nil || actualType == "" {
 				continue
 			}
@@ -585,11 +588,14 @@ func validateRefTargets(
 	return issues
 }
 
-func resolveReferenceType(rt *readsvc.Runtime, parseOpts *parser.ParseOptions, rawRef string) (string, error) {
+func resolveReferenceType(rt *readsvc.Runtime, objectTypes map[string]string, parseOpts *parser.ParseOptions, rawRef string) (string, error) {
 	resolved, err := readsvc.ResolveReference(rawRef, rt, false)
 	if err != nil {
 		return "", err
 	}
+	if objectType, ok := objectTypes[resolved.ObjectID]; ok && indexedFileCurrent(rt, resolved.FilePath) {
+		return objectType, nil
+	}
 
 	content, err := os.ReadFile(resolved.FilePath)
 	if err != nil {
@@ -610,6 +616,27 @@ func resolveReferenceType(rt *readsvc.Runtime, parseOpts *parser.ParseOptions, r
 	return "", fmt.Errorf("resolved object %q not found in parsed document", resolved.ObjectID)
 }
 
+// indexedFileCurrent reports whether the file has not been modified since it
+// was last indexed, so its indexed object types can be trusted.
+func indexedFileCurrent(rt *readsvc.Runtime, filePath string) bool {
+	if rt.DB == nil {
+		return false
+	}
+	relPath, err := filepath.Rel(rt.VaultPath, filePath)
+	if err != nil {
+		return false
+	}
+	indexedMtime, err := rt.DB.GetFileMtime(filepath.ToSlash(relPath))
+	if err != nil || indexedMtime == 0 {
+		return false
+	}
+	info, err := os.Stat(filePath)
+	if err != nil {
+		return false
+	}
+	return info.ModTime().Unix() <= indexedMtime
+}
+
 func parseJSONObject(raw string) (map[string]interface{}, error) {
 	var obj map[string]interface{}
 	if err := json.Unmarshal([]byte(raw), &obj); err != nil {
@@ -98,6 +98,13 @@ func FieldValueToYAMLValue(value schema.FieldValue) interface{} {
 		}
 		return items
 	}
+	if m, ok := value.AsMap(); ok {
+		entries := make(map[string]interface{}, len(m))
+		for key, item := range m {
+			entries[key] = FieldValueToYAMLValue(item)
+		}
+		return entries
+	}
 	if s, ok := value.AsString(); ok {
 		return s
 	}
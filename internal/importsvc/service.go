@@ -492,7 +492,7 @@ func createObject(
 		nil,
 		typedFields,
 		sch,
-		map[string]bool{"type": true, "alias": true},
+		map[string]bool{"type": true, "alias": true, "aliases": true},
 		&fieldmutation.RefValidationContext{
 			VaultPath:   vaultPath,
 			VaultConfig: vaultCfg,
@@ -566,7 +566,7 @@ func updateObject(
 		typeName,
 		typedUpdates,
 		sch,
-		map[string]bool{"type": true, "alias": true},
+		map[string]bool{"type": true, "alias": true, "aliases": true},
 		&fieldmutation.RefValidationContext{
 			VaultPath:   vaultPath,
 			VaultConfig: vaultCfg,
@@ -2,12 +2,15 @@
 package index
 
 import (
+	"bytes"
+	"context"
 	"database/sql"
 	"encoding/json"
 	"errors"
 	"fmt"
 	"os"
 	"path/filepath"
+	"sort"
 	"strconv"
 	"strings"
 	"time"
@@ -27,6 +30,11 @@ type Database struct {
 	db              *sql.DB
 	dailyDirectory  string
 	autoResolveRefs bool
+	// bulkConn is the connection pinned by BeginBulk. PRAGMAs only apply to
+	// the connection they run on, so write transactions use it while set.
+	bulkConn *sql.Conn
+	// bulkRestore holds the PRAGMA statements EndBulk runs to undo BeginBulk.
+	bulkRestore []string
 }
 
 var (
@@ -41,14 +49,19 @@ func (d *Database) DB() *sql.DB {
 	return d.db
 }
 
+// DBPath returns the path of the index database for a vault.
+func DBPath(vaultPath string) string {
+	return filepath.Join(vaultPath, ".raven", "index.db")
+}
+
 // Open opens or creates the database.
 func Open(vaultPath string) (*Database, error) {
-	dbDir := filepath.Join(vaultPath, ".raven")
+	dbPath := DBPath(vaultPath)
+	dbDir := filepath.Dir(dbPath)
 	if err := os.MkdirAll(dbDir, 0755); err != nil {
 		return nil, fmt.Errorf("failed to create .raven directory: %w", err)
 	}
 
-	dbPath := filepath.Join(dbDir, "index.db")
 	isNewDB, err := isNewDatabaseFile(dbPath)
 	if err != nil {
 		return nil, err
@@ -70,8 +83,8 @@ func Open(vaultPath string) (*Database, error) {
 // OpenWithRebuild opens the database, rebuilding if schema is incompatible.
 // Returns (database, wasRebuilt, error).
 func OpenWithRebuild(vaultPath string) (*Database, bool, error) {
-	dbDir := filepath.Join(vaultPath, ".raven")
-	dbPath := filepath.Join(dbDir, "index.db")
+	dbPath := DBPath(vaultPath)
+	dbDir := filepath.Dir(dbPath)
 
 	lock, err := acquireIndexLock(dbDir)
 	if err != nil {
@@ -186,9 +199,86 @@ func OpenInMemory() (*Database, error) {
 
 // Close closes the database.
 func (d *Database) Close() error {
+	if d.bulkConn != nil {
+		_ = d.bulkConn.Close()
+		d.bulkConn = nil
+	}
 	return d.db.Close()
 }
 
+// bulkCacheSizeKB is the page cache used during bulk writes (negative = KB).
+const bulkCacheSizeKB = -256000
+
+// begin starts a write transaction, on the bulk connection when one is pinned.
+func (d *Database) begin() (*sql.Tx, error) {
+	if d.bulkConn != nil {
+		return d.bulkConn.BeginTx(context.Background(), nil)
+	}
+	return d.db.Begin()
+}
+
+// BeginBulk pins one connection for a bulk rebuild and switches it to faster
+// settings: synchronous = NORMAL and an enlarged page cache. Write
+// transactions run on that connection until EndBulk. With WAL, NORMAL can
+// lose the last commits on power loss but does not corrupt the database;
+// a lost commit is repaired by the next reindex.
+func (d *Database) BeginBulk() error {
+	if d.bulkConn != nil {
+		return nil
+	}
+
+	ctx := context.Background()
+	conn, err := d.db.Conn(ctx)
+	if err != nil {
+		return fmt.Errorf("pin connection: %w", err)
+	}
+
+	var synchronous, cacheSize int
+	if err := conn.QueryRowContext(ctx, "PRAGMA synchronous").Scan(&synchronous); err != nil {
+		conn.Close()
+		return fmt.Errorf("read synchronous pragma: %w", err)
+	}
+	if err := conn.QueryRowContext(ctx, "PRAGMA cache_size").Scan(&cacheSize); err != nil {
+		conn.Close()
+		return fmt.Errorf("read cache_size pragma: %w", err)
+	}
+
+	d.bulkConn = conn
+	d.bulkRestore = []string{
+		fmt.Sprintf("PRAGMA synchronous = %d", synchronous),
+		fmt.Sprintf("PRAGMA cache_size = %d", cacheSize),
+	}
+	for _, stmt := range []string{"PRAGMA synchronous = NORMAL", fmt.Sprintf("PRAGMA cache_size = %d", bulkCacheSizeKB)} {
+		if _, err := conn.ExecContext(ctx, stmt); err != nil {
+			_ = d.EndBulk()
+			return fmt.Errorf("set pragma: %w", err)
+		}
+	}
+	return nil
+}
+
+// EndBulk restores the settings saved by BeginBulk and releases the pinned
+// connection. Every setting is restored even if an earlier one fails; the
+// first error is returned. It is a no-op when bulk mode is not active.
+func (d *Database) EndBulk() error {
+	if d.bulkConn == nil {
+		return nil
+	}
+
+	var firstErr error
+	for _, stmt := range d.bulkRestore {
+		if _, err := d.bulkConn.ExecContext(context.Background(), stmt); err != nil && firstErr == nil {
+			firstErr = fmt.Errorf("restore pragma: %w", err)
+		}
+	}
+	if err := d.bulkConn.Close(); err != nil && firstErr == nil {
+		firstErr = fmt.Errorf("release connection: %w", err)
+	}
+	d.bulkConn = nil
+	d.bulkRestore = nil
+	return firstErr
+}
+
 // SetDailyDirectory configures the daily notes directory for reference resolution.
 func (d *Database) SetDailyDirectory(dailyDir string) {
 	if dailyDir == "" {
@@ -210,6 +300,19 @@ func (d *Database) Analyze() error {
 	return err
 }
 
+// Vacuum rebuilds the database file to reclaim space left by deleted rows.
+func (d *Database) Vacuum() error {
+	_, err := d.db.Exec("VACUUM")
+	return err
+}
+
+// Checkpoint copies the write-ahead log into the main database file and
+// truncates the WAL file.
+func (d *Database) Checkpoint() error {
+	_, err := d.db.Exec("PRAGMA wal_checkpoint(TRUNCATE)")
+	return err
+}
+
 // CurrentDBVersion is the current database schema version.
 // v7: Added composite indexes for trait refs matching and performance PRAGMAs
 // v8: Added alias column to objects table for reference aliasing
@@ -219,7 +322,11 @@ func (d *Database) Analyze() error {
 // v12: Added first-class sections table
 // v13: Removed object hierarchy/heading columns; objects are file-backed only
 // v14: Added subtree line ranges for heading-derived sections
-const CurrentDBVersion = 14
+// v15: Added object_aliases table for multi-alias reference resolution
+// v16: Added word_count column to objects table
+// v17: Added type_explicit column to objects table
+// v18: Added blocks table for ^block-id anchors
+const CurrentDBVersion = 18
 
 // initialize creates the database schema.
 func (d *Database) initialize(isNewDB bool) error {
@@ -244,13 +351,23 @@ func (d *Database) initialize(isNewDB bool) error {
 			id TEXT PRIMARY KEY,
 			file_path TEXT NOT NULL,
 			type TEXT NOT NULL,
+			type_explicit INTEGER NOT NULL DEFAULT 0, -- 1 when frontmatter declares the type
 			fields TEXT NOT NULL DEFAULT '{}',
 			line_start INTEGER NOT NULL,
 			alias TEXT,                 -- Optional alias for reference resolution
 			file_mtime INTEGER,         -- File modification time from filesystem (Unix timestamp)
+			word_count INTEGER,         -- Prose words in the file body (see parser.CountWords)
 			indexed_at INTEGER          -- When this row was written to the index
 		);
 
+		-- Aliases from the reserved alias/aliases frontmatter keys
+		CREATE TABLE IF NOT EXISTS object_aliases (
+			object_id TEXT NOT NULL,
+			alias TEXT NOT NULL,
+			file_path TEXT NOT NULL,
+			PRIMARY KEY (object_id, alias)
+		);
+
 		-- Markdown heading-derived sections. Sections are addressable scopes, not objects.
 		CREATE TABLE IF NOT EXISTS sections (
 			id TEXT PRIMARY KEY,
@@ -265,6 +382,19 @@ func (d *Database) initialize(isNewDB bool) error {
 			parent_section_id TEXT,
 			indexed_at INTEGER
 		);
+
+		-- Block IDs declared with a trailing ^id. Like sections, blocks are
+		-- addressable (file#^id) but are not objects.
+		CREATE TABLE IF NOT EXISTS blocks (
+			id TEXT PRIMARY KEY,
+			file_object_id TEXT NOT NULL,
+			file_path TEXT NOT NULL,
+			block_id TEXT NOT NULL,
+			parent_id TEXT NOT NULL,
+			content TEXT NOT NULL,
+			line_number INTEGER NOT NULL,
+			indexed_at INTEGER
+		);
 		
 		-- All trait annotations (single-valued)
 		CREATE TABLE IF NOT EXISTS traits (
@@ -319,10 +449,14 @@ func (d *Database) initialize(isNewDB bool) error {
 		CREATE INDEX IF NOT EXISTS idx_objects_file ON objects(file_path);
 		CREATE INDEX IF NOT EXISTS idx_objects_type ON objects(type);
 		CREATE INDEX IF NOT EXISTS idx_objects_alias ON objects(alias) WHERE alias IS NOT NULL;
+		CREATE INDEX IF NOT EXISTS idx_object_aliases_alias ON object_aliases(alias);
+		CREATE INDEX IF NOT EXISTS idx_object_aliases_file ON object_aliases(file_path);
 
 		CREATE INDEX IF NOT EXISTS idx_sections_file ON sections(file_path);
 		CREATE INDEX IF NOT EXISTS idx_sections_file_object ON sections(file_object_id);
 		CREATE INDEX IF NOT EXISTS idx_sections_parent ON sections(parent_section_id);
+
+		CREATE INDEX IF NOT EXISTS idx_blocks_file ON blocks(file_path);
 		
 		CREATE INDEX IF NOT EXISTS idx_traits_file ON traits(file_path);
 		CREATE INDEX IF NOT EXISTS idx_traits_type ON traits(trait_type);
@@ -438,12 +572,21 @@ func (d *Database) IndexDocument(doc *parser.ParsedDocument, sch *schema.Schema)
 // fileMtime should be the file's modification time as Unix timestamp (seconds).
 // Pass 0 if mtime is unknown (will use current time as fallback).
 func (d *Database) IndexDocumentWithMtime(doc *parser.ParsedDocument, sch *schema.Schema, fileMtime int64) error {
-	tx, err := d.db.Begin()
+	tx, err := d.begin()
 	if err != nil {
 		return err
 	}
 	defer tx.Rollback()
 
+	// Batch writers turn auto-resolve off and resolve once when they finish,
+	// so only single-file updates pay for this lookup.
+	addsObjects := false
+	if d.autoResolveRefs {
+		if addsObjects, err = documentAddsObjectIDs(tx, doc); err != nil {
+			return err
+		}
+	}
+
 	// Delete existing data for this file
 	if err := deleteByFilePath(tx, doc.FilePath); err != nil {
 		return err
@@ -454,12 +597,15 @@ func (d *Database) IndexDocumentWithMtime(doc *parser.ParsedDocument, sch *schem
 	// Use provided mtime or fall back to current time
 	mtime := indexedMtime(now, fileMtime)
 
-	if err := indexObjects(tx, doc, mtime, now); err != nil {
+	if err := indexObjects(tx, doc, sch, mtime, now); err != nil {
 		return err
 	}
 	if err := indexSections(tx, doc, now); err != nil {
 		return err
 	}
+	if err := indexBlocks(tx, doc, now); err != nil {
+		return err
+	}
 	if err := indexInlineTraits(tx, doc, sch, now); err != nil {
 		return err
 	}
@@ -481,7 +627,13 @@ func (d *Database) IndexDocumentWithMtime(doc *parser.ParsedDocument, sch *schem
 	}
 
 	if d.autoResolveRefs && d.dailyDirectory != "" {
-		if _, err := d.ResolveReferencesForFileWithSchema(doc.FilePath, d.dailyDirectory, sch); err != nil {
+		// New object IDs can satisfy refs in other files that were dangling
+		// until now, so re-check every unresolved ref rather than only this file's.
+		if addsObjects {
+			if _, err := d.ResolveReferencesWithSchema(d.dailyDirectory, sch); err != nil {
+				return err
+			}
+		} else if _, err := d.ResolveReferencesForFileWithSchema(doc.FilePath, d.dailyDirectory, sch); err != nil {
 			return err
 		}
 	}
@@ -489,6 +641,40 @@ func (d *Database) IndexDocumentWithMtime(doc *parser.ParsedDocument, sch *schem
 	return nil
 }
 
+// documentAddsObjectIDs reports whether doc defines any object or block ID
+// that is not already indexed for its file.
+func documentAddsObjectIDs(tx *sql.Tx, doc *parser.ParsedDocument) (bool, error) {
+	rows, err := tx.Query(`SELECT id FROM objects WHERE file_path = ? UNION SELECT id FROM blocks WHERE file_path = ?`, doc.FilePath, doc.FilePath)
+	if err != nil {
+		return false, err
+	}
+	defer rows.Close()
+
+	existing := make(map[string]struct{})
+	for rows.Next() {
+		var id string
+		if err := rows.Scan(&id); err != nil {
+			return false, err
+		}
+		existing[id] = struct{}{}
+	}
+	if err := rows.Err(); err != nil {
+		return false, err
+	}
+
+	for _, obj := range doc.Objects {
+		if _, ok := existing[obj.ID]; !ok {
+			return true, nil
+		}
+	}
+	for _, block := range doc.Blocks {
+		if _, ok := existing[block.ID]; !ok {
+			return true, nil
+		}
+	}
+	return false, nil
+}
+
 func indexedMtime(now, fileMtime int64) int64 {
 	mtime := fileMtime
 	if mtime == 0 {
@@ -502,7 +688,7 @@ func (d *Database) IndexAsset(asset *model.Asset) error {
 	if asset == nil {
 		return nil
 	}
-	tx, err := d.db.Begin()
+	tx, err := d.begin()
 	if err != nil {
 		return err
 	}
@@ -540,18 +726,29 @@ func nullableString(value string) interface{} {
 	return value
 }
 
-func indexObjects(tx *sql.Tx, doc *parser.ParsedDocument, mtime, indexedAt int64) error {
+func indexObjects(tx *sql.Tx, doc *parser.ParsedDocument, sch *schema.Schema, mtime, indexedAt int64) error {
 	objStmt, err := tx.Prepare(`
-		INSERT INTO objects (id, file_path, type, fields, line_start, alias, file_mtime, indexed_at)
-		VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+		INSERT INTO objects (id, file_path, type, type_explicit, fields, line_start, alias, file_mtime, word_count, indexed_at)
+		VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
 	`)
 	if err != nil {
 		return err
 	}
 	defer objStmt.Close()
 
+	wordCount := parser.CountWords(doc.Body)
+
+	aliasStmt, err := tx.Prepare(`
+		INSERT OR IGNORE INTO object_aliases (object_id, alias, file_path)
+		VALUES (?, ?, ?)
+	`)
+	if err != nil {
+		return err
+	}
+	defer aliasStmt.Close()
+
 	for _, obj := range doc.Objects {
-		fieldsJSON, err := json.Marshal(fieldsToMap(obj.Fields))
+		fieldsJSON, err := orderedFieldsJSON(normalizedEnumFields(obj, sch), obj.FieldOrder)
 		if err != nil {
 			return err
 		}
@@ -568,20 +765,61 @@ func indexObjects(tx *sql.Tx, doc *parser.ParsedDocument, mtime, indexedAt int64
 			obj.ID,
 			doc.FilePath,
 			obj.ObjectType,
+			obj.TypeExplicit,
 			string(fieldsJSON),
 			obj.LineStart,
 			alias,
 			mtime,
+			wordCount,
 			indexedAt,
 		)
 		if err != nil {
 			return err
 		}
+
+		for _, a := range objectAliases(obj.Fields) {
+			if _, err := aliasStmt.Exec(obj.ID, a, doc.FilePath); err != nil {
+				return err
+			}
+		}
 	}
 
 	return nil
 }
 
+// objectAliases collects aliases from the reserved `alias` (string) and
+// `aliases` (string or list) frontmatter keys, trimmed and de-duplicated.
+func objectAliases(fields map[string]schema.FieldValue) []string {
+	var aliases []string
+	seen := make(map[string]bool)
+	add := func(value schema.FieldValue) {
+		s, ok := value.AsString()
+		if !ok {
+			return
+		}
+		s = strings.TrimSpace(s)
+		if s == "" || seen[s] {
+			return
+		}
+		seen[s] = true
+		aliases = append(aliases, s)
+	}
+
+	if value, ok := fields["alias"]; ok {
+		add(value)
+	}
+	if value, ok := fields["aliases"]; ok {
+		if items, ok := value.AsArray(); ok {
+			for _, item := range items {
+				add(item)
+			}
+		} else {
+			add(value)
+		}
+	}
+	return aliases
+}
+
 func indexSections(tx *sql.Tx, doc *parser.ParsedDocument, indexedAt int64) error {
 	stmt, err := tx.Prepare(`
 		INSERT INTO sections (id, file_object_id, file_path, slug, title, level, line_start, line_end, subtree_line_end, parent_section_id, indexed_at)
@@ -613,6 +851,34 @@ func indexSections(tx *sql.Tx, doc *parser.ParsedDocument, indexedAt int64) erro
 	return nil
 }
 
+func indexBlocks(tx *sql.Tx, doc *parser.ParsedDocument, indexedAt int64) error {
+	stmt, err := tx.Prepare(`
+		INSERT INTO blocks (id, file_object_id, file_path, block_id, parent_id, content, line_number, indexed_at)
+		VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+	`)
+	if err != nil {
+		return err
+	}
+	defer stmt.Close()
+
+	for _, block := range doc.Blocks {
+		_, err := stmt.Exec(
+			block.ID,
+			block.FileObjectID,
+			doc.FilePath,
+			block.BlockID,
+			block.ParentID,
+			block.Content,
+			block.Line,
+			indexedAt,
+		)
+		if err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
 func indexInlineTraits(tx *sql.Tx, doc *parser.ParsedDocument, sch *schema.Schema, indexedAt int64) error {
 	traitStmt, err := tx.Prepare(`
 		INSERT INTO traits (id, file_path, parent_object_id, trait_type, value, content, line_number, indexed_at)
@@ -941,7 +1207,7 @@ func (d *Database) RemoveFiles(filePaths []string) error {
 		return nil
 	}
 
-	tx, err := d.db.Begin()
+	tx, err := d.begin()
 	if err != nil {
 		return err
 	}
@@ -959,7 +1225,7 @@ func (d *Database) RemoveFiles(filePaths []string) error {
 // ClearAllData removes all indexed data from the database.
 // This is used for full reindex to ensure a clean slate.
 func (d *Database) ClearAllData() error {
-	tx, err := d.db.Begin()
+	tx, err := d.begin()
 	if err != nil {
 		return err
 	}
@@ -967,7 +1233,9 @@ func (d *Database) ClearAllData() error {
 
 	for _, stmt := range []string{
 		"DELETE FROM objects",
+		"DELETE FROM object_aliases",
 		"DELETE FROM sections",
+		"DELETE FROM blocks",
 		"DELETE FROM traits",
 		"DELETE FROM refs",
 		"DELETE FROM field_refs",
@@ -1081,7 +1349,7 @@ func (d *Database) RemoveDocument(objectID string) error {
 	// the markdown file without rewriting content.
 	baseID := baseDocumentID(objectID)
 
-	tx, err := d.db.Begin()
+	tx, err := d.begin()
 	if err != nil {
 		return err
 	}
@@ -1121,7 +1389,7 @@ func baseDocumentID(objectID string) string {
 func (d *Database) Stats() (*IndexStats, error) {
 	var stats IndexStats
 
-	if err := d.db.QueryRow("SELECT COUNT(*) FROM objects").Scan(&stats.ObjectCount); err != nil {
+	if err := d.db.QueryRow("SELECT COUNT(*), COALESCE(SUM(word_count), 0) FROM objects").Scan(&stats.ObjectCount, &stats.WordCount); err != nil {
 		return nil, err
 	}
 	if err := d.db.QueryRow("SELECT COUNT(*) FROM traits").Scan(&stats.TraitCount); err != nil {
@@ -1154,6 +1422,44 @@ type IndexStats struct {
 	RefCount    int
 	FileCount   int
 	AssetCount  int
+	WordCount   int
+}
+
+// TypeCount is a name (object or trait type) and how many times it occurs.
+type TypeCount struct {
+	Type  string `json:"type"`
+	Count int    `json:"count"`
+}
+
+// ObjectCountsByType returns the number of indexed objects per type, most
+// common first, then by type name. Sections live in their own table and are
+// not counted.
+func (d *Database) ObjectCountsByType() ([]TypeCount, error) {
+	return d.typeCounts(`SELECT type, COUNT(*) AS n FROM objects GROUP BY type ORDER BY n DESC, type ASC`)
+}
+
+// TraitCountsByType returns the number of trait annotations per trait type,
+// most common first, then by trait name.
+func (d *Database) TraitCountsByType() ([]TypeCount, error) {
+	return d.typeCounts(`SELECT trait_type, COUNT(*) AS n FROM traits GROUP BY trait_type ORDER BY n DESC, trait_type ASC`)
+}
+
+func (d *Database) typeCounts(query string) ([]TypeCount, error) {
+	rows, err := d.db.Query(query)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	results := []TypeCount{}
+	for rows.Next() {
+		var result TypeCount
+		if err := rows.Scan(&result.Type, &result.Count); err != nil {
+			return nil, err
+		}
+		results = append(results, result)
+	}
+	return results, rows.Err()
 }
 
 // AllObjectIDs returns all object IDs (for reference resolution).
@@ -1161,6 +1467,27 @@ func (d *Database) AllObjectIDs() ([]string, error) {
 	return allObjectIDsFromDB(d.db)
 }
 
+// ObjectTypesByID returns a map from object ID to type name for every indexed
+// object. Sections are not included. Load it once when many resolved references
+// need their target type checked, instead of querying or parsing per ref.
+func (d *Database) ObjectTypesByID() (map[string]string, error) {
+	rows, err := d.db.Query("SELECT id, type FROM objects")
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	types := make(map[string]string)
+	for rows.Next() {
+		var id, objectType string
+		if err := rows.Scan(&id, &objectType); err != nil {
+			return nil, err
+		}
+		types[id] = objectType
+	}
+	return types, rows.Err()
+}
+
 // AllAliases returns a map from alias to object ID for all objects with aliases.
 // This is used for reference resolution where [[alias]] should resolve to the object.
 // If multiple objects have the same alias, the first one encountered in ID order wins.
@@ -1169,6 +1496,25 @@ func (d *Database) AllAliases() (map[string]string, error) {
 	return allAliasesFromDB(d.db)
 }
 
+// ObjectAliases returns the aliases declared by an object, sorted.
+func (d *Database) ObjectAliases(objectID string) ([]string, error) {
+	rows, err := d.db.Query("SELECT alias FROM object_aliases WHERE object_id = ? ORDER BY alias", objectID)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var aliases []string
+	for rows.Next() {
+		var alias string
+		if err := rows.Scan(&alias); err != nil {
+			return nil, err
+		}
+		aliases = append(aliases, alias)
+	}
+	return aliases, rows.Err()
+}
+
 // ResolverOptions configures resolver creation.
 type ResolverOptions struct {
 	// DailyDirectory is the directory for daily notes (default: "daily").
@@ -1295,6 +1641,13 @@ func allObjectIDsFromDB(db *sql.DB) ([]string, error) {
 	if hasSections {
 		query += "\nUNION\nSELECT id FROM sections"
 	}
+	hasBlocks, err := objectsTableHasColumn(db, "blocks", "id")
+	if err != nil {
+		return nil, err
+	}
+	if hasBlocks {
+		query += "\nUNION\nSELECT id FROM blocks"
+	}
 	rows, err := db.Query(query)
 	if err != nil {
 		return nil, err
@@ -1340,15 +1693,15 @@ func allAssetIDsFromDB(db *sql.DB) ([]string, error) {
 }
 
 func allAliasesFromDB(db *sql.DB) (map[string]string, error) {
-	hasAliasColumn, err := objectsTableHasColumn(db, "objects", "alias")
+	hasAliasTable, err := objectsTableHasColumn(db, "object_aliases", "alias")
 	if err != nil {
 		return nil, err
 	}
-	if !hasAliasColumn {
+	if !hasAliasTable {
 		return map[string]string{}, nil
 	}
 
-	rows, err := db.Query("SELECT alias, id FROM objects WHERE alias IS NOT NULL AND alias != '' ORDER BY id")
+	rows, err := db.Query("SELECT alias, object_id FROM object_aliases ORDER BY object_id, alias")
 	if err != nil {
 		return nil, err
 	}
@@ -1370,15 +1723,15 @@ func allAliasesFromDB(db *sql.DB) (map[string]string, error) {
 }
 
 func allAliasMatchesFromDB(db *sql.DB) (map[string][]string, error) {
-	hasAliasColumn, err := objectsTableHasColumn(db, "objects", "alias")
+	hasAliasTable, err := objectsTableHasColumn(db, "object_aliases", "alias")
 	if err != nil {
 		return nil, err
 	}
-	if !hasAliasColumn {
+	if !hasAliasTable {
 		return map[string][]string{}, nil
 	}
 
-	rows, err := db.Query("SELECT alias, id FROM objects WHERE alias IS NOT NULL AND alias != '' ORDER BY id")
+	rows, err := db.Query("SELECT alias, object_id FROM object_aliases ORDER BY object_id, alias")
 	if err != nil {
 		return nil, err
 	}
@@ -1508,10 +1861,9 @@ type DuplicateAlias struct {
 func (d *Database) FindDuplicateAliases() ([]DuplicateAlias, error) {
 	// Find aliases that appear more than once
 	rows, err := d.db.Query(`
-		SELECT alias, GROUP_CONCAT(id, '|') as ids
-		FROM objects 
-		WHERE alias IS NOT NULL AND alias != ''
-		GROUP BY alias 
+		SELECT alias, GROUP_CONCAT(object_id, '|') as ids
+		FROM object_aliases
+		GROUP BY alias
 		HAVING COUNT(*) > 1
 		ORDER BY alias
 	`)
@@ -1536,13 +1888,79 @@ func (d *Database) FindDuplicateAliases() ([]DuplicateAlias, error) {
 	return duplicates, rows.Err()
 }
 
-// Helper to convert FieldValue map to interface map for JSON serialization.
-func fieldsToMap(fields map[string]schema.FieldValue) map[string]interface{} {
-	result := make(map[string]interface{}, len(fields))
-	for k, v := range fields {
-		result[k] = v.Raw()
+// normalizedEnumFields returns the object's fields with enum synonyms replaced
+// by the values they stand for, so queries on the canonical value match them.
+// The parsed fields are left untouched.
+func normalizedEnumFields(obj *parser.ParsedObject, sch *schema.Schema) map[string]schema.FieldValue {
+	if sch == nil {
+		return obj.Fields
 	}
-	return result
+	typeDef, ok := sch.Types[obj.ObjectType]
+	if !ok || typeDef == nil {
+		return obj.Fields
+	}
+
+	var normalized map[string]schema.FieldValue
+	for name, value := range obj.Fields {
+		fieldDef := typeDef.Fields[name]
+		if fieldDef == nil || len(fieldDef.Synonyms) == 0 {
+			continue
+		}
+		if normalized == nil {
+			normalized = make(map[string]schema.FieldValue, len(obj.Fields))
+			for k, v := range obj.Fields {
+				normalized[k] = v
+			}
+		}
+		normalized[name] = fieldDef.NormalizeEnumSynonyms(value)
+	}
+	if normalized == nil {
+		return obj.Fields
+	}
+	return normalized
+}
+
+// orderedFieldsJSON encodes fields as a JSON object whose keys follow order
+// (frontmatter declaration order). Fields missing from order come last, sorted,
+// so the stored JSON is always deterministic.
+func orderedFieldsJSON(fields map[string]schema.FieldValue, order []string) ([]byte, error) {
+	keys := make([]string, 0, len(fields))
+	seen := make(map[string]bool, len(fields))
+	for _, key := range order {
+		if _, ok := fields[key]; ok && !seen[key] {
+			seen[key] = true
+			keys = append(keys, key)
+		}
+	}
+	rest := make([]string, 0, len(fields)-len(keys))
+	for key := range fields {
+		if !seen[key] {
+			rest = append(rest, key)
+		}
+	}
+	sort.Strings(rest)
+	keys = append(keys, rest...)
+
+	var buf bytes.Buffer
+	buf.WriteByte('{')
+	for i, key := range keys {
+		keyJSON, err := json.Marshal(key)
+		if err != nil {
+			return nil, err
+		}
+		valueJSON, err := json.Marshal(fields[key].Raw())
+		if err != nil {
+			return nil, err
+		}
+		if i > 0 {
+			buf.WriteByte(',')
+		}
+		buf.Write(keyJSON)
+		buf.WriteByte(':')
+		buf.Write(valueJSON)
+	}
+	buf.WriteByte('}')
+	return buf.Bytes(), nil
 }
 
 // getTraitDefault returns the default value for a trait from the schema.
@@ -1661,6 +2079,74 @@ func isFileStaleAgainstIndexedMtime(fullPath string, indexedMtime sql.NullInt64)
 	return false, checked, nil
 }
 
+// FileDiff lists how the files on disk differ from the index.
+type FileDiff struct {
+	New      []string // on disk but not indexed
+	Modified []string // changed on disk since they were indexed
+	Deleted  []string // indexed but no longer on disk
+}
+
+// DiffFiles compares the indexed files against onDisk, the vault-relative
+// paths of the files currently managed by Raven. A file counts as modified
+// under the same rule CheckStaleness uses: its mtime is newer than the indexed
+// one, or no mtime was recorded. Indexed files missing from onDisk (deleted,
+// or now excluded) are reported as deleted. Nothing is written.
+func (d *Database) DiffFiles(vaultPath string, onDisk []string) (*FileDiff, error) {
+	diff := &FileDiff{New: []string{}, Modified: []string{}, Deleted: []string{}}
+
+	current := make(map[string]struct{}, len(onDisk))
+	for _, filePath := range onDisk {
+		current[filePath] = struct{}{}
+	}
+
+	rows, err := stalenessRows(d.db)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	// A file can appear once per distinct mtime, so classify each path once.
+	indexed := make(map[string]bool)
+	for rows.Next() {
+		filePath, indexedMtime, err := scanStalenessRow(rows)
+		if err != nil {
+			return nil, err
+		}
+		if _, ok := current[filePath]; !ok {
+			indexed[filePath] = false
+			continue
+		}
+		stale, _, err := isFileStaleAgainstIndexedMtime(filepath.Join(vaultPath, filePath), indexedMtime)
+		if err != nil {
+			// Removed between the walk and this check.
+			delete(current, filePath)
+			indexed[filePath] = false
+			continue
+		}
+		indexed[filePath] = indexed[filePath] || stale
+	}
+	if err := rows.Err(); err != nil {
+		return nil, err
+	}
+
+	for filePath, stale := range indexed {
+		if _, ok := current[filePath]; !ok {
+			diff.Deleted = append(diff.Deleted, filePath)
+		} else if stale {
+			diff.Modified = append(diff.Modified, filePath)
+		}
+	}
+	for filePath := range current {
+		if _, ok := indexed[filePath]; !ok {
+			diff.New = append(diff.New, filePath)
+		}
+	}
+	sort.Strings(diff.New)
+	sort.Strings(diff.Modified)
+	sort.Strings(diff.Deleted)
+	return diff, nil
+}
+
 // GetFileMtime returns the indexed mtime for a file, or 0 if not found.
 func (d *Database) GetFileMtime(filePath string) (int64, error) {
 	var mtime sql.NullInt64
@@ -1865,7 +2351,7 @@ func (d *Database) fetchUnresolvedFieldRefsBatch(filePath *string, afterID int64
 func (d *Database) resolveRefBatch(res *resolver.Resolver, refs []refToResolve, result *ReferenceResolutionResult) error {
 	result.Total += len(refs)
 
-	tx, err := d.db.Begin()
+	tx, err := d.begin()
 	if err != nil {
 		return err
 	}
@@ -1900,7 +2386,7 @@ func (d *Database) resolveRefBatch(res *resolver.Resolver, refs []refToResolve,
 func (d *Database) resolveFieldRefBatch(res *resolver.Resolver, refs []fieldRefToResolve, result *ReferenceResolutionResult) error {
 	result.FieldTotal += len(refs)
 
-	tx, err := d.db.Begin()
+	tx, err := d.begin()
 	if err != nil {
 		return err
 	}
@@ -1,11 +1,14 @@
 package index
 
 import (
+	"context"
 	"database/sql"
 	"errors"
+	"fmt"
 	"os"
 	"path/filepath"
 	"strconv"
+	"strings"
 	"testing"
 
 	"github.com/aidanlsb/raven/internal/filelock"
@@ -156,6 +159,53 @@ func TestDatabase(t *testing.T) {
 		}
 	})
 
+	t.Run("indexes list-of-maps fields as queryable JSON", func(t *testing.T) {
+		db, err := OpenInMemory()
+		if err != nil {
+			t.Fatalf("failed to open database: %v", err)
+		}
+		defer db.Close()
+
+		attendees := schema.Array([]schema.FieldValue{
+			schema.Map(map[string]schema.FieldValue{
+				"name": schema.String("Alice"),
+				"role": schema.String("host"),
+			}),
+			schema.Map(map[string]schema.FieldValue{
+				"name": schema.String("Bob"),
+			}),
+		})
+		doc := &parser.ParsedDocument{
+			FilePath: "meetings/kickoff.md",
+			Objects: []*parser.ParsedObject{
+				{
+					ID:         "meetings/kickoff",
+					ObjectType: "page",
+					Fields:     map[string]schema.FieldValue{"attendees": attendees},
+					LineStart:  1,
+				},
+			},
+		}
+
+		if err := db.IndexDocument(doc, schema.New()); err != nil {
+			t.Fatalf("failed to index document: %v", err)
+		}
+
+		var role string
+		var count int
+		err = db.db.QueryRow(`
+			SELECT json_extract(fields, '$.attendees[0].role'), json_array_length(fields, '$.attendees')
+			FROM objects
+			WHERE id = 'meetings/kickoff'
+		`).Scan(&role, &count)
+		if err != nil {
+			t.Fatalf("failed to query list-of-maps field: %v", err)
+		}
+		if role != "host" || count != 2 {
+			t.Fatalf("attendees[0].role = %q, len = %d; want %q, 2", role, count, "host")
+		}
+	})
+
 	t.Run("indexes generated date field for date objects", func(t *testing.T) {
 		db, err := OpenInMemory()
 		if err != nil {
@@ -1169,6 +1219,117 @@ func TestTraitIDsStableAcrossReindexForMultilineParagraph(t *testing.T) {
 	}
 }
 
+func TestIndexDocumentManySections(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	sch := schema.New()
+	sch.Traits["todo"] = &schema.TraitDefinition{Type: schema.FieldTypeString}
+
+	const sectionCount = 500
+	var content strings.Builder
+	content.WriteString("# Big\n\n")
+	for i := 0; i < sectionCount; i++ {
+		fmt.Fprintf(&content, "## Part %d\n\nStep %d @todo open with [[people/p%d]]\n\n", i, i, i)
+	}
+
+	doc, err := parser.ParseDocument(content.String(), "/vault/notes/big.md", "/vault")
+	if err != nil {
+		t.Fatalf("failed to parse document: %v", err)
+	}
+	if err := db.IndexDocument(doc, sch); err != nil {
+		t.Fatalf("failed to index document: %v", err)
+	}
+
+	counts := map[string]int{
+		"sections": sectionCount + 1,
+		"traits":   sectionCount,
+		"refs":     sectionCount,
+	}
+	for table, want := range counts {
+		var got int
+		if err := db.db.QueryRow("SELECT COUNT(*) FROM " + table + " WHERE file_path = 'notes/big.md'").Scan(&got); err != nil {
+			t.Fatalf("failed to count %s: %v", table, err)
+		}
+		if got != want {
+			t.Errorf("%s rows = %d, want %d", table, got, want)
+		}
+	}
+
+	var lastTraitLine int
+	if err := db.db.QueryRow("SELECT line_number FROM traits WHERE id = ?", fmt.Sprintf("notes/big.md:trait:%d", sectionCount-1)).Scan(&lastTraitLine); err != nil {
+		t.Fatalf("failed to query last trait: %v", err)
+	}
+	if want := 4*sectionCount + 1; lastTraitLine != want {
+		t.Errorf("last trait line = %d, want %d", lastTraitLine, want)
+	}
+}
+
+func TestBulkPragmasRoundTrip(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+	// With a single connection, any write that bypassed the pinned bulk
+	// connection would block, and the restored settings are observable
+	// through the pool afterwards.
+	db.db.SetMaxOpenConns(1)
+	db.SetAutoResolveRefs(false)
+
+	type querier interface {
+		QueryRowContext(ctx context.Context, query string, args ...interface{}) *sql.Row
+	}
+	pragmas := func(t *testing.T, q querier) (int, int) {
+		t.Helper()
+		var synchronous, cacheSize int
+		if err := q.QueryRowContext(context.Background(), "PRAGMA synchronous").Scan(&synchronous); err != nil {
+			t.Fatalf("failed to read synchronous: %v", err)
+		}
+		if err := q.QueryRowContext(context.Background(), "PRAGMA cache_size").Scan(&cacheSize); err != nil {
+			t.Fatalf("failed to read cache_size: %v", err)
+		}
+		return synchronous, cacheSize
+	}
+
+	origSync, origCache := pragmas(t, db.db)
+
+	if err := db.BeginBulk(); err != nil {
+		t.Fatalf("BeginBulk failed: %v", err)
+	}
+	if sync, cache := pragmas(t, db.bulkConn); sync != 1 || cache != bulkCacheSizeKB {
+		t.Fatalf("bulk pragmas = (%d, %d), want (1, %d)", sync, cache, bulkCacheSizeKB)
+	}
+
+	doc, err := parser.ParseDocument("# Note\n\nSee [[people/freya]].\n", "/vault/notes/bulk.md", "/vault")
+	if err != nil {
+		t.Fatalf("failed to parse document: %v", err)
+	}
+	if err := db.IndexDocument(doc, schema.New()); err != nil {
+		t.Fatalf("failed to index document in bulk mode: %v", err)
+	}
+
+	if err := db.EndBulk(); err != nil {
+		t.Fatalf("EndBulk failed: %v", err)
+	}
+	if sync, cache := pragmas(t, db.db); sync != origSync || cache != origCache {
+		t.Fatalf("restored pragmas = (%d, %d), want (%d, %d)", sync, cache, origSync, origCache)
+	}
+
+	obj, err := db.GetObject("notes/bulk")
+	if err != nil || obj == nil {
+		t.Fatalf("expected notes/bulk to be indexed, got %v (err=%v)", obj, err)
+	}
+	if err := db.EndBulk(); err != nil {
+		t.Fatalf("EndBulk without BeginBulk should be a no-op: %v", err)
+	}
+}
+
 func TestAliasIndexing(t *testing.T) {
 	t.Parallel()
 	db, err := OpenInMemory()
@@ -1343,6 +1504,87 @@ func TestAliasIndexing(t *testing.T) {
 		}
 	})
 
+	t.Run("aliases list resolves each alias", func(t *testing.T) {
+		db.ClearAllData()
+		db.SetAutoResolveRefs(false)
+
+		doc1 := &parser.ParsedDocument{
+			FilePath: "people/alice.md",
+			Objects: []*parser.ParsedObject{
+				{
+					ID:         "people/alice",
+					ObjectType: "person",
+					Fields: map[string]schema.FieldValue{
+						"alias":   schema.String("Alice"),
+						"aliases": schema.Array([]schema.FieldValue{schema.String("AC"), schema.String("Alice")}),
+					},
+					LineStart: 1,
+				},
+			},
+		}
+		if err := db.IndexDocument(doc1, sch); err != nil {
+			t.Fatalf("failed to index document: %v", err)
+		}
+
+		got, err := db.ObjectAliases("people/alice")
+		if err != nil {
+			t.Fatalf("failed to get object aliases: %v", err)
+		}
+		if len(got) != 2 || got[0] != "AC" || got[1] != "Alice" {
+			t.Fatalf("expected aliases [AC Alice], got %v", got)
+		}
+
+		doc2 := &parser.ParsedDocument{
+			FilePath: "notes/standup.md",
+			Objects: []*parser.ParsedObject{
+				{
+					ID:         "notes/standup",
+					ObjectType: "page",
+					Fields:     map[string]schema.FieldValue{},
+					LineStart:  1,
+				},
+			},
+			Refs: []*parser.ParsedRef{
+				{SourceID: "notes/standup", TargetRaw: "AC", Line: 3, Start: 0, End: 6},
+				{SourceID: "notes/standup", TargetRaw: "Alice", Line: 4, Start: 0, End: 9},
+			},
+		}
+		if err := db.IndexDocument(doc2, sch); err != nil {
+			t.Fatalf("failed to index document: %v", err)
+		}
+
+		result, err := db.ResolveReferences("daily")
+		if err != nil {
+			t.Fatalf("failed to resolve references: %v", err)
+		}
+		if result.Resolved != 2 || result.Unresolved != 0 {
+			t.Fatalf("expected 2 resolved and 0 unresolved, got %+v", result)
+		}
+
+		for _, raw := range []string{"AC", "Alice"} {
+			var targetID string
+			if err := db.db.QueryRow(`SELECT target_id FROM refs WHERE target_raw = ?`, raw).Scan(&targetID); err != nil {
+				t.Fatalf("failed to query ref %q: %v", raw, err)
+			}
+			if targetID != "people/alice" {
+				t.Errorf("expected %q to resolve to people/alice, got %q", raw, targetID)
+			}
+		}
+
+		// Reindexing the file without aliases drops them.
+		doc1.Objects[0].Fields = map[string]schema.FieldValue{}
+		if err := db.IndexDocument(doc1, sch); err != nil {
+			t.Fatalf("failed to reindex document: %v", err)
+		}
+		got, err = db.ObjectAliases("people/alice")
+		if err != nil {
+			t.Fatalf("failed to get object aliases: %v", err)
+		}
+		if len(got) != 0 {
+			t.Fatalf("expected aliases to be removed on reindex, got %v", got)
+		}
+	})
+
 	t.Run("detect duplicate aliases", func(t *testing.T) {
 		db2, err := OpenInMemory()
 		if err != nil {
@@ -1642,6 +1884,80 @@ func TestAllIndexedFilePaths(t *testing.T) {
 	}
 }
 
+func TestObjectTypesByID(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	sch := schema.New()
+	sch.Types["person"] = &schema.TypeDefinition{Fields: map[string]*schema.FieldDefinition{}}
+	files := map[string]string{
+		"people/freya.md":  "---\ntype: person\n---\n# Freya\n\n## Contact\n",
+		"notes/welcome.md": "# Welcome\n",
+	}
+	for path, content := range files {
+		doc, err := parser.ParseDocument(content, "/vault/"+path, "/vault")
+		if err != nil {
+			t.Fatalf("failed to parse %s: %v", path, err)
+		}
+		if err := db.IndexDocument(doc, sch); err != nil {
+			t.Fatalf("failed to index %s: %v", path, err)
+		}
+	}
+
+	types, err := db.ObjectTypesByID()
+	if err != nil {
+		t.Fatalf("ObjectTypesByID failed: %v", err)
+	}
+	want := map[string]string{
+		"people/freya":  "person",
+		"notes/welcome": "page",
+	}
+	for id, wantType := range want {
+		if types[id] != wantType {
+			t.Errorf("types[%q] = %q, want %q (all: %v)", id, types[id], wantType, types)
+		}
+	}
+	if len(types) != len(want) {
+		t.Errorf("types = %v, want only %v (sections excluded)", types, want)
+	}
+}
+
+func TestStatsWordCount(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	sch := schema.New()
+	files := map[string]string{
+		"notes/essay.md": "---\ntitle: Essay with many frontmatter words\n---\n# On Ravens\n\nHuginn and Muninn fly over [[places/midgard|Midgard]] each day.\n\n```\nignored code words here\n```\n- @todo Finish the draft\n",
+		"notes/short.md": "Just two\n",
+	}
+	for path, content := range files {
+		doc, err := parser.ParseDocument(content, "/vault/"+path, "/vault")
+		if err != nil {
+			t.Fatalf("failed to parse %s: %v", path, err)
+		}
+		if err := db.IndexDocument(doc, sch); err != nil {
+			t.Fatalf("failed to index %s: %v", path, err)
+		}
+	}
+
+	stats, err := db.Stats()
+	if err != nil {
+		t.Fatalf("Stats failed: %v", err)
+	}
+	if stats.WordCount != 15 {
+		t.Fatalf("stats word count = %d, want 15", stats.WordCount)
+	}
+}
+
 func TestOpenWithRebuildLock(t *testing.T) {
 	t.Parallel()
 	vaultDir := t.TempDir()
@@ -1885,3 +2201,116 @@ func TestResolveReferencesBatched(t *testing.T) {
 		t.Fatalf("expected 0 unresolved refs, got %d", result.Unresolved)
 	}
 }
+
+func TestIndexDocumentRecordsExplicitType(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	sch := schema.New()
+	for path, content := range map[string]string{
+		"declared.md": "---\ntype: page\n---\nDeclared\n",
+		"fallback.md": "---\ntitle: Fallback\n---\nNo type\n",
+		"bare.md":     "No frontmatter\n",
+	} {
+		doc, err := parser.ParseDocument(content, "/vault/"+path, "/vault")
+		if err != nil {
+			t.Fatalf("parse %s: %v", path, err)
+		}
+		if err := db.IndexDocument(doc, sch); err != nil {
+			t.Fatalf("index %s: %v", path, err)
+		}
+	}
+
+	all, err := db.UntypedPages(false)
+	if err != nil {
+		t.Fatalf("UntypedPages(false): %v", err)
+	}
+	if len(all) != 3 {
+		t.Errorf("expected 3 page files, got %v", all)
+	}
+	implicit, err := db.UntypedPages(true)
+	if err != nil {
+		t.Fatalf("UntypedPages(true): %v", err)
+	}
+	if len(implicit) != 2 || implicit[0] != "bare.md" || implicit[1] != "fallback.md" {
+		t.Errorf("expected bare.md and fallback.md, got %v", implicit)
+	}
+}
+
+func TestGetObjectPreservesFrontmatterFieldOrder(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	content := "---\ntype: page\nzeta: 1\nalpha: two\nmiddle: [a, b]\n---\nBody\n"
+	doc, err := parser.ParseDocument(content, "/vault/notes/ordered.md", "/vault")
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+	if err := db.IndexDocument(doc, schema.New()); err != nil {
+		t.Fatalf("index: %v", err)
+	}
+
+	obj, err := db.GetObject("notes/ordered")
+	if err != nil || obj == nil {
+		t.Fatalf("GetObject = %v, %v", obj, err)
+	}
+	if got := strings.Join(obj.FieldOrder, ","); got != "zeta,alpha,middle" {
+		t.Fatalf("field order = %q, want zeta,alpha,middle", got)
+	}
+}
+
+func TestIndexDocumentNormalizesEnumSynonyms(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	sch := schema.New()
+	sch.Types["task"] = &schema.TypeDefinition{
+		Fields: map[string]*schema.FieldDefinition{
+			"status": {
+				Type:     schema.FieldTypeEnum,
+				Values:   []string{"todo", "in_progress", "done"},
+				Synonyms: map[string][]string{"in_progress": {"doing", "wip"}},
+			},
+		},
+	}
+
+	for name, status := range map[string]string{"a": "in_progress", "b": "wip", "c": "doing", "d": "done"} {
+		content := "---\ntype: task\nstatus: " + status + "\n---\n"
+		doc, err := parser.ParseDocument(content, "/vault/tasks/"+name+".md", "/vault")
+		if err != nil {
+			t.Fatalf("parse %s: %v", name, err)
+		}
+		if err := db.IndexDocument(doc, sch); err != nil {
+			t.Fatalf("index %s: %v", name, err)
+		}
+	}
+
+	rows, err := db.DB().Query(`SELECT id FROM objects WHERE json_extract(fields, '$.status') = 'in_progress' ORDER BY id`)
+	if err != nil {
+		t.Fatalf("query: %v", err)
+	}
+	defer rows.Close()
+	var ids []string
+	for rows.Next() {
+		var id string
+		if err := rows.Scan(&id); err != nil {
+			t.Fatalf("scan: %v", err)
+		}
+		ids = append(ids, id)
+	}
+	if got := strings.Join(ids, ","); got != "tasks/a,tasks/b,tasks/c" {
+		t.Fatalf("in_progress tasks = %q, want tasks/a,tasks/b,tasks/c", got)
+	}
+}
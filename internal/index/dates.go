@@ -31,8 +31,13 @@ func ParseDateFilterWithOptions(filter string, fieldExpr string, opts DateFilter
 // - condition + args when ok=true
 // - ok=false when value is not a date keyword/date literal
 // - err when value looks like a date input but is invalid
+//
+// Range keywords such as this-month or last-7-days become a lexical BETWEEN
+// over the first ten characters of the value, so datetimes on the last day of
+// the range still match. < and >= compare against the first day of the range;
+// <= and > against its last day.
 func TryParseDateComparisonWithOptions(filter string, op string, fieldExpr string, opts DateFilterOptions) (condition string, args []interface{}, ok bool, err error) {
-	dateValue, isDate, err := resolveDateFilterValue(filter, opts)
+	dateValue, rangeEnd, isDate, err := resolveDateFilterValue(filter, opts)
 	if err != nil {
 		return "", nil, false, err
 	}
@@ -40,6 +45,23 @@ func TryParseDateComparisonWithOptions(filter string, op string, fieldExpr strin
 		return "", nil, false, nil
 	}
 
+	if rangeEnd != "" {
+		// Each condition uses fieldExpr once; callers may bind a placeholder inside it.
+		datePart := "substr(" + fieldExpr + ", 1, 10)"
+		switch op {
+		case "=":
+			return datePart + " BETWEEN ? AND ?", []interface{}{dateValue, rangeEnd}, true, nil
+		case "!=":
+			return "NOT (" + datePart + " BETWEEN ? AND ?)", []interface{}{dateValue, rangeEnd}, true, nil
+		case "<", ">=":
+			return fieldExpr + " " + op + " ?", []interface{}{dateValue}, true, nil
+		case "<=", ">":
+			return datePart + " " + op + " ?", []interface{}{rangeEnd}, true, nil
+		default:
+			return "", nil, false, fmt.Errorf("unsupported date comparison operator: %s", op)
+		}
+	}
+
 	switch op {
 	case "=", "!=", "<", "<=", ">", ">=":
 		return fieldExpr + " " + op + " ?", []interface{}{dateValue}, true, nil
@@ -55,29 +77,36 @@ func normalizeDateFilterOptions(opts DateFilterOptions) DateFilterOptions {
 	return opts
 }
 
-func resolveDateFilterValue(filter string, opts DateFilterOptions) (string, bool, error) {
+// resolveDateFilterValue returns the date a filter value refers to. For range
+// keywords it returns the first and last day of the range.
+func resolveDateFilterValue(filter string, opts DateFilterOptions) (string, string, bool, error) {
 	normalized := strings.TrimSpace(filter)
 	if normalized == "" {
-		return "", false, fmt.Errorf("invalid date filter: %q", normalized)
+		return "", "", false, fmt.Errorf("invalid date filter: %q", normalized)
 	}
 
 	opts = normalizeDateFilterOptions(opts)
 
 	if dates.IsValidDate(normalized) {
-		return normalized, true, nil
+		return normalized, "", true, nil
 	}
 
 	relative, ok := dates.ResolveRelativeDateKeyword(normalized, opts.Now, time.Monday)
-	if ok && relative.Kind == dates.RelativeDateInstant {
-		return relative.Date.Format(dates.DateLayout), true, nil
+	if ok {
+		switch relative.Kind {
+		case dates.RelativeDateInstant:
+			return relative.Date.Format(dates.DateLayout), "", true, nil
+		case dates.RelativeDateRange:
+			return relative.Start.Format(dates.DateLayout), relative.End.Format(dates.DateLayout), true, nil
+		}
 	}
 
 	// If value looks like a date literal but isn't valid, surface an explicit error.
 	if looksLikeDateLiteral(normalized) {
-		return "", false, fmt.Errorf("invalid date filter: %q", normalized)
+		return "", "", false, fmt.Errorf("invalid date filter: %q", normalized)
 	}
 
-	return "", false, nil
+	return "", "", false, nil
 }
 
 func looksLikeDateLiteral(value string) bool {
@@ -0,0 +1,361 @@
+package index
+
+import (
+	"database/sql"
+	"encoding/json"
+	"fmt"
+)
+
+// VaultExport is a structured dump of the index rows produced by ExportAll.
+type VaultExport struct {
+	Version int            `json:"version"`
+	Objects []ExportObject `json:"objects"`
+	Traits  []ExportTrait  `json:"traits"`
+	Refs    []ExportRef    `json:"refs"`
+	Assets  []ExportAsset  `json:"assets"`
+}
+
+// ExportObject mirrors a row of the objects table. Fields holds the decoded
+// frontmatter field JSON and Aliases the object's object_aliases rows.
+type ExportObject struct {
+	ID           string          `json:"id"`
+	FilePath     string          `json:"file_path"`
+	Type         string          `json:"type"`
+	TypeExplicit bool            `json:"type_explicit,omitempty"`
+	Fields       json.RawMessage `json:"fields"`
+	LineStart    int             `json:"line_start"`
+	Alias        *string         `json:"alias,omitempty"`
+	Aliases      []string        `json:"aliases,omitempty"`
+	FileMtime    *int64          `json:"file_mtime,omitempty"`
+	WordCount    *int64          `json:"word_count,omitempty"`
+	IndexedAt    *int64          `json:"indexed_at,omitempty"`
+}
+
+// ExportTrait mirrors a row of the traits table.
+type ExportTrait struct {
+	ID             string  `json:"id"`
+	FilePath       string  `json:"file_path"`
+	ParentObjectID string  `json:"parent_object_id"`
+	TraitType      string  `json:"trait_type"`
+	Value          *string `json:"value,omitempty"`
+	Content        string  `json:"content"`
+	Line           int     `json:"line_number"`
+	IndexedAt      *int64  `json:"indexed_at,omitempty"`
+}
+
+// ExportRef mirrors a row of the refs table.
+type ExportRef struct {
+	SourceID      string  `json:"source_id"`
+	TargetID      *string `json:"target_id,omitempty"`
+	TargetRaw     string  `json:"target_raw"`
+	DisplayText   *string `json:"display_text,omitempty"`
+	FilePath      string  `json:"file_path"`
+	Line          *int    `json:"line_number,omitempty"`
+	PositionStart *int    `json:"position_start,omitempty"`
+	PositionEnd   *int    `json:"position_end,omitempty"`
+}
+
+// ExportAsset mirrors a row of the assets table.
+type ExportAsset struct {
+	ID        string  `json:"id"`
+	FilePath  string  `json:"file_path"`
+	MediaType *string `json:"media_type,omitempty"`
+	Extension *string `json:"extension,omitempty"`
+	Filename  string  `json:"filename"`
+	SizeBytes int64   `json:"size_bytes"`
+	FileMtime *int64  `json:"file_mtime,omitempty"`
+	IndexedAt *int64  `json:"indexed_at,omitempty"`
+}
+
+// ExportAll dumps every object, trait, reference, and asset in the index.
+// Rows are ordered by file path and position so exports diff cleanly.
+func (d *Database) ExportAll() (*VaultExport, error) {
+	export := &VaultExport{
+		Version: CurrentDBVersion,
+		Objects: []ExportObject{},
+		Traits:  []ExportTrait{},
+		Refs:    []ExportRef{},
+		Assets:  []ExportAsset{},
+	}
+
+	objectRows, err := d.db.Query(`
+		SELECT id, file_path, type, type_explicit, fields, line_start, alias, file_mtime, word_count, indexed_at
+		FROM objects
+		ORDER BY file_path, line_start, id
+	`)
+	if err != nil {
+		return nil, fmt.Errorf("export objects: %w", err)
+	}
+	defer objectRows.Close()
+	for objectRows.Next() {
+		var obj ExportObject
+		var fields string
+		var alias sql.NullString
+		var mtime, wordCount, indexedAt sql.NullInt64
+		if err := objectRows.Scan(&obj.ID, &obj.FilePath, &obj.Type, &obj.TypeExplicit, &fields, &obj.LineStart, &alias, &mtime, &wordCount, &indexedAt); err != nil {
+			return nil, fmt.Errorf("export objects: %w", err)
+		}
+		if !json.Valid([]byte(fields)) {
+			return nil, fmt.Errorf("export objects: %s has invalid fields JSON", obj.ID)
+		}
+		obj.Fields = json.RawMessage(fields)
+		obj.Alias = nullStringPtr(alias)
+		obj.FileMtime = nullInt64Ptr(mtime)
+		obj.WordCount = nullInt64Ptr(wordCount)
+		obj.IndexedAt = nullInt64Ptr(indexedAt)
+		export.Objects = append(export.Objects, obj)
+	}
+	if err := objectRows.Err(); err != nil {
+		return nil, fmt.Errorf("export objects: %w", err)
+	}
+
+	aliasRows, err := d.db.Query("SELECT object_id, alias FROM object_aliases ORDER BY object_id, alias")
+	if err != nil {
+		return nil, fmt.Errorf("export aliases: %w", err)
+	}
+	defer aliasRows.Close()
+	aliases := make(map[string][]string)
+	for aliasRows.Next() {
+		var objectID, alias string
+		if err := aliasRows.Scan(&objectID, &alias); err != nil {
+			return nil, fmt.Errorf("export aliases: %w", err)
+		}
+		aliases[objectID] = append(aliases[objectID], alias)
+	}
+	if err := aliasRows.Err(); err != nil {
+		return nil, fmt.Errorf("export aliases: %w", err)
+	}
+	for i := range export.Objects {
+		export.Objects[i].Aliases = aliases[export.Objects[i].ID]
+	}
+
+	traitRows, err := d.db.Query(`
+		SELECT id, file_path, parent_object_id, trait_type, value, content, line_number, indexed_at
+		FROM traits
+		ORDER BY file_path, line_number, id
+	`)
+	if err != nil {
+		return nil, fmt.Errorf("export traits: %w", err)
+	}
+	defer traitRows.Close()
+	for traitRows.Next() {
+		var trait ExportTrait
+		var value sql.NullString
+		var indexedAt sql.NullInt64
+		if err := traitRows.Scan(&trait.ID, &trait.FilePath, &trait.ParentObjectID, &trait.TraitType, &value, &trait.Content, &trait.Line, &indexedAt); err != nil {
+			return nil, fmt.Errorf("export traits: %w", err)
+		}
+		trait.Value = nullStringPtr(value)
+		trait.IndexedAt = nullInt64Ptr(indexedAt)
+		export.Traits = append(export.Traits, trait)
+	}
+	if err := traitRows.Err(); err != nil {
+		return nil, fmt.Errorf("export traits: %w", err)
+	}
+
+	refRows, err := d.db.Query(`
+		SELECT source_id, target_id, target_raw, display_text, file_path, line_number, position_start, position_end
+		FROM refs
+		ORDER BY file_path, line_number, position_start, id
+	`)
+	if err != nil {
+		return nil, fmt.Errorf("export refs: %w", err)
+	}
+	defer refRows.Close()
+	for refRows.Next() {
+		var ref ExportRef
+		var targetID, displayText sql.NullString
+		var line, posStart, posEnd sql.NullInt64
+		if err := refRows.Scan(&ref.SourceID, &targetID, &ref.TargetRaw, &displayText, &ref.FilePath, &line, &posStart, &posEnd); err != nil {
+			return nil, fmt.Errorf("export refs: %w", err)
+		}
+		ref.TargetID = nullStringPtr(targetID)
+		ref.DisplayText = nullStringPtr(displayText)
+		ref.Line = nullIntPtr(line)
+		ref.PositionStart = nullIntPtr(posStart)
+		ref.PositionEnd = nullIntPtr(posEnd)
+		export.Refs = append(export.Refs, ref)
+	}
+	if err := refRows.Err(); err != nil {
+		return nil, fmt.Errorf("export refs: %w", err)
+	}
+
+	assetRows, err := d.db.Query(`
+		SELECT id, file_path, media_type, extension, filename, size_bytes, file_mtime, indexed_at
+		FROM assets
+		ORDER BY file_path
+	`)
+	if err != nil {
+		return nil, fmt.Errorf("export assets: %w", err)
+	}
+	defer assetRows.Close()
+	for assetRows.Next() {
+		var asset ExportAsset
+		var mediaType, extension sql.NullString
+		var mtime, indexedAt sql.NullInt64
+		if err := assetRows.Scan(&asset.ID, &asset.FilePath, &mediaType, &extension, &asset.Filename, &asset.SizeBytes, &mtime, &indexedAt); err != nil {
+			return nil, fmt.Errorf("export assets: %w", err)
+		}
+		asset.MediaType = nullStringPtr(mediaType)
+		asset.Extension = nullStringPtr(extension)
+		asset.FileMtime = nullInt64Ptr(mtime)
+		asset.IndexedAt = nullInt64Ptr(indexedAt)
+		export.Assets = append(export.Assets, asset)
+	}
+	if err := assetRows.Err(); err != nil {
+		return nil, fmt.Errorf("export assets: %w", err)
+	}
+
+	return export, nil
+}
+
+// ImportAll replaces the index contents with the rows of a previous export,
+// in a single transaction. Object, trait, and asset IDs must be unique and
+// object fields must be JSON objects. Sections, blocks, field refs, dates,
+// and full-text content are not part of an export, so file mtimes are left
+// NULL: every restored file counts as stale and the next incremental reindex
+// reparses it and rebuilds those tables.
+func (d *Database) ImportAll(export *VaultExport) error {
+	if err := validateExport(export); err != nil {
+		return err
+	}
+
+	tx, err := d.begin()
+	if err != nil {
+		return err
+	}
+	defer tx.Rollback()
+
+	for _, stmt := range []string{
+		"DELETE FROM objects",
+		"DELETE FROM object_aliases",
+		"DELETE FROM sections",
+		"DELETE FROM blocks",
+		"DELETE FROM traits",
+		"DELETE FROM refs",
+		"DELETE FROM field_refs",
+		"DELETE FROM date_index",
+		"DELETE FROM fts_content",
+		"DELETE FROM assets",
+	} {
+		if _, err := tx.Exec(stmt); err != nil {
+			return err
+		}
+	}
+
+	for _, obj := range export.Objects {
+		fields := string(obj.Fields)
+		if len(obj.Fields) == 0 || fields == "null" {
+			fields = "{}"
+		}
+		if _, err := tx.Exec(`
+			INSERT INTO objects (id, file_path, type, type_explicit, fields, line_start, alias, file_mtime, word_count, indexed_at)
+			VALUES (?, ?, ?, ?, ?, ?, ?, NULL, ?, ?)
+		`, obj.ID, obj.FilePath, obj.Type, obj.TypeExplicit, fields, obj.LineStart, obj.Alias, obj.WordCount, obj.IndexedAt); err != nil {
+			return fmt.Errorf("import object %s: %w", obj.ID, err)
+		}
+		for _, alias := range obj.Aliases {
+			if _, err := tx.Exec(
+				"INSERT OR IGNORE INTO object_aliases (object_id, alias, file_path) VALUES (?, ?, ?)",
+				obj.ID, alias, obj.FilePath,
+			); err != nil {
+				return fmt.Errorf("import alias %q for %s: %w", alias, obj.ID, err)
+			}
+		}
+	}
+
+	for _, trait := range export.Traits {
+		if _, err := tx.Exec(`
+			INSERT INTO traits (id, file_path, parent_object_id, trait_type, value, content, line_number, indexed_at)
+			VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+		`, trait.ID, trait.FilePath, trait.ParentObjectID, trait.TraitType, trait.Value, trait.Content, trait.Line, trait.IndexedAt); err != nil {
+			return fmt.Errorf("import trait %s: %w", trait.ID, err)
+		}
+	}
+
+	for _, ref := range export.Refs {
+		if _, err := tx.Exec(`
+			INSERT INTO refs (source_id, target_id, target_raw, display_text, file_path, line_number, position_start, position_end)
+			VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+		`, ref.SourceID, ref.TargetID, ref.TargetRaw, ref.DisplayText, ref.FilePath, ref.Line, ref.PositionStart, ref.PositionEnd); err != nil {
+			return fmt.Errorf("import ref from %s: %w", ref.SourceID, err)
+		}
+	}
+
+	for _, asset := range export.Assets {
+		if _, err := tx.Exec(`
+			INSERT INTO assets (id, file_path, media_type, extension, filename, size_bytes, file_mtime, indexed_at)
+			VALUES (?, ?, ?, ?, ?, ?, NULL, ?)
+		`, asset.ID, asset.FilePath, asset.MediaType, asset.Extension, asset.Filename, asset.SizeBytes, asset.IndexedAt); err != nil {
+			return fmt.Errorf("import asset %s: %w", asset.ID, err)
+		}
+	}
+
+	return tx.Commit()
+}
+
+func validateExport(export *VaultExport) error {
+	if export == nil {
+		return fmt.Errorf("export is empty")
+	}
+	if export.Version != CurrentDBVersion {
+		return fmt.Errorf("export is from index version %d, but this Raven uses version %d", export.Version, CurrentDBVersion)
+	}
+
+	objectIDs := make(map[string]struct{}, len(export.Objects))
+	for _, obj := range export.Objects {
+		if obj.ID == "" {
+			return fmt.Errorf("object with empty id in %s", obj.FilePath)
+		}
+		if _, dup := objectIDs[obj.ID]; dup {
+			return fmt.Errorf("duplicate object id %q", obj.ID)
+		}
+		objectIDs[obj.ID] = struct{}{}
+		if len(obj.Fields) > 0 {
+			var fields map[string]interface{}
+			if err := json.Unmarshal(obj.Fields, &fields); err != nil {
+				return fmt.Errorf("object %q has invalid fields: %w", obj.ID, err)
+			}
+		}
+	}
+
+	traitIDs := make(map[string]struct{}, len(export.Traits))
+	for _, trait := range export.Traits {
+		if _, dup := traitIDs[trait.ID]; dup {
+			return fmt.Errorf("duplicate trait id %q", trait.ID)
+		}
+		traitIDs[trait.ID] = struct{}{}
+	}
+
+	assetIDs := make(map[string]struct{}, len(export.Assets))
+	for _, asset := range export.Assets {
+		if _, dup := assetIDs[asset.ID]; dup {
+			return fmt.Errorf("duplicate asset id %q", asset.ID)
+		}
+		assetIDs[asset.ID] = struct{}{}
+	}
+
+	return nil
+}
+
+func nullStringPtr(v sql.NullString) *string {
+	if !v.Valid {
+		return nil
+	}
+	return &v.String
+}
+
+func nullInt64Ptr(v sql.NullInt64) *int64 {
+	if !v.Valid {
+		return nil
+	}
+	return &v.Int64
+}
+
+func nullIntPtr(v sql.NullInt64) *int {
+	if !v.Valid {
+		return nil
+	}
+	n := int(v.Int64)
+	return &n
+}
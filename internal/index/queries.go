@@ -17,7 +17,7 @@ import (
 //   - OR with pipe: "today|tomorrow" → value matches either
 //   - NOT with bang: "!done" → value != 'done'
 //   - Combined: "!done|!cancelled" → value not in (done, cancelled)
-//   - Date filters: "today", "tomorrow", "yesterday", YYYY-MM-DD (also work with | and !)
+//   - Date filters: "today", "tomorrow", "yesterday", ranges like "this-week", YYYY-MM-DD (also work with | and !)
 func (d *Database) QueryTraits(traitType string, valueFilter *string) ([]model.Trait, error) {
 	query := `
 		SELECT id, trait_type, value, content, file_path, line_number, parent_object_id
@@ -57,6 +57,143 @@ func (d *Database) QueryTraits(traitType string, valueFilter *string) ([]model.T
 	return results, rows.Err()
 }
 
+// TraitsInFiles returns all traits in the given files, ordered by file and line.
+func (d *Database) TraitsInFiles(filePaths []string) ([]model.Trait, error) {
+	if len(filePaths) == 0 {
+		return nil, nil
+	}
+
+	placeholders := make([]string, len(filePaths))
+	args := make([]interface{}, len(filePaths))
+	for i, filePath := range filePaths {
+		placeholders[i] = "?"
+		args[i] = filePath
+	}
+
+	query := fmt.Sprintf(`
+		SELECT id, trait_type, value, content, file_path, line_number, parent_object_id
+		FROM traits
+		WHERE file_path IN (%s)
+		ORDER BY file_path, line_number
+	`, strings.Join(placeholders, ", "))
+
+	rows, err := d.db.Query(query, args...)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var results []model.Trait
+	for rows.Next() {
+		var result model.Trait
+		if err := rows.Scan(&result.ID, &result.TraitType, &result.Value, &result.Content, &result.FilePath, &result.Line, &result.ParentObjectID); err != nil {
+			return nil, err
+		}
+		results = append(results, result)
+	}
+
+	return results, rows.Err()
+}
+
+// DatedTrait is a date- or datetime-valued trait together with the
+// recurrence declared by an @every trait on the same line, if any.
+type DatedTrait struct {
+	model.Trait
+	Every *string
+}
+
+// TraitValue is a trait annotation with a specific value, such as @todo(done).
+type TraitValue struct {
+	TraitType string
+	Value     string
+}
+
+// QueryDatedTraits returns traits of the given types whose value falls within
+// [from, to] (YYYY-MM-DD; datetimes match on their date), plus recurring
+// traits that start on or before to, ordered by value.
+// Traits sharing a line with any of the excluded trait values (for example
+// @todo(done)) are skipped.
+// Expanding recurring traits into occurrences is left to the caller.
+func (d *Database) QueryDatedTraits(traitTypes []string, from, to string, excluded []TraitValue) ([]DatedTrait, error) {
+	if len(traitTypes) == 0 {
+		return nil, nil
+	}
+
+	lastDay, err := time.Parse(dates.DateLayout, to)
+	if err != nil {
+		return nil, fmt.Errorf("invalid date: %q", to)
+	}
+	opts := DateFilterOptions{}
+	// Compare against the day after to so datetimes on the last day still match.
+	upperCond, upperArgs, ok, err := TryParseDateComparisonWithOptions(lastDay.AddDate(0, 0, 1).Format(dates.DateLayout), "<", "t.value", opts)
+	if err != nil || !ok {
+		return nil, fmt.Errorf("invalid date: %q", to)
+	}
+	lowerCond, lowerArgs, ok, err := TryParseDateComparisonWithOptions(from, ">=", "t.value", opts)
+	if err != nil || !ok {
+		return nil, fmt.Errorf("invalid date: %q", from)
+	}
+
+	placeholders := make([]string, len(traitTypes))
+	args := make([]interface{}, 0, len(traitTypes)+2+2*len(excluded))
+	for i, traitType := range traitTypes {
+		placeholders[i] = "?"
+		args = append(args, traitType)
+	}
+	args = append(args, upperArgs...)
+	args = append(args, lowerArgs...)
+
+	excludeFilter := ""
+	if len(excluded) > 0 {
+		matches := make([]string, len(excluded))
+		for i, tv := range excluded {
+			matches[i] = "(x.trait_type = ? AND x.value = ?)"
+			args = append(args, tv.TraitType, tv.Value)
+		}
+		excludeFilter = fmt.Sprintf(`
+			AND NOT EXISTS (
+				SELECT 1 FROM traits x
+				WHERE x.file_path = t.file_path AND x.line_number = t.line_number
+					AND (%s)
+			)`, strings.Join(matches, " OR "))
+	}
+
+	query := fmt.Sprintf(`
+		SELECT t.id, t.trait_type, t.value, t.content, t.file_path, t.line_number, t.parent_object_id, e.value
+		FROM traits t
+		LEFT JOIN traits e
+			ON e.file_path = t.file_path AND e.line_number = t.line_number AND e.trait_type = 'every'
+		WHERE t.trait_type IN (%s)
+			AND t.value IS NOT NULL
+			AND %s
+			AND (%s OR e.value IS NOT NULL)%s
+		ORDER BY t.value, t.file_path, t.line_number
+	`, strings.Join(placeholders, ", "), upperCond, lowerCond, excludeFilter)
+
+	rows, err := d.db.Query(query, args...)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var results []DatedTrait
+	seen := make(map[string]struct{})
+	for rows.Next() {
+		var result DatedTrait
+		if err := rows.Scan(&result.ID, &result.TraitType, &result.Value, &result.Content, &result.FilePath, &result.Line, &result.ParentObjectID, &result.Every); err != nil {
+			return nil, err
+		}
+		// A line with several @every traits joins once per recurrence; keep the first.
+		if _, dup := seen[result.ID]; dup {
+			continue
+		}
+		seen[result.ID] = struct{}{}
+		results = append(results, result)
+	}
+
+	return results, rows.Err()
+}
+
 // GetSection returns a heading-derived section by ID.
 func (d *Database) GetSection(id string) (*model.Section, error) {
 	var section model.Section
@@ -216,6 +353,106 @@ func (d *Database) QueryObjects(objectType string) ([]model.Object, error) {
 	return results, rows.Err()
 }
 
+// FieldCount is a frontmatter field key and the number of objects using it.
+type FieldCount struct {
+	Name  string
+	Count int
+}
+
+// ObservedFields returns the frontmatter field keys used by objects of the
+// given type, with how many objects set each key. Results are ordered by
+// count (descending), then key.
+func (d *Database) ObservedFields(objectType string) ([]FieldCount, error) {
+	rows, err := d.db.Query(`
+		SELECT je.key, COUNT(*) AS uses
+		FROM objects o, json_each(o.fields) je
+		WHERE o.type = ?
+		GROUP BY je.key
+		ORDER BY uses DESC, je.key ASC
+	`, objectType)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var results []FieldCount
+	for rows.Next() {
+		var result FieldCount
+		if err := rows.Scan(&result.Name, &result.Count); err != nil {
+			return nil, err
+		}
+		results = append(results, result)
+	}
+	return results, rows.Err()
+}
+
+// RefTargetsByObject returns the raw wikilink targets written in objects of
+// the given type, keyed by object ID. Field values are indexed without their
+// brackets, so this tells a ref field value apart from a plain string.
+func (d *Database) RefTargetsByObject(objectType string) (map[string]map[string]struct{}, error) {
+	rows, err := d.db.Query(`
+		SELECT r.source_id, r.target_raw
+		FROM refs r
+		JOIN objects o ON o.id = r.source_id
+		WHERE o.type = ?
+	`, objectType)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	results := make(map[string]map[string]struct{})
+	for rows.Next() {
+		var sourceID, targetRaw string
+		if err := rows.Scan(&sourceID, &targetRaw); err != nil {
+			return nil, err
+		}
+		if results[sourceID] == nil {
+			results[sourceID] = make(map[string]struct{})
+		}
+		results[sourceID][targetRaw] = struct{}{}
+	}
+	return results, rows.Err()
+}
+
+// RecentObject is an indexed object with the modification time of its file.
+type RecentObject struct {
+	ID        string `json:"id"`
+	Type      string `json:"type"`
+	FilePath  string `json:"file_path"`
+	FileMtime int64  `json:"file_mtime"`
+}
+
+// RecentlyModified returns up to limit objects ordered by file modification
+// time (newest first), then ID. A limit of 0 or less returns every object.
+func (d *Database) RecentlyModified(limit int) ([]RecentObject, error) {
+	query := `
+		SELECT id, type, file_path, COALESCE(file_mtime, 0) AS mtime
+		FROM objects
+		ORDER BY mtime DESC, id ASC
+	`
+	var args []interface{}
+	if limit > 0 {
+		query += " LIMIT ?"
+		args = append(args, limit)
+	}
+	rows, err := d.db.Query(query, args...)
+	if err != nil {
+		return nil, err
+	}
+	defer rows.Close()
+
+	var results []RecentObject
+	for rows.Next() {
+		var result RecentObject
+		if err := rows.Scan(&result.ID, &result.Type, &result.FilePath, &result.FileMtime); err != nil {
+			return nil, err
+		}
+		results = append(results, result)
+	}
+	return results, rows.Err()
+}
+
 // AllObjects returns all indexed file-backed objects.
 func (d *Database) AllObjects() ([]model.Object, error) {
 	rows, err := d.db.Query(`
@@ -314,22 +551,98 @@ func (d *Database) QueryAssets() ([]model.Asset, error) {
 
 // Backlinks returns all objects that reference the given target.
 func (d *Database) Backlinks(targetID string) ([]model.Reference, error) {
-	return d.BacklinksWithRoots(targetID, "", "")
+	return d.backlinks(targetID, "", "", "")
+}
+
+// BacklinksFromType returns references to the given target whose source is an
+// object of sourceType. A link from a section counts as coming from the type
+// of the document that contains it. An empty sourceType matches every source.
+func (d *Database) BacklinksFromType(targetID, sourceType string) ([]model.Reference, error) {
+	return d.backlinks(targetID, "", "", sourceType)
+}
+
+// MaxBacklinkDepth caps BacklinksDepth so cyclic or densely linked graphs
+// cannot trigger a runaway traversal.
+const MaxBacklinkDepth = 5
+
+// BacklinksDepth returns references reaching targetID within depth hops:
+// depth 1 is the direct backlinks, depth 2 adds notes linking to those
+// sources, and so on. Each reference is annotated with its hop distance.
+//
+// Sources are expanded by their document ID, so a link from a section
+// continues from the file that contains it. Sources already reached at a
+// shorter distance (including the target itself) are not revisited.
+func (d *Database) BacklinksDepth(targetID string, depth int) ([]model.Reference, error) {
+	if depth < 1 {
+		depth = 1
+	}
+	if depth > MaxBacklinkDepth {
+		depth = MaxBacklinkDepth
+	}
+
+	visited := map[string]bool{baseDocumentID(targetID): true}
+	frontier := []string{targetID}
+	var results []model.Reference
+
+	for hop := 1; hop <= depth && len(frontier) > 0; hop++ {
+		var next []string
+		reached := make(map[string]bool)
+		for _, id := range frontier {
+			links, err := d.Backlinks(id)
+			if err != nil {
+				return nil, err
+			}
+			for _, link := range links {
+				sourceID := baseDocumentID(link.SourceID)
+				if hop > 1 && visited[sourceID] {
+					continue
+				}
+				link.Depth = hop
+				results = append(results, link)
+				if !visited[sourceID] && !reached[sourceID] {
+					reached[sourceID] = true
+					next = append(next, sourceID)
+				}
+			}
+		}
+		for _, id := range next {
+			visited[id] = true
+		}
+		frontier = next
+	}
+
+	return results, nil
 }
 
 // Outlinks returns all references made by the given source object.
 //
 // Includes refs whose source_id is a section of the source (source_id LIKE '<source>#%').
 func (d *Database) Outlinks(sourceID string) ([]model.Reference, error) {
+	return d.outlinks(sourceID, true)
+}
+
+// DirectOutlinks returns only the references attributed to the source itself,
+// leaving out refs made from within its sections.
+func (d *Database) DirectOutlinks(sourceID string) ([]model.Reference, error) {
+	return d.outlinks(sourceID, false)
+}
+
+func (d *Database) outlinks(sourceID string, includeSections bool) ([]model.Reference, error) {
+	where := "r.source_id = ?"
+	args := []interface{}{sourceID}
+	if includeSections {
+		where += " OR r.source_id LIKE ?"
+		args = append(args, sourceID+"#%")
+	}
 	query := `
-		SELECT r.source_id, o.type, r.target_raw, r.file_path, r.line_number, r.display_text
+		SELECT r.source_id, o.type, r.target_raw, r.target_id, r.file_path, r.line_number, r.display_text
 		FROM refs r
 		LEFT JOIN objects o ON r.source_id = o.id
-		WHERE r.source_id = ? OR r.source_id LIKE ?
+		WHERE ` + where + `
 		ORDER BY r.file_path, r.line_number, r.position_start
 	`
 
-	rows, err := d.db.Query(query, sourceID, sourceID+"#%")
+	rows, err := d.db.Query(query, args...)
 	if err != nil {
 		return nil, err
 	}
@@ -338,13 +651,16 @@ func (d *Database) Outlinks(sourceID string) ([]model.Reference, error) {
 	var results []model.Reference
 	for rows.Next() {
 		var result model.Reference
-		var sourceType sql.NullString
-		if err := rows.Scan(&result.SourceID, &sourceType, &result.TargetRaw, &result.FilePath, &result.Line, &result.DisplayText); err != nil {
+		var sourceType, targetID sql.NullString
+		if err := rows.Scan(&result.SourceID, &sourceType, &result.TargetRaw, &targetID, &result.FilePath, &result.Line, &result.DisplayText); err != nil {
 			return nil, err
 		}
 		if sourceType.Valid {
 			result.SourceType = sourceType.String
 		}
+		if targetID.Valid && targetID.String != "" {
+			result.TargetID = &targetID.String
+		}
 		results = append(results, result)
 	}
 
@@ -355,6 +671,10 @@ func (d *Database) Outlinks(sourceID string) ([]model.Reference, error) {
 // including refs that use directory-prefixed paths (e.g., [[objects/people/freya]]).
 // This is important for move operations to find all variants of a reference.
 func (d *Database) BacklinksWithRoots(targetID, objectRoot, pageRoot string) ([]model.Reference, error) {
+	return d.backlinks(targetID, objectRoot, pageRoot, "")
+}
+
+func (d *Database) backlinks(targetID, objectRoot, pageRoot, sourceType string) ([]model.Reference, error) {
 	// Build list of target patterns to search for
 	patterns := []string{targetID}
 
@@ -384,7 +704,17 @@ func (d *Database) BacklinksWithRoots(targetID, objectRoot, pageRoot string) ([]
 		SELECT r.source_id, o.type, r.target_raw, r.file_path, r.line_number, r.display_text
 		FROM refs r
 		LEFT JOIN objects o ON r.source_id = o.id
-		WHERE ` + strings.Join(conditions, " OR ")
+		WHERE (` + strings.Join(conditions, " OR ") + `)`
+	if sourceType != "" {
+		// Match on the containing document so links from sections are included.
+		query += `
+		AND EXISTS (
+			SELECT 1 FROM objects so
+			WHERE so.type = ?
+			AND (so.id = r.source_id OR so.id = substr(r.source_id, 1, instr(r.source_id, '#') - 1))
+		)`
+		args = append(args, sourceType)
+	}
 
 	rows, err := d.db.Query(query, args...)
 	if err != nil {
@@ -427,10 +757,37 @@ func (d *Database) GetObject(id string) (*model.Object, error) {
 	if err := json.Unmarshal([]byte(fieldsJSON), &result.Fields); err != nil || result.Fields == nil {
 		result.Fields = make(map[string]interface{})
 	}
+	result.FieldOrder = jsonObjectKeys(fieldsJSON)
 
 	return &result, nil
 }
 
+// jsonObjectKeys returns the top-level keys of a stored fields object in the
+// order they were written. Returns nil if the JSON is not an object.
+func jsonObjectKeys(data string) []string {
+	dec := json.NewDecoder(strings.NewReader(data))
+	if tok, err := dec.Token(); err != nil || tok != json.Delim('{') {
+		return nil
+	}
+	var keys []string
+	for dec.More() {
+		tok, err := dec.Token()
+		if err != nil {
+			return nil
+		}
+		key, ok := tok.(string)
+		if !ok {
+			return nil
+		}
+		var value json.RawMessage
+		if err := dec.Decode(&value); err != nil {
+			return nil
+		}
+		keys = append(keys, key)
+	}
+	return keys
+}
+
 // GetTrait retrieves a single trait by ID.
 func (d *Database) GetTrait(id string) (*model.Trait, error) {
 	var result model.Trait
@@ -481,11 +838,15 @@ func (d *Database) QueryDateIndex(date string) ([]DateIndexResult, error) {
 	return results, rows.Err()
 }
 
-// UntypedPages returns file paths of all objects using the fallback 'page' type.
-func (d *Database) UntypedPages() ([]string, error) {
-	rows, err := d.db.Query(
-		"SELECT DISTINCT file_path FROM objects WHERE type = 'page' ORDER BY file_path",
-	)
+// UntypedPages returns file paths of all objects of type 'page'. With
+// implicitOnly, files that declare `type: page` in frontmatter are left out so
+// only notes falling back to 'page' (no type at all) are returned.
+func (d *Database) UntypedPages(implicitOnly bool) ([]string, error) {
+	query := "SELECT DISTINCT file_path FROM objects WHERE type = 'page'"
+	if implicitOnly {
+		query += " AND type_explicit = 0"
+	}
+	rows, err := d.db.Query(query + " ORDER BY file_path")
 	if err != nil {
 		return nil, err
 	}
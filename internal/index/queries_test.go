@@ -1,6 +1,7 @@
 package index
 
 import (
+	"reflect"
 	"testing"
 	"time"
 )
@@ -455,6 +456,53 @@ func TestQueryTraitsWithFilterExpressions(t *testing.T) {
 	})
 }
 
+func TestQueryDatedTraits(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	_, err = db.db.Exec(`
+		INSERT INTO traits (id, trait_type, value, content, file_path, line_number, parent_object_id)
+		VALUES
+			('t1', 'due', '2026-03-01', 'Before window', 'tasks.md', 1, 'tasks'),
+			('t2', 'due', '2026-03-02', 'First day', 'tasks.md', 2, 'tasks'),
+			('t3', 'remind', '2026-03-08T18:30', 'Last day evening', 'tasks.md', 3, 'tasks'),
+			('t4', 'due', '2026-03-09', 'After window', 'tasks.md', 4, 'tasks'),
+			('t5', 'due', '2026-03-05', 'Finished', 'tasks.md', 5, 'tasks'),
+			('t6', 'todo', 'done', 'Finished', 'tasks.md', 5, 'tasks')
+	`)
+	if err != nil {
+		t.Fatalf("failed to insert test traits: %v", err)
+	}
+
+	ids := func(results []DatedTrait) []string {
+		var out []string
+		for _, result := range results {
+			out = append(out, result.ID)
+		}
+		return out
+	}
+
+	results, err := db.QueryDatedTraits([]string{"due", "remind"}, "2026-03-02", "2026-03-08", nil)
+	if err != nil {
+		t.Fatalf("query failed: %v", err)
+	}
+	if got, want := ids(results), []string{"t2", "t5", "t3"}; !reflect.DeepEqual(got, want) {
+		t.Errorf("ids = %v, want %v", got, want)
+	}
+
+	results, err = db.QueryDatedTraits([]string{"due", "remind"}, "2026-03-02", "2026-03-08", []TraitValue{{TraitType: "todo", Value: "done"}})
+	if err != nil {
+		t.Fatalf("query with exclusions failed: %v", err)
+	}
+	if got, want := ids(results), []string{"t2", "t3"}; !reflect.DeepEqual(got, want) {
+		t.Errorf("ids with exclusions = %v, want %v", got, want)
+	}
+}
+
 func TestBacklinks(t *testing.T) {
 	t.Parallel()
 	db, err := OpenInMemory()
@@ -498,6 +546,48 @@ func TestBacklinks(t *testing.T) {
 		}
 	})
 
+	t.Run("short raw target matches by resolved id", func(t *testing.T) {
+		results, err := db.Backlinks("people/freya")
+		if err != nil {
+			t.Fatalf("query failed: %v", err)
+		}
+		found := false
+		for _, r := range results {
+			if r.TargetRaw == "freya" && r.SourceID == "projects/bifrost" {
+				found = true
+			}
+		}
+		if !found {
+			t.Errorf("expected [[freya]] backlink via target_id, got %+v", results)
+		}
+	})
+
+	t.Run("section target matches only section refs", func(t *testing.T) {
+		results, err := db.Backlinks("people/freya#notes")
+		if err != nil {
+			t.Fatalf("query failed: %v", err)
+		}
+		if len(results) != 1 || results[0].TargetRaw != "freya#notes" {
+			t.Errorf("expected 1 section backlink, got %+v", results)
+		}
+	})
+
+	t.Run("unresolved refs fall back to raw target", func(t *testing.T) {
+		if _, err := db.db.Exec(`
+			INSERT INTO refs (source_id, target_id, target_raw, file_path, line_number)
+			VALUES ('daily/2025-02-01', NULL, 'people/thor', 'daily/2025-02-01.md', 7)
+		`); err != nil {
+			t.Fatalf("failed to insert unresolved ref: %v", err)
+		}
+		results, err := db.Backlinks("people/thor")
+		if err != nil {
+			t.Fatalf("query failed: %v", err)
+		}
+		if len(results) != 1 {
+			t.Errorf("expected 1 raw-matched backlink, got %d", len(results))
+		}
+	})
+
 	t.Run("no backlinks", func(t *testing.T) {
 		results, err := db.Backlinks("projects/bifrost")
 		if err != nil {
@@ -509,6 +599,118 @@ func TestBacklinks(t *testing.T) {
 	})
 }
 
+func TestBacklinksDepth(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	// notes/c -> notes/b#intro -> notes/a -> projects/website, plus a cycle
+	// from the target back to notes/a.
+	_, err = db.db.Exec(`
+		INSERT INTO refs (source_id, target_id, target_raw, file_path, line_number)
+		VALUES
+			('notes/a', 'projects/website', 'projects/website', 'notes/a.md', 3),
+			('notes/b#intro', 'notes/a', 'notes/a', 'notes/b.md', 5),
+			('notes/c', 'notes/b', 'notes/b', 'notes/c.md', 2),
+			('projects/website', 'notes/a', 'notes/a', 'projects/website.md', 8)
+	`)
+	if err != nil {
+		t.Fatalf("failed to insert test refs: %v", err)
+	}
+
+	depthsBySource := func(t *testing.T, depth int) map[string]int {
+		t.Helper()
+		results, err := db.BacklinksDepth("projects/website", depth)
+		if err != nil {
+			t.Fatalf("query failed: %v", err)
+		}
+		got := make(map[string]int, len(results))
+		for _, r := range results {
+			if _, dup := got[r.SourceID]; dup {
+				t.Fatalf("source %q returned more than once: %+v", r.SourceID, results)
+			}
+			got[r.SourceID] = r.Depth
+		}
+		return got
+	}
+
+	t.Run("depth 1 returns direct backlinks", func(t *testing.T) {
+		got := depthsBySource(t, 1)
+		if len(got) != 1 || got["notes/a"] != 1 {
+			t.Errorf("got %v, want notes/a at depth 1", got)
+		}
+	})
+
+	t.Run("depth 2 follows section sources and skips the cycle", func(t *testing.T) {
+		got := depthsBySource(t, 2)
+		if len(got) != 2 || got["notes/a"] != 1 || got["notes/b#intro"] != 2 {
+			t.Errorf("got %v, want notes/a at 1 and notes/b#intro at 2", got)
+		}
+	})
+
+	t.Run("depth is capped", func(t *testing.T) {
+		got := depthsBySource(t, 100)
+		if len(got) != 3 || got["notes/c"] != 3 {
+			t.Errorf("got %v, want notes/c at depth 3", got)
+		}
+	})
+}
+
+func TestBacklinksFromType(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	_, err = db.db.Exec(`
+		INSERT INTO objects (id, file_path, type, line_start, fields)
+		VALUES
+			('people/freya', 'people/freya.md', 'person', 1, '{}'),
+			('meetings/standup', 'meetings/standup.md', 'meeting', 1, '{}'),
+			('meetings/retro', 'meetings/retro.md', 'meeting', 1, '{}'),
+			('meetings/retro#actions', 'meetings/retro.md', 'section', 4, '{}'),
+			('daily/2025-02-01', 'daily/2025-02-01.md', 'date', 1, '{}')
+	`)
+	if err != nil {
+		t.Fatalf("failed to insert test objects: %v", err)
+	}
+	_, err = db.db.Exec(`
+		INSERT INTO refs (source_id, target_id, target_raw, file_path, line_number)
+		VALUES
+			('meetings/standup', 'people/freya', 'people/freya', 'meetings/standup.md', 3),
+			('meetings/retro#actions', 'people/freya', 'freya', 'meetings/retro.md', 6),
+			('daily/2025-02-01', 'people/freya', 'people/freya', 'daily/2025-02-01.md', 5)
+	`)
+	if err != nil {
+		t.Fatalf("failed to insert test refs: %v", err)
+	}
+
+	results, err := db.BacklinksFromType("people/freya", "meeting")
+	if err != nil {
+		t.Fatalf("query failed: %v", err)
+	}
+	got := make(map[string]bool, len(results))
+	for _, r := range results {
+		got[r.SourceID] = true
+	}
+	if len(got) != 2 || !got["meetings/standup"] || !got["meetings/retro#actions"] {
+		t.Errorf("got %v, want only links from meeting notes", got)
+	}
+
+	results, err = db.BacklinksFromType("people/freya", "")
+	if err != nil {
+		t.Fatalf("query failed: %v", err)
+	}
+	if len(results) != 3 {
+		t.Errorf("got %d backlinks without a type filter, want 3", len(results))
+	}
+}
+
 func TestOutlinks(t *testing.T) {
 	t.Parallel()
 	db, err := OpenInMemory()
@@ -560,6 +762,22 @@ func TestOutlinks(t *testing.T) {
 			t.Errorf("expected 1 outlink, got %d", len(results))
 		}
 	})
+
+	t.Run("direct outlinks skip section refs", func(t *testing.T) {
+		results, err := db.DirectOutlinks("projects/bifrost")
+		if err != nil {
+			t.Fatalf("query failed: %v", err)
+		}
+		if len(results) != 1 || results[0].SourceID != "projects/bifrost" {
+			t.Fatalf("expected only the file-level outlink, got %+v", results)
+		}
+		if results[0].TargetID == nil || *results[0].TargetID != "people/freya" {
+			t.Errorf("expected resolved target people/freya, got %v", results[0].TargetID)
+		}
+		if results[0].Line == nil || *results[0].Line != 10 {
+			t.Errorf("expected line 10, got %v", results[0].Line)
+		}
+	})
 }
 
 func TestGetObject(t *testing.T) {
@@ -613,18 +831,18 @@ func TestUntypedPages(t *testing.T) {
 
 	// Insert test objects
 	_, err = db.db.Exec(`
-		INSERT INTO objects (id, file_path, type, line_start, fields)
+		INSERT INTO objects (id, file_path, type, type_explicit, line_start, fields)
 		VALUES 
-			('notes/random', 'notes/random.md', 'page', 1, '{}'),
-			('people/freya', 'people/freya.md', 'person', 1, '{}'),
-			('notes/another', 'notes/another.md', 'page', 1, '{}')
+			('notes/random', 'notes/random.md', 'page', 0, 1, '{}'),
+			('people/freya', 'people/freya.md', 'person', 1, 1, '{}'),
+			('notes/another', 'notes/another.md', 'page', 1, 1, '{}')
 	`)
 	if err != nil {
 		t.Fatalf("failed to insert test objects: %v", err)
 	}
 
 	t.Run("find untyped pages", func(t *testing.T) {
-		results, err := db.UntypedPages()
+		results, err := db.UntypedPages(false)
 		if err != nil {
 			t.Fatalf("query failed: %v", err)
 		}
@@ -632,6 +850,16 @@ func TestUntypedPages(t *testing.T) {
 			t.Errorf("expected 2 untyped pages, got %d", len(results))
 		}
 	})
+
+	t.Run("implicit only skips declared pages", func(t *testing.T) {
+		results, err := db.UntypedPages(true)
+		if err != nil {
+			t.Fatalf("query failed: %v", err)
+		}
+		if len(results) != 1 || results[0] != "notes/random.md" {
+			t.Errorf("expected only notes/random.md, got %v", results)
+		}
+	})
 }
 
 func TestQueryObjects(t *testing.T) {
@@ -691,6 +919,96 @@ func TestQueryObjects(t *testing.T) {
 	})
 }
 
+func TestObservedFields(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	_, err = db.db.Exec(`
+		INSERT INTO objects (id, file_path, type, line_start, fields)
+		VALUES
+			('people/freya', 'people/freya.md', 'person', 1, '{"name": "Freya", "email": "freya@asgard.realm", "nickname": "Van"}'),
+			('people/thor', 'people/thor.md', 'person', 1, '{"name": "Thor", "email": "thor@asgard.realm"}'),
+			('projects/bifrost', 'projects/bifrost.md', 'project', 1, '{"title": "Bifrost"}')
+	`)
+	if err != nil {
+		t.Fatalf("failed to insert test objects: %v", err)
+	}
+
+	fields, err := db.ObservedFields("person")
+	if err != nil {
+		t.Fatalf("ObservedFields failed: %v", err)
+	}
+	want := []FieldCount{{Name: "email", Count: 2}, {Name: "name", Count: 2}, {Name: "nickname", Count: 1}}
+	if len(fields) != len(want) {
+		t.Fatalf("fields = %+v, want %+v", fields, want)
+	}
+	for i := range want {
+		if fields[i] != want[i] {
+			t.Fatalf("fields = %+v, want %+v", fields, want)
+		}
+	}
+
+	none, err := db.ObservedFields("company")
+	if err != nil {
+		t.Fatalf("ObservedFields failed: %v", err)
+	}
+	if len(none) != 0 {
+		t.Fatalf("fields for unused type = %+v, want none", none)
+	}
+}
+
+func TestRecentlyModified(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	_, err = db.db.Exec(`
+		INSERT INTO objects (id, file_path, type, line_start, fields, file_mtime)
+		VALUES
+			('people/freya', 'people/freya.md', 'person', 1, '{}', 1700000300),
+			('projects/bifrost', 'projects/bifrost.md', 'project', 1, '{}', 1700000100),
+			('daily/2025-02-01', 'daily/2025-02-01.md', 'date', 1, '{}', 1700000500),
+			('people/thor', 'people/thor.md', 'person', 1, '{}', 1700000300),
+			('notes/legacy', 'notes/legacy.md', 'page', 1, '{}', NULL)
+	`)
+	if err != nil {
+		t.Fatalf("failed to insert test objects: %v", err)
+	}
+
+	recent, err := db.RecentlyModified(3)
+	if err != nil {
+		t.Fatalf("RecentlyModified failed: %v", err)
+	}
+	want := []RecentObject{
+		{ID: "daily/2025-02-01", Type: "date", FilePath: "daily/2025-02-01.md", FileMtime: 1700000500},
+		{ID: "people/freya", Type: "person", FilePath: "people/freya.md", FileMtime: 1700000300},
+		{ID: "people/thor", Type: "person", FilePath: "people/thor.md", FileMtime: 1700000300},
+	}
+	if len(recent) != len(want) {
+		t.Fatalf("recent = %+v, want %+v", recent, want)
+	}
+	for i := range want {
+		if recent[i] != want[i] {
+			t.Fatalf("recent = %+v, want %+v", recent, want)
+		}
+	}
+
+	all, err := db.RecentlyModified(0)
+	if err != nil {
+		t.Fatalf("RecentlyModified failed: %v", err)
+	}
+	if len(all) != 5 || all[4].ID != "notes/legacy" {
+		t.Fatalf("all = %+v, want 5 objects with the unknown mtime last", all)
+	}
+}
+
 func TestAllSections(t *testing.T) {
 	t.Parallel()
 	db, err := OpenInMemory()
@@ -1,6 +1,7 @@
 package index
 
 import (
+	"database/sql"
 	"os"
 	"path/filepath"
 	"testing"
@@ -616,3 +617,61 @@ company: "[[cursor]]"
 		t.Error("expected backlink from projects/hiring to companies/cursor")
 	}
 }
+
+func TestBlockRefResolution(t *testing.T) {
+	t.Parallel()
+
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer db.Close()
+	sch := schema.New()
+
+	indexFile := func(path, content string) {
+		t.Helper()
+		doc, err := parser.ParseDocument(content, filepath.Join("/vault", path), "/vault")
+		if err != nil {
+			t.Fatalf("parse %s: %v", path, err)
+		}
+		if err := db.IndexDocument(doc, sch); err != nil {
+			t.Fatalf("index %s: %v", path, err)
+		}
+	}
+	targetOf := func(raw string) string {
+		t.Helper()
+		var targetID sql.NullString
+		if err := db.db.QueryRow(`SELECT target_id FROM refs WHERE target_raw = ?`, raw).Scan(&targetID); err != nil {
+			t.Fatalf("query ref %q: %v", raw, err)
+		}
+		return targetID.String
+	}
+
+	indexFile("projects/plan.md", "# Plan\n\n## Launch\n\nShip the beta by Friday\n")
+	indexFile("notes/today.md", "See [[projects/plan#^launch]], [[projects/plan#launch]] and [[plan#^call-freya]].\n")
+	if got := targetOf("projects/plan#^launch"); got != "" {
+		t.Fatalf("block ref resolved to %q before the block exists", got)
+	}
+
+	// Declaring the blocks later resolves the dangling refs in other files.
+	indexFile("projects/plan.md", "# Plan\n\n## Launch\n\nShip the beta by Friday ^launch\n\n- Call Freya ^call-freya\n")
+
+	var content string
+	var line int
+	if err := db.db.QueryRow(`SELECT content, line_number FROM blocks WHERE id = ?`, "projects/plan#^launch").Scan(&content, &line); err != nil {
+		t.Fatalf("query block: %v", err)
+	}
+	if content != "Ship the beta by Friday" || line != 5 {
+		t.Fatalf("block = %q on line %d, want %q on line 5", content, line, "Ship the beta by Friday")
+	}
+
+	for raw, want := range map[string]string{
+		"projects/plan#^launch": "projects/plan#^launch",
+		"projects/plan#launch":  "projects/plan#launch",
+		"plan#^call-freya":      "projects/plan#^call-freya",
+	} {
+		if got := targetOf(raw); got != want {
+			t.Errorf("%s resolved to %q, want %q", raw, got, want)
+		}
+	}
+}
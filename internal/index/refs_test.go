@@ -120,6 +120,67 @@ func TestResolveReferences(t *testing.T) {
 	}
 }
 
+func TestResolveReferences_CaseAndSpaceTolerantTargets(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+	db.SetAutoResolveRefs(false)
+
+	sch := schema.New()
+
+	person := &parser.ParsedDocument{
+		FilePath: "people/alice-chen.md",
+		Objects: []*parser.ParsedObject{
+			{ID: "people/alice-chen", ObjectType: "person", Fields: map[string]schema.FieldValue{}, LineStart: 1},
+		},
+	}
+	exact := &parser.ParsedDocument{
+		FilePath: "people/Alice.md",
+		Objects: []*parser.ParsedObject{
+			{ID: "people/Alice", ObjectType: "person", Fields: map[string]schema.FieldValue{}, LineStart: 1},
+		},
+	}
+	note := &parser.ParsedDocument{
+		FilePath: "notes/standup.md",
+		Objects: []*parser.ParsedObject{
+			{ID: "notes/standup", ObjectType: "page", Fields: map[string]schema.FieldValue{}, LineStart: 1},
+		},
+		Refs: []*parser.ParsedRef{
+			{SourceID: "notes/standup", TargetRaw: "Alice Chen", Line: 1},
+			{SourceID: "notes/standup", TargetRaw: "People/ALICE-CHEN", Line: 2},
+			{SourceID: "notes/standup", TargetRaw: "people/Alice", Line: 3},
+		},
+	}
+	for _, doc := range []*parser.ParsedDocument{person, exact, note} {
+		if err := db.IndexDocument(doc, sch); err != nil {
+			t.Fatalf("failed to index %s: %v", doc.FilePath, err)
+		}
+	}
+
+	if _, err := db.ResolveReferences("daily"); err != nil {
+		t.Fatalf("failed to resolve references: %v", err)
+	}
+
+	want := map[string]string{
+		"Alice Chen":        "people/alice-chen",
+		"People/ALICE-CHEN": "people/alice-chen",
+		// Exact IDs win over the slugified fallback.
+		"people/Alice": "people/Alice",
+	}
+	for raw, wantID := range want {
+		var targetID string
+		if err := db.db.QueryRow(`SELECT target_id FROM refs WHERE target_raw = ?`, raw).Scan(&targetID); err != nil {
+			t.Fatalf("failed to query ref %q: %v", raw, err)
+		}
+		if targetID != wantID {
+			t.Errorf("ref %q resolved to %q, want %q", raw, targetID, wantID)
+		}
+	}
+}
+
 func TestResolveReferences_DateShorthand(t *testing.T) {
 	t.Parallel()
 	db, err := OpenInMemory()
@@ -252,3 +313,68 @@ func TestResolveReferences_UnresolvedRef(t *testing.T) {
 		t.Errorf("expected target_id to be NULL, got '%s'", *targetID)
 	}
 }
+
+func TestIndexDocument_NewObjectResolvesDanglingRefsInOtherFiles(t *testing.T) {
+	t.Parallel()
+	db, err := OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open database: %v", err)
+	}
+	defer db.Close()
+
+	sch := schema.New()
+
+	note := &parser.ParsedDocument{
+		FilePath:   "notes/standup.md",
+		RawContent: "Met with [[people/freya]]",
+		Objects: []*parser.ParsedObject{
+			{
+				ID:         "notes/standup",
+				ObjectType: "page",
+				Fields:     map[string]schema.FieldValue{},
+				LineStart:  1,
+			},
+		},
+		Refs: []*parser.ParsedRef{
+			{
+				SourceID:  "notes/standup",
+				TargetRaw: "people/freya",
+				Line:      1,
+			},
+		},
+	}
+	if err := db.IndexDocument(note, sch); err != nil {
+		t.Fatalf("failed to index note: %v", err)
+	}
+
+	var targetID *string
+	if err := db.db.QueryRow(`SELECT target_id FROM refs WHERE target_raw = ?`, "people/freya").Scan(&targetID); err != nil {
+		t.Fatalf("failed to query refs: %v", err)
+	}
+	if targetID != nil {
+		t.Fatalf("expected dangling ref before target exists, got '%s'", *targetID)
+	}
+
+	person := &parser.ParsedDocument{
+		FilePath:   "people/freya.md",
+		RawContent: "# Freya",
+		Objects: []*parser.ParsedObject{
+			{
+				ID:         "people/freya",
+				ObjectType: "person",
+				Fields:     map[string]schema.FieldValue{},
+				LineStart:  1,
+			},
+		},
+	}
+	if err := db.IndexDocument(person, sch); err != nil {
+		t.Fatalf("failed to index person: %v", err)
+	}
+
+	if err := db.db.QueryRow(`SELECT target_id FROM refs WHERE target_raw = ?`, "people/freya").Scan(&targetID); err != nil {
+		t.Fatalf("failed to query refs: %v", err)
+	}
+	if targetID == nil || *targetID != "people/freya" {
+		t.Fatalf("expected incremental index to resolve ref to people/freya, got %v", targetID)
+	}
+}
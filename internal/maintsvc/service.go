@@ -2,11 +2,15 @@ package maintsvc
 
 import (
 	stdbuildinfo "debug/buildinfo"
+	"encoding/json"
 	"errors"
+	"fmt"
+	"os"
 	"runtime"
 	"runtime/debug"
 	"strings"
 
+	"github.com/aidanlsb/raven/internal/atomicfile"
 	"github.com/aidanlsb/raven/internal/buildinfo"
 	"github.com/aidanlsb/raven/internal/codes"
 	"github.com/aidanlsb/raven/internal/index"
@@ -15,8 +19,10 @@ import (
 type Code = codes.ErrorCode
 
 const (
-	CodeInvalidInput  Code = codes.ErrInvalidInput
-	CodeDatabaseError Code = codes.ErrDatabase
+	CodeInvalidInput   Code = codes.ErrInvalidInput
+	CodeDatabaseError  Code = codes.ErrDatabase
+	CodeFileReadError  Code = codes.ErrFileRead
+	CodeFileWriteError Code = codes.ErrFileWrite
 )
 
 type Error struct {
@@ -58,14 +64,27 @@ func AsError(err error) (*Error, bool) {
 	return nil, false
 }
 
+type StatsRequest struct {
+	VaultPath string
+	// ByType adds the number of objects per type.
+	ByType bool
+	// ByTrait adds the number of trait annotations per trait type.
+	ByTrait bool
+}
+
 type StatsResult struct {
 	FileCount   int `json:"file_count"`
 	ObjectCount int `json:"object_count"`
 	TraitCount  int `json:"trait_count"`
 	RefCount    int `json:"ref_count"`
+	WordCount   int `json:"word_count"`
+
+	ObjectsByType []index.TypeCount `json:"objects_by_type,omitempty"`
+	TraitsByType  []index.TypeCount `json:"traits_by_type,omitempty"`
 }
 
-func Stats(vaultPath string) (*StatsResult, error) {
+func Stats(req StatsRequest) (*StatsResult, error) {
+	vaultPath := req.VaultPath
 	if strings.TrimSpace(vaultPath) == "" {
 		return nil, newError(CodeInvalidInput, "vault path is required", "", nil)
 	}
@@ -81,11 +100,177 @@ func Stats(vaultPath string) (*StatsResult, error) {
 		return nil, newError(CodeDatabaseError, "failed to query stats", "", err)
 	}
 
+	result := &StatsResult{
+		FileCount:   stats.FileCount,
+		ObjectCount: stats.ObjectCount,
+		TraitCount:  stats.TraitCount,
+		RefCount:    stats.RefCount,
+		WordCount:   stats.WordCount,
+	}
+	if req.ByType {
+		result.ObjectsByType, err = db.ObjectCountsByType()
+		if err != nil {
+			return nil, newError(CodeDatabaseError, "failed to count objects by type", "", err)
+		}
+	}
+	if req.ByTrait {
+		result.TraitsByType, err = db.TraitCountsByType()
+		if err != nil {
+			return nil, newError(CodeDatabaseError, "failed to count traits by type", "", err)
+		}
+	}
+	return result, nil
+}
+
+type CompactResult struct {
+	SizeBefore int64 `json:"size_before"`
+	SizeAfter  int64 `json:"size_after"`
+}
+
+// Compact checkpoints the WAL, vacuums, and analyzes the index database.
+// Sizes include the database file and its WAL/shared-memory sidecars.
+func Compact(vaultPath string) (*CompactResult, error) {
+	if strings.TrimSpace(vaultPath) == "" {
+		return nil, newError(CodeInvalidInput, "vault path is required", "", nil)
+	}
+
+	dbPath := index.DBPath(vaultPath)
+	result := &CompactResult{SizeBefore: indexFilesSize(dbPath)}
+
+	db, err := index.Open(vaultPath)
+	if err != nil {
+		return nil, newError(CodeDatabaseError, "failed to open database", "Run 'rvn reindex' to rebuild the database", err)
+	}
+	defer db.Close()
+
+	if err := db.Checkpoint(); err != nil {
+		return nil, newError(CodeDatabaseError, "failed to checkpoint database", "", err)
+	}
+	if err := db.Vacuum(); err != nil {
+		return nil, newError(CodeDatabaseError, "failed to vacuum database", "Close other Raven processes using this vault and try again", err)
+	}
+	if err := db.Analyze(); err != nil {
+		return nil, newError(CodeDatabaseError, "failed to analyze database", "", err)
+	}
+	if err := db.Checkpoint(); err != nil {
+		return nil, newError(CodeDatabaseError, "failed to checkpoint database", "", err)
+	}
+
+	result.SizeAfter = indexFilesSize(dbPath)
+	return result, nil
+}
+
+func indexFilesSize(dbPath string) int64 {
+	var total int64
+	for _, path := range []string{dbPath, dbPath + "-wal", dbPath + "-shm"} {
+		if info, err := os.Stat(path); err == nil {
+			total += info.Size()
+		}
+	}
+	return total
+}
+
+type ExportRequest struct {
+	VaultPath string
+	// OutPath, when set, receives the export as indented JSON instead of
+	// returning it inline.
+	OutPath string
+}
+
+type ExportResult struct {
+	Export      *index.VaultExport `json:"export,omitempty"`
+	OutPath     string             `json:"out,omitempty"`
+	ObjectCount int                `json:"object_count"`
+	TraitCount  int                `json:"trait_count"`
+	RefCount    int                `json:"ref_count"`
+	AssetCount  int                `json:"asset_count"`
+}
+
+// Export dumps the index rows for backup or external tooling.
+func Export(req ExportRequest) (*ExportResult, error) {
+	if strings.TrimSpace(req.VaultPath) == "" {
+		return nil, newError(CodeInvalidInput, "vault path is required", "", nil)
+	}
+
+	db, err := index.Open(req.VaultPath)
+	if err != nil {
+		return nil, newError(CodeDatabaseError, "failed to open database", "Run 'rvn reindex' to rebuild the database", err)
+	}
+	defer db.Close()
+
+	export, err := db.ExportAll()
+	if err != nil {
+		return nil, newError(CodeDatabaseError, "failed to export index", "Run 'rvn reindex' to rebuild the database", err)
+	}
+
+	result := &ExportResult{
+		ObjectCount: len(export.Objects),
+		TraitCount:  len(export.Traits),
+		RefCount:    len(export.Refs),
+		AssetCount:  len(export.Assets),
+	}
+	outPath := strings.TrimSpace(req.OutPath)
+	if outPath == "" {
+		result.Export = export
+		return result, nil
+	}
+
+	data, err := json.MarshalIndent(export, "", "  ")
+	if err != nil {
+		return nil, newError(CodeDatabaseError, "failed to encode export", "", err)
+	}
+	if err := atomicfile.WriteFile(outPath, append(data, '\n'), 0o644); err != nil {
+		return nil, newError(CodeFileWriteError, fmt.Sprintf("failed to write %s", outPath), "Check that the directory exists and is writable", err)
+	}
+	result.OutPath = outPath
+	return result, nil
+}
+
+type RestoreRequest struct {
+	VaultPath string
+	InPath    string
+}
+
+// Restore replaces the index with the rows of an export written by Export.
+// Vault files are not read, so the result mirrors the exporting machine.
+func Restore(req RestoreRequest) (*StatsResult, error) {
+	if strings.TrimSpace(req.VaultPath) == "" {
+		return nil, newError(CodeInvalidInput, "vault path is required", "", nil)
+	}
+	inPath := strings.TrimSpace(req.InPath)
+	if inPath == "" {
+		return nil, newError(CodeInvalidInput, "export file is required", "Usage: rvn restore <file>", nil)
+	}
+
+	data, err := os.ReadFile(inPath)
+	if err != nil {
+		return nil, newError(CodeFileReadError, fmt.Sprintf("failed to read %s", inPath), "Check the export path and try again", err)
+	}
+	var export index.VaultExport
+	if err := json.Unmarshal(data, &export); err != nil {
+		return nil, newError(CodeInvalidInput, fmt.Sprintf("%s is not a valid export: %v", inPath, err), "Create the file with 'rvn export'", err)
+	}
+
+	db, err := index.Open(req.VaultPath)
+	if err != nil {
+		return nil, newError(CodeDatabaseError, "failed to open database", "Run 'rvn reindex' to rebuild the database", err)
+	}
+	defer db.Close()
+
+	if err := db.ImportAll(&export); err != nil {
+		return nil, newError(CodeInvalidInput, fmt.Sprintf("failed to restore index: %v", err), "Re-create the export with 'rvn export' from a matching Raven version", err)
+	}
+
+	stats, err := db.Stats()
+	if err != nil {
+		return nil, newError(CodeDatabaseError, "failed to query stats", "", err)
+	}
 	return &StatsResult{
 		FileCount:   stats.FileCount,
 		ObjectCount: stats.ObjectCount,
 		TraitCount:  stats.TraitCount,
 		RefCount:    stats.RefCount,
+		WordCount:   stats.WordCount,
 	}, nil
 }
 
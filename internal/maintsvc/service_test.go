@@ -1,6 +1,10 @@
 package maintsvc
 
 import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
 	"runtime/debug"
 	"testing"
 
@@ -24,7 +28,7 @@ func assertCode(t *testing.T, err error, want Code) {
 
 func TestStats_InvalidInput(t *testing.T) {
 	t.Parallel()
-	_, err := Stats(" ")
+	_, err := Stats(StatsRequest{VaultPath: " "})
 	assertCode(t, err, CodeInvalidInput)
 }
 
@@ -65,13 +69,259 @@ func TestStats_HappyPath(t *testing.T) {
 		t.Fatalf("failed to close db: %v", err)
 	}
 
-	stats, err := Stats(vaultPath)
+	stats, err := Stats(StatsRequest{VaultPath: vaultPath})
 	if err != nil {
 		t.Fatalf("Stats returned error: %v", err)
 	}
 	if stats.ObjectCount != 2 || stats.TraitCount != 1 || stats.RefCount != 1 || stats.FileCount != 2 {
 		t.Fatalf("unexpected stats: %#v", stats)
 	}
+	if stats.ObjectsByType != nil || stats.TraitsByType != nil {
+		t.Fatalf("expected no breakdowns without flags, got %#v", stats)
+	}
+
+	stats, err = Stats(StatsRequest{VaultPath: vaultPath, ByType: true, ByTrait: true})
+	if err != nil {
+		t.Fatalf("Stats with breakdowns returned error: %v", err)
+	}
+	wantTypes := []index.TypeCount{{Type: "page", Count: 1}, {Type: "project", Count: 1}}
+	if fmt.Sprint(stats.ObjectsByType) != fmt.Sprint(wantTypes) {
+		t.Fatalf("objects by type = %v, want %v", stats.ObjectsByType, wantTypes)
+	}
+	wantTraits := []index.TypeCount{{Type: "todo", Count: 1}}
+	if fmt.Sprint(stats.TraitsByType) != fmt.Sprint(wantTraits) {
+		t.Fatalf("traits by type = %v, want %v", stats.TraitsByType, wantTraits)
+	}
+}
+
+func TestCompact_InvalidInput(t *testing.T) {
+	t.Parallel()
+	_, err := Compact(" ")
+	assertCode(t, err, CodeInvalidInput)
+}
+
+func TestCompact_PopulatedDatabase(t *testing.T) {
+	t.Parallel()
+	vaultPath := t.TempDir()
+	db, err := index.Open(vaultPath)
+	if err != nil {
+		t.Fatalf("failed to open index db: %v", err)
+	}
+	for i := 0; i < 200; i++ {
+		if _, err := db.DB().Exec(
+			`INSERT INTO objects (id, file_path, type, line_start, fields) VALUES (?, ?, 'page', 1, '{}')`,
+			fmt.Sprintf("page/%d", i), fmt.Sprintf("pages/%d.md", i),
+		); err != nil {
+			t.Fatalf("failed to insert object: %v", err)
+		}
+	}
+	if _, err := db.DB().Exec(`DELETE FROM objects`); err != nil {
+		t.Fatalf("failed to delete objects: %v", err)
+	}
+	if err := db.Close(); err != nil {
+		t.Fatalf("failed to close db: %v", err)
+	}
+
+	result, err := Compact(vaultPath)
+	if err != nil {
+		t.Fatalf("Compact returned error: %v", err)
+	}
+	if result.SizeBefore <= 0 || result.SizeAfter <= 0 {
+		t.Fatalf("expected non-zero sizes, got %#v", result)
+	}
+	if result.SizeAfter > result.SizeBefore {
+		t.Fatalf("expected compact not to grow the index, got %#v", result)
+	}
+
+	stats, err := Stats(StatsRequest{VaultPath: vaultPath})
+	if err != nil {
+		t.Fatalf("Stats after compact returned error: %v", err)
+	}
+	if stats.ObjectCount != 0 {
+		t.Fatalf("expected empty index after compact, got %#v", stats)
+	}
+}
+
+func TestExport_InvalidInput(t *testing.T) {
+	t.Parallel()
+	_, err := Export(ExportRequest{VaultPath: " "})
+	assertCode(t, err, CodeInvalidInput)
+}
+
+func TestExport_RoundTripsSeededIndex(t *testing.T) {
+	t.Parallel()
+	vaultPath := t.TempDir()
+	db, err := index.Open(vaultPath)
+	if err != nil {
+		t.Fatalf("failed to open index db: %v", err)
+	}
+	_, err = db.DB().Exec(`
+		INSERT INTO objects (id, file_path, type, line_start, fields, alias) VALUES
+			('page/one', 'pages/one.md', 'page', 1, '{"tags":["a","b"]}', NULL),
+			('project/raven', 'projects/raven.md', 'project', 1, '{"status":"active"}', 'Raven')
+	`)
+	if err != nil {
+		t.Fatalf("failed to insert objects: %v", err)
+	}
+	_, err = db.DB().Exec(`
+		INSERT INTO traits (id, trait_type, value, content, file_path, line_number, parent_object_id) VALUES
+			('pages/one.md:trait:0', 'todo', 'open', 'Task', 'pages/one.md', 3, 'page/one'),
+			('pages/one.md:trait:1', 'highlight', NULL, 'Note', 'pages/one.md', 5, 'page/one')
+	`)
+	if err != nil {
+		t.Fatalf("failed to insert traits: %v", err)
+	}
+	_, err = db.DB().Exec(`
+		INSERT INTO refs (source_id, target_id, target_raw, file_path, line_number) VALUES
+			('page/one', 'project/raven', 'project/raven', 'pages/one.md', 4),
+			('page/one', NULL, 'missing', 'pages/one.md', 6)
+	`)
+	if err != nil {
+		t.Fatalf("failed to insert refs: %v", err)
+	}
+	if err := db.Close(); err != nil {
+		t.Fatalf("failed to close db: %v", err)
+	}
+
+	outPath := filepath.Join(t.TempDir(), "vault.json")
+	result, err := Export(ExportRequest{VaultPath: vaultPath, OutPath: outPath})
+	if err != nil {
+		t.Fatalf("Export returned error: %v", err)
+	}
+	if result.Export != nil || result.OutPath != outPath {
+		t.Fatalf("expected export written to %s only, got %#v", outPath, result)
+	}
+
+	data, err := os.ReadFile(outPath)
+	if err != nil {
+		t.Fatalf("failed to read export: %v", err)
+	}
+	var export index.VaultExport
+	if err := json.Unmarshal(data, &export); err != nil {
+		t.Fatalf("export is not valid JSON: %v", err)
+	}
+	if len(export.Objects) != 2 || len(export.Traits) != 2 || len(export.Refs) != 2 || len(export.Assets) != 0 {
+		t.Fatalf("unexpected export row counts: %d objects, %d traits, %d refs, %d assets",
+			len(export.Objects), len(export.Traits), len(export.Refs), len(export.Assets))
+	}
+	if export.Version != index.CurrentDBVersion {
+		t.Fatalf("version = %d, want %d", export.Version, index.CurrentDBVersion)
+	}
+
+	var fields map[string]interface{}
+	if err := json.Unmarshal(export.Objects[0].Fields, &fields); err != nil {
+		t.Fatalf("object fields are not a JSON object: %v", err)
+	}
+	if tags, ok := fields["tags"].([]interface{}); !ok || len(tags) != 2 {
+		t.Fatalf("expected decoded tags field, got %#v", fields)
+	}
+	if alias := export.Objects[1].Alias; alias == nil || *alias != "Raven" {
+		t.Fatalf("expected project alias to round-trip, got %v", alias)
+	}
+	if export.Traits[1].Value != nil {
+		t.Fatalf("expected NULL trait value to stay nil, got %q", *export.Traits[1].Value)
+	}
+	if export.Refs[1].TargetID != nil {
+		t.Fatalf("expected unresolved ref to have no target_id, got %q", *export.Refs[1].TargetID)
+	}
+
+	inline, err := Export(ExportRequest{VaultPath: vaultPath})
+	if err != nil {
+		t.Fatalf("inline Export returned error: %v", err)
+	}
+	if inline.Export == nil || len(inline.Export.Objects) != 2 || inline.OutPath != "" {
+		t.Fatalf("expected inline export, got %#v", inline)
+	}
+}
+
+func TestRestore_ExportRoundTripPreservesStats(t *testing.T) {
+	t.Parallel()
+	sourceVault := t.TempDir()
+	db, err := index.Open(sourceVault)
+	if err != nil {
+		t.Fatalf("failed to open index db: %v", err)
+	}
+	for _, stmt := range []string{
+		`INSERT INTO objects (id, file_path, type, line_start, fields) VALUES
+			('page/one', 'pages/one.md', 'page', 1, '{"tags":["a"]}'),
+			('person/freya', 'people/freya.md', 'person', 1, '{"name":"Freya"}')`,
+		`INSERT INTO object_aliases (object_id, alias, file_path) VALUES
+			('person/freya', 'The Queen', 'people/freya.md')`,
+		`INSERT INTO traits (id, trait_type, value, content, file_path, line_number, parent_object_id) VALUES
+			('pages/one.md:trait:0', 'todo', 'open', 'Task', 'pages/one.md', 3, 'page/one')`,
+		`INSERT INTO refs (source_id, target_id, target_raw, file_path, line_number) VALUES
+			('page/one', 'person/freya', 'The Queen', 'pages/one.md', 4)`,
+		`INSERT INTO assets (id, file_path, filename, size_bytes) VALUES
+			('assets/diagram.png', 'assets/diagram.png', 'diagram.png', 42)`,
+	} {
+		if _, err := db.DB().Exec(stmt); err != nil {
+			t.Fatalf("failed to seed index: %v", err)
+		}
+	}
+	if err := db.Close(); err != nil {
+		t.Fatalf("failed to close db: %v", err)
+	}
+
+	exportPath := filepath.Join(t.TempDir(), "vault.json")
+	if _, err := Export(ExportRequest{VaultPath: sourceVault, OutPath: exportPath}); err != nil {
+		t.Fatalf("Export returned error: %v", err)
+	}
+
+	want, err := Stats(sourceVault)
+	if err != nil {
+		t.Fatalf("Stats returned error: %v", err)
+	}
+
+	targetVault := t.TempDir()
+	got, err := Restore(RestoreRequest{VaultPath: targetVault, InPath: exportPath})
+	if err != nil {
+		t.Fatalf("Restore returned error: %v", err)
+	}
+	if *got != *want {
+		t.Fatalf("restored stats = %#v, want %#v", got, want)
+	}
+
+	restored, err := index.Open(targetVault)
+	if err != nil {
+		t.Fatalf("failed to open restored index: %v", err)
+	}
+	defer restored.Close()
+	aliases, err := restored.ObjectAliases("person/freya")
+	if err != nil {
+		t.Fatalf("ObjectAliases returned error: %v", err)
+	}
+	if len(aliases) != 1 || aliases[0] != "The Queen" {
+		t.Fatalf("expected alias to be restored, got %v", aliases)
+	}
+}
+
+func TestRestore_RejectsInvalidExport(t *testing.T) {
+	t.Parallel()
+	dir := t.TempDir()
+	tests := []struct {
+		name    string
+		content string
+	}{
+		{name: "malformed", content: `{"version":`},
+		{name: "version mismatch", content: `{"version": 1, "objects": []}`},
+		{name: "duplicate object ids", content: fmt.Sprintf(`{"version": %d, "objects": [
+			{"id": "page/one", "file_path": "a.md", "type": "page", "fields": {}, "line_start": 1},
+			{"id": "page/one", "file_path": "b.md", "type": "page", "fields": {}, "line_start": 1}
+		]}`, index.CurrentDBVersion)},
+		{name: "non-object fields", content: fmt.Sprintf(`{"version": %d, "objects": [
+			{"id": "page/one", "file_path": "a.md", "type": "page", "fields": [1, 2], "line_start": 1}
+		]}`, index.CurrentDBVersion)},
+	}
+	for i, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			path := filepath.Join(dir, fmt.Sprintf("export-%d.json", i))
+			if err := os.WriteFile(path, []byte(tt.content), 0o644); err != nil {
+				t.Fatalf("failed to write export: %v", err)
+			}
+			_, err := Restore(RestoreRequest{VaultPath: t.TempDir(), InPath: path})
+			assertCode(t, err, CodeInvalidInput)
+		})
+	}
 }
 
 func TestCurrentVersionInfoWithReader(t *testing.T) {
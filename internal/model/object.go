@@ -13,6 +13,9 @@ type Object struct {
 	// Fields contains the frontmatter field values, parsed from YAML.
 	Fields map[string]interface{} `json:"fields,omitempty"`
 
+	// FieldOrder lists the keys of Fields in frontmatter order, when known.
+	FieldOrder []string `json:"field_order,omitempty"`
+
 	// FilePath is the path to the file containing this object,
 	// relative to the vault root.
 	FilePath string `json:"file_path"`
@@ -12,6 +12,10 @@ type Reference struct {
 	// TargetRaw is the raw target as written in the wikilink.
 	TargetRaw string `json:"target_raw"`
 
+	// TargetID is the object ID the reference resolved to at index time.
+	// Only populated by outlinks; nil when the target did not resolve.
+	TargetID *string `json:"target_id,omitempty"`
+
 	// FilePath is the path to the file containing this reference.
 	FilePath string `json:"file_path"`
 
@@ -21,6 +25,14 @@ type Reference struct {
 
 	// DisplayText is the display text of the wikilink, if different from target.
 	DisplayText *string `json:"display_text,omitempty"`
+
+	// Context is the trimmed source line containing the reference.
+	// Only populated by commands that read it back from the source file.
+	Context string `json:"context,omitempty"`
+
+	// Depth is the hop distance from the target for transitive backlinks
+	// (1 = links directly). Zero when traversal depth was not requested.
+	Depth int `json:"depth,omitempty"`
 }
 
 // ReferenceInputError describes a non-fatal error for one input in a bulk
@@ -21,6 +21,9 @@ type CreateRequest struct {
 	PagesRoot   string
 	TemplateDir string
 	TemplateID  string
+	// Unique appends -2, -3, ... to the target path instead of failing when
+	// the planned file already exists.
+	Unique bool
 }
 
 type CreateResult struct {
@@ -80,6 +83,10 @@ func Create(req CreateRequest) (*CreateResult, error) {
 		)
 	}
 
+	if req.Unique {
+		targetPath = uniqueTargetPath(req.VaultPath, targetPath, req.TypeName, req.Schema, req.ObjectsRoot, req.PagesRoot)
+	}
+
 	resolvedTargetPath := pages.ResolveTargetPathWithRoots(targetPath, req.TypeName, req.Schema, req.ObjectsRoot, req.PagesRoot)
 	resolvedSlugPath := pages.SlugifyPath(resolvedTargetPath)
 	plannedRelPath := resolvedSlugPath
@@ -93,7 +100,7 @@ func Create(req CreateRequest) (*CreateResult, error) {
 		return nil, newError(
 			ErrorFileExists,
 			fmt.Sprintf("file already exists: %s.md", resolvedSlugPath),
-			"Choose a different title, or use `rvn open <reference>` to open the existing object",
+			"Choose a different title, pass --unique to create a suffixed copy, or use `rvn open <reference>` to open the existing object",
 			nil,
 			nil,
 		)
@@ -132,6 +139,17 @@ func Create(req CreateRequest) (*CreateResult, error) {
 	}, nil
 }
 
+// uniqueTargetPath returns targetPath, or the first targetPath-N (N >= 2)
+// whose resolved file does not exist yet.
+func uniqueTargetPath(vaultPath, targetPath, typeName string, sch *schema.Schema, objectsRoot, pagesRoot string) string {
+	base := strings.TrimSuffix(targetPath, ".md")
+	candidate := targetPath
+	for n := 2; pages.Exists(vaultPath, pages.ResolveTargetPathWithRoots(candidate, typeName, sch, objectsRoot, pagesRoot)); n++ {
+		candidate = fmt.Sprintf("%s-%d", base, n)
+	}
+	return candidate
+}
+
 func buildFieldTemplateExample(missingFields []string) string {
 	parts := make([]string, 0, len(missingFields))
 	for _, f := range missingFields {
@@ -170,6 +170,55 @@ traits: {}
 	}
 }
 
+func TestCreateUniqueSuffixesExistingFile(t *testing.T) {
+	t.Parallel()
+	vaultPath := t.TempDir()
+	writeTestSchema(t, vaultPath, `
+types:
+  person:
+    default_path: people/
+    name_field: name
+    fields:
+      name:
+        type: string
+        required: true
+traits: {}
+`)
+	sch := loadTestSchema(t, vaultPath)
+
+	if err := os.MkdirAll(filepath.Join(vaultPath, "people"), 0o755); err != nil {
+		t.Fatalf("mkdir people: %v", err)
+	}
+	for _, name := range []string{"alice-chen.md", "alice-chen-2.md"} {
+		if err := os.WriteFile(filepath.Join(vaultPath, "people", name), []byte("---\ntype: person\nname: Alice Chen\n---\n"), 0o644); err != nil {
+			t.Fatalf("seed %s: %v", name, err)
+		}
+	}
+
+	result, err := Create(CreateRequest{
+		VaultPath:  vaultPath,
+		TypeName:   "person",
+		Title:      "Alice Chen",
+		TargetPath: "Alice Chen",
+		Schema:     sch,
+		Unique:     true,
+	})
+	if err != nil {
+		t.Fatalf("Create: %v", err)
+	}
+	if result.RelativePath != "people/alice-chen-3.md" {
+		t.Fatalf("RelativePath = %q, want %q", result.RelativePath, "people/alice-chen-3.md")
+	}
+
+	content, err := os.ReadFile(result.FilePath)
+	if err != nil {
+		t.Fatalf("read created file: %v", err)
+	}
+	if !strings.Contains(string(content), "name: Alice Chen") {
+		t.Fatalf("expected title to stay unsuffixed in name field, got:\n%s", string(content))
+	}
+}
+
 func TestCreateRejectsWrongRefTargetType(t *testing.T) {
 	t.Parallel()
 	vaultPath := t.TempDir()
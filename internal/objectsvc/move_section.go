@@ -0,0 +1,340 @@
+package objectsvc
+
+import (
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/parser"
+	"github.com/aidanlsb/raven/internal/paths"
+	"github.com/aidanlsb/raven/internal/readsvc"
+	"github.com/aidanlsb/raven/internal/schema"
+)
+
+// sectionRewrite holds the working copy of one file touched by a section move.
+type sectionRewrite struct {
+	snapshot *fileSnapshot
+	relPath  string
+	content  string
+	created  bool
+}
+
+// moveSection extracts a section, including its subsections, into another
+// file. A missing destination is created from the section's lines; an
+// existing one gets them appended. References to the moved sections are
+// rewritten to their new IDs.
+func moveSection(req MoveByReferenceRequest, resolved *readsvc.ResolveResult) (*MoveByReferenceResult, error) {
+	sourceFile := resolved.FilePath
+	if err := paths.ValidateNotePath(req.VaultPath, sourceFile, req.VaultConfig.FollowSymlinks); err != nil {
+		return nil, newError(ErrorValidationFailed, "source path is outside vault", "Files can only be moved within the vault", nil, err)
+	}
+	sourceRel, err := filepath.Rel(req.VaultPath, sourceFile)
+	if err != nil {
+		return nil, newError(ErrorUnexpected, "failed to resolve source path", "", nil, err)
+	}
+	sourceRel = paths.NormalizeVaultRelPath(sourceRel)
+
+	destRel, destFile, err := sectionMoveDestination(req, resolved.ObjectID)
+	if err != nil {
+		return nil, err
+	}
+	if destRel == sourceRel {
+		return nil, newError(ErrorInvalidInput, "cannot move a section into the file that contains it", "Choose a different destination file", nil, nil)
+	}
+
+	sourceSnapshot, err := readFileSnapshot(sourceFile)
+	if err != nil {
+		return nil, newError(ErrorFileRead, "failed to read source file", "", nil, err)
+	}
+	sourceDoc, err := parser.ParseDocumentWithOptions(string(sourceSnapshot.content), sourceFile, req.VaultPath, req.ParseOptions)
+	if err != nil {
+		return nil, newError(ErrorValidationFailed, "failed to parse source file", "Failed to parse source file", nil, err)
+	}
+	var section *parser.ParsedSection
+	for _, candidate := range sourceDoc.Sections {
+		if candidate.ID == resolved.ObjectID {
+			section = candidate
+			break
+		}
+	}
+	if section == nil {
+		return nil, newError(ErrorRefNotFound, fmt.Sprintf("section '%s' not found in %s", resolved.ObjectID, sourceRel), "Run 'rvn reindex' and try again", nil, nil)
+	}
+
+	sourceLines := strings.Split(string(sourceSnapshot.content), "\n")
+	start := section.LineStart
+	end := len(sourceLines)
+	if section.SubtreeLineEnd != nil && *section.SubtreeLineEnd < end {
+		end = *section.SubtreeLineEnd
+	}
+
+	files := map[string]*sectionRewrite{
+		sourceRel: {snapshot: sourceSnapshot, relPath: sourceRel, content: string(sourceSnapshot.content)},
+	}
+	dest := &sectionRewrite{relPath: destRel, created: true}
+	if info, statErr := os.Stat(destFile); statErr == nil {
+		if info.IsDir() {
+			return nil, newError(ErrorValidationFailed, fmt.Sprintf("Destination '%s' is a directory", destRel), "Use a directory ending with / or a file path", nil, nil)
+		}
+		snapshot, err := readFileSnapshot(destFile)
+		if err != nil {
+			return nil, newError(ErrorFileRead, "failed to read destination file", "", nil, err)
+		}
+		dest = &sectionRewrite{snapshot: snapshot, relPath: destRel, content: string(snapshot.content)}
+	}
+	files[destRel] = dest
+
+	// Map each moved section to its ID in the destination, using the
+	// heading's line offset within the moved block.
+	destObjectID := req.VaultConfig.FilePathToObjectID(destRel)
+	destContent, blockStart := appendSectionBlock(dest.content, sectionBlock(sourceLines, start, end))
+	destDoc, err := parser.ParseDocumentWithOptions(destContent, destFile, req.VaultPath, req.ParseOptions)
+	if err != nil {
+		return nil, newError(ErrorValidationFailed, "failed to parse destination file", "", nil, err)
+	}
+	destSectionsByLine := make(map[int]string, len(destDoc.Sections))
+	for _, s := range destDoc.Sections {
+		destSectionsByLine[s.LineStart] = s.ID
+	}
+	newIDs := make(map[string]string)
+	var movedIDs []string
+	for _, s := range sourceDoc.Sections {
+		if s.LineStart < start || s.LineStart > end {
+			continue
+		}
+		newID := destSectionsByLine[s.LineStart-start+blockStart]
+		if s.ID == section.ID && dest.created {
+			newID = destObjectID
+		}
+		if newID == "" {
+			continue
+		}
+		newIDs[s.ID] = newID
+		movedIDs = append(movedIDs, s.ID)
+	}
+
+	result := &MoveByReferenceResult{
+		SourceID:       section.ID,
+		SourceRelative: sourceRel,
+		DestinationID:  newIDs[section.ID],
+		DestinationRel: destRel,
+	}
+	if result.DestinationID == "" {
+		result.DestinationID = destObjectID
+	}
+
+	var db *index.Database
+	db, err = index.Open(req.VaultPath)
+	if err != nil {
+		if req.FailOnIndexErr {
+			return nil, newError(ErrorValidationFailed, "failed to open index database for move", "Run 'rvn reindex' to rebuild the database", nil, err)
+		}
+		result.WarningMessages = append(result.WarningMessages, fmt.Sprintf("Failed to open index database for move update: %v", err))
+	} else {
+		defer db.Close()
+		db.SetDailyDirectory(req.VaultConfig.GetDailyDirectory())
+	}
+
+	if req.UpdateRefs && db != nil {
+		seen := make(map[string]bool)
+		for _, oldID := range movedIDs {
+			backlinks, err := db.BacklinksWithRoots(oldID, req.VaultConfig.GetObjectsRoot(), req.VaultConfig.GetPagesRoot())
+			if err != nil {
+				result.WarningMessages = append(result.WarningMessages, fmt.Sprintf("Failed to read backlinks for move update: %v", err))
+				continue
+			}
+			for _, bl := range backlinks {
+				oldRaw := strings.TrimPrefix(strings.TrimSuffix(strings.TrimSpace(bl.TargetRaw), "]]"), "[[")
+				relPath := paths.NormalizeVaultRelPath(bl.FilePath)
+				file := files[relPath]
+				if file == nil {
+					filePath := filepath.Join(req.VaultPath, relPath)
+					if err := ValidateContentMutationFilePath(req.VaultPath, req.VaultConfig, filePath); err != nil {
+						return nil, err
+					}
+					snapshot, err := readFileSnapshot(filePath)
+					if err != nil {
+						result.WarningMessages = append(result.WarningMessages, fmt.Sprintf("Failed to update refs in %s: %v", bl.SourceID, err))
+						continue
+					}
+					file = &sectionRewrite{snapshot: snapshot, relPath: relPath, content: string(snapshot.content)}
+					files[relPath] = file
+				}
+
+				line := 0
+				if bl.Line != nil {
+					line = *bl.Line
+				}
+				updated := replaceSectionRefAtLine(file.content, line, oldRaw, newIDs[oldID])
+				if updated == file.content {
+					continue
+				}
+				file.content = updated
+				if !seen[bl.SourceID] {
+					seen[bl.SourceID] = true
+					result.UpdatedRefs = append(result.UpdatedRefs, bl.SourceID)
+				}
+			}
+		}
+	}
+
+	// Ref rewrites never add or remove lines, so the section's range still
+	// holds in the rewritten source.
+	source := files[sourceRel]
+	rewrittenLines := strings.Split(source.content, "\n")
+	dest.content, _ = appendSectionBlock(dest.content, sectionBlock(rewrittenLines, start, end))
+	source.content = cutSectionBlock(rewrittenLines, start, end)
+
+	if req.Preview {
+		return result, nil
+	}
+
+	var written []*sectionRewrite
+	rollback := func(cause error, message string) error {
+		var rollbackErr error
+		for i := len(written) - 1; i >= 0; i-- {
+			file := written[i]
+			path := filepath.Join(req.VaultPath, file.relPath)
+			if file.created {
+				if err := os.Remove(path); err != nil && !errors.Is(err, os.ErrNotExist) {
+					rollbackErr = errors.Join(rollbackErr, fmt.Errorf("remove %s: %w", file.relPath, err))
+				}
+				continue
+			}
+			if err := writeMoveFile(path, file.snapshot.content, file.snapshot.perm); err != nil {
+				rollbackErr = errors.Join(rollbackErr, fmt.Errorf("restore %s: %w", file.relPath, err))
+			}
+		}
+		return moveRollbackError(message, cause, rollbackErr)
+	}
+
+	if err := os.MkdirAll(filepath.Dir(destFile), 0o755); err != nil {
+		return nil, newError(ErrorFileWrite, "failed to create destination directory", "", nil, err)
+	}
+	order := []*sectionRewrite{dest, source}
+	for relPath, file := range files {
+		if relPath != sourceRel && relPath != destRel {
+			order = append(order, file)
+		}
+	}
+	for _, file := range order {
+		if !file.created && file.content == string(file.snapshot.content) {
+			continue
+		}
+		perm := sourceSnapshot.perm
+		if file.snapshot != nil {
+			perm = file.snapshot.perm
+		}
+		if err := writeMoveFile(filepath.Join(req.VaultPath, file.relPath), []byte(file.content), perm); err != nil {
+			return nil, rollback(err, fmt.Sprintf("failed to write %s", file.relPath))
+		}
+		written = append(written, file)
+	}
+
+	if db == nil {
+		return result, nil
+	}
+	sch := req.Schema
+	if sch == nil {
+		sch = schema.New()
+	}
+	for _, file := range written {
+		path := filepath.Join(req.VaultPath, file.relPath)
+		doc, err := parser.ParseDocumentWithOptions(file.content, path, req.VaultPath, req.ParseOptions)
+		if err == nil {
+			err = db.IndexDocument(doc, sch)
+		}
+		if err != nil {
+			if req.FailOnIndexErr {
+				return nil, rollback(err, fmt.Sprintf("failed to index %s", file.relPath))
+			}
+			result.WarningMessages = append(result.WarningMessages, fmt.Sprintf("Failed to index %s: %v", file.relPath, err))
+		}
+	}
+
+	return result, nil
+}
+
+// sectionMoveDestination resolves the destination file for a section move. A
+// destination ending in / takes the section's slug as the filename.
+func sectionMoveDestination(req MoveByReferenceRequest, sectionID string) (string, string, error) {
+	destination := req.Destination
+	if strings.HasSuffix(destination, "/") || strings.HasSuffix(destination, "\\") {
+		slug := sectionID[strings.LastIndex(sectionID, "#")+1:]
+		destination = filepath.ToSlash(filepath.Join(destination, slug))
+	}
+	destination = paths.EnsureMDExtension(destination)
+	if strings.TrimSpace(strings.TrimSuffix(filepath.Base(destination), ".md")) == "" {
+		return "", "", newError(ErrorInvalidInput, "destination has an empty filename", "Use a non-empty destination filename or a directory ending with /", nil, nil)
+	}
+
+	destPath := destination
+	if req.VaultConfig.HasDirectoriesConfig() {
+		destPath = req.VaultConfig.ResolveReferenceToFilePath(strings.TrimSuffix(destination, ".md"))
+	}
+	destPath = paths.NormalizeVaultRelPath(destPath)
+	destFile := filepath.Join(req.VaultPath, destPath)
+	if err := paths.ValidateWithinVault(req.VaultPath, destFile); err != nil {
+		return "", "", newError(ErrorValidationFailed, "destination path is outside vault", "Files can only be moved within the vault", nil, err)
+	}
+	if err := ValidateContentMutationRelPath(req.VaultConfig, destPath); err != nil {
+		return "", "", err
+	}
+	return destPath, destFile, nil
+}
+
+// sectionBlock returns lines start..end (1-indexed, inclusive) without
+// trailing blank lines.
+func sectionBlock(lines []string, start, end int) []string {
+	block := append([]string(nil), lines[start-1:end]...)
+	for len(block) > 1 && strings.TrimSpace(block[len(block)-1]) == "" {
+		block = block[:len(block)-1]
+	}
+	return block
+}
+
+// appendSectionBlock appends block to content, separated by a blank line, and
+// returns the new content with the block's first line number.
+func appendSectionBlock(content string, block []string) (string, int) {
+	prefix := strings.TrimRight(content, "\n")
+	if prefix != "" {
+		prefix += "\n\n"
+	}
+	return prefix + strings.Join(block, "\n") + "\n", strings.Count(prefix, "\n") + 1
+}
+
+// cutSectionBlock removes lines start..end (1-indexed, inclusive).
+func cutSectionBlock(lines []string, start, end int) string {
+	kept := append(append([]string(nil), lines[:start-1]...), lines[end:]...)
+	content := strings.Join(kept, "\n")
+	if !strings.HasSuffix(content, "\n") {
+		content += "\n"
+	}
+	return content
+}
+
+// replaceSectionRefAtLine points wikilinks and markdown links to oldRaw at
+// newRef, preferring the indexed line and falling back to the whole file.
+func replaceSectionRefAtLine(content string, line int, oldRaw, newRef string) string {
+	if oldRaw == "" || newRef == "" {
+		return content
+	}
+	replacer := strings.NewReplacer(
+		"[["+oldRaw+"]]", "[["+newRef+"]]",
+		"[["+oldRaw+"|", "[["+newRef+"|",
+		"]("+oldRaw+")", "]("+newRef+")",
+		"](<"+oldRaw+">)", "](<"+newRef+">)",
+	)
+
+	lines := strings.Split(content, "\n")
+	if idx := line - 1; idx >= 0 && idx < len(lines) {
+		if updated := replacer.Replace(lines[idx]); updated != lines[idx] {
+			lines[idx] = updated
+			return strings.Join(lines, "\n")
+		}
+	}
+	return replacer.Replace(content)
+}
@@ -60,11 +60,12 @@ func MoveByReference(req MoveByReferenceRequest) (*MoveByReferenceResult, error)
 		return nil, err
 	}
 	if resolved.IsSection {
-		return nil, newError(ErrorInvalidInput, "move only supports file-level objects", "Use a file-level object ID without a section fragment", nil, nil)
+		return moveSection(req, resolved)
 	}
 	sourceFile := resolved.FilePath
 
-	if err := paths.ValidateWithinVault(req.VaultPath, sourceFile); err != nil {
+	followSymlinks := req.VaultConfig.FollowSymlinks && paths.HasMDExtension(sourceFile)
+	if err := paths.ValidateNotePath(req.VaultPath, sourceFile, followSymlinks); err != nil {
 		return nil, newError(ErrorValidationFailed, "source path is outside vault", "Files can only be moved within the vault", nil, err)
 	}
 
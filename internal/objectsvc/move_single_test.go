@@ -6,6 +6,7 @@ import (
 	"testing"
 
 	"github.com/aidanlsb/raven/internal/config"
+	"github.com/aidanlsb/raven/internal/testutil"
 )
 
 func TestMoveByReferenceSuccess(t *testing.T) {
@@ -168,3 +169,66 @@ traits: {}
 		t.Fatalf("expected actual type person, got %q", result.TypeMismatch.ActualType)
 	}
 }
+
+func TestMoveByReferenceExtractsSectionAndUpdatesRefs(t *testing.T) {
+	t.Parallel()
+
+	v := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("notes/week.md", "# Week\n\n## Standup\nDiscussed [[notes/ideas]].\n\n### Blockers\nNone.\n\n## Planning\nNext steps.\n").
+		WithFile("notes/ideas.md", "Recap in [[notes/week#standup]].\nSee [[notes/week#blockers|blockers]] and [[notes/week#planning]].\n").
+		Build()
+	sch := loadTestSchema(t, v.Path)
+	indexVaultFiles(t, v.Path, sch, "notes/week.md", "notes/ideas.md")
+	resolveVaultRefs(t, v.Path, sch)
+
+	result, err := MoveByReference(MoveByReferenceRequest{
+		VaultPath:      v.Path,
+		VaultConfig:    &config.VaultConfig{},
+		Schema:         sch,
+		Reference:      "notes/week#standup",
+		Destination:    "meetings/standup",
+		UpdateRefs:     true,
+		FailOnIndexErr: true,
+	})
+	if err != nil {
+		t.Fatalf("MoveByReference: %v", err)
+	}
+	if result.SourceID != "notes/week#standup" || result.DestinationID != "meetings/standup" {
+		t.Fatalf("result = %+v, want notes/week#standup -> meetings/standup", result)
+	}
+	if len(result.UpdatedRefs) != 1 || result.UpdatedRefs[0] != "notes/ideas" {
+		t.Fatalf("updated refs = %v, want [notes/ideas]", result.UpdatedRefs)
+	}
+
+	assertVaultFileContent(t, v, "meetings/standup.md", "## Standup\nDiscussed [[notes/ideas]].\n\n### Blockers\nNone.\n")
+	assertVaultFileContent(t, v, "notes/week.md", "# Week\n\n## Planning\nNext steps.\n")
+	assertVaultFileContent(t, v, "notes/ideas.md", "Recap in [[meetings/standup]].\nSee [[meetings/standup#blockers|blockers]] and [[notes/week#planning]].\n")
+
+	// Moving into an existing file appends the section and links to its new fragment.
+	result, err = MoveByReference(MoveByReferenceRequest{
+		VaultPath:      v.Path,
+		VaultConfig:    &config.VaultConfig{},
+		Schema:         sch,
+		Reference:      "notes/week#planning",
+		Destination:    "meetings/standup",
+		UpdateRefs:     true,
+		FailOnIndexErr: true,
+	})
+	if err != nil {
+		t.Fatalf("MoveByReference into existing file: %v", err)
+	}
+	if result.DestinationID != "meetings/standup#planning" {
+		t.Fatalf("destination = %q, want meetings/standup#planning", result.DestinationID)
+	}
+	assertVaultFileContent(t, v, "meetings/standup.md", "## Standup\nDiscussed [[notes/ideas]].\n\n### Blockers\nNone.\n\n## Planning\nNext steps.\n")
+	assertVaultFileContent(t, v, "notes/week.md", "# Week\n")
+	assertVaultFileContent(t, v, "notes/ideas.md", "Recap in [[meetings/standup]].\nSee [[meetings/standup#blockers|blockers]] and [[meetings/standup#planning]].\n")
+}
+
+func assertVaultFileContent(t *testing.T, v *testutil.TestVault, relPath, want string) {
+	t.Helper()
+	if got := v.ReadFile(relPath); got != want {
+		t.Fatalf("%s content = %q, want %q", relPath, got, want)
+	}
+}
@@ -203,7 +203,7 @@ func Reclassify(req ReclassifyRequest) (*ReclassifyResult, error) {
 		nil,
 		nextFieldValues,
 		req.Schema,
-		map[string]bool{"type": true, "alias": true},
+		map[string]bool{"type": true, "alias": true, "aliases": true},
 		&fieldmutation.RefValidationContext{
 			VaultPath:   req.VaultPath,
 			VaultConfig: req.VaultConfig,
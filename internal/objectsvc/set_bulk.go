@@ -98,7 +98,7 @@ func PreviewSetBulk(req SetBulkRequest) (*SetBulkPreview, error) {
 			fm.Fields,
 			req.TypedUpdates,
 			req.Schema,
-			map[string]bool{"alias": true},
+			map[string]bool{"alias": true, "aliases": true},
 			&fieldmutation.RefValidationContext{
 				VaultPath:    req.VaultPath,
 				VaultConfig:  req.VaultConfig,
@@ -170,7 +170,7 @@ func ApplySetBulk(req SetBulkRequest, onModified func(filePath string)) (*SetBul
 			ObjectID:      id,
 			TypedUpdates:  req.TypedUpdates,
 			Schema:        req.Schema,
-			AllowedFields: map[string]bool{"alias": true},
+			AllowedFields: map[string]bool{"alias": true, "aliases": true},
 			ParseOptions:  req.ParseOptions,
 		})
 		if err != nil {
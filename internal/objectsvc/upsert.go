@@ -233,7 +233,7 @@ func Upsert(req UpsertRequest) (*UpsertResult, error) {
 				req.TypeName,
 				updates,
 				req.Schema,
-				map[string]bool{"type": true, "alias": true},
+				map[string]bool{"type": true, "alias": true, "aliases": true},
 				&fieldmutation.RefValidationContext{
 					VaultPath:   req.VaultPath,
 					VaultConfig: req.VaultConfig,
@@ -241,7 +241,7 @@ traits: {}
 	if err := os.MkdirAll(filepath.Join(vaultPath, "templates", "interview"), 0o755); err != nil {
 		t.Fatalf("mkdir templates/interview: %v", err)
 	}
-	if err := os.WriteFile(filepath.Join(vaultPath, "templates", "interview", "default.md"), []byte("## Interview Template\n"), 0o644); err != nil {
+	if err := os.WriteFile(filepath.Join(vaultPath, "templates", "interview", "default.md"), []byte("## Interview Template\n\nCandidate: {{title}} ({{type}})\n"), 0o644); err != nil {
 		t.Fatalf("write template: %v", err)
 	}
 	sch := loadTestSchema(t, vaultPath)
@@ -267,6 +267,9 @@ traits: {}
 	if !strings.Contains(string(created), "## Interview Template") {
 		t.Fatalf("expected default template content, got:\n%s", string(created))
 	}
+	if !strings.Contains(string(created), "Candidate: Jane Doe (interview)") {
+		t.Fatalf("expected title/type placeholders to be substituted, got:\n%s", string(created))
+	}
 }
 
 func writeTestSchema(t *testing.T, vaultPath, content string) {
@@ -114,6 +114,12 @@ func TestResolveDefaultPathWithRoots(t *testing.T) {
 			pages:      "pages/",
 			want:       "objects/Freya",
 		},
+		{
+			name:       "falls back to vault root for type without default path",
+			targetPath: "Alice Chen",
+			typeName:   "person",
+			want:       "Alice Chen",
+		},
 	}
 
 	for _, tt := range tests {
@@ -17,13 +17,15 @@ import (
 
 // ASTContent holds all Raven syntax extracted from a markdown AST.
 type ASTContent struct {
-	Headings []Heading
-	Traits   []TraitAnnotation
-	Refs     []Reference
+	Headings   []Heading
+	Traits     []TraitAnnotation
+	Refs       []Reference
+	Checkboxes []Checkbox
+	Blocks     []BlockAnchor
 }
 
 // ExtractFromAST parses markdown content with goldmark and extracts all
-// Raven-specific syntax (headings, traits, references).
+// Raven-specific syntax (headings, traits, references, block IDs).
 //
 // Code blocks (fenced, indented, inline) are automatically skipped - any
 // @traits or [[references]] inside code will not be extracted.
@@ -36,29 +38,17 @@ func ExtractFromAST(content []byte, startLine int) (*ASTContent, error) {
 
 	result := &ASTContent{}
 
-	// First pass: extract headings.
+	// Single walk: collect headings, then traits and refs from non-code,
+	// non-consumed content. We process at the paragraph/list-item level because
+	// goldmark splits wikilinks like [[target]] across multiple Text nodes (due
+	// to [ being link syntax).
 	if err := ast.Walk(doc, func(n ast.Node, entering bool) (ast.WalkStatus, error) {
 		if !entering {
 			return ast.WalkContinue, nil
 		}
 
 		if heading, ok := n.(*ast.Heading); ok {
-			headingInfo := extractHeadingFromNode(heading, content, lineStarts, startLine)
-			if headingInfo != nil {
-				result.Headings = append(result.Headings, *headingInfo)
-			}
-		}
-
-		return ast.WalkContinue, nil
-	}); err != nil {
-		return nil, err
-	}
-
-	// Second pass: extract traits and refs from non-code, non-consumed content.
-	// We process at the paragraph/list-item level because goldmark splits wikilinks
-	// like [[target]] across multiple Text nodes (due to [ being link syntax).
-	if err := ast.Walk(doc, func(n ast.Node, entering bool) (ast.WalkStatus, error) {
-		if !entering {
+			result.appendHeading(heading, content, lineStarts, startLine)
 			return ast.WalkContinue, nil
 		}
 
@@ -73,28 +63,62 @@ func ExtractFromAST(content []byte, startLine int) (*ASTContent, error) {
 		// Goldmark splits wikilinks like [[target]] across multiple Text nodes,
 		// so we need to collect text at the block level.
 		var processNode ast.Node
+		isListItem := false
 		switch node := n.(type) {
 		case *ast.Paragraph:
 			processNode = node
 		case *ast.ListItem:
 			processNode = node
+			isListItem = true
 		}
 
 		if processNode != nil {
 			// Collect all text from this node, skipping inline code
 			segments := collectTextSegments(processNode, content, lineStarts)
+			firstTrait := len(result.Traits)
 			for _, seg := range segments {
 				line := startLine + offsetToLine(lineStarts, seg.start)
 
-				// Parse traits
-				traits := ParseTraitAnnotations(seg.text, line)
+				// Parse traits, leaving a trailing ^block-id out of their content
+				traitText := seg.text
+				if _, rest, ok := parseBlockID(traitText); ok {
+					traitText = rest
+				}
+				traits := ParseTraitAnnotations(traitText, line)
 				result.Traits = append(result.Traits, traits...)
 
 				// Parse refs
 				refs := extractRefsFromText(seg.text, line)
 				result.Refs = append(result.Refs, refs...)
+
+				// Nested list items are collected with their parent, so every
+				// line of a list item is a candidate checkbox.
+				if isListItem {
+					if checkbox, ok := parseCheckbox(seg.text, line); ok {
+						result.Checkboxes = append(result.Checkboxes, checkbox)
+					}
+				}
 			}
 			result.Refs = append(result.Refs, extractMarkdownAssetRefs(processNode, content, lineStarts, startLine)...)
+			result.appendBlockAnchors(segments, isListItem, lineStarts, startLine)
+
+			if quote, ok := processNode.NextSibling().(*ast.Blockquote); ok && !isListItem && len(segments) > 0 {
+				lastLine := startLine + offsetToLine(lineStarts, segments[len(segments)-1].start)
+				attachBlockquoteContent(result.Traits[firstTrait:], lastLine, quote, content, lineStarts)
+			}
+
+			// The item's children are skipped below, but a list item can
+			// still contain headings ("- # Title"), so pick those up here.
+			if isListItem {
+				if err := ast.Walk(processNode, func(child ast.Node, entering bool) (ast.WalkStatus, error) {
+					if heading, ok := child.(*ast.Heading); ok && entering {
+						result.appendHeading(heading, content, lineStarts, startLine)
+					}
+					return ast.WalkContinue, nil
+				}); err != nil {
+					return ast.WalkStop, err
+				}
+			}
 
 			return ast.WalkSkipChildren, nil
 		}
@@ -107,6 +131,37 @@ func ExtractFromAST(content []byte, startLine int) (*ASTContent, error) {
 	return result, nil
 }
 
+// quoteTraitName is the only trait that captures a following blockquote.
+const quoteTraitName = "quote"
+
+// attachBlockquoteContent gives a quote trait that stands alone on the last
+// line of a paragraph the text of the blockquote right after it, so "@quote"
+// on its own line captures the quoted passage below.
+func attachBlockquoteContent(traits []TraitAnnotation, line int, quote *ast.Blockquote, content []byte, lineStarts []int) {
+	quoteText := ""
+	for i := range traits {
+		if traits[i].TraitName != quoteTraitName || traits[i].Line != line || traits[i].Content != "" {
+			continue
+		}
+		if quoteText == "" {
+			var parts []string
+			for _, seg := range collectTextSegments(quote, content, lineStarts) {
+				if text := StripTraitAnnotations(seg.text); text != "" {
+					parts = append(parts, text)
+				}
+			}
+			quoteText = strings.Join(parts, " ")
+		}
+		traits[i].Content = quoteText
+	}
+}
+
+func (c *ASTContent) appendHeading(heading *ast.Heading, content []byte, lineStarts []int, startLine int) {
+	if headingInfo := extractHeadingFromNode(heading, content, lineStarts, startLine); headingInfo != nil {
+		c.Headings = append(c.Headings, *headingInfo)
+	}
+}
+
 // extractHeadingFromNode extracts heading information from a goldmark Heading node.
 func extractHeadingFromNode(heading *ast.Heading, content []byte, lineStarts []int, startLine int) *Heading {
 	// Get heading text by concatenating all text children
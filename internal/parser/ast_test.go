@@ -61,6 +61,45 @@ More content.
 		}
 	})
 
+	t.Run("trait alone above a blockquote captures the quote", func(t *testing.T) {
+		content := "# Reading\n\n@quote\n> We suffer more often\n> in imagination than in reality.\n>\n> Seneca\n\nNot a quote @highlight\n> Plain quote\n"
+
+		result, err := ExtractFromAST([]byte(content), 1)
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+
+		if len(result.Traits) != 2 {
+			t.Fatalf("got %d traits, want 2: %+v", len(result.Traits), result.Traits)
+		}
+		quote := result.Traits[0]
+		if quote.TraitName != "quote" || quote.Line != 3 {
+			t.Fatalf("first trait = %s on line %d, want quote on line 3", quote.TraitName, quote.Line)
+		}
+		if want := "We suffer more often in imagination than in reality. Seneca"; quote.Content != want {
+			t.Errorf("quote content = %q, want %q", quote.Content, want)
+		}
+		if highlight := result.Traits[1]; highlight.Content != "Not a quote" {
+			t.Errorf("highlight content = %q, want only its own line", highlight.Content)
+		}
+	})
+
+	t.Run("other standalone traits ignore a following blockquote", func(t *testing.T) {
+		content := "# Reading\n\n@highlight\n> Not captured\n"
+
+		result, err := ExtractFromAST([]byte(content), 1)
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+
+		if len(result.Traits) != 1 {
+			t.Fatalf("got %d traits, want 1: %+v", len(result.Traits), result.Traits)
+		}
+		if highlight := result.Traits[0]; highlight.TraitName != "highlight" || highlight.Content != "" {
+			t.Errorf("highlight = %s with content %q, want highlight with no content", highlight.TraitName, highlight.Content)
+		}
+	})
+
 	t.Run("extracts refs from wikilinks", func(t *testing.T) {
 		content := "# Notes\n\nSee [[people/freya]] and [[projects/website]] for details.\n"
 
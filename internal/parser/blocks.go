@@ -0,0 +1,64 @@
+package parser
+
+import (
+	"regexp"
+	"strings"
+)
+
+// BlockAnchor is a block ID declared with a trailing "^id" on a paragraph or
+// list item, e.g. "Ship the beta by Friday ^launch".
+type BlockAnchor struct {
+	BlockID string
+	Content string // Block text with the marker and trait annotations removed
+	Line    int    // First line of the block
+}
+
+// blockIDRegex matches a block ID at the end of a line. The caret must start
+// the line or follow whitespace so "x^2" is not read as a block ID.
+var blockIDRegex = regexp.MustCompile(`(^|\s)\^([A-Za-z0-9][A-Za-z0-9-]*)\s*$`)
+
+// parseBlockID returns the block ID that ends text and the text before it.
+func parseBlockID(text string) (id string, rest string, ok bool) {
+	m := blockIDRegex.FindStringSubmatchIndex(text)
+	if m == nil {
+		return "", "", false
+	}
+	return text[m[4]:m[5]], text[:m[0]], true
+}
+
+// appendBlockAnchors records block IDs from a paragraph or list item. A list
+// item line ending in "^id" anchors that line; a paragraph is anchored as a
+// whole when its last line ends in "^id".
+func (c *ASTContent) appendBlockAnchors(segments []textSegment, isListItem bool, lineStarts []int, startLine int) {
+	if len(segments) == 0 {
+		return
+	}
+	if isListItem {
+		for _, seg := range segments {
+			if id, rest, ok := parseBlockID(seg.text); ok {
+				c.Blocks = append(c.Blocks, BlockAnchor{
+					BlockID: id,
+					Content: StripTraitAnnotations(rest),
+					Line:    startLine + offsetToLine(lineStarts, seg.start),
+				})
+			}
+		}
+		return
+	}
+
+	last := segments[len(segments)-1]
+	id, rest, ok := parseBlockID(last.text)
+	if !ok {
+		return
+	}
+	parts := make([]string, 0, len(segments))
+	for _, seg := range segments[:len(segments)-1] {
+		parts = append(parts, StripTraitAnnotations(seg.text))
+	}
+	parts = append(parts, StripTraitAnnotations(rest))
+	c.Blocks = append(c.Blocks, BlockAnchor{
+		BlockID: id,
+		Content: strings.TrimSpace(strings.Join(parts, " ")),
+		Line:    startLine + offsetToLine(lineStarts, segments[0].start),
+	})
+}
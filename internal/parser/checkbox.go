@@ -0,0 +1,47 @@
+package parser
+
+import (
+	"regexp"
+	"strings"
+)
+
+// CheckboxTaskTrait is the trait name synthesized for markdown checkbox items
+// when checkbox tasks are enabled.
+const CheckboxTaskTrait = "task"
+
+// Checkbox task statuses used as the synthesized trait value.
+const (
+	CheckboxStatusTodo = "todo"
+	CheckboxStatusDone = "done"
+)
+
+// Checkbox is a markdown task-list item: "- [ ] text" or "- [x] text".
+type Checkbox struct {
+	Checked bool
+	Content string // Item text with trait annotations removed
+	Line    int
+}
+
+// Status returns the task status for the checkbox.
+func (c Checkbox) Status() string {
+	if c.Checked {
+		return CheckboxStatusDone
+	}
+	return CheckboxStatusTodo
+}
+
+// checkboxRegex matches list item text that starts with a task-list marker.
+// The list marker itself ("- ", "* ", "1. ") is already consumed by goldmark.
+var checkboxRegex = regexp.MustCompile(`^\[([ xX])\](?:\s+(.*))?$`)
+
+func parseCheckbox(text string, line int) (Checkbox, bool) {
+	m := checkboxRegex.FindStringSubmatch(strings.TrimRight(text, " \t"))
+	if m == nil {
+		return Checkbox{}, false
+	}
+	return Checkbox{
+		Checked: m[1] != " ",
+		Content: StripTraitAnnotations(m[2]),
+		Line:    line,
+	}, true
+}
@@ -17,16 +17,19 @@ type ParsedDocument struct {
 	Body       string          // Content without frontmatter (for full-text search indexing)
 	Objects    []*ParsedObject // All objects in this document
 	Sections   []*ParsedSection
+	Blocks     []*ParsedBlock
 	Traits     []*ParsedTrait // All traits in this document
 	Refs       []*ParsedRef   // All references in this document
 }
 
 // ParsedObject represents a parsed file-backed object.
 type ParsedObject struct {
-	ID         string                       // Unique file-backed object ID
-	ObjectType string                       // Type name
-	Fields     map[string]schema.FieldValue // Fields/metadata
-	LineStart  int                          // Line where this object starts
+	ID           string                       // Unique file-backed object ID
+	ObjectType   string                       // Type name
+	TypeExplicit bool                         // True when frontmatter declares the type (not the fallback 'page')
+	Fields       map[string]schema.FieldValue // Fields/metadata
+	FieldOrder   []string                     // Field names in frontmatter order
+	LineStart    int                          // Line where this object starts
 }
 
 // ParsedSection represents a markdown heading-derived section.
@@ -42,6 +45,16 @@ type ParsedSection struct {
 	ParentSectionID *string // Parent section ID, nil for top-level sections
 }
 
+// ParsedBlock represents a block ID declared with a trailing ^id.
+type ParsedBlock struct {
+	ID           string // Unique ID: file-id#^block-id
+	FileObjectID string // Containing file-backed object ID
+	BlockID      string // Block ID without the caret
+	ParentID     string // Enclosing section or file object ID
+	Content      string // Block text without the marker
+	Line         int    // First line of the block
+}
+
 // ParsedTrait represents a parsed trait annotation.
 type ParsedTrait struct {
 	TraitType      string             // Trait type name (e.g., "due", "priority", "highlight")
@@ -80,8 +93,26 @@ type ParseOptions struct {
 	// PagesRoot is the root directory for untyped pages (e.g., "pages/").
 	// If set, this prefix is stripped from file paths when computing object IDs.
 	PagesRoot string
+
+	// SectionIDStyle selects how section IDs are derived from headings.
+	// Empty means SectionIDStyleSlug.
+	SectionIDStyle string
+
+	// CheckboxTasks turns markdown checkbox items ("- [ ]", "- [x]") into
+	// task traits with a todo/done value.
+	CheckboxTasks bool
 }
 
+// Section ID styles.
+const (
+	// SectionIDStyleSlug slugifies the heading text: "## Team Sync" -> "team-sync".
+	SectionIDStyleSlug = "slug"
+	// SectionIDStyleIndex numbers sections in document order: "sec-1", "sec-2", ...
+	SectionIDStyleIndex = "index"
+	// SectionIDStyleHeadingPath joins the slugs of all ancestor headings: "intro/background".
+	SectionIDStyleHeadingPath = "heading-path"
+)
+
 // ParseDocument parses a markdown document.
 func ParseDocument(content string, filePath string, vaultPath string) (*ParsedDocument, error) {
 	return ParseDocumentWithOptions(content, filePath, vaultPath, nil)
@@ -94,26 +125,34 @@ func ParseDocumentWithOptions(content string, filePath string, vaultPath string,
 
 	var objects []*ParsedObject
 	var sections []*ParsedSection
+	var blocks []*ParsedBlock
 	var traits []*ParsedTrait
 	var refs []*ParsedRef
 
+	// Parse against LF line endings so CRLF files yield the same line numbers,
+	// headings, and trait content. RawContent keeps the original bytes for
+	// callers that rewrite the file.
+	parseContent := strings.ReplaceAll(content, "\r\n", "\n")
+
 	// Parse frontmatter
-	frontmatter, err := ParseFrontmatter(content)
+	frontmatter, err := ParseFrontmatter(parseContent)
 	if err != nil {
 		return nil, err
 	}
 
-	contentStartLine, bodyContent := frontmatterBody(content, frontmatter)
+	contentStartLine, bodyContent := frontmatterBody(parseContent, frontmatter)
 
 	// Create file-level object
 	fileFields := copyFrontmatterFields(frontmatter)
 	fileType := fileObjectType(frontmatter)
 
 	objects = append(objects, &ParsedObject{
-		ID:         fileID,
-		ObjectType: fileType,
-		Fields:     fileFields,
-		LineStart:  1,
+		ID:           fileID,
+		ObjectType:   fileType,
+		TypeExplicit: frontmatter != nil && frontmatter.ObjectType != "",
+		Fields:       fileFields,
+		FieldOrder:   frontmatterFieldOrder(frontmatter),
+		LineStart:    1,
 	})
 
 	// Extract references from frontmatter, if present.
@@ -139,22 +178,36 @@ func ParseDocumentWithOptions(content string, filePath string, vaultPath string,
 	// Track used IDs to ensure uniqueness
 	usedIDs := make(map[string]int)
 
+	sectionIDStyle := SectionIDStyleSlug
+	if opts != nil && opts.SectionIDStyle != "" {
+		sectionIDStyle = opts.SectionIDStyle
+	}
+
 	// Parent stack for tracking section hierarchy
 	type parentEntry struct {
 		id    string
+		slug  string
 		level int
 	}
 	parentStack := []parentEntry{{id: fileID, level: 0}}
 
 	// Process each heading
-	for _, heading := range headings {
+	for i, heading := range headings {
 		// Pop parents that are at same or deeper level
 		for len(parentStack) > 1 && parentStack[len(parentStack)-1].level >= heading.Level {
 			parentStack = parentStack[:len(parentStack)-1]
 		}
 		currentParent := parentStack[len(parentStack)-1].id
 
-		slug := sectionHeadingSlug(heading.Text, usedIDs)
+		var slug string
+		switch sectionIDStyle {
+		case SectionIDStyleIndex:
+			slug = uniqueSlug("sec-"+strconv.Itoa(i+1), usedIDs)
+		case SectionIDStyleHeadingPath:
+			slug = sectionHeadingPathSlug(heading.Text, parentStack[len(parentStack)-1].slug, usedIDs)
+		default:
+			slug = sectionHeadingSlug(heading.Text, usedIDs)
+		}
 		sectionID := fileID + "#" + slug
 		var parentSectionID *string
 		if currentParent != fileID {
@@ -172,7 +225,7 @@ func ParseDocumentWithOptions(content string, filePath string, vaultPath string,
 			ParentSectionID: parentSectionID,
 		})
 
-		parentStack = append(parentStack, parentEntry{id: sectionID, level: heading.Level})
+		parentStack = append(parentStack, parentEntry{id: sectionID, slug: slug, level: heading.Level})
 	}
 	// Process traits from AST extraction - assign to the correct parent based on line number
 	// Code blocks are already filtered out by the AST walker.
@@ -188,6 +241,20 @@ func ParseDocumentWithOptions(content string, filePath string, vaultPath string,
 		})
 	}
 
+	if opts != nil && opts.CheckboxTasks {
+		for _, checkbox := range astContent.Checkboxes {
+			status := schema.String(checkbox.Status())
+			traits = append(traits, &ParsedTrait{
+				TraitType:      CheckboxTaskTrait,
+				Value:          &status,
+				Content:        checkbox.Content,
+				ParentObjectID: findScopeForLine(fileID, sections, checkbox.Line),
+				Line:           checkbox.Line,
+			})
+		}
+		sort.SliceStable(traits, func(i, j int) bool { return traits[i].Line < traits[j].Line })
+	}
+
 	// Process references from AST extraction
 	// Code blocks are already filtered out by the AST walker.
 	for _, astRef := range astContent.Refs {
@@ -203,6 +270,23 @@ func ParseDocumentWithOptions(content string, filePath string, vaultPath string,
 		})
 	}
 
+	// Block IDs are unique per file; a repeated ID keeps its first block.
+	seenBlocks := make(map[string]struct{}, len(astContent.Blocks))
+	for _, anchor := range astContent.Blocks {
+		if _, dup := seenBlocks[anchor.BlockID]; dup {
+			continue
+		}
+		seenBlocks[anchor.BlockID] = struct{}{}
+		blocks = append(blocks, &ParsedBlock{
+			ID:           fileID + "#" + paths.BlockIDPrefix + anchor.BlockID,
+			FileObjectID: fileID,
+			BlockID:      anchor.BlockID,
+			ParentID:     findScopeForLine(fileID, sections, anchor.Line),
+			Content:      anchor.Content,
+			Line:         anchor.Line,
+		})
+	}
+
 	computeSectionLineEnds(sections)
 
 	return &ParsedDocument{
@@ -211,6 +295,7 @@ func ParseDocumentWithOptions(content string, filePath string, vaultPath string,
 		Body:       bodyContent,
 		Objects:    objects,
 		Sections:   sections,
+		Blocks:     blocks,
 		Traits:     traits,
 		Refs:       refs,
 	}, nil
@@ -246,12 +331,18 @@ func frontmatterBody(content string, frontmatter *Frontmatter) (contentStartLine
 	}
 
 	contentStartLine = frontmatter.EndLine + 1
-	lines := strings.Split(content, "\n")
-	if frontmatter.EndLine < len(lines) {
-		bodyContent = strings.Join(lines[frontmatter.EndLine:], "\n")
-	} else {
-		bodyContent = ""
+	// The body starts after the EndLine-th newline; slice instead of
+	// splitting and re-joining every line.
+	bodyContent = ""
+	offset := 0
+	for i := 0; i < frontmatter.EndLine; i++ {
+		next := strings.IndexByte(content[offset:], '\n')
+		if next < 0 {
+			return contentStartLine, bodyContent
+		}
+		offset += next + 1
 	}
+	bodyContent = content[offset:]
 	return contentStartLine, bodyContent
 }
 
@@ -266,6 +357,13 @@ func copyFrontmatterFields(frontmatter *Frontmatter) map[string]schema.FieldValu
 	return fileFields
 }
 
+func frontmatterFieldOrder(frontmatter *Frontmatter) []string {
+	if frontmatter == nil {
+		return nil
+	}
+	return append([]string(nil), frontmatter.FieldOrder...)
+}
+
 func fileObjectType(frontmatter *Frontmatter) string {
 	fileType := "page"
 	if frontmatter != nil && frontmatter.ObjectType != "" {
@@ -301,6 +399,19 @@ func sectionHeadingSlug(headingText string, usedIDs map[string]int) string {
 	return uniqueSlug(baseSlug, usedIDs)
 }
 
+// sectionHeadingPathSlug prefixes the heading slug with its parent section's
+// slug, so nested headings get IDs like "intro/background".
+func sectionHeadingPathSlug(headingText, parentSlug string, usedIDs map[string]int) string {
+	baseSlug := Slugify(headingText)
+	if baseSlug == "" {
+		baseSlug = "section"
+	}
+	if parentSlug != "" {
+		baseSlug = parentSlug + "/" + baseSlug
+	}
+	return uniqueSlug(baseSlug, usedIDs)
+}
+
 func uniqueSlug(baseSlug string, usedIDs map[string]int) string {
 	next := usedIDs[baseSlug] + 1
 	for {
@@ -319,7 +430,10 @@ func uniqueSlug(baseSlug string, usedIDs map[string]int) string {
 	}
 }
 
-// findScopeForLine finds the nearest containing scope ID for a line.
+// findScopeForLine finds the nearest containing scope ID for a line: the last
+// section whose heading starts on or before it. Lines before the first heading
+// (the preamble, including line 1 of a file without frontmatter) belong to the
+// file object. sections must be in document order.
 func findScopeForLine(fileID string, sections []*ParsedSection, line int) string {
 	idx := sort.Search(len(sections), func(i int) bool {
 		return sections[i].LineStart > line
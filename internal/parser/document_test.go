@@ -1,6 +1,8 @@
 package parser
 
 import (
+	"fmt"
+	"strings"
 	"testing"
 )
 
@@ -623,6 +625,194 @@ func TestParseDocument_DeepHeadingHierarchy(t *testing.T) {
 	}
 }
 
+func TestParseDocument_SectionIDStyles(t *testing.T) {
+	t.Parallel()
+
+	content := `# Intro
+
+## Background
+
+## Background
+
+# Usage
+
+### Deep Dive
+`
+
+	tests := []struct {
+		style string
+		want  []string
+	}{
+		{
+			style: "",
+			want:  []string{"doc#intro", "doc#background", "doc#background-2", "doc#usage", "doc#deep-dive"},
+		},
+		{
+			style: SectionIDStyleIndex,
+			want:  []string{"doc#sec-1", "doc#sec-2", "doc#sec-3", "doc#sec-4", "doc#sec-5"},
+		},
+		{
+			style: SectionIDStyleHeadingPath,
+			want:  []string{"doc#intro", "doc#intro/background", "doc#intro/background-2", "doc#usage", "doc#usage/deep-dive"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run("style="+tt.style, func(t *testing.T) {
+			t.Parallel()
+
+			doc, err := ParseDocumentWithOptions(content, "/vault/doc.md", "/vault", &ParseOptions{SectionIDStyle: tt.style})
+			if err != nil {
+				t.Fatalf("unexpected error: %v", err)
+			}
+			if len(doc.Sections) != len(tt.want) {
+				t.Fatalf("got %d sections, want %d", len(doc.Sections), len(tt.want))
+			}
+			for i, want := range tt.want {
+				if doc.Sections[i].ID != want {
+					t.Errorf("section %d ID = %q, want %q", i, doc.Sections[i].ID, want)
+				}
+			}
+		})
+	}
+
+	t.Run("heading-path parents follow ancestry", func(t *testing.T) {
+		t.Parallel()
+
+		doc, err := ParseDocumentWithOptions(content, "/vault/doc.md", "/vault", &ParseOptions{SectionIDStyle: SectionIDStyleHeadingPath})
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+		deep := doc.Sections[4]
+		if deep.ParentSectionID == nil || *deep.ParentSectionID != "doc#usage" {
+			t.Fatalf("deep dive parent = %v, want doc#usage", deep.ParentSectionID)
+		}
+		if deep.Slug != "usage/deep-dive" {
+			t.Fatalf("deep dive slug = %q, want usage/deep-dive", deep.Slug)
+		}
+	})
+}
+
+func TestParseDocument_CheckboxTasks(t *testing.T) {
+	t.Parallel()
+
+	content := `# Tasks
+
+- [ ] Write the report @due(2026-03-01)
+- [x] Email [[people/freya]]
+- plain item
+  - [X] nested done item
+
+[ ] not a list item
+`
+
+	t.Run("disabled by default", func(t *testing.T) {
+		t.Parallel()
+
+		doc, err := ParseDocument(content, "/vault/doc.md", "/vault")
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+		for _, trait := range doc.Traits {
+			if trait.TraitType == CheckboxTaskTrait {
+				t.Fatalf("unexpected checkbox task trait without opt-in: %+v", trait)
+			}
+		}
+	})
+
+	t.Run("checked and unchecked items become task traits", func(t *testing.T) {
+		t.Parallel()
+
+		doc, err := ParseDocumentWithOptions(content, "/vault/doc.md", "/vault", &ParseOptions{CheckboxTasks: true})
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+
+		type task struct {
+			status  string
+			content string
+			line    int
+		}
+		var got []task
+		for _, trait := range doc.Traits {
+			if trait.TraitType != CheckboxTaskTrait {
+				continue
+			}
+			if trait.ParentObjectID != "doc#tasks" {
+				t.Errorf("task %q parent = %q, want doc#tasks", trait.Content, trait.ParentObjectID)
+			}
+			got = append(got, task{status: trait.ValueString(), content: trait.Content, line: trait.Line})
+		}
+
+		want := []task{
+			{status: "todo", content: "Write the report", line: 3},
+			{status: "done", content: "Email [[people/freya]]", line: 4},
+			{status: "done", content: "nested done item", line: 6},
+		}
+		if len(got) != len(want) {
+			t.Fatalf("got %d task traits %+v, want %d", len(got), got, len(want))
+		}
+		for i := range want {
+			if got[i] != want[i] {
+				t.Errorf("task %d = %+v, want %+v", i, got[i], want[i])
+			}
+		}
+
+		// Inline traits on checkbox lines are still extracted.
+		var hasDue bool
+		for _, trait := range doc.Traits {
+			if trait.TraitType == "due" && trait.Line == 3 {
+				hasDue = true
+			}
+		}
+		if !hasDue {
+			t.Fatal("expected @due on the checkbox line to still be parsed")
+		}
+	})
+}
+
+func TestParseDocument_BlockIDs(t *testing.T) {
+	t.Parallel()
+
+	content := "# Plan\n\n## Launch\n\nShip the beta\nby Friday @due(2026-03-06) ^launch\n\n" +
+		"- Call Freya ^call-freya\n- Solve x^2 later\n- Repeat ^launch\n\n" +
+		"```\ncode ^skipped\n```\n\nSee [[projects/plan#^launch]].\n"
+
+	doc, err := ParseDocument(content, "/vault/projects/plan.md", "/vault")
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+
+	want := []ParsedBlock{
+		{ID: "projects/plan#^launch", FileObjectID: "projects/plan", BlockID: "launch", ParentID: "projects/plan#launch", Content: "Ship the beta by Friday", Line: 5},
+		{ID: "projects/plan#^call-freya", FileObjectID: "projects/plan", BlockID: "call-freya", ParentID: "projects/plan#launch", Content: "Call Freya", Line: 8},
+	}
+	if len(doc.Blocks) != len(want) {
+		t.Fatalf("got %d blocks %+v, want %d", len(doc.Blocks), doc.Blocks, len(want))
+	}
+	for i := range want {
+		if *doc.Blocks[i] != want[i] {
+			t.Errorf("block %d = %+v, want %+v", i, *doc.Blocks[i], want[i])
+		}
+	}
+
+	for _, trait := range doc.Traits {
+		if trait.TraitType == "due" && trait.Content != "by Friday" {
+			t.Errorf("@due content = %q, want the block marker left out", trait.Content)
+		}
+	}
+
+	var blockRef *ParsedRef
+	for _, ref := range doc.Refs {
+		if ref.Line == 16 {
+			blockRef = ref
+		}
+	}
+	if blockRef == nil || blockRef.TargetRaw != "projects/plan#^launch" {
+		t.Fatalf("refs = %+v, want [[projects/plan#^launch]] on line 16", doc.Refs)
+	}
+}
+
 func TestFindScopeForLine(t *testing.T) {
 	t.Parallel()
 
@@ -653,6 +843,56 @@ func TestFindScopeForLine(t *testing.T) {
 	}
 }
 
+func TestParseDocument_PreambleTraitsStayOnFileObject(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name    string
+		content string
+		want    map[int]string
+	}{
+		{
+			name:    "frontmatter then preamble",
+			content: "---\ntype: page\n---\n- @todo Preamble task\n\n## First\n- @todo Section task\n",
+			want:    map[int]string{4: "notes/plan", 7: "notes/plan#first"},
+		},
+		{
+			name:    "no frontmatter, task on line 1",
+			content: "- @todo Preamble task\n\n# First\n- @todo Section task\n",
+			want:    map[int]string{1: "notes/plan", 4: "notes/plan#first"},
+		},
+		{
+			name:    "heading on line 1",
+			content: "# First\n- @todo Section task\n## Second\n- @todo Nested task\n",
+			want:    map[int]string{2: "notes/plan#first", 4: "notes/plan#second"},
+		},
+		{
+			name:    "heading right after frontmatter",
+			content: "---\ntype: page\n---\n## First\n- @todo Section task\n",
+			want:    map[int]string{5: "notes/plan#first"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			t.Parallel()
+
+			doc, err := ParseDocument(tt.content, "/vault/notes/plan.md", "/vault")
+			if err != nil {
+				t.Fatalf("unexpected error: %v", err)
+			}
+			if len(doc.Traits) != len(tt.want) {
+				t.Fatalf("got %d traits, want %d: %+v", len(doc.Traits), len(tt.want), doc.Traits)
+			}
+			for _, trait := range doc.Traits {
+				if want := tt.want[trait.Line]; trait.ParentObjectID != want {
+					t.Errorf("trait on line %d parent = %q, want %q", trait.Line, trait.ParentObjectID, want)
+				}
+			}
+		})
+	}
+}
+
 func TestComputeSectionLineEndsDirectAndSubtreeRanges(t *testing.T) {
 	t.Parallel()
 
@@ -713,3 +953,181 @@ func sameIntPtr(a, b *int) bool {
 	}
 	return *a == *b
 }
+
+func TestParseDocument_LargeDocumentLineNumbers(t *testing.T) {
+	t.Parallel()
+
+	// Each 9-line block has a heading, a list with a trait/ref item and a
+	// heading item, and a fenced code block whose contents must be ignored.
+	const blocks = 300
+	var b strings.Builder
+	b.WriteString("---\ntype: page\n---\n")
+	for i := 0; i < blocks; i++ {
+		fmt.Fprintf(&b, "## Part %d\n\n- item @todo open [[people/p%d]]\n- # Nested %d\n\n```\n@decorator [[not/a/ref]]\n```\n\n", i, i, i)
+	}
+
+	doc, err := ParseDocument(b.String(), "/vault/notes/large.md", "/vault")
+	if err != nil {
+		t.Fatalf("ParseDocument() error = %v", err)
+	}
+
+	if len(doc.Sections) != 2*blocks {
+		t.Fatalf("got %d sections, want %d", len(doc.Sections), 2*blocks)
+	}
+	if len(doc.Traits) != blocks {
+		t.Fatalf("got %d traits, want %d", len(doc.Traits), blocks)
+	}
+	if len(doc.Refs) != blocks {
+		t.Fatalf("got %d refs, want %d", len(doc.Refs), blocks)
+	}
+
+	for i := 0; i < blocks; i++ {
+		blockStart := 4 + 9*i
+		if got := doc.Sections[2*i].LineStart; got != blockStart {
+			t.Fatalf("section %q line = %d, want %d", doc.Sections[2*i].Title, got, blockStart)
+		}
+		if got := doc.Sections[2*i+1].LineStart; got != blockStart+3 {
+			t.Fatalf("section %q line = %d, want %d", doc.Sections[2*i+1].Title, got, blockStart+3)
+		}
+		if got := doc.Traits[i].Line; got != blockStart+2 {
+			t.Fatalf("trait %d line = %d, want %d", i, got, blockStart+2)
+		}
+		ref := doc.Refs[i]
+		if ref.TargetRaw != fmt.Sprintf("people/p%d", i) || ref.Line != blockStart+2 {
+			t.Fatalf("ref %d = %s at line %d, want people/p%d at line %d", i, ref.TargetRaw, ref.Line, i, blockStart+2)
+		}
+	}
+}
+
+func TestParseDocument_CRLFLineNumbers(t *testing.T) {
+	t.Parallel()
+
+	lf := "---\ntype: page\n---\n\n# Title\n\n- [[people/freya]] @due(2025-02-01)\n\n## Next\n\n@todo Follow up\n"
+	crlf := strings.ReplaceAll(lf, "\n", "\r\n")
+
+	want, err := ParseDocument(lf, "/vault/notes/eol.md", "/vault")
+	if err != nil {
+		t.Fatalf("ParseDocument(LF) error = %v", err)
+	}
+	got, err := ParseDocument(crlf, "/vault/notes/eol.md", "/vault")
+	if err != nil {
+		t.Fatalf("ParseDocument(CRLF) error = %v", err)
+	}
+
+	if got.RawContent != crlf {
+		t.Errorf("RawContent should keep the original CRLF bytes")
+	}
+	if len(got.Sections) != 2 || got.Sections[0].LineStart != 5 || got.Sections[1].LineStart != 9 {
+		t.Fatalf("sections = %+v, want headings on lines 5 and 9", got.Sections)
+	}
+	for i, section := range got.Sections {
+		if section.Title != want.Sections[i].Title || section.ID != want.Sections[i].ID {
+			t.Errorf("section %d = %q (%s), want %q (%s)", i, section.Title, section.ID, want.Sections[i].Title, want.Sections[i].ID)
+		}
+	}
+
+	if len(got.Traits) != 2 {
+		t.Fatalf("got %d traits, want 2", len(got.Traits))
+	}
+	for i, trait := range got.Traits {
+		wantTrait := want.Traits[i]
+		if trait.Line != wantTrait.Line || trait.Content != wantTrait.Content || trait.ValueString() != wantTrait.ValueString() {
+			t.Errorf("trait %d = line %d %q (%q), want line %d %q (%q)",
+				i, trait.Line, trait.Content, trait.ValueString(), wantTrait.Line, wantTrait.Content, wantTrait.ValueString())
+		}
+	}
+	if got.Traits[0].Line != 7 || got.Traits[1].Line != 11 {
+		t.Errorf("trait lines = %d, %d, want 7, 11", got.Traits[0].Line, got.Traits[1].Line)
+	}
+
+	if len(got.Refs) != 1 || got.Refs[0].Line != 7 || got.Refs[0].TargetRaw != "people/freya" {
+		t.Fatalf("refs = %+v, want people/freya on line 7", got.Refs)
+	}
+}
+
+func TestParseDocument_BodyStartingWithThematicBreak(t *testing.T) {
+	t.Parallel()
+
+	t.Run("hr directly after frontmatter", func(t *testing.T) {
+		content := "---\ntype: page\n---\n---\n@todo Right after\n\n# Heading\n\n@done Later\n"
+		doc, err := ParseDocument(content, "/vault/notes/hr.md", "/vault")
+		if err != nil {
+			t.Fatalf("ParseDocument() error = %v", err)
+		}
+
+		if doc.Objects[0].LineStart != 1 {
+			t.Errorf("file object line = %d, want 1", doc.Objects[0].LineStart)
+		}
+		if len(doc.Sections) != 1 || doc.Sections[0].LineStart != 7 {
+			t.Fatalf("sections = %+v, want one heading on line 7", doc.Sections)
+		}
+		if len(doc.Traits) != 2 {
+			t.Fatalf("got %d traits, want 2", len(doc.Traits))
+		}
+		if doc.Traits[0].Line != 5 || doc.Traits[0].ParentObjectID != "notes/hr" {
+			t.Errorf("first trait = line %d parent %q, want line 5 parent notes/hr", doc.Traits[0].Line, doc.Traits[0].ParentObjectID)
+		}
+		if doc.Traits[1].Line != 9 || doc.Traits[1].ParentObjectID != doc.Sections[0].ID {
+			t.Errorf("second trait = line %d parent %q, want line 9 parent %q", doc.Traits[1].Line, doc.Traits[1].ParentObjectID, doc.Sections[0].ID)
+		}
+	})
+
+	t.Run("yaml-like block in body is not frontmatter", func(t *testing.T) {
+		content := "---\ntype: page\n---\n@todo First\n\n---\nstatus: draft\n---\n"
+		doc, err := ParseDocument(content, "/vault/notes/yamlish.md", "/vault")
+		if err != nil {
+			t.Fatalf("ParseDocument() error = %v", err)
+		}
+
+		if _, ok := doc.Objects[0].Fields["status"]; ok {
+			t.Errorf("body block should not be parsed as frontmatter, got fields %v", doc.Objects[0].Fields)
+		}
+		if len(doc.Traits) != 1 || doc.Traits[0].Line != 4 || doc.Traits[0].ParentObjectID != "notes/yamlish" {
+			t.Fatalf("traits = %+v, want one trait on line 4 owned by the file", doc.Traits)
+		}
+	})
+}
+
+func TestParseDocument_IgnoresCodeTraitsAndRefs(t *testing.T) {
+	t.Parallel()
+
+	content := "# Notes\n\n" +
+		"```\n- [ ] @todo fenced task [[people/fenced]]\n```\n\n" +
+		"~~~md\n@due(2025-01-01) [[people/tilde]]\n~~~\n\n" +
+		"Use `@todo` and `[[people/inline]]` literally, but see [[people/real]] @todo real\n"
+
+	doc, err := ParseDocumentWithOptions(content, "/vault/notes/code.md", "/vault", &ParseOptions{CheckboxTasks: true})
+	if err != nil {
+		t.Fatalf("ParseDocumentWithOptions() error = %v", err)
+	}
+
+	if len(doc.Refs) != 1 || doc.Refs[0].TargetRaw != "people/real" {
+		t.Errorf("refs = %+v, want only people/real", doc.Refs)
+	}
+	if len(doc.Traits) != 1 || doc.Traits[0].TraitType != "todo" || doc.Traits[0].Line != 11 {
+		t.Errorf("traits = %+v, want only the @todo on line 11", doc.Traits)
+	}
+}
+
+func TestParseDocument_EscapedRefsAndTraits(t *testing.T) {
+	t.Parallel()
+
+	content := "# Syntax\n\n" +
+		"Write \\[[not a link]] and \\@task literally.\n\n" +
+		"- @todo Document \\@due and link [[people/freya]]\n"
+
+	doc, err := ParseDocument(content, "/vault/notes/syntax.md", "/vault")
+	if err != nil {
+		t.Fatalf("ParseDocument() error = %v", err)
+	}
+
+	if len(doc.Refs) != 1 || doc.Refs[0].TargetRaw != "people/freya" {
+		t.Errorf("refs = %+v, want only people/freya", doc.Refs)
+	}
+	if len(doc.Traits) != 1 || doc.Traits[0].TraitType != "todo" {
+		t.Fatalf("traits = %+v, want only the @todo", doc.Traits)
+	}
+	if want := "Document @due and link [[people/freya]]"; doc.Traits[0].Content != want {
+		t.Errorf("trait content = %q, want %q", doc.Traits[0].Content, want)
+	}
+}
@@ -21,6 +21,10 @@ type Frontmatter struct {
 	// Fields are all other fields.
 	Fields map[string]schema.FieldValue
 
+	// FieldOrder lists the keys of Fields in the order they appear in the
+	// frontmatter.
+	FieldOrder []string
+
 	// Raw is the raw frontmatter content.
 	Raw string
 
@@ -64,6 +68,9 @@ func ParseFrontmatter(content string) (*Frontmatter, error) {
 	// Parse as YAML
 	var yamlData map[string]interface{}
 	if err := yaml.Unmarshal([]byte(frontmatterContent), &yamlData); err != nil {
+		if kind := nonMappingRootKind(frontmatterContent); kind != "" {
+			return nil, fmt.Errorf("frontmatter must be a YAML mapping of key: value pairs, got %s", kind)
+		}
 		return nil, fmt.Errorf("failed to parse frontmatter as YAML: %w", err)
 	}
 
@@ -92,14 +99,101 @@ func ParseFrontmatter(content string) (*Frontmatter, error) {
 			fm.Fields[key] = FieldValueFromYAML(value)
 		}
 	}
+	for _, key := range mappingKeyOrder(frontmatterContent) {
+		if _, ok := fm.Fields[key]; ok {
+			fm.FieldOrder = append(fm.FieldOrder, key)
+		}
+	}
 
 	return fm, nil
 }
 
+// mappingKeyOrder returns the top-level keys of a YAML mapping in document
+// order. Keys pulled in by a merge key (<<: *defaults) take the merge key's
+// position. Duplicates are dropped; the decoded map keeps only one value anyway.
+func mappingKeyOrder(content string) []string {
+	var node yaml.Node
+	if err := yaml.Unmarshal([]byte(content), &node); err != nil || len(node.Content) == 0 {
+		return nil
+	}
+	root := node.Content[0]
+	if root.Kind != yaml.MappingNode {
+		return nil
+	}
+	keys := make([]string, 0, len(root.Content)/2)
+	return appendMappingKeys(keys, make(map[string]bool, len(root.Content)/2), root, 0)
+}
+
+// maxMergeDepth bounds merge-key expansion so self-referencing anchors cannot
+// recurse forever.
+const maxMergeDepth = 32
+
+// isMergeKey reports whether a mapping key is the YAML merge key (an unquoted <<).
+func isMergeKey(node *yaml.Node) bool {
+	return node.Kind == yaml.ScalarNode && node.Value == "<<" && (node.Tag == "" || node.ShortTag() == "!!merge")
+}
+
+func appendMappingKeys(keys []string, seen map[string]bool, node *yaml.Node, depth int) []string {
+	if node == nil || depth > maxMergeDepth {
+		return keys
+	}
+	switch node.Kind {
+	case yaml.AliasNode:
+		return appendMappingKeys(keys, seen, node.Alias, depth+1)
+	case yaml.SequenceNode:
+		// <<: [*a, *b] merges several mappings in order.
+		for _, item := range node.Content {
+			keys = appendMappingKeys(keys, seen, item, depth+1)
+		}
+		return keys
+	}
+	if node.Kind != yaml.MappingNode {
+		return keys
+	}
+
+	for i := 0; i+1 < len(node.Content); i += 2 {
+		keyNode := node.Content[i]
+		if isMergeKey(keyNode) {
+			keys = appendMappingKeys(keys, seen, node.Content[i+1], depth+1)
+			continue
+		}
+		key := keyNode.Value
+		if seen[key] {
+			continue
+		}
+		seen[key] = true
+		keys = append(keys, key)
+	}
+	return keys
+}
+
+// nonMappingRootKind describes the YAML root node when it is valid YAML but not a mapping.
+// Returns "" when the root is a mapping or the content cannot be parsed at all.
+func nonMappingRootKind(content string) string {
+	var node yaml.Node
+	if err := yaml.Unmarshal([]byte(content), &node); err != nil || len(node.Content) == 0 {
+		return ""
+	}
+	switch node.Content[0].Kind {
+	case yaml.SequenceNode:
+		return "a list"
+	case yaml.ScalarNode:
+		return "a scalar value"
+	default:
+		return ""
+	}
+}
+
 func validateYAMLFieldValue(value interface{}, path string) error {
 	switch v := value.(type) {
+	case map[interface{}]interface{}:
+		return fmt.Errorf("unsupported non-string keys in YAML object for field %q", path)
 	case map[string]interface{}:
-		return fmt.Errorf("unsupported nested YAML object for field %q", path)
+		for key, item := range v {
+			if err := validateYAMLFieldValue(item, path+"."+key); err != nil {
+				return err
+			}
+		}
 	case []interface{}:
 		for i, item := range v {
 			if err := validateYAMLFieldValue(item, fmt.Sprintf("%s[%d]", path, i)); err != nil {
@@ -142,11 +236,17 @@ func FieldValueFromYAML(value interface{}) schema.FieldValue {
 			items = append(items, FieldValueFromYAML(item))
 		}
 		return schema.Array(items)
+	case map[string]interface{}:
+		entries := make(map[string]schema.FieldValue, len(v))
+		for key, item := range v {
+			entries[key] = FieldValueFromYAML(item)
+		}
+		return schema.Map(entries)
 	case nil:
 		return schema.Null()
 	default:
-		// Unsupported YAML structures such as nested objects are expected to be
-		// rejected by higher-level validation before reaching this conversion path.
+		// Unsupported YAML structures such as non-string mapping keys are expected
+		// to be rejected by higher-level validation before reaching this conversion path.
 		return schema.Null()
 	}
 }
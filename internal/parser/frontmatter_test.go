@@ -1,6 +1,7 @@
 package parser
 
 import (
+	"encoding/json"
 	"strings"
 	"testing"
 )
@@ -140,23 +141,29 @@ name: Freya
 			wantNil: true,
 		},
 		{
-			name: "nested YAML object is rejected",
+			name: "list frontmatter is rejected",
 			content: `---
-type: person
-address:
-  city: Oslo
-  country: Norway
+- person
+- Freya
+---
+`,
+			wantErr: true,
+		},
+		{
+			name: "scalar frontmatter is rejected",
+			content: `---
+just a sentence
 ---
 `,
 			wantErr: true,
 		},
 		{
-			name: "nested YAML object inside array is rejected",
+			name: "non-string YAML object keys are rejected",
 			content: `---
 type: person
-history:
-  - year: 2025
-    city: Oslo
+scores:
+  1: high
+  2: low
 ---
 `,
 			wantErr: true,
@@ -221,7 +228,119 @@ history:
 	}
 }
 
-func TestFieldValueFromYAML_UnsupportedMapReturnsNull(t *testing.T) {
+func TestParseFrontmatter_NestedObjectsPreserved(t *testing.T) {
+	t.Parallel()
+
+	fm, err := ParseFrontmatter(`---
+type: person
+address:
+  city: Oslo
+  geo:
+    lat: 59.91
+history:
+  - year: 2025
+    place:
+      city: Bergen
+---
+`)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+
+	address, ok := fm.Fields["address"].AsMap()
+	if !ok {
+		t.Fatalf("expected address to be a map, got %v", fm.Fields["address"])
+	}
+	if city, _ := address["city"].AsString(); city != "Oslo" {
+		t.Fatalf("address.city = %q, want %q", city, "Oslo")
+	}
+
+	data, err := json.Marshal(fm.Fields)
+	if err != nil {
+		t.Fatalf("marshal: %v", err)
+	}
+	var roundTrip map[string]interface{}
+	if err := json.Unmarshal(data, &roundTrip); err != nil {
+		t.Fatalf("unmarshal: %v", err)
+	}
+	geo := roundTrip["address"].(map[string]interface{})["geo"].(map[string]interface{})
+	if geo["lat"] != 59.91 {
+		t.Fatalf("address.geo.lat = %v, want 59.91", geo["lat"])
+	}
+	place := roundTrip["history"].([]interface{})[0].(map[string]interface{})["place"].(map[string]interface{})
+	if place["city"] != "Bergen" {
+		t.Fatalf("history[0].place.city = %v, want Bergen", place["city"])
+	}
+}
+
+func TestParseFrontmatter_NonMappingRootExplainsShape(t *testing.T) {
+	t.Parallel()
+
+	_, err := ParseFrontmatter("---\n- person\n- Freya\n---\n")
+	if err == nil {
+		t.Fatal("expected error for list frontmatter")
+	}
+	if !strings.Contains(err.Error(), "must be a YAML mapping") || !strings.Contains(err.Error(), "a list") {
+		t.Fatalf("unexpected error: %v", err)
+	}
+}
+
+func TestParseFrontmatter_FieldOrderFollowsDocument(t *testing.T) {
+	t.Parallel()
+
+	fm, err := ParseFrontmatter("---\ntype: book\ntitle: Dune\nauthor: Frank\n# comment\npages: 412\nrating: 5\n---\n")
+	if err != nil {
+		t.Fatalf("ParseFrontmatter returned error: %v", err)
+	}
+	want := []string{"title", "author", "pages", "rating"}
+	if strings.Join(fm.FieldOrder, ",") != strings.Join(want, ",") {
+		t.Fatalf("field order = %v, want %v", fm.FieldOrder, want)
+	}
+}
+
+func TestParseFrontmatter_MergeKeysContributeFields(t *testing.T) {
+	t.Parallel()
+
+	content := "---\n" +
+		"type: task\n" +
+		"defaults: &defaults\n" +
+		"  status: active\n" +
+		"  priority: low\n" +
+		"title: Ship it\n" +
+		"<<: *defaults\n" +
+		"priority: high\n" +
+		"---\n"
+	fm, err := ParseFrontmatter(content)
+	if err != nil {
+		t.Fatalf("ParseFrontmatter returned error: %v", err)
+	}
+	if fm.ObjectType != "task" {
+		t.Fatalf("object type = %q, want task", fm.ObjectType)
+	}
+	if status, _ := fm.Fields["status"].AsString(); status != "active" {
+		t.Errorf("status = %q, want merged value active", status)
+	}
+	if priority, _ := fm.Fields["priority"].AsString(); priority != "high" {
+		t.Errorf("priority = %q, want explicit value high to override the merge", priority)
+	}
+	if _, ok := fm.Fields["<<"]; ok {
+		t.Errorf("merge key should not become a field: %v", fm.Fields)
+	}
+	want := []string{"defaults", "title", "status", "priority"}
+	if strings.Join(fm.FieldOrder, ",") != strings.Join(want, ",") {
+		t.Fatalf("field order = %v, want %v", fm.FieldOrder, want)
+	}
+
+	doc, err := ParseDocument(content, "/vault/tasks/ship.md", "/vault")
+	if err != nil {
+		t.Fatalf("ParseDocument returned error: %v", err)
+	}
+	if status, _ := doc.Objects[0].Fields["status"].AsString(); status != "active" {
+		t.Errorf("object status = %q, want merged value active", status)
+	}
+}
+
+func TestFieldValueFromYAML_MapReturnsMap(t *testing.T) {
 	t.Parallel()
 
 	got := FieldValueFromYAML(map[string]interface{}{
@@ -229,8 +348,50 @@ func TestFieldValueFromYAML_UnsupportedMapReturnsNull(t *testing.T) {
 		"country": "Norway",
 	})
 
-	if !got.IsNull() {
-		t.Fatalf("expected null, got %v", got)
+	m, ok := got.AsMap()
+	if !ok {
+		t.Fatalf("expected map value, got %v", got)
+	}
+	if s, ok := m["city"].AsString(); !ok || s != "Oslo" {
+		t.Fatalf("city = %v, want string %q", m["city"], "Oslo")
+	}
+}
+
+func TestParseFrontmatter_ListOfMapsPreserved(t *testing.T) {
+	t.Parallel()
+
+	fm, err := ParseFrontmatter(`---
+type: meeting
+attendees: [{name: Alice, role: host}, {name: "[[people/bob]]"}]
+---
+`)
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+
+	attendees, ok := fm.Fields["attendees"].AsArray()
+	if !ok || len(attendees) != 2 {
+		t.Fatalf("expected 2 attendees, got %v", fm.Fields["attendees"])
+	}
+	host, ok := attendees[0].AsMap()
+	if !ok {
+		t.Fatalf("expected first attendee to be a map, got %v", attendees[0])
+	}
+	if role, _ := host["role"].AsString(); role != "host" {
+		t.Fatalf("attendees[0].role = %q, want %q", role, "host")
+	}
+	guest, _ := attendees[1].AsMap()
+	if ref, ok := guest["name"].AsRef(); !ok || ref != "people/bob" {
+		t.Fatalf("attendees[1].name = %v, want ref people/bob", guest["name"])
+	}
+
+	data, err := json.Marshal(fm.Fields["attendees"])
+	if err != nil {
+		t.Fatalf("marshal: %v", err)
+	}
+	want := `[{"name":"Alice","role":"host"},{"name":"people/bob"}]`
+	if string(data) != want {
+		t.Fatalf("json = %s, want %s", data, want)
 	}
 }
 
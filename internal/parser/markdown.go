@@ -1,6 +1,8 @@
 package parser
 
 import (
+	"sort"
+
 	"github.com/aidanlsb/raven/internal/slugs"
 )
 
@@ -29,10 +31,10 @@ func computeLineStarts(content string) []int {
 
 // offsetToLine converts a byte offset to a 0-indexed line number.
 func offsetToLine(lineStarts []int, offset int) int {
-	for i := len(lineStarts) - 1; i >= 0; i-- {
-		if lineStarts[i] <= offset {
-			return i
-		}
+	// Index of the last line start <= offset.
+	i := sort.Search(len(lineStarts), func(i int) bool { return lineStarts[i] > offset })
+	if i == 0 {
+		return 0
 	}
-	return 0
+	return i - 1
 }
@@ -30,3 +30,22 @@ func TestSlugify(t *testing.T) {
 		})
 	}
 }
+
+func TestOffsetToLine(t *testing.T) {
+	t.Parallel()
+	content := "one\n\nthree\nfour"
+	lineStarts := computeLineStarts(content)
+
+	// Reference: count newlines before the offset.
+	for offset := 0; offset <= len(content); offset++ {
+		want := 0
+		for i := 0; i < offset && i < len(content); i++ {
+			if content[i] == '\n' {
+				want++
+			}
+		}
+		if got := offsetToLine(lineStarts, offset); got != want {
+			t.Errorf("offsetToLine(%d) = %d, want %d", offset, got, want)
+		}
+	}
+}
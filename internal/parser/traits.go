@@ -76,7 +76,7 @@ func FormatFieldValueLiteral(value schema.FieldValue) string {
 // traitRegex matches @trait-name or @trait-name(value) for parsing.
 // The (^|[\s\-\*\(\[\{>]) ensures @ is at start of line or after common delimiters
 // (whitespace/list markers/parentheses/brackets). This strict pattern prevents
-// matching things like email addresses.
+// matching things like email addresses and escaped annotations like \@task.
 var traitRegex = regexp.MustCompile(`(^|[\s\-\*\(\[\{>])@([\w-]+)(?:\s*\(([^)]*)\))?`)
 
 // TraitHighlightPattern is a regex for highlighting traits in already-parsed content.
@@ -93,7 +93,7 @@ var TraitHighlightPattern = regexp.MustCompile(`@([\w-]+)(?:\(([^)]*)\))?`)
 func StripTraitAnnotations(line string) string {
 	replaced := traitRegex.ReplaceAllString(line, "$1")
 	// Collapse any double spaces introduced by removal.
-	return strings.Join(strings.Fields(replaced), " ")
+	return unescapeLiteralMarkers(strings.Join(strings.Fields(replaced), " "))
 }
 
 // ParseTraitAnnotations parses all trait annotations from a text segment.
@@ -144,7 +144,7 @@ func ParseTraitAnnotations(line string, lineNumber int) []TraitAnnotation {
 
 func stripTraitAnnotationsFromLine(line string, matches [][]int) string {
 	if len(matches) == 0 {
-		return strings.Join(strings.Fields(line), " ")
+		return unescapeLiteralMarkers(strings.Join(strings.Fields(line), " "))
 	}
 
 	var b strings.Builder
@@ -171,7 +171,28 @@ func stripTraitAnnotationsFromLine(line string, matches [][]int) string {
 		b.WriteString(line[last:])
 	}
 
-	return strings.Join(strings.Fields(b.String()), " ")
+	return unescapeLiteralMarkers(strings.Join(strings.Fields(b.String()), " "))
+}
+
+// unescapeLiteralMarkers drops the backslash from escaped \[[ and \@ sequences
+// so stored content shows the literal text. Inline code is left untouched.
+func unescapeLiteralMarkers(content string) string {
+	if !strings.Contains(content, `\`) {
+		return content
+	}
+	codeSpans := inlineCodeSpans(content)
+
+	var b strings.Builder
+	for i := 0; i < len(content); i++ {
+		if content[i] == '\\' && i+1 < len(content) && !matchInsideInlineCode(i, i+1, codeSpans) {
+			rest := content[i+1:]
+			if strings.HasPrefix(rest, "[[") || strings.HasPrefix(rest, "@") {
+				continue
+			}
+		}
+		b.WriteByte(content[i])
+	}
+	return b.String()
 }
 
 // ParseTrait parses a single trait from a line (returns first match).
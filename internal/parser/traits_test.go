@@ -358,6 +358,16 @@ func TestParseTraitAnnotations_Content(t *testing.T) {
 			line:        "@todo Use `foo()` when calling",
 			wantContent: "Use `foo()` when calling",
 		},
+		{
+			name:        "escaped markers are unescaped in content",
+			line:        `@todo Type \@task and \[[page]] literally`,
+			wantContent: "Type @task and [[page]] literally",
+		},
+		{
+			name:        "escapes inside inline code are kept",
+			line:        "@todo Run `echo \\@task` first",
+			wantContent: "Run `echo \\@task` first",
+		},
 	}
 
 	for _, tt := range tests {
@@ -376,6 +386,22 @@ func TestParseTraitAnnotations_Content(t *testing.T) {
 	}
 }
 
+func TestParseTraitAnnotations_Escaped(t *testing.T) {
+	t.Parallel()
+
+	if traits := ParseTraitAnnotations(`Write \@task to mention the trait`, 1); len(traits) != 0 {
+		t.Fatalf("expected no traits for escaped annotation, got %+v", traits)
+	}
+
+	traits := ParseTraitAnnotations(`@due(2025-01-01) Explain \@todo syntax`, 1)
+	if len(traits) != 1 || traits[0].TraitName != "due" {
+		t.Fatalf("expected only the @due trait, got %+v", traits)
+	}
+	if traits[0].Content != "Explain @todo syntax" {
+		t.Errorf("trait.Content = %q, want %q", traits[0].Content, "Explain @todo syntax")
+	}
+}
+
 func TestIsRefOnTraitLine(t *testing.T) {
 	t.Parallel()
 	// This test documents the CONTENT SCOPE RULE:
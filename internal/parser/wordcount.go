@@ -0,0 +1,66 @@
+package parser
+
+import (
+	"regexp"
+	"strings"
+	"unicode"
+
+	"github.com/aidanlsb/raven/internal/wikilink"
+)
+
+var (
+	wordCountListRegex  = regexp.MustCompile(`^\s*(?:>\s*)*(?:[-*+]|\d+[.)])\s+`)
+	wordCountImageRegex = regexp.MustCompile(`!\[[^\]]*\]\([^)]*\)`)
+	wordCountLinkRegex  = regexp.MustCompile(`\[([^\]]*)\]\([^)]*\)`)
+)
+
+// CountWords counts the prose words in a markdown body (frontmatter already
+// removed). Fenced code blocks, images, trait annotations, and link targets
+// are skipped; link and wikilink display text is counted. Tokens made only of
+// markdown punctuation (list markers, heading hashes, rules) are not words.
+func CountWords(body string) int {
+	var fence FenceState
+	count := 0
+	for _, line := range strings.Split(body, "\n") {
+		if fence.UpdateFenceState(line) || fence.InFence {
+			continue
+		}
+		line = wordCountListRegex.ReplaceAllString(line, "")
+		line = wordCountImageRegex.ReplaceAllString(line, " ")
+		line = wikilinkWordText(line)
+		line = wordCountLinkRegex.ReplaceAllString(line, " $1 ")
+		line = traitRegex.ReplaceAllString(line, "$1 ")
+		for _, token := range strings.Fields(line) {
+			if strings.IndexFunc(token, isWordRune) >= 0 {
+				count++
+			}
+		}
+	}
+	return count
+}
+
+// wikilinkWordText replaces each wikilink on line with its display text, or
+// its target when it has none.
+func wikilinkWordText(line string) string {
+	matches := wikilink.FindAllInLine(line, true)
+	if len(matches) == 0 {
+		return line
+	}
+	var b strings.Builder
+	last := 0
+	for _, m := range matches {
+		b.WriteString(line[last:m.Start])
+		text := m.Target
+		if m.DisplayText != nil {
+			text = *m.DisplayText
+		}
+		b.WriteString(" " + text + " ")
+		last = m.End
+	}
+	b.WriteString(line[last:])
+	return b.String()
+}
+
+func isWordRune(r rune) bool {
+	return unicode.IsLetter(r) || unicode.IsDigit(r)
+}
@@ -0,0 +1,27 @@
+package parser
+
+import "testing"
+
+func TestCountWords(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name string
+		body string
+		want int
+	}{
+		{name: "empty", body: "", want: 0},
+		{name: "plain prose", body: "The ravens fly at dawn.\nThey return at dusk.\n", want: 9},
+		{name: "markdown syntax", body: "# Heading one\n\n- **bold** item\n1. _second_ item\n> quoted line\n\n---\n", want: 8},
+		{name: "links", body: "See [[people/freya|Freya Odinsdottir]] and [[projects/bifrost]] or [the docs](https://example.com/docs).\n", want: 8},
+		{name: "bracketed display text", body: "Read [[notes/ch3|Chapter [3] notes]] today\n", want: 5},
+		{name: "images skipped", body: "Before ![a diagram of the tree](tree.png) after\n", want: 2},
+		{name: "traits skipped", body: "- @due(2025-02-01) Ship the release @priority(high)\n", want: 3},
+		{name: "code fence skipped", body: "Intro text\n```go\nfunc main() { fmt.Println(\"hi\") }\n```\nOutro\n", want: 3},
+	}
+	for _, tt := range tests {
+		if got := CountWords(tt.body); got != tt.want {
+			t.Errorf("%s: CountWords = %d, want %d", tt.name, got, tt.want)
+		}
+	}
+}
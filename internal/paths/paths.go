@@ -182,6 +182,16 @@ func ParseSectionID(id string) (fileID, fragment string, isSection bool) {
 	return id, "", false
 }
 
+// BlockIDPrefix starts a fragment that names a block rather than a section,
+// as in "people/freya#^intro".
+const BlockIDPrefix = "^"
+
+// IsBlockID reports whether id addresses a block ("file#^block-id").
+func IsBlockID(id string) bool {
+	_, fragment, isSection := ParseSectionID(id)
+	return isSection && strings.HasPrefix(fragment, BlockIDPrefix)
+}
+
 // ShortNameFromID extracts the short name from an object ID.
 // For "people/freya" -> "freya"
 // For "daily/2025-02-01#standup" -> "standup"
@@ -268,6 +278,31 @@ func ValidateWithinVault(vaultPath, targetPath string) error {
 	return nil
 }
 
+// ValidateNotePath checks that an existing note belongs to the vault under the
+// follow_symlinks policy. Without followSymlinks it is ValidateWithinVault.
+// With it, a path located inside the vault is accepted even when a symlink
+// along it resolves elsewhere, since the walk indexes such notes under the
+// link's path. New files (move and create destinations) should still use
+// ValidateWithinVault.
+func ValidateNotePath(vaultPath, targetPath string, followSymlinks bool) error {
+	if !followSymlinks {
+		return ValidateWithinVault(vaultPath, targetPath)
+	}
+
+	absVault, err := filepath.Abs(vaultPath)
+	if err != nil {
+		return err
+	}
+	absTarget, err := filepath.Abs(targetPath)
+	if err != nil {
+		return err
+	}
+	if !isWithinPath(absVault, absTarget) {
+		return ErrPathOutsideVault
+	}
+	return nil
+}
+
 // resolveTargetPath resolves symlinks for the nearest existing ancestor of absTarget,
 // then reconstructs the full path. This prevents symlink escapes when the final
 // path doesn't exist yet.
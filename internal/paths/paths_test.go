@@ -169,3 +169,37 @@ func TestValidateWithinVault_SymlinkEscape(t *testing.T) {
 		t.Fatalf("ValidateWithinVault() = %v, want ErrPathOutsideVault", err)
 	}
 }
+
+func TestValidateNotePath_FollowSymlinks(t *testing.T) {
+	t.Parallel()
+	if runtime.GOOS == "windows" {
+		t.Skip("symlink tests are not reliable on windows")
+	}
+
+	rootDir := t.TempDir()
+	vaultDir := filepath.Join(rootDir, "vault")
+	outsideDir := filepath.Join(rootDir, "outside")
+
+	if err := os.MkdirAll(vaultDir, 0o755); err != nil {
+		t.Fatalf("mkdir vault: %v", err)
+	}
+	if err := os.MkdirAll(outsideDir, 0o755); err != nil {
+		t.Fatalf("mkdir outside: %v", err)
+	}
+
+	linkPath := filepath.Join(vaultDir, "link")
+	if err := os.Symlink(outsideDir, linkPath); err != nil {
+		t.Skipf("symlink not supported: %v", err)
+	}
+
+	target := filepath.Join(linkPath, "note.md")
+	if err := ValidateNotePath(vaultDir, target, false); !errors.Is(err, ErrPathOutsideVault) {
+		t.Fatalf("ValidateNotePath(follow=false) = %v, want ErrPathOutsideVault", err)
+	}
+	if err := ValidateNotePath(vaultDir, target, true); err != nil {
+		t.Fatalf("ValidateNotePath(follow=true) = %v, want nil", err)
+	}
+	if err := ValidateNotePath(vaultDir, filepath.Join(vaultDir, "..", "outside", "note.md"), true); !errors.Is(err, ErrPathOutsideVault) {
+		t.Fatalf("ValidateNotePath(follow=true, ../) = %v, want ErrPathOutsideVault", err)
+	}
+}
@@ -18,6 +18,8 @@ type Executor struct {
 	nowFn                      func() time.Time
 	fieldRefAmbiguityCache     map[fieldRefAmbiguityKey]fieldRefAmbiguityResult
 	ambiguousFieldRefQueryHook func()
+	objectSortField            string // Set by SetObjectSort; empty keeps file order
+	objectSortDesc             bool
 }
 
 // NewExecutor creates a new query executor.
@@ -37,3 +37,43 @@ func TestObjectFieldEquality_NumericArrayMembership(t *testing.T) {
 		t.Fatalf("unexpected ids: %#v", got)
 	}
 }
+
+func TestObjectFieldOr_MixesArrayMembershipAndScalarField(t *testing.T) {
+	t.Parallel()
+	db := setupTestDB(t)
+	defer db.Close()
+
+	_, err := db.Exec(`
+		INSERT INTO objects (id, file_path, type, fields, line_start) VALUES
+			('notes/a', 'notes/a.md', 'note', '{"tags":["urgent","home"],"priority":"low"}', 1),
+			('notes/b', 'notes/b.md', 'note', '{"tags":["work"],"priority":"high"}', 1),
+			('notes/c', 'notes/c.md', 'note', '{"tags":["work"],"priority":"low"}', 1);
+	`)
+	if err != nil {
+		t.Fatalf("insert: %v", err)
+	}
+
+	e := NewExecutor(db)
+	for _, queryStr := range []string{
+		"type:note (.tags==urgent | .priority==high)",
+		"type:note any(.tags, _ == urgent) | .priority==high",
+	} {
+		q, err := Parse(queryStr)
+		if err != nil {
+			t.Fatalf("parse %q: %v", queryStr, err)
+		}
+
+		results, err := e.ExecuteObjectQuery(q)
+		if err != nil {
+			t.Fatalf("exec %q: %v", queryStr, err)
+		}
+
+		got := make(map[string]bool)
+		for _, r := range results {
+			got[r.ID] = true
+		}
+		if len(got) != 2 || !got["notes/a"] || !got["notes/b"] {
+			t.Fatalf("%q: unexpected ids: %#v", queryStr, got)
+		}
+	}
+}
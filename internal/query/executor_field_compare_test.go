@@ -51,6 +51,65 @@ func TestObjectFieldComparison_NumericUsesNumericOrdering(t *testing.T) {
 	}
 }
 
+func TestObjectFieldComparison_NumericGreaterOrEqualAlongsideStringEquality(t *testing.T) {
+	t.Parallel()
+	db := setupTestDB(t)
+	defer db.Close()
+
+	_, err := db.Exec(`
+		INSERT INTO objects (id, file_path, type, fields, line_start) VALUES
+			('book/dune', 'book/dune.md', 'book', '{"rating": 5, "format": "hardcover"}', 1),
+			('book/emma', 'book/emma.md', 'book', '{"rating": 4, "format": "ebook"}', 1),
+			('book/ulysses', 'book/ulysses.md', 'book', '{"rating": 3, "format": "paperback"}', 1);
+	`)
+	if err != nil {
+		t.Fatalf("insert: %v", err)
+	}
+
+	e := NewExecutor(db)
+
+	tests := []struct {
+		name    string
+		query   string
+		wantIDs []string
+	}{
+		{
+			name:    "numeric greater or equal",
+			query:   "type:book .rating>=4",
+			wantIDs: []string{"book/dune", "book/emma"},
+		},
+		{
+			name:    "string equality stays textual",
+			query:   "type:book .format==Paperback",
+			wantIDs: []string{"book/ulysses"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			q, err := Parse(tt.query)
+			if err != nil {
+				t.Fatalf("parse: %v", err)
+			}
+
+			results, err := e.ExecuteObjectQuery(q)
+			if err != nil {
+				t.Fatalf("exec: %v", err)
+			}
+
+			ids := make([]string, 0, len(results))
+			for _, r := range results {
+				ids = append(ids, r.ID)
+			}
+			slices.Sort(ids)
+
+			if !slices.Equal(ids, tt.wantIDs) {
+				t.Fatalf("got ids %#v, want %#v", ids, tt.wantIDs)
+			}
+		})
+	}
+}
+
 func TestObjectFieldComparison_BooleanLiteralsUseBoolStorage(t *testing.T) {
 	t.Parallel()
 	db := setupTestDB(t)
@@ -133,6 +192,80 @@ func TestObjectFieldComparison_BooleanLiteralsUseBoolStorage(t *testing.T) {
 	}
 }
 
+func TestObjectFieldComparison_EnumSynonymLiteralMatchesCanonicalValue(t *testing.T) {
+	t.Parallel()
+	db := setupTestDB(t)
+	defer db.Close()
+
+	_, err := db.Exec(`
+		INSERT INTO objects (id, file_path, type, fields, line_start) VALUES
+			('project/site', 'project/site.md', 'project', '{"status": "in_progress"}', 1),
+			('project/app', 'project/app.md', 'project', '{"status": "done"}', 1);
+	`)
+	if err != nil {
+		t.Fatalf("insert: %v", err)
+	}
+
+	e := NewExecutor(db)
+	e.SetSchema(&schema.Schema{
+		Types: map[string]*schema.TypeDefinition{
+			"project": {
+				Fields: map[string]*schema.FieldDefinition{
+					"status": {
+						Type:     schema.FieldTypeEnum,
+						Values:   []string{"todo", "in_progress", "done"},
+						Synonyms: map[string][]string{"in_progress": {"doing", "wip"}},
+					},
+				},
+			},
+		},
+	})
+
+	tests := []struct {
+		name    string
+		query   string
+		wantIDs []string
+	}{
+		{
+			name:    "synonym equality",
+			query:   "type:project .status==wip",
+			wantIDs: []string{"project/site"},
+		},
+		{
+			name:    "synonym inequality",
+			query:   "type:project .status!=doing",
+			wantIDs: []string{"project/app"},
+		},
+		{
+			name:    "canonical equality",
+			query:   "type:project .status==in_progress",
+			wantIDs: []string{"project/site"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			q, err := Parse(tt.query)
+			if err != nil {
+				t.Fatalf("parse: %v", err)
+			}
+
+			results, err := e.ExecuteObjectQuery(q)
+			if err != nil {
+				t.Fatalf("exec: %v", err)
+			}
+
+			ids := make([]string, 0, len(results))
+			for _, r := range results {
+				ids = append(ids, r.ID)
+			}
+			if !slices.Equal(ids, tt.wantIDs) {
+				t.Fatalf("got ids %#v, want %#v", ids, tt.wantIDs)
+			}
+		})
+	}
+}
+
 func TestObjectFieldComparison_RelativeDateKeywordOrdering(t *testing.T) {
 	t.Parallel()
 	db := setupTestDB(t)
@@ -231,3 +364,70 @@ func TestObjectFieldComparison_RelativeDateUsesSingleExecutionTimestamp(t *testi
 		t.Fatalf("nowFn callCount = %d, want 1", callCount)
 	}
 }
+
+func TestObjectFieldComparison_RelativeDateRanges(t *testing.T) {
+	t.Parallel()
+	db := setupTestDB(t)
+	defer db.Close()
+
+	_, err := db.Exec(`
+		INSERT INTO objects (id, file_path, type, fields, line_start) VALUES
+			('meeting/march-end', 'meeting/march-end.md', 'meeting', '{"date":"2026-03-31"}', 1),
+			('meeting/kickoff', 'meeting/kickoff.md', 'meeting', '{"date":"2026-04-02"}', 1),
+			('meeting/review', 'meeting/review.md', 'meeting', '{"date":"2026-04-10T09:30"}', 1),
+			('meeting/standup', 'meeting/standup.md', 'meeting', '{"date":"2026-04-15"}', 1),
+			('meeting/planning', 'meeting/planning.md', 'meeting', '{"date":"2026-04-30T16:00"}', 1),
+			('meeting/may', 'meeting/may.md', 'meeting', '{"date":"2026-05-01"}', 1);
+	`)
+	if err != nil {
+		t.Fatalf("insert: %v", err)
+	}
+
+	e := NewExecutor(db)
+	e.nowFn = func() time.Time {
+		return time.Date(2026, 4, 15, 12, 0, 0, 0, time.UTC)
+	}
+
+	tests := []struct {
+		query   string
+		wantIDs []string
+	}{
+		{
+			query:   "type:meeting .date==this-month",
+			wantIDs: []string{"meeting/kickoff", "meeting/planning", "meeting/review", "meeting/standup"},
+		},
+		{
+			query:   "type:meeting .date==last-7-days",
+			wantIDs: []string{"meeting/review", "meeting/standup"},
+		},
+		{
+			query:   "type:meeting .date!=this-month",
+			wantIDs: []string{"meeting/march-end", "meeting/may"},
+		},
+		{
+			query:   "type:meeting .date>this-month",
+			wantIDs: []string{"meeting/may"},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.query, func(t *testing.T) {
+			q, err := Parse(tt.query)
+			if err != nil {
+				t.Fatalf("parse: %v", err)
+			}
+			results, err := e.ExecuteObjectQuery(q)
+			if err != nil {
+				t.Fatalf("exec: %v", err)
+			}
+			ids := make([]string, 0, len(results))
+			for _, r := range results {
+				ids = append(ids, r.ID)
+			}
+			slices.Sort(ids)
+			if !slices.Equal(ids, tt.wantIDs) {
+				t.Fatalf("got %v, want %v", ids, tt.wantIDs)
+			}
+		})
+	}
+}
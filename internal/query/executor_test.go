@@ -606,6 +606,48 @@ func TestExecuteAssetIDAndCountQueries(t *testing.T) {
 	}
 }
 
+func TestExecuteSectionQuery_LevelAndTypeSectionAlias(t *testing.T) {
+	t.Parallel()
+	db := setupTestDB(t)
+	defer db.Close()
+
+	if _, err := db.Exec(`INSERT INTO sections (id, file_object_id, file_path, slug, title, level, line_start, parent_section_id) VALUES
+		('projects/website#mockups', 'projects/website', 'projects/website.md', 'mockups', 'Mockups', 3, 55, 'projects/website#design')`); err != nil {
+		t.Fatalf("insert section: %v", err)
+	}
+
+	executor := NewExecutor(db)
+	for _, tc := range []struct {
+		query string
+		want  int
+	}{
+		{query: "section .level==2", want: 5},
+		{query: "type:section .level==2", want: 5},
+		{query: "type:section .level==3", want: 1},
+		{query: "type:section .level>2", want: 1},
+	} {
+		q, err := Parse(tc.query)
+		if err != nil {
+			t.Fatalf("parse %q: %v", tc.query, err)
+		}
+		if q.Type != QueryTypeSection {
+			t.Fatalf("%q parsed as %v, want section query", tc.query, q.Type)
+		}
+		results, err := executor.executeSectionQuery(q)
+		if err != nil {
+			t.Fatalf("execute %q: %v", tc.query, err)
+		}
+		if len(results) != tc.want {
+			t.Fatalf("%q returned %d sections, want %d: %+v", tc.query, len(results), tc.want, results)
+		}
+		for _, section := range results {
+			if tc.want == 1 && section.ID != "projects/website#mockups" {
+				t.Fatalf("%q matched %s, want projects/website#mockups", tc.query, section.ID)
+			}
+		}
+	}
+}
+
 func TestExecuteTraitQuery_MatchesDirectRefsAcrossRootVariants(t *testing.T) {
 	t.Parallel()
 	db := setupRefRegressionDB(t)
@@ -8,13 +8,11 @@ import (
 // parseFieldPredicate parses .field==value, .field!=value, .field>value, etc.
 // For string matching, use function-style predicates: includes(.field, "str"), startswith(...), etc.
 func (p *Parser) parseFieldPredicate(negated bool) (Predicate, error) {
-	if p.curr.Type != TokenIdent {
-		return nil, fmt.Errorf("expected field name after '.'")
+	field, err := p.parseFieldName()
+	if err != nil {
+		return nil, err
 	}
 
-	field := p.curr.Value
-	p.advance()
-
 	// Determine the operator
 	var compareOp CompareOp
 
@@ -142,11 +140,10 @@ func (p *Parser) parseInPredicate(negated bool) (Predicate, error) {
 		return nil, fmt.Errorf("expected .field as first argument to oneof()")
 	}
 	p.advance()
-	if p.curr.Type != TokenIdent {
-		return nil, fmt.Errorf("expected field name after '.'")
+	field, err := p.parseFieldName()
+	if err != nil {
+		return nil, err
 	}
-	field := p.curr.Value
-	p.advance()
 
 	if err := p.expect(TokenComma); err != nil {
 		return nil, err
@@ -212,11 +209,11 @@ func (p *Parser) parseStringFuncPredicate(negated bool, funcType StringFuncType)
 	// Parse first argument: .field or _
 	if p.curr.Type == TokenDot {
 		p.advance()
-		if p.curr.Type != TokenIdent {
-			return nil, fmt.Errorf("expected field name after '.'")
+		field, err := p.parseFieldName()
+		if err != nil {
+			return nil, err
 		}
-		pred.Field = p.curr.Value
-		p.advance()
+		pred.Field = field
 	} else if p.curr.Type == TokenUnderscore {
 		pred.Field = "_"
 		pred.IsElementRef = true
@@ -288,11 +285,11 @@ func (p *Parser) parseArrayQuantifierPredicate(negated bool, quantifier ArrayQua
 		return nil, fmt.Errorf("expected .field as first argument to %s()", quantifier)
 	}
 	p.advance()
-	if p.curr.Type != TokenIdent {
-		return nil, fmt.Errorf("expected field name after '.'")
+	field, err := p.parseFieldName()
+	if err != nil {
+		return nil, err
 	}
-	pred.Field = p.curr.Value
-	p.advance()
+	pred.Field = field
 
 	// Expect comma
 	if err := p.expect(TokenComma); err != nil {
@@ -1,6 +1,7 @@
 package query
 
 import (
+	"errors"
 	"fmt"
 	"strings"
 )
@@ -58,7 +59,8 @@ func Parse(input string) (*Query, error) {
 	}
 	q, err := p.parseQuery()
 	if err != nil {
-		if p.curr.Type == TokenError {
+		var fieldErr *invalidFieldNameError
+		if p.curr.Type == TokenError && !errors.As(err, &fieldErr) {
 			return nil, fmt.Errorf("%s at pos %d", p.curr.Value, p.curr.Pos)
 		}
 		return nil, err
@@ -88,6 +90,39 @@ func (p *Parser) expect(t TokenType) error {
 	return nil
 }
 
+// invalidFieldNameError reports a field name containing characters the query
+// language does not allow. Field names end up in SQL (as JSON paths), so they
+// are rejected outright instead of being parsed around.
+type invalidFieldNameError struct {
+	name string
+	ch   string
+	pos  int
+}
+
+func (e *invalidFieldNameError) Error() string {
+	if e.name == "" {
+		return fmt.Sprintf("invalid field name: unexpected %q after '.' at pos %d", e.ch, e.pos)
+	}
+	return fmt.Sprintf("invalid field name %q: unexpected %q at pos %d (field names may only contain letters, digits, '_', '-', '/', and '#')", e.name, e.ch, e.pos)
+}
+
+// parseFieldName consumes the identifier following a '.' and rejects names
+// that run straight into a character the lexer could not tokenize.
+func (p *Parser) parseFieldName() (string, error) {
+	if p.curr.Type == TokenError {
+		return "", &invalidFieldNameError{ch: p.curr.Value, pos: p.curr.Pos}
+	}
+	if p.curr.Type != TokenIdent {
+		return "", fmt.Errorf("expected field name after '.'")
+	}
+	field := p.curr.Value
+	if p.peek.Type == TokenError && p.peek.Pos == p.curr.Pos+len(field) {
+		return "", &invalidFieldNameError{name: field, ch: p.peek.Value, pos: p.peek.Pos}
+	}
+	p.advance()
+	return field, nil
+}
+
 // parseQuery parses a top-level query (type:<name>, trait:<name>, section, or asset).
 func (p *Parser) parseQuery() (*Query, error) {
 	if p.curr.Type != TokenIdent {
@@ -135,6 +170,12 @@ func (p *Parser) parseQuery() (*Query, error) {
 	switch queryKind {
 	case "type":
 		query.Type = QueryTypeObject
+		// Sections live in their own table rather than as objects, so
+		// type:section is read as the bare section query root.
+		if typeName == "section" {
+			query.Type = QueryTypeSection
+			typeName = ""
+		}
 	case "trait":
 		query.Type = QueryTypeTrait
 	default:
@@ -363,11 +404,10 @@ func (p *Parser) parseExistsPredicate(negated bool) (Predicate, error) {
 		return nil, fmt.Errorf("expected .field as argument to exists()")
 	}
 	p.advance()
-	if p.curr.Type != TokenIdent {
-		return nil, fmt.Errorf("expected field name after '.'")
+	field, err := p.parseFieldName()
+	if err != nil {
+		return nil, err
 	}
-	field := p.curr.Value
-	p.advance()
 	if err := p.expect(TokenRParen); err != nil {
 		return nil, err
 	}
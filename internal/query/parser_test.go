@@ -36,6 +36,11 @@ func TestParseObjectQuery(t *testing.T) {
 			input:    "asset .extension==pdf",
 			wantType: QueryTypeAsset,
 		},
+		{
+			name:     "type:section reads as section query",
+			input:    "type:section .level==2",
+			wantType: QueryTypeSection,
+		},
 		{
 			name:    "invalid query type",
 			input:   "foo:bar",
@@ -208,6 +213,39 @@ func TestParseUnexpectedTokenUsesReadableTokenName(t *testing.T) {
 	}
 }
 
+func TestParseRejectsInvalidFieldNames(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name  string
+		input string
+	}{
+		{name: "quote in comparison", input: `type:book .x');DROP TABLE objects;--==1`},
+		{name: "quote as first character", input: `type:book .'x'==1`},
+		{name: "semicolon in exists", input: `type:book exists(.title;)`},
+		{name: "quote in quantifier", input: `type:book any(.tags', _ == "x")`},
+		{name: "quote in oneof", input: `type:book oneof(.status', [a, b])`},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			_, err := Parse(tt.input)
+			if err == nil {
+				t.Fatal("expected parse error, got nil")
+			}
+			if !strings.Contains(err.Error(), "invalid field name") {
+				t.Fatalf("expected invalid field name error, got %q", err.Error())
+			}
+		})
+	}
+
+	for _, input := range []string{`type:book .due-date==today`, `type:book .status == active`, `type:book exists(.title)`} {
+		if _, err := Parse(input); err != nil {
+			t.Errorf("Parse(%q) returned error: %v", input, err)
+		}
+	}
+}
+
 func TestParseFieldPredicates(t *testing.T) {
 	t.Parallel()
 	tests := []struct {
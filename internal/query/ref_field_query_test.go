@@ -70,6 +70,70 @@ func TestRefFieldQueryResolvesCanonicalTargets(t *testing.T) {
 	}
 }
 
+func TestRefArrayFieldQueryMatchesMember(t *testing.T) {
+	t.Parallel()
+	db := setupTestDB(t)
+	defer db.Close()
+
+	standupFields, _ := json.Marshal(map[string]interface{}{
+		"attendees": []string{"people/alice", "people/bob"},
+	})
+	retroFields, _ := json.Marshal(map[string]interface{}{
+		"attendees": []string{"people/bob"},
+	})
+
+	_, err := db.Exec(`
+		INSERT INTO objects (id, file_path, type, fields, line_start)
+		VALUES
+			('people/alice', 'people/alice.md', 'person', '{}', 1),
+			('people/bob', 'people/bob.md', 'person', '{}', 1),
+			('meetings/standup', 'meetings/standup.md', 'meeting', ?, 1),
+			('meetings/retro', 'meetings/retro.md', 'meeting', ?, 1)
+	`, string(standupFields), string(retroFields))
+	if err != nil {
+		t.Fatalf("failed to insert objects: %v", err)
+	}
+
+	_, err = db.Exec(`
+		INSERT INTO field_refs (source_id, field_name, target_id, target_raw, resolution_status, file_path, line_number)
+		VALUES
+			('meetings/standup', 'attendees', 'people/alice', 'people/alice', 'resolved', 'meetings/standup.md', 1),
+			('meetings/standup', 'attendees', 'people/bob', 'people/bob', 'resolved', 'meetings/standup.md', 1),
+			('meetings/retro', 'attendees', 'people/bob', 'people/bob', 'resolved', 'meetings/retro.md', 1)
+	`)
+	if err != nil {
+		t.Fatalf("failed to insert field_refs: %v", err)
+	}
+
+	sch := schema.New()
+	sch.Types["meeting"] = &schema.TypeDefinition{
+		Fields: map[string]*schema.FieldDefinition{
+			"attendees": {Type: schema.FieldTypeRefArray, Target: "person"},
+		},
+	}
+	sch.Types["person"] = &schema.TypeDefinition{Fields: map[string]*schema.FieldDefinition{}}
+
+	executor := NewExecutor(db)
+	executor.SetSchema(sch)
+
+	for _, queryStr := range []string{
+		"type:meeting .attendees==[[people/alice]]",
+		"type:meeting .attendees==people/alice",
+	} {
+		q, err := Parse(queryStr)
+		if err != nil {
+			t.Fatalf("parse %q: %v", queryStr, err)
+		}
+		results, err := executor.ExecuteObjectQuery(q)
+		if err != nil {
+			t.Fatalf("query %q: %v", queryStr, err)
+		}
+		if len(results) != 1 || results[0].ID != "meetings/standup" {
+			t.Fatalf("%q: expected meetings/standup, got %+v", queryStr, results)
+		}
+	}
+}
+
 func TestRefFieldQueryErrorsOnAmbiguousStoredValue(t *testing.T) {
 	t.Parallel()
 	db := setupTestDB(t)
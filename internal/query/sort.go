@@ -0,0 +1,40 @@
+package query
+
+import (
+	"fmt"
+	"regexp"
+)
+
+// plainFieldNamePattern limits sort and select fields to plain frontmatter keys
+// so they map onto simple JSON paths.
+var plainFieldNamePattern = regexp.MustCompile(`^[A-Za-z0-9_]+$`)
+
+// IsPlainFieldName reports whether name is a plain frontmatter key made of
+// letters, digits, and underscores.
+func IsPlainFieldName(name string) bool {
+	return plainFieldNamePattern.MatchString(name)
+}
+
+// SetObjectSort orders type query results by a frontmatter field instead of
+// file position. Objects without the field sort last; ties keep file order.
+func (e *Executor) SetObjectSort(field string, descending bool) error {
+	if !IsPlainFieldName(field) {
+		return fmt.Errorf("invalid sort field %q: use letters, digits, and underscores", field)
+	}
+	e.objectSortField = field
+	e.objectSortDesc = descending
+	return nil
+}
+
+func (e *Executor) objectOrderBy() (string, []interface{}) {
+	if e.objectSortField == "" {
+		return "o.file_path, o.line_start", nil
+	}
+	direction := "ASC"
+	if e.objectSortDesc {
+		direction = "DESC"
+	}
+	jsonPath := jsonFieldPath(e.objectSortField)
+	orderBy := fmt.Sprintf("json_extract(o.fields, ?) IS NULL, json_extract(o.fields, ?) %s, o.file_path, o.line_start", direction)
+	return orderBy, []interface{}{jsonPath, jsonPath}
+}
@@ -0,0 +1,76 @@
+package query
+
+import (
+	"slices"
+	"testing"
+)
+
+func TestObjectSortByField(t *testing.T) {
+	t.Parallel()
+	db := setupTestDB(t)
+	defer db.Close()
+
+	_, err := db.Exec(`
+		INSERT INTO objects (id, file_path, type, fields, line_start) VALUES
+			('book/dune', 'book/dune.md', 'book', '{"title": "Dune", "rating": 5}', 1),
+			('book/emma', 'book/emma.md', 'book', '{"title": "Emma", "rating": 3}', 1),
+			('book/notes', 'book/notes.md', 'book', '{"title": "Notes"}', 1),
+			('book/ulysses', 'book/ulysses.md', 'book', '{"title": "Ulysses", "rating": 10}', 1);
+	`)
+	if err != nil {
+		t.Fatalf("insert: %v", err)
+	}
+
+	q, err := Parse("type:book")
+	if err != nil {
+		t.Fatalf("parse: %v", err)
+	}
+
+	tests := []struct {
+		name       string
+		field      string
+		descending bool
+		wantIDs    []string
+	}{
+		{"ascending numbers with missing last", "rating", false, []string{"book/emma", "book/dune", "book/ulysses", "book/notes"}},
+		{"descending numbers with missing last", "rating", true, []string{"book/ulysses", "book/dune", "book/emma", "book/notes"}},
+		{"descending strings", "title", true, []string{"book/ulysses", "book/notes", "book/emma", "book/dune"}},
+	}
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			e := NewExecutor(db)
+			if err := e.SetObjectSort(tt.field, tt.descending); err != nil {
+				t.Fatalf("SetObjectSort: %v", err)
+			}
+			results, err := e.ExecuteObjectQuery(q)
+			if err != nil {
+				t.Fatalf("exec: %v", err)
+			}
+			ids := make([]string, 0, len(results))
+			for _, r := range results {
+				ids = append(ids, r.ID)
+			}
+			if !slices.Equal(ids, tt.wantIDs) {
+				t.Fatalf("got %v, want %v", ids, tt.wantIDs)
+			}
+
+			pageIDs, err := e.ExecuteObjectIDQuery(q, 2, 1)
+			if err != nil {
+				t.Fatalf("exec ids: %v", err)
+			}
+			if !slices.Equal(pageIDs, tt.wantIDs[1:3]) {
+				t.Fatalf("paged ids = %v, want %v", pageIDs, tt.wantIDs[1:3])
+			}
+		})
+	}
+}
+
+func TestSetObjectSortRejectsUnsafeFields(t *testing.T) {
+	t.Parallel()
+	e := NewExecutor(nil)
+	for _, field := range []string{"", "rating desc", "a.b", "x'); DROP TABLE objects; --"} {
+		if err := e.SetObjectSort(field, false); err == nil {
+			t.Errorf("SetObjectSort(%q) returned nil error", field)
+		}
+	}
+}
@@ -103,12 +103,14 @@ func (e *Executor) buildObjectPageSQL(q *Query, limit, offset int) (string, []in
 	if err != nil {
 		return "", nil, err
 	}
+	orderBy, orderArgs := e.objectOrderBy()
+	args = append(args, orderArgs...)
 	sqlStr := fmt.Sprintf(`
 		SELECT o.id, o.type, o.fields, o.file_path, o.line_start
 		FROM objects o
 		WHERE %s
-		ORDER BY o.file_path, o.line_start
-	`, whereClause)
+		ORDER BY %s
+	`, whereClause, orderBy)
 
 	sqlStr, args = appendLimitOffset(sqlStr, args, limit, offset)
 	return sqlStr, args, nil
@@ -119,12 +121,14 @@ func (e *Executor) buildObjectIDSQL(q *Query, limit, offset int) (string, []inte
 	if err != nil {
 		return "", nil, err
 	}
+	orderBy, orderArgs := e.objectOrderBy()
+	args = append(args, orderArgs...)
 	sqlStr := fmt.Sprintf(`
 		SELECT o.id
 		FROM objects o
 		WHERE %s
-		ORDER BY o.file_path, o.line_start
-	`, whereClause)
+		ORDER BY %s
+	`, whereClause, orderBy)
 
 	sqlStr, args = appendLimitOffset(sqlStr, args, limit, offset)
 	return sqlStr, args, nil
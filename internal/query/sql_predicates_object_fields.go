@@ -63,6 +63,7 @@ func (e *Executor) buildFieldPredicateSQL(p *FieldPredicate, alias, typeName str
 	}
 
 	value = e.normalizeBoolFieldComparisonValue(typeName, p.Field, value, p.CompareOp)
+	value = e.normalizeEnumFieldComparisonValue(typeName, p.Field, value, p.CompareOp)
 
 	if p.CompareOp == CompareNeq {
 		if altValue != "" {
@@ -145,7 +146,7 @@ func (e *Executor) buildDateVirtualFieldPredicateSQL(p *FieldPredicate, alias st
 		return "", nil, err
 	}
 	if !ok {
-		return "", nil, fmt.Errorf("invalid date value for .date: %q (use YYYY-MM-DD, today, tomorrow, or yesterday)", p.Value)
+		return "", nil, fmt.Errorf("invalid date value for .date: %q (use YYYY-MM-DD, today, tomorrow, yesterday, or a range such as this-month)", p.Value)
 	}
 
 	cond := fmt.Sprintf("(%s AND %s)", existsCond, dateCond)
@@ -257,6 +258,29 @@ func (e *Executor) normalizeBoolFieldComparisonValue(typeName, fieldName, value
 	}
 }
 
+// normalizeEnumFieldComparisonValue maps an enum synonym to the value it stands
+// for, since the index stores enum fields in their canonical form.
+func (e *Executor) normalizeEnumFieldComparisonValue(typeName, fieldName, value string, op CompareOp) string {
+	if op != CompareEq && op != CompareNeq {
+		return value
+	}
+	if e.schema == nil || typeName == "" {
+		return value
+	}
+	typeDef := e.schema.Types[typeName]
+	if typeDef == nil {
+		return value
+	}
+	fieldDef := typeDef.Fields[fieldName]
+	if fieldDef == nil {
+		return value
+	}
+	if fieldDef.Type != schema.FieldTypeEnum && fieldDef.Type != schema.FieldTypeEnumArray {
+		return value
+	}
+	return fieldDef.CanonicalEnumValue(value)
+}
+
 func (e *Executor) fieldEqualityMode(typeName, fieldName string) fieldEqualityMode {
 	if e.schema == nil || typeName == "" {
 		return fieldEqualityModeFallback
@@ -676,7 +700,7 @@ func (e *Executor) buildArrayQuantifierPredicateSQL(p *ArrayQuantifierPredicate,
 		}
 	}
 
-	jsonPath := fmt.Sprintf("$.%s", p.Field)
+	jsonPath := jsonFieldPath(p.Field)
 
 	var cond string
 	var args []interface{}
@@ -2,6 +2,9 @@ package readsvc
 
 import (
 	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
 
 	"github.com/aidanlsb/raven/internal/model"
 )
@@ -23,9 +26,63 @@ func Backlinks(rt *Runtime, target string) ([]model.Reference, error) {
 	return rt.DB.Backlinks(target)
 }
 
-func Outlinks(rt *Runtime, source string) ([]model.Reference, error) {
+// BacklinksWithContext returns backlinks with each reference's Context set to
+// the trimmed source line it appears on. Frontmatter references and lines that
+// can no longer be read are left without context.
+//
+// A depth greater than 1 also follows links to the backlinking notes,
+// annotating each reference with its hop distance. A non-empty sourceType
+// keeps only direct backlinks from objects of that type and cannot be
+// combined with a depth greater than 1.
+func BacklinksWithContext(rt *Runtime, target string, depth int, sourceType string) ([]model.Reference, error) {
 	if rt == nil || rt.DB == nil {
 		return nil, fmt.Errorf("runtime with database is required")
 	}
+	var (
+		links []model.Reference
+		err   error
+	)
+	switch {
+	case depth > 1 && sourceType != "":
+		return nil, fmt.Errorf("a source type filter cannot be combined with depth %d", depth)
+	case depth > 1:
+		links, err = rt.DB.BacklinksDepth(target, depth)
+	default:
+		links, err = rt.DB.BacklinksFromType(target, sourceType)
+	}
+	if err != nil {
+		return nil, err
+	}
+
+	fileCache := make(map[string][]string)
+	for i := range links {
+		link := &links[i]
+		if link.Line == nil || *link.Line <= 0 {
+			continue
+		}
+		lines, ok := fileCache[link.FilePath]
+		if !ok {
+			content, readErr := os.ReadFile(filepath.Join(rt.VaultPath, link.FilePath))
+			if readErr == nil {
+				lines = strings.Split(string(content), "\n")
+			}
+			fileCache[link.FilePath] = lines
+		}
+		if index := *link.Line - 1; index < len(lines) {
+			link.Context = strings.TrimSpace(lines[index])
+		}
+	}
+	return links, nil
+}
+
+// Outlinks returns the references made by source. With direct, refs made from
+// within the source's sections are left out.
+func Outlinks(rt *Runtime, source string, direct bool) ([]model.Reference, error) {
+	if rt == nil || rt.DB == nil {
+		return nil, fmt.Errorf("runtime with database is required")
+	}
+	if direct {
+		return rt.DB.DirectOutlinks(source)
+	}
 	return rt.DB.Outlinks(source)
 }
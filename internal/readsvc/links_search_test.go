@@ -0,0 +1,66 @@
+package readsvc
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/config"
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/parser"
+	"github.com/aidanlsb/raven/internal/schema"
+)
+
+func TestBacklinksWithContext(t *testing.T) {
+	t.Parallel()
+
+	vaultPath := t.TempDir()
+	files := map[string]string{
+		"people/freya.md":  "# Freya\n",
+		"notes/standup.md": "# Standup\n\n  - Paired with [[people/freya]] on the release.  \n",
+	}
+
+	db, err := index.OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open in-memory db: %v", err)
+	}
+	t.Cleanup(func() { _ = db.Close() })
+
+	for relPath, content := range files {
+		absPath := filepath.Join(vaultPath, filepath.FromSlash(relPath))
+		if err := os.MkdirAll(filepath.Dir(absPath), 0o755); err != nil {
+			t.Fatalf("create directory: %v", err)
+		}
+		if err := os.WriteFile(absPath, []byte(content), 0o644); err != nil {
+			t.Fatalf("write %s: %v", relPath, err)
+		}
+		doc, err := parser.ParseDocument(content, absPath, vaultPath)
+		if err != nil {
+			t.Fatalf("parse %s: %v", relPath, err)
+		}
+		if err := db.IndexDocument(doc, schema.New()); err != nil {
+			t.Fatalf("index %s: %v", relPath, err)
+		}
+	}
+
+	rt := &Runtime{
+		VaultPath: vaultPath,
+		VaultCfg:  &config.VaultConfig{},
+		DB:        db,
+	}
+
+	links, err := BacklinksWithContext(rt, "people/freya", 1, "")
+	if err != nil {
+		t.Fatalf("unexpected error: %v", err)
+	}
+
+	bodyContext := ""
+	for _, link := range links {
+		if link.Line != nil && *link.Line == 3 {
+			bodyContext = link.Context
+		}
+	}
+	if bodyContext != "- Paired with [[people/freya]] on the release." {
+		t.Fatalf("context = %q, want trimmed source line; links = %+v", bodyContext, links)
+	}
+}
@@ -0,0 +1,141 @@
+package readsvc
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+
+	"github.com/aidanlsb/raven/internal/atomicfile"
+	"github.com/aidanlsb/raven/internal/model"
+)
+
+// backlinkCursorFile records, per target object, the backlinking file most
+// recently opened by `rvn open --backlink` so repeated calls cycle through them.
+const backlinkCursorFile = "open-backlinks.json"
+
+// BacklinkOpenTarget is the file chosen by ResolveBacklinkOpenTarget.
+type BacklinkOpenTarget struct {
+	OpenTarget
+	TargetObjectID string `json:"target_object_id"`
+	Position       int    `json:"position"`
+	BacklinkCount  int    `json:"backlink_count"`
+}
+
+// ResolveBacklinkOpenTarget resolves reference and picks the next file that
+// links to it, after the one recorded by the per-target cursor under .raven/.
+// LineStart is the first line in that file that links to the reference.
+// It also returns the resolved reference target. The cursor is not advanced;
+// call SaveBacklinkOpenCursor once the file has been opened.
+//
+// The backlink target is nil (with no error) when nothing links to the reference.
+// Errors after the reference resolves come from the index.
+func ResolveBacklinkOpenTarget(rt *Runtime, reference string) (*BacklinkOpenTarget, *OpenTarget, error) {
+	target, err := ResolveOpenTarget(rt, reference)
+	if err != nil {
+		return nil, nil, err
+	}
+	if err := ensureReadDB(rt); err != nil {
+		return nil, target, err
+	}
+
+	links, err := Backlinks(rt, target.ObjectID)
+	if err != nil {
+		return nil, target, err
+	}
+	files, firstLines := backlinkFiles(links, target.RelativePath)
+	if len(files) == 0 {
+		return nil, target, nil
+	}
+
+	next := nextBacklinkFile(files, loadBacklinkCursors(rt.VaultPath)[target.ObjectID])
+
+	return &BacklinkOpenTarget{
+		OpenTarget: OpenTarget{
+			Reference:    reference,
+			ObjectID:     rt.VaultCfg.FilePathToObjectID(next),
+			FilePath:     filepath.Join(rt.VaultPath, filepath.FromSlash(next)),
+			RelativePath: next,
+			LineStart:    firstLines[next],
+		},
+		TargetObjectID: target.ObjectID,
+		Position:       sort.SearchStrings(files, next) + 1,
+		BacklinkCount:  len(files),
+	}, target, nil
+}
+
+// SaveBacklinkOpenCursor records picked as the last backlink opened for its
+// target, so the next call moves on to the following file.
+func SaveBacklinkOpenCursor(rt *Runtime, picked *BacklinkOpenTarget) error {
+	cursors := loadBacklinkCursors(rt.VaultPath)
+	cursors[picked.TargetObjectID] = picked.RelativePath
+	return saveBacklinkCursors(rt.VaultPath, cursors)
+}
+
+// backlinkFiles returns the sorted, de-duplicated files containing links,
+// excluding the target's own file, and the first known link line in each.
+func backlinkFiles(links []model.Reference, selfPath string) ([]string, map[string]int) {
+	firstLines := make(map[string]int, len(links))
+	files := make([]string, 0, len(links))
+	for _, link := range links {
+		path := filepath.ToSlash(link.FilePath)
+		if path == "" || path == selfPath {
+			continue
+		}
+		line, seen := firstLines[path]
+		if !seen {
+			files = append(files, path)
+		}
+		if link.Line != nil && (line == 0 || *link.Line < line) {
+			line = *link.Line
+		}
+		firstLines[path] = line
+	}
+	sort.Strings(files)
+	return files, firstLines
+}
+
+// nextBacklinkFile returns the file after last in sorted order, wrapping
+// around. If last is no longer a backlink, the next file after where it
+// would sort is used, so the cycle continues instead of restarting.
+func nextBacklinkFile(files []string, last string) string {
+	if last == "" {
+		return files[0]
+	}
+	i := sort.SearchStrings(files, last)
+	if i < len(files) && files[i] == last {
+		i++
+	}
+	return files[i%len(files)]
+}
+
+func backlinkCursorPath(vaultPath string) string {
+	return filepath.Join(vaultPath, ".raven", backlinkCursorFile)
+}
+
+// loadBacklinkCursors reads the cursor file. A missing or unreadable file
+// just restarts every cycle.
+func loadBacklinkCursors(vaultPath string) map[string]string {
+	cursors := make(map[string]string)
+	data, err := os.ReadFile(backlinkCursorPath(vaultPath))
+	if err != nil {
+		return cursors
+	}
+	if err := json.Unmarshal(data, &cursors); err != nil || cursors == nil {
+		return make(map[string]string)
+	}
+	return cursors
+}
+
+func saveBacklinkCursors(vaultPath string, cursors map[string]string) error {
+	data, err := json.MarshalIndent(cursors, "", "  ")
+	if err != nil {
+		return err
+	}
+	path := backlinkCursorPath(vaultPath)
+	if err := os.MkdirAll(filepath.Dir(path), 0o755); err != nil {
+		return fmt.Errorf("failed to create %s: %w", filepath.Dir(path), err)
+	}
+	return atomicfile.WriteFile(path, append(data, '\n'), 0o644)
+}
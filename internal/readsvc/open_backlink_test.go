@@ -0,0 +1,131 @@
+package readsvc
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/config"
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/parser"
+	"github.com/aidanlsb/raven/internal/schema"
+)
+
+func TestNextBacklinkFileCycles(t *testing.T) {
+	t.Parallel()
+
+	files := []string{"a.md", "c.md", "e.md"}
+	tests := []struct {
+		last string
+		want string
+	}{
+		{last: "", want: "a.md"},
+		{last: "a.md", want: "c.md"},
+		{last: "c.md", want: "e.md"},
+		{last: "e.md", want: "a.md"},
+		{last: "b.md", want: "c.md"}, // removed backlink continues from its sort position
+		{last: "z.md", want: "a.md"},
+	}
+	for _, tt := range tests {
+		if got := nextBacklinkFile(files, tt.last); got != tt.want {
+			t.Errorf("nextBacklinkFile(%q) = %q, want %q", tt.last, got, tt.want)
+		}
+	}
+}
+
+func TestResolveBacklinkOpenTarget(t *testing.T) {
+	t.Parallel()
+
+	vaultPath := t.TempDir()
+	files := map[string]string{
+		"people/freya.md": "# Freya\n\nLinks to [[people/freya]] itself.\n",
+		"notes/beta.md":   "# Beta\n\nMet [[people/freya]].\n\nAgain [[people/freya]].\n",
+		"notes/alpha.md":  "# Alpha\n\nSee [[people/freya]].\n",
+		"notes/orphan.md": "# Orphan\n",
+		"notes/gamma.md":  "# Gamma\n\nSee [[notes/alpha]].\n",
+	}
+
+	db, err := index.OpenInMemory()
+	if err != nil {
+		t.Fatalf("failed to open in-memory db: %v", err)
+	}
+	t.Cleanup(func() { _ = db.Close() })
+
+	for relPath, content := range files {
+		absPath := filepath.Join(vaultPath, filepath.FromSlash(relPath))
+		if err := os.MkdirAll(filepath.Dir(absPath), 0o755); err != nil {
+			t.Fatalf("create directory: %v", err)
+		}
+		if err := os.WriteFile(absPath, []byte(content), 0o644); err != nil {
+			t.Fatalf("write %s: %v", relPath, err)
+		}
+		doc, err := parser.ParseDocument(content, absPath, vaultPath)
+		if err != nil {
+			t.Fatalf("parse %s: %v", relPath, err)
+		}
+		if err := db.IndexDocument(doc, schema.New()); err != nil {
+			t.Fatalf("index %s: %v", relPath, err)
+		}
+	}
+
+	rt := &Runtime{
+		VaultPath: vaultPath,
+		VaultCfg:  &config.VaultConfig{},
+		DB:        db,
+	}
+
+	t.Run("cycles through backlinking files across calls", func(t *testing.T) {
+		want := []string{"notes/alpha.md", "notes/beta.md", "notes/alpha.md"}
+		for i, wantFile := range want {
+			picked, target, err := ResolveBacklinkOpenTarget(rt, "freya")
+			if err != nil {
+				t.Fatalf("call %d: unexpected error: %v", i+1, err)
+			}
+			if picked == nil {
+				t.Fatalf("call %d: expected a backlink target", i+1)
+			}
+			if target.ObjectID != "people/freya" {
+				t.Fatalf("call %d: target = %q, want people/freya", i+1, target.ObjectID)
+			}
+			if picked.RelativePath != wantFile {
+				t.Fatalf("call %d: picked %q, want %q", i+1, picked.RelativePath, wantFile)
+			}
+			if picked.BacklinkCount != 2 {
+				t.Fatalf("call %d: backlink_count = %d, want 2 (self links excluded, files de-duplicated)", i+1, picked.BacklinkCount)
+			}
+			if picked.LineStart != 3 {
+				t.Fatalf("call %d: line_start = %d, want first link line 3", i+1, picked.LineStart)
+			}
+			if err := SaveBacklinkOpenCursor(rt, picked); err != nil {
+				t.Fatalf("call %d: save cursor: %v", i+1, err)
+			}
+		}
+	})
+
+	t.Run("cursor does not advance until saved", func(t *testing.T) {
+		first, _, err := ResolveBacklinkOpenTarget(rt, "freya")
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+		second, _, err := ResolveBacklinkOpenTarget(rt, "freya")
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+		if first.RelativePath != second.RelativePath {
+			t.Fatalf("unsaved cursor advanced: %q then %q", first.RelativePath, second.RelativePath)
+		}
+	})
+
+	t.Run("no backlinks returns nil target without error", func(t *testing.T) {
+		picked, target, err := ResolveBacklinkOpenTarget(rt, "notes/orphan")
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+		if picked != nil {
+			t.Fatalf("expected no backlink target, got %+v", picked)
+		}
+		if target == nil || target.ObjectID != "notes/orphan" {
+			t.Fatalf("expected resolved target notes/orphan, got %+v", target)
+		}
+	})
+}
@@ -2,6 +2,9 @@ package readsvc
 
 import (
 	"fmt"
+	"math/rand/v2"
+	"sort"
+	"strings"
 
 	"github.com/aidanlsb/raven/internal/model"
 	"github.com/aidanlsb/raven/internal/query"
@@ -13,6 +16,13 @@ type ExecuteQueryRequest struct {
 	Limit       int
 	Offset      int
 	CountOnly   bool
+	// Sample, when > 0, returns a random subset of at most Sample matches.
+	// The same Seed always selects the same subset for an unchanged index.
+	Sample int
+	Seed   int64
+	// Sort orders type query results by a frontmatter field; a leading '-'
+	// sorts descending. Empty keeps file order.
+	Sort string
 }
 
 type ExecuteQueryResult struct {
@@ -39,6 +49,22 @@ func ExecuteQuery(rt *Runtime, req ExecuteQueryRequest) (*ExecuteQueryResult, er
 	if req.Offset < 0 {
 		return nil, fmt.Errorf("offset must be >= 0")
 	}
+	if req.Sample < 0 {
+		return nil, fmt.Errorf("sample must be >= 0")
+	}
+	if req.Sample > 0 {
+		if req.Limit > 0 || req.Offset > 0 || req.CountOnly {
+			return nil, fmt.Errorf("sample cannot be combined with limit, offset, or count-only")
+		}
+		full := req
+		full.Sample = 0
+		result, err := ExecuteQuery(rt, full)
+		if err != nil {
+			return nil, err
+		}
+		sampleQueryResult(result, req.Sample, req.Seed)
+		return result, nil
+	}
 
 	q, err := query.Parse(req.QueryString)
 	if err != nil {
@@ -55,6 +81,15 @@ func ExecuteQuery(rt *Runtime, req ExecuteQueryRequest) (*ExecuteQueryResult, er
 	executor := query.NewExecutor(rt.DB.DB())
 	executor.SetDailyDirectory(rt.VaultCfg.GetDailyDirectory())
 	executor.SetSchema(rt.Schema)
+	if req.Sort != "" {
+		if q.Type != query.QueryTypeObject {
+			return nil, fmt.Errorf("sort is only supported for type queries")
+		}
+		field, descending := strings.CutPrefix(req.Sort, "-")
+		if err := executor.SetObjectSort(field, descending); err != nil {
+			return nil, err
+		}
+	}
 
 	queryKind := "trait"
 	if q.Type == query.QueryTypeObject {
@@ -286,3 +321,38 @@ func ExecuteQuery(rt *Runtime, req ExecuteQueryRequest) (*ExecuteQueryResult, er
 	result.Returned = len(rows)
 	return result, nil
 }
+
+// sampleQueryResult narrows result to n randomly chosen rows using a seeded
+// RNG. Selected rows keep their original query order so output stays stable
+// for a given seed. Total continues to report the full match count.
+func sampleQueryResult(result *ExecuteQueryResult, n int, seed int64) {
+	count := result.Returned
+	if n >= count {
+		return
+	}
+	rng := rand.New(rand.NewPCG(uint64(seed), 0))
+	picked := rng.Perm(count)[:n]
+	sort.Ints(picked)
+
+	switch {
+	case result.IDs != nil:
+		result.IDs = pickRows(result.IDs, picked)
+	case result.Objects != nil:
+		result.Objects = pickRows(result.Objects, picked)
+	case result.Traits != nil:
+		result.Traits = pickRows(result.Traits, picked)
+	case result.Assets != nil:
+		result.Assets = pickRows(result.Assets, picked)
+	case result.Sections != nil:
+		result.Sections = pickRows(result.Sections, picked)
+	}
+	result.Returned = n
+}
+
+func pickRows[T any](rows []T, indices []int) []T {
+	out := make([]T, 0, len(indices))
+	for _, idx := range indices {
+		out = append(out, rows[idx])
+	}
+	return out
+}
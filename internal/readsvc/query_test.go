@@ -127,6 +127,65 @@ func TestExecuteQuery_TraitModes(t *testing.T) {
 	}
 }
 
+func TestExecuteQuery_SampleIsReproducibleWithSeed(t *testing.T) {
+	t.Parallel()
+	rt := seededRuntime(t)
+
+	_, err := rt.DB.DB().Exec(`
+		INSERT INTO objects (id, file_path, type, line_start, fields) VALUES
+			('project/beacon', 'projects/beacon.md', 'project', 1, '{}'),
+			('project/comet', 'projects/comet.md', 'project', 1, '{}'),
+			('project/delta', 'projects/delta.md', 'project', 1, '{}'),
+			('project/ember', 'projects/ember.md', 'project', 1, '{}')
+	`)
+	if err != nil {
+		t.Fatalf("failed to seed extra projects: %v", err)
+	}
+
+	sample := func(seed int64) []string {
+		t.Helper()
+		result, err := ExecuteQuery(rt, ExecuteQueryRequest{QueryString: "type:project", IDsOnly: true, Sample: 3, Seed: seed})
+		if err != nil {
+			t.Fatalf("unexpected sample error: %v", err)
+		}
+		if result.Total != 6 || result.Returned != 3 || len(result.IDs) != 3 {
+			t.Fatalf("unexpected sample result: %#v", result)
+		}
+		return result.IDs
+	}
+
+	first := sample(42)
+	second := sample(42)
+	if strings.Join(first, ",") != strings.Join(second, ",") {
+		t.Fatalf("expected same seed to yield same sample, got %v and %v", first, second)
+	}
+
+	rows, err := ExecuteQuery(rt, ExecuteQueryRequest{QueryString: "type:project", Sample: 3, Seed: 42})
+	if err != nil {
+		t.Fatalf("unexpected sample error: %v", err)
+	}
+	rowIDs := make([]string, 0, len(rows.Objects))
+	for _, obj := range rows.Objects {
+		rowIDs = append(rowIDs, obj.ID)
+	}
+	if strings.Join(rowIDs, ",") != strings.Join(first, ",") {
+		t.Fatalf("expected full rows to match ids-only sample, got %v and %v", rowIDs, first)
+	}
+
+	all, err := ExecuteQuery(rt, ExecuteQueryRequest{QueryString: "type:project", Sample: 10, Seed: 1})
+	if err != nil {
+		t.Fatalf("unexpected oversized sample error: %v", err)
+	}
+	if all.Returned != 6 {
+		t.Fatalf("expected oversized sample to return all matches, got %#v", all)
+	}
+
+	_, err = ExecuteQuery(rt, ExecuteQueryRequest{QueryString: "type:project", Sample: 2, Limit: 1})
+	if err == nil {
+		t.Fatalf("expected sample with limit to fail")
+	}
+}
+
 func TestExecuteQuery_RefPredicateUsesLazyResolver(t *testing.T) {
 	t.Parallel()
 	rt := seededRuntime(t)
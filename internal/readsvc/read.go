@@ -234,6 +234,34 @@ func readRawRange(content string, lineCount, reqStartLine, reqEndLine int, inclu
 	return result, nil
 }
 
+// ResolvedBody returns the body of a resolved reference: the file content
+// after frontmatter for file objects, or the heading's line range for sections.
+func ResolvedBody(resolved *ResolveResult) (string, error) {
+	contentBytes, err := os.ReadFile(resolved.FilePath)
+	if err != nil {
+		return "", err
+	}
+	content := string(contentBytes)
+	if !resolved.IsSection || resolved.LineStart <= 0 {
+		_, body := splitFrontmatterBody(content)
+		return body, nil
+	}
+
+	lineCount := strings.Count(content, "\n")
+	if len(content) > 0 && !strings.HasSuffix(content, "\n") {
+		lineCount++
+	}
+	rangeEnd := lineCount
+	if resolved.SubtreeLineEnd != nil {
+		rangeEnd = *resolved.SubtreeLineEnd
+	}
+	rawResult, err := readRawRange(content, lineCount, resolved.LineStart, rangeEnd, false)
+	if err != nil {
+		return "", err
+	}
+	return rawResult.Content, nil
+}
+
 func splitFrontmatterBody(content string) (frontmatter, body string) {
 	lines := strings.Split(content, "\n")
 	_, endLine, ok := parser.FrontmatterBounds(lines)
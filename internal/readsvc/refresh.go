@@ -5,7 +5,6 @@ import (
 
 	"github.com/aidanlsb/raven/internal/config"
 	ravenignore "github.com/aidanlsb/raven/internal/ignore"
-	"github.com/aidanlsb/raven/internal/parser"
 	"github.com/aidanlsb/raven/internal/schema"
 	"github.com/aidanlsb/raven/internal/vault"
 )
@@ -62,7 +61,12 @@ func SmartReindex(rt *Runtime) (int, error) {
 		return 0, err
 	}
 
-	walkOpts := &vault.WalkOptions{ParseOptions: buildParseOptions(vaultCfg), ExcludeMatcher: matcher}
+	// Resolve refs once after the walk instead of re-checking every dangling
+	// ref each time a refreshed file adds an object.
+	rt.DB.SetAutoResolveRefs(false)
+	defer rt.DB.SetAutoResolveRefs(true)
+
+	walkOpts := &vault.WalkOptions{ParseOptions: vault.ParseOptionsFromConfig(vaultCfg), ExcludeMatcher: matcher, FollowSymlinks: vaultCfg.FollowSymlinks}
 	reindexed := 0
 	err = vault.WalkMarkdownFilesWithOptions(rt.VaultPath, walkOpts, func(result vault.WalkResult) error {
 		if result.Error != nil {
@@ -85,6 +89,12 @@ func SmartReindex(rt *Runtime) (int, error) {
 		return 0, err
 	}
 
+	if reindexed > 0 {
+		if _, err := rt.DB.ResolveReferencesWithSchema(vaultCfg.GetDailyDirectory(), sch); err != nil {
+			return reindexed, err
+		}
+	}
+
 	return reindexed, nil
 }
 
@@ -131,13 +141,3 @@ func filterIncluded(paths []string, matcher *ravenignore.Matcher) []string {
 	}
 	return out
 }
-
-func buildParseOptions(vaultCfg *config.VaultConfig) *parser.ParseOptions {
-	if vaultCfg == nil || !vaultCfg.HasDirectoriesConfig() {
-		return nil
-	}
-	return &parser.ParseOptions{
-		ObjectsRoot: vaultCfg.GetObjectsRoot(),
-		PagesRoot:   vaultCfg.GetPagesRoot(),
-	}
-}
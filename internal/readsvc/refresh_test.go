@@ -0,0 +1,53 @@
+package readsvc
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/config"
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/schema"
+)
+
+func TestSmartReindexResolvesRefsBetweenRefreshedFiles(t *testing.T) {
+	t.Parallel()
+
+	vaultPath := t.TempDir()
+	files := map[string]string{
+		"notes/standup.md": "# Standup\n\nMet with [[people/freya]].\n",
+		"people/freya.md":  "# Freya\n",
+	}
+	for relPath, content := range files {
+		fullPath := filepath.Join(vaultPath, relPath)
+		if err := os.MkdirAll(filepath.Dir(fullPath), 0o755); err != nil {
+			t.Fatalf("create %s directory: %v", relPath, err)
+		}
+		if err := os.WriteFile(fullPath, []byte(content), 0o644); err != nil {
+			t.Fatalf("write %s: %v", relPath, err)
+		}
+	}
+
+	db, err := index.OpenInMemory()
+	if err != nil {
+		t.Fatalf("open in-memory index: %v", err)
+	}
+	t.Cleanup(func() { _ = db.Close() })
+
+	rt := &Runtime{VaultPath: vaultPath, VaultCfg: &config.VaultConfig{}, Schema: schema.New(), DB: db}
+	reindexed, err := SmartReindex(rt)
+	if err != nil {
+		t.Fatalf("SmartReindex failed: %v", err)
+	}
+	if reindexed != 2 {
+		t.Fatalf("reindexed = %d, want 2", reindexed)
+	}
+
+	var targetID *string
+	if err := db.DB().QueryRow(`SELECT target_id FROM refs WHERE target_raw = ?`, "people/freya").Scan(&targetID); err != nil {
+		t.Fatalf("query refs: %v", err)
+	}
+	if targetID == nil || *targetID != "people/freya" {
+		t.Fatalf("expected ref to resolve to people/freya, got %v", targetID)
+	}
+}
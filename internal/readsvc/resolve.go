@@ -19,17 +19,21 @@ type ResolveResult struct {
 	ObjectID       string
 	FilePath       string
 	IsSection      bool
+	IsAsset        bool
 	FileObjectID   string
 	LineStart      int
 	LineEnd        *int
 	SubtreeLineEnd *int
 	MatchSource    string
+	// Trace is the resolver's decision trace; only set when explaining.
+	Trace []resolver.TraceStep
 }
 
 type AmbiguousRefError struct {
 	Reference    string
 	Matches      []string
 	MatchSources map[string]string
+	Trace        []resolver.TraceStep
 }
 
 func (e *AmbiguousRefError) Error() string {
@@ -39,6 +43,7 @@ func (e *AmbiguousRefError) Error() string {
 type RefNotFoundError struct {
 	Reference string
 	Detail    string
+	Trace     []resolver.TraceStep
 }
 
 func (e *RefNotFoundError) Error() string {
@@ -63,6 +68,7 @@ type resolveOperation struct {
 	db       *index.Database
 	closeDB  bool
 	resolver *resolver.Resolver
+	explain  bool
 }
 
 func newResolveOperation(rt *Runtime) (*resolveOperation, error) {
@@ -138,10 +144,15 @@ func (op *resolveOperation) resolveReference(reference string, allowMissing bool
 	}
 
 	ref := strings.TrimSpace(reference)
-	literalPathResult, err := tryLiteralPath(ref, op.rt.VaultPath, op.rt.VaultCfg)
+	literalPathResult, err := tryLiteralPath(ref, op.rt.VaultPath, op.rt.VaultCfg, op.rt.VaultCfg != nil && op.rt.VaultCfg.FollowSymlinks)
 	if err != nil {
 		return nil, err
 	}
+	var trace []resolver.TraceStep
+	if literalPathResult != nil && op.explain {
+		trace = []resolver.TraceStep{{Rule: "literal_path", Candidates: []string{literalPathResult.ObjectID}}}
+		literalPathResult.Trace = trace
+	}
 	if literalPathResult != nil && !dates.IsValidDate(ref) {
 		return literalPathResult, nil
 	}
@@ -151,9 +162,16 @@ func (op *resolveOperation) resolveReference(reference string, allowMissing bool
 		return nil, err
 	}
 
-	resolved := res.Resolve(ref)
+	var resolved resolver.ResolveResult
+	if op.explain {
+		resolved = res.Explain(ref)
+		trace = append(trace, resolved.Trace...)
+	} else {
+		resolved = res.Resolve(ref)
+	}
 	if literalPathResult != nil {
 		if ambiguousErr := isoDateLiteralPathAmbiguity(ref, literalPathResult, resolved); ambiguousErr != nil {
+			ambiguousErr.Trace = trace
 			return nil, ambiguousErr
 		}
 	}
@@ -162,13 +180,15 @@ func (op *resolveOperation) resolveReference(reference string, allowMissing bool
 			Reference:    ref,
 			Matches:      resolved.Matches,
 			MatchSources: resolved.MatchSources,
+			Trace:        trace,
 		}
 	}
 	if resolved.TargetID == "" {
 		if literalPathResult != nil {
+			literalPathResult.Trace = trace
 			return literalPathResult, nil
 		}
-		return nil, &RefNotFoundError{Reference: ref}
+		return nil, &RefNotFoundError{Reference: ref, Trace: trace}
 	}
 
 	matchSource := ""
@@ -178,6 +198,7 @@ func (op *resolveOperation) resolveReference(reference string, allowMissing bool
 	result := &ResolveResult{
 		ObjectID:    resolved.TargetID,
 		MatchSource: matchSource,
+		Trace:       trace,
 	}
 
 	if idx := strings.Index(resolved.TargetID, "#"); idx >= 0 {
@@ -191,6 +212,7 @@ func (op *resolveOperation) resolveReference(reference string, allowMissing bool
 		return nil, err
 	} else if ok {
 		result.FilePath = assetPath
+		result.IsAsset = true
 		return result, nil
 	}
 
@@ -204,6 +226,7 @@ func (op *resolveOperation) resolveReference(reference string, allowMissing bool
 		return nil, &RefNotFoundError{
 			Reference: ref,
 			Detail:    fmt.Sprintf("resolved to '%s' but file not found", resolved.TargetID),
+			Trace:     trace,
 		}
 	}
 
@@ -259,7 +282,7 @@ func tryResolvedAssetPath(vaultPath, targetID string) (string, bool, error) {
 	return fullPath, true, nil
 }
 
-func isoDateLiteralPathAmbiguity(reference string, literalPathResult *ResolveResult, resolved resolver.ResolveResult) error {
+func isoDateLiteralPathAmbiguity(reference string, literalPathResult *ResolveResult, resolved resolver.ResolveResult) *AmbiguousRefError {
 	if literalPathResult == nil || !dates.IsValidDate(reference) {
 		return nil
 	}
@@ -319,6 +342,9 @@ func (op *resolveOperation) resolveReferenceWithDynamicDates(reference string, a
 		if dynErr != nil {
 			return nil, dynErr
 		}
+		if op.explain {
+			dynResult.Trace = []resolver.TraceStep{{Rule: "dynamic_date", Candidates: []string{dynResult.ObjectID}}}
+		}
 		return dynResult, nil
 	}
 
@@ -350,9 +376,22 @@ func ResolveReferenceWithDynamicDates(reference string, rt *Runtime, allowDynami
 	return op.resolveReferenceWithDynamicDates(reference, allowDynamicMissing)
 }
 
+// ExplainReferenceWithDynamicDates resolves like ResolveReferenceWithDynamicDates
+// and records the resolver's decision trace on the result, or on the
+// AmbiguousRefError / RefNotFoundError when resolution fails.
+func ExplainReferenceWithDynamicDates(reference string, rt *Runtime, allowDynamicMissing bool) (*ResolveResult, error) {
+	op, err := newResolveOperation(rt)
+	if err != nil {
+		return nil, err
+	}
+	defer op.Close()
+	op.explain = true
+	return op.resolveReferenceWithDynamicDates(reference, allowDynamicMissing)
+}
+
 func tryLiteralPath(reference, vaultPath string, vaultCfg interface {
 	FilePathToObjectID(string) string
-}) (*ResolveResult, error) {
+}, followSymlinks bool) (*ResolveResult, error) {
 	candidates := []string{reference}
 	if !strings.HasSuffix(reference, ".md") {
 		candidates = append(candidates, reference+".md")
@@ -360,7 +399,7 @@ func tryLiteralPath(reference, vaultPath string, vaultCfg interface {
 
 	for _, candidate := range candidates {
 		fullPath := filepath.Join(vaultPath, candidate)
-		if err := paths.ValidateWithinVault(vaultPath, fullPath); err != nil {
+		if err := paths.ValidateNotePath(vaultPath, fullPath, followSymlinks && paths.HasMDExtension(candidate)); err != nil {
 			continue
 		}
 		if info, err := os.Stat(fullPath); err == nil && !info.IsDir() {
@@ -372,6 +411,7 @@ func tryLiteralPath(reference, vaultPath string, vaultCfg interface {
 				ObjectID:     objectID,
 				FilePath:     fullPath,
 				IsSection:    false,
+				IsAsset:      !strings.HasSuffix(candidate, ".md"),
 				FileObjectID: objectID,
 				MatchSource:  "literal_path",
 			}, nil
@@ -0,0 +1,98 @@
+package reindexsvc
+
+import (
+	"fmt"
+	"os"
+	"sort"
+	"strings"
+
+	"github.com/aidanlsb/raven/internal/config"
+	ravenignore "github.com/aidanlsb/raven/internal/ignore"
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/vault"
+)
+
+type DiffRequest struct {
+	VaultPath string
+}
+
+type DiffResult struct {
+	New      []string
+	Modified []string
+	Deleted  []string
+	// Indexed is false when the vault has no index yet, in which case every
+	// managed file is reported as new.
+	Indexed bool
+}
+
+func (r *DiffResult) Data() map[string]interface{} {
+	return map[string]interface{}{
+		"new":      r.New,
+		"modified": r.Modified,
+		"deleted":  r.Deleted,
+		"indexed":  r.Indexed,
+		"stale":    len(r.New)+len(r.Modified)+len(r.Deleted) > 0,
+	}
+}
+
+// Diff reports which managed files are new, modified, or deleted since the
+// last index, using the same mtime comparison incremental reindex relies on.
+// The index is only read: it is never created, rebuilt, or updated.
+func Diff(req DiffRequest) (*DiffResult, error) {
+	vaultPath := strings.TrimSpace(req.VaultPath)
+	if vaultPath == "" {
+		return nil, newError(CodeInvalidInput, "vault path is required", "", nil)
+	}
+
+	vaultCfg, err := config.LoadVaultConfig(vaultPath)
+	if err != nil {
+		return nil, newError(CodeConfigInvalid, fmt.Sprintf("failed to load raven.yaml: %v", err), "Fix raven.yaml and try again", err)
+	}
+	if vaultCfg == nil {
+		vaultCfg = &config.VaultConfig{}
+	}
+	excludeMatcher, err := ravenignore.NewMatcher(vaultCfg.GetExcludePatterns())
+	if err != nil {
+		return nil, newError(CodeConfigInvalid, fmt.Sprintf("invalid exclude config: %v", err), "Fix raven.yaml exclude patterns and try again", err)
+	}
+
+	onDisk := []string{}
+	walkOpts := &vault.WalkOptions{ExcludeMatcher: excludeMatcher, FollowSymlinks: vaultCfg.FollowSymlinks, SkipParse: true}
+	err = vault.WalkMarkdownFilesWithOptions(vaultPath, walkOpts, func(walkResult vault.WalkResult) error {
+		if walkResult.Error == nil {
+			onDisk = append(onDisk, walkResult.RelativePath)
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, newError(CodeFileReadError, fmt.Sprintf("error walking vault: %v", err), "", err)
+	}
+	err = vault.WalkAssetFilesWithOptions(vaultPath, vaultCfg, &vault.AssetWalkOptions{ExcludeMatcher: excludeMatcher}, func(walkResult vault.AssetWalkResult) error {
+		if walkResult.Error == nil && walkResult.Asset != nil {
+			onDisk = append(onDisk, walkResult.RelativePath)
+		}
+		return nil
+	})
+	if err != nil {
+		return nil, newError(CodeFileReadError, fmt.Sprintf("error walking asset files: %v", err), "", err)
+	}
+
+	// index.Open creates a missing database, so check first rather than
+	// leaving an empty index behind.
+	if _, statErr := os.Stat(index.DBPath(vaultPath)); os.IsNotExist(statErr) {
+		sort.Strings(onDisk)
+		return &DiffResult{New: onDisk, Modified: []string{}, Deleted: []string{}}, nil
+	}
+
+	db, err := index.Open(vaultPath)
+	if err != nil {
+		return nil, newError(CodeDatabaseError, fmt.Sprintf("failed to open database: %v", err), "Run 'rvn reindex' to rebuild the database", err)
+	}
+	defer db.Close()
+
+	diff, err := db.DiffFiles(vaultPath, onDisk)
+	if err != nil {
+		return nil, newError(CodeDatabaseError, fmt.Sprintf("failed to compare index with vault: %v", err), "Run 'rvn reindex --full' to rebuild the database", err)
+	}
+	return &DiffResult{New: diff.New, Modified: diff.Modified, Deleted: diff.Deleted, Indexed: true}, nil
+}
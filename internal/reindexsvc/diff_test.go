@@ -0,0 +1,95 @@
+package reindexsvc
+
+import (
+	"os"
+	"path/filepath"
+	"reflect"
+	"testing"
+	"time"
+
+	"github.com/aidanlsb/raven/internal/index"
+)
+
+func TestDiffReportsNewModifiedAndDeletedFiles(t *testing.T) {
+	t.Parallel()
+
+	vaultPath := t.TempDir()
+	writeTestFile(t, vaultPath, "same.md", "# Same\n")
+	writeTestFile(t, vaultPath, "notes/changed.md", "# Changed\n")
+	writeTestFile(t, vaultPath, "notes/gone.md", "# Gone\n")
+	if _, err := Run(RunRequest{VaultPath: vaultPath, Full: true}); err != nil {
+		t.Fatalf("initial Run returned error: %v", err)
+	}
+
+	writeTestFile(t, vaultPath, "notes/added.md", "# Added\n")
+	writeTestFile(t, vaultPath, "notes/changed.md", "# Changed\n\nMore text.\n")
+	later := time.Now().Add(time.Hour)
+	if err := os.Chtimes(filepath.Join(vaultPath, "notes/changed.md"), later, later); err != nil {
+		t.Fatalf("chtimes notes/changed.md: %v", err)
+	}
+	if err := os.Remove(filepath.Join(vaultPath, "notes/gone.md")); err != nil {
+		t.Fatalf("remove notes/gone.md: %v", err)
+	}
+
+	result, err := Diff(DiffRequest{VaultPath: vaultPath})
+	if err != nil {
+		t.Fatalf("Diff returned error: %v", err)
+	}
+	if !result.Indexed {
+		t.Fatal("expected Indexed to be true after a reindex")
+	}
+	if want := []string{"notes/added.md"}; !reflect.DeepEqual(result.New, want) {
+		t.Errorf("new = %#v, want %#v", result.New, want)
+	}
+	if want := []string{"notes/changed.md"}; !reflect.DeepEqual(result.Modified, want) {
+		t.Errorf("modified = %#v, want %#v", result.Modified, want)
+	}
+	if want := []string{"notes/gone.md"}; !reflect.DeepEqual(result.Deleted, want) {
+		t.Errorf("deleted = %#v, want %#v", result.Deleted, want)
+	}
+
+	// Diff only reads the index, so a reindex still sees the same changes.
+	run, err := Run(RunRequest{VaultPath: vaultPath})
+	if err != nil {
+		t.Fatalf("incremental Run returned error: %v", err)
+	}
+	if run.FilesIndexed != 2 || len(run.DeletedFiles) != 1 {
+		t.Fatalf("files indexed = %d, deleted = %#v; want 2 and notes/gone.md", run.FilesIndexed, run.DeletedFiles)
+	}
+
+	result, err = Diff(DiffRequest{VaultPath: vaultPath})
+	if err != nil {
+		t.Fatalf("Diff after reindex returned error: %v", err)
+	}
+	if len(result.New)+len(result.Modified)+len(result.Deleted) != 0 {
+		t.Fatalf("expected no changes after reindex, got %+v", result)
+	}
+}
+
+func TestDiffWithoutIndexListsEverythingAsNew(t *testing.T) {
+	t.Parallel()
+
+	vaultPath := t.TempDir()
+	writeTestFile(t, vaultPath, "b.md", "# B\n")
+	writeTestFile(t, vaultPath, "a.md", "# A\n")
+
+	result, err := Diff(DiffRequest{VaultPath: vaultPath})
+	if err != nil {
+		t.Fatalf("Diff returned error: %v", err)
+	}
+	if result.Indexed {
+		t.Fatal("expected Indexed to be false without an index")
+	}
+	if want := []string{"a.md", "b.md"}; !reflect.DeepEqual(result.New, want) {
+		t.Fatalf("new = %#v, want %#v", result.New, want)
+	}
+	if _, err := os.Stat(index.DBPath(vaultPath)); !os.IsNotExist(err) {
+		t.Fatalf("expected Diff not to create an index, stat err = %v", err)
+	}
+}
+
+func TestDiffInvalidInput(t *testing.T) {
+	t.Parallel()
+	_, err := Diff(DiffRequest{VaultPath: "  "})
+	assertReindexCode(t, err, CodeInvalidInput)
+}
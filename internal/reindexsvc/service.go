@@ -13,6 +13,7 @@ import (
 	ravenignore "github.com/aidanlsb/raven/internal/ignore"
 	"github.com/aidanlsb/raven/internal/index"
 	"github.com/aidanlsb/raven/internal/parser"
+	"github.com/aidanlsb/raven/internal/paths"
 	"github.com/aidanlsb/raven/internal/schema"
 	"github.com/aidanlsb/raven/internal/vault"
 )
@@ -69,9 +70,12 @@ func AsError(err error) (*Error, bool) {
 
 type RunRequest struct {
 	VaultPath string
-	Full      bool
-	DryRun    bool
-	Context   context.Context
+	// Path limits the reindex to one file or directory (vault-relative or
+	// absolute). Everything under it is reparsed regardless of mtime.
+	Path    string
+	Full    bool
+	DryRun  bool
+	Context context.Context
 }
 
 type RunResult struct {
@@ -79,6 +83,7 @@ type RunResult struct {
 	FilesSkipped  int
 	FilesDeleted  int
 	FilesExcluded int
+	Path          string
 	Objects       int
 	Traits        int
 	References    int
@@ -114,6 +119,9 @@ func (r *RunResult) Data() map[string]interface{} {
 		"dry_run":        r.DryRun,
 		"errors":         r.Errors,
 	}
+	if r.Path != "" {
+		data["path"] = r.Path
+	}
 	if r.Incremental {
 		data["stale_files"] = r.StaleFiles
 		data["deleted_files"] = r.DeletedFiles
@@ -150,6 +158,14 @@ func Run(req RunRequest) (*RunResult, error) {
 		vaultCfg = &config.VaultConfig{}
 	}
 
+	scopeAbs, scopeRel, err := resolveScope(vaultPath, req.Path, vaultCfg.FollowSymlinks)
+	if err != nil {
+		return nil, err
+	}
+	if scopeRel != "" && req.Full {
+		return nil, newError(CodeInvalidInput, "cannot combine a path with --full", "Reindex the path, or run 'rvn reindex --full' for the whole vault", nil)
+	}
+
 	db, wasRebuilt, err := index.OpenWithRebuild(vaultPath)
 	if err != nil {
 		return nil, newError(CodeDatabaseError, fmt.Sprintf("failed to open database: %v", err), "Run 'rvn reindex' to rebuild the database", err)
@@ -157,8 +173,13 @@ func Run(req RunRequest) (*RunResult, error) {
 	defer db.Close()
 
 	incremental := !req.Full
+	var rebuildWarning string
 	if wasRebuilt {
 		incremental = false
+		if scopeRel != "" {
+			rebuildWarning = fmt.Sprintf("index was rebuilt, so the whole vault was reindexed instead of %s", scopeRel)
+			scopeAbs, scopeRel = "", ""
+		}
 	}
 
 	if !incremental && !req.DryRun {
@@ -176,9 +197,14 @@ func Run(req RunRequest) (*RunResult, error) {
 		// Bulk reindex always does a full resolver pass after indexing the walk set.
 		// Avoid rebuilding whole-vault resolver state once per file on the hot path.
 		db.SetAutoResolveRefs(false)
+
+		if err := db.BeginBulk(); err != nil {
+			return nil, newError(CodeDatabaseError, fmt.Sprintf("failed to configure database for reindex: %v", err), "", err)
+		}
+		defer func() { _ = db.EndBulk() }()
 	}
 
-	parseOpts := buildParseOptions(vaultCfg)
+	parseOpts := vault.ParseOptionsFromConfig(vaultCfg)
 	excludeMatcher, err := ravenignore.NewMatcher(vaultCfg.GetExcludePatterns())
 	if err != nil {
 		return nil, newError(CodeConfigInvalid, fmt.Sprintf("invalid exclude config: %v", err), "Fix raven.yaml exclude patterns and try again", err)
@@ -188,6 +214,7 @@ func Run(req RunRequest) (*RunResult, error) {
 		SchemaRebuilt:   wasRebuilt,
 		Incremental:     incremental,
 		DryRun:          req.DryRun,
+		Path:            scopeRel,
 		Errors:          []string{},
 		StaleFiles:      []string{},
 		DeletedFiles:    []string{},
@@ -208,6 +235,9 @@ func Run(req RunRequest) (*RunResult, error) {
 	dryRunFileStats := make(map[string]index.IndexStats)
 	dryRunAssetFiles := make(map[string]struct{})
 	dryRunStats := index.IndexStats{}
+	if rebuildWarning != "" {
+		result.WarningMessages = append(result.WarningMessages, rebuildWarning)
+	}
 
 	trashRemoved, err := db.RemoveFilesWithPrefix(".trash/")
 	if err != nil {
@@ -217,7 +247,18 @@ func Run(req RunRequest) (*RunResult, error) {
 		result.WarningMessages = append(result.WarningMessages, fmt.Sprintf("Cleaned up %d files from .trash/ in index", trashRemoved))
 	}
 
-	if incremental {
+	if scopeRel != "" {
+		removed, scopeErr := pruneScope(db, vaultPath, scopeRel, excludeMatcher, req.DryRun, result)
+		if scopeErr != nil {
+			return nil, scopeErr
+		}
+		if _, statErr := os.Stat(scopeAbs); os.IsNotExist(statErr) {
+			if removed == 0 {
+				return nil, newError(CodeInvalidInput, fmt.Sprintf("path not found: %s", scopeRel), "Pass a file or directory inside the vault", statErr)
+			}
+			scopeAbs = ""
+		}
+	} else if incremental {
 		excludedFiles, excludedErr := indexedExcludedFiles(db, excludeMatcher)
 		if excludedErr != nil {
 			result.WarningMessages = append(result.WarningMessages, fmt.Sprintf("failed to check for excluded files: %v", excludedErr))
@@ -254,8 +295,7 @@ func Run(req RunRequest) (*RunResult, error) {
 		}
 	}
 
-	walkOpts := &vault.WalkOptions{ParseOptions: parseOpts, ExcludeMatcher: excludeMatcher}
-	walkErr := vault.WalkMarkdownFilesWithOptions(vaultPath, walkOpts, func(walkResult vault.WalkResult) error {
+	indexMarkdown := func(walkResult vault.WalkResult) error {
 		select {
 		case <-ctx.Done():
 			return ctx.Err()
@@ -268,10 +308,12 @@ func Run(req RunRequest) (*RunResult, error) {
 		}
 
 		if incremental {
-			indexedMtime, mtimeErr := db.GetFileMtime(walkResult.RelativePath)
-			if mtimeErr == nil && indexedMtime > 0 && walkResult.FileMtime <= indexedMtime {
-				result.FilesSkipped++
-				return nil
+			if scopeRel == "" {
+				indexedMtime, mtimeErr := db.GetFileMtime(walkResult.RelativePath)
+				if mtimeErr == nil && indexedMtime > 0 && walkResult.FileMtime <= indexedMtime {
+					result.FilesSkipped++
+					return nil
+				}
 			}
 			result.StaleFiles = append(result.StaleFiles, walkResult.RelativePath)
 		}
@@ -293,12 +335,16 @@ func Run(req RunRequest) (*RunResult, error) {
 
 		result.FilesIndexed++
 		return nil
-	})
-	if walkErr != nil {
-		return nil, newError(CodeFileReadError, fmt.Sprintf("error walking vault: %v", walkErr), "", walkErr)
+	}
+	// A scoped path that no longer exists only needed its rows pruned.
+	if scopeRel == "" || scopeAbs != "" {
+		walkOpts := &vault.WalkOptions{ParseOptions: parseOpts, ExcludeMatcher: excludeMatcher, FollowSymlinks: vaultCfg.FollowSymlinks, Root: scopeAbs}
+		if walkErr := vault.WalkMarkdownFilesWithOptions(vaultPath, walkOpts, indexMarkdown); walkErr != nil {
+			return nil, newError(CodeFileReadError, fmt.Sprintf("error walking vault: %v", walkErr), "", walkErr)
+		}
 	}
 
-	assetWalkErr := vault.WalkAssetFilesWithOptions(vaultPath, vaultCfg, &vault.AssetWalkOptions{ExcludeMatcher: excludeMatcher}, func(walkResult vault.AssetWalkResult) error {
+	indexAsset := func(walkResult vault.AssetWalkResult) error {
 		select {
 		case <-ctx.Done():
 			return ctx.Err()
@@ -331,9 +377,12 @@ func Run(req RunRequest) (*RunResult, error) {
 		}
 		result.FilesIndexed++
 		return nil
-	})
-	if assetWalkErr != nil {
-		return nil, newError(CodeFileReadError, fmt.Sprintf("error walking asset files: %v", assetWalkErr), "", assetWalkErr)
+	}
+	// Assets live under their own root, so a scoped reindex leaves them alone.
+	if scopeRel == "" {
+		if assetWalkErr := vault.WalkAssetFilesWithOptions(vaultPath, vaultCfg, &vault.AssetWalkOptions{ExcludeMatcher: excludeMatcher}, indexAsset); assetWalkErr != nil {
+			return nil, newError(CodeFileReadError, fmt.Sprintf("error walking asset files: %v", assetWalkErr), "", assetWalkErr)
+		}
 	}
 
 	if req.DryRun {
@@ -359,7 +408,7 @@ func Run(req RunRequest) (*RunResult, error) {
 		return result, nil
 	}
 
-	if !req.DryRun && result.FilesIndexed > 0 {
+	if !req.DryRun && (result.FilesIndexed > 0 || (scopeRel != "" && result.FilesDeleted > 0)) {
 		refResult, refErr := db.ResolveReferencesWithSchema(dailyDir, sch)
 		if refErr != nil {
 			result.WarningMessages = append(result.WarningMessages, fmt.Sprintf("failed to resolve references: %v", refErr))
@@ -445,6 +494,63 @@ func projectedDryRunAssetCount(db *index.Database, deletedFiles []string, reinde
 	return len(current), nil
 }
 
+// resolveScope turns a reindex path argument into absolute and vault-relative
+// forms. An empty path, or the vault root itself, means the whole vault.
+func resolveScope(vaultPath, rawPath string, followSymlinks bool) (string, string, error) {
+	rawPath = strings.TrimSpace(rawPath)
+	if rawPath == "" {
+		return "", "", nil
+	}
+	absPath := rawPath
+	if !filepath.IsAbs(absPath) {
+		absPath = filepath.Join(vaultPath, rawPath)
+	}
+	if err := paths.ValidateNotePath(vaultPath, absPath, followSymlinks); err != nil {
+		return "", "", newError(CodeInvalidInput, fmt.Sprintf("'%s' is not inside the vault", rawPath), "Pass a file or directory inside the vault", err)
+	}
+	relPath, err := filepath.Rel(vaultPath, absPath)
+	if err != nil {
+		return "", "", newError(CodeInvalidInput, fmt.Sprintf("'%s' is not inside the vault", rawPath), "Pass a file or directory inside the vault", err)
+	}
+	relPath = filepath.ToSlash(relPath)
+	if relPath == "." {
+		return "", "", nil
+	}
+	return absPath, relPath, nil
+}
+
+// pruneScope removes index rows for files under scopeRel that no longer exist
+// or are now excluded, recording them on result. It returns how many indexed
+// files were under the scope.
+func pruneScope(db *index.Database, vaultPath, scopeRel string, matcher *ravenignore.Matcher, dryRun bool, result *RunResult) (int, error) {
+	indexedPaths, err := db.AllIndexedFilePaths()
+	if err != nil {
+		return 0, newError(CodeDatabaseError, fmt.Sprintf("failed to list indexed files: %v", err), "", err)
+	}
+
+	inScope := 0
+	for _, relPath := range indexedPaths {
+		if relPath != scopeRel && !strings.HasPrefix(relPath, scopeRel+"/") {
+			continue
+		}
+		inScope++
+		if matcher != nil && matcher.Match(relPath, false) {
+			result.ExcludedFiles = append(result.ExcludedFiles, relPath)
+		} else if _, statErr := os.Stat(filepath.Join(vaultPath, relPath)); os.IsNotExist(statErr) {
+			result.DeletedFiles = append(result.DeletedFiles, relPath)
+		}
+	}
+	result.FilesDeleted = len(result.DeletedFiles)
+	result.FilesExcluded = len(result.ExcludedFiles)
+
+	if !dryRun {
+		if err := db.RemoveFiles(uniqueStrings(result.DeletedFiles, result.ExcludedFiles)); err != nil {
+			return 0, newError(CodeDatabaseError, fmt.Sprintf("failed to remove stale files from index: %v", err), "", err)
+		}
+	}
+	return inScope, nil
+}
+
 func indexedExcludedFiles(db *index.Database, matcher *ravenignore.Matcher) ([]string, error) {
 	if matcher == nil {
 		return nil, nil
@@ -494,13 +600,3 @@ func fileIndexStats(db *index.Database, filePath string) (index.IndexStats, erro
 	}
 	return stats, nil
 }
-
-func buildParseOptions(vaultCfg *config.VaultConfig) *parser.ParseOptions {
-	if vaultCfg == nil || !vaultCfg.HasDirectoriesConfig() {
-		return nil
-	}
-	return &parser.ParseOptions{
-		ObjectsRoot: vaultCfg.GetObjectsRoot(),
-		PagesRoot:   vaultCfg.GetPagesRoot(),
-	}
-}
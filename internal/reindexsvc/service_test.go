@@ -5,7 +5,6 @@ import (
 	"path/filepath"
 	"testing"
 
-	"github.com/aidanlsb/raven/internal/config"
 	"github.com/aidanlsb/raven/internal/index"
 )
 
@@ -315,6 +314,40 @@ func TestRunSkipsExcludedMarkdownAndAssets(t *testing.T) {
 	}
 }
 
+func TestRunSkipsRavenIgnoreDirectories(t *testing.T) {
+	t.Parallel()
+
+	vaultPath := t.TempDir()
+	writeTestFile(t, vaultPath, ".ravenignore", "# not Raven content\narchive/\n")
+	writeTestFile(t, vaultPath, "keep.md", "# Keep\n")
+	writeTestFile(t, vaultPath, "archive/2019/old.md", "# Old\n")
+
+	result, err := Run(RunRequest{VaultPath: vaultPath, Full: true})
+	if err != nil {
+		t.Fatalf("Run returned error: %v", err)
+	}
+	if result.FilesIndexed != 1 {
+		t.Fatalf("files indexed = %d, want 1", result.FilesIndexed)
+	}
+
+	db, err := index.Open(vaultPath)
+	if err != nil {
+		t.Fatalf("failed to reopen index: %v", err)
+	}
+	t.Cleanup(func() { _ = db.Close() })
+
+	paths, err := db.AllIndexedFilePaths()
+	if err != nil {
+		t.Fatalf("AllIndexedFilePaths returned error: %v", err)
+	}
+	if containsString(paths, "archive/2019/old.md") {
+		t.Fatalf("indexed paths = %#v, did not expect archive/2019/old.md", paths)
+	}
+	if !containsString(paths, "keep.md") {
+		t.Fatalf("indexed paths = %#v, expected keep.md", paths)
+	}
+}
+
 func TestRunIncrementalPurgesNewlyExcludedFiles(t *testing.T) {
 	t.Parallel()
 
@@ -353,29 +386,101 @@ func TestRunIncrementalPurgesNewlyExcludedFiles(t *testing.T) {
 	}
 }
 
-func TestBuildParseOptions(t *testing.T) {
+func TestRunPathReindexesOnlyThatFile(t *testing.T) {
 	t.Parallel()
-	if got := buildParseOptions(nil); got != nil {
-		t.Fatalf("expected nil parse options for nil config, got %#v", got)
+
+	vaultPath := t.TempDir()
+	writeTestFile(t, vaultPath, "a.md", "# A\n")
+	writeTestFile(t, vaultPath, "b.md", "# B\n")
+	if _, err := Run(RunRequest{VaultPath: vaultPath, Full: true}); err != nil {
+		t.Fatalf("initial Run returned error: %v", err)
 	}
 
-	if got := buildParseOptions(&config.VaultConfig{}); got != nil {
-		t.Fatalf("expected nil parse options without directories, got %#v", got)
+	writeTestFile(t, vaultPath, "a.md", "# A\n\n- @todo Write it\n")
+	writeTestFile(t, vaultPath, "b.md", "# B\n\n- @todo Not yet indexed\n")
+
+	result, err := Run(RunRequest{VaultPath: vaultPath, Path: "a.md"})
+	if err != nil {
+		t.Fatalf("path Run returned error: %v", err)
+	}
+	if result.Path != "a.md" || result.FilesIndexed != 1 {
+		t.Fatalf("path = %q, files indexed = %d; want a.md and 1", result.Path, result.FilesIndexed)
 	}
 
-	cfg := &config.VaultConfig{
-		Directories: &config.DirectoriesConfig{
-			Object: "objects",
-			Page:   "pages",
-		},
+	db, err := index.Open(vaultPath)
+	if err != nil {
+		t.Fatalf("failed to reopen index: %v", err)
 	}
-	got := buildParseOptions(cfg)
-	if got == nil {
-		t.Fatal("expected parse options when directories are configured")
+	t.Cleanup(func() { _ = db.Close() })
+
+	for file, want := range map[string]int{"a.md": 1, "b.md": 0} {
+		var count int
+		if err := db.DB().QueryRow(`SELECT COUNT(*) FROM traits WHERE file_path = ?`, file).Scan(&count); err != nil {
+			t.Fatalf("query traits for %s: %v", file, err)
+		}
+		if count != want {
+			t.Errorf("%s traits = %d, want %d", file, count, want)
+		}
+	}
+}
+
+func TestRunPathPrunesDeletedFilesUnderDirectory(t *testing.T) {
+	t.Parallel()
+
+	vaultPath := t.TempDir()
+	writeTestFile(t, vaultPath, "notes/keep.md", "# Keep\n")
+	writeTestFile(t, vaultPath, "notes/gone.md", "# Gone\n")
+	writeTestFile(t, vaultPath, "other.md", "# Other\n")
+	if _, err := Run(RunRequest{VaultPath: vaultPath, Full: true}); err != nil {
+		t.Fatalf("initial Run returned error: %v", err)
+	}
+	for _, relPath := range []string{"notes/gone.md", "other.md"} {
+		if err := os.Remove(filepath.Join(vaultPath, relPath)); err != nil {
+			t.Fatalf("remove %s: %v", relPath, err)
+		}
+	}
+
+	result, err := Run(RunRequest{VaultPath: vaultPath, Path: filepath.Join(vaultPath, "notes")})
+	if err != nil {
+		t.Fatalf("path Run returned error: %v", err)
+	}
+	if result.Path != "notes" || result.FilesIndexed != 1 {
+		t.Fatalf("path = %q, files indexed = %d; want notes and 1", result.Path, result.FilesIndexed)
 	}
-	if got.ObjectsRoot != "objects/" || got.PagesRoot != "pages/" {
-		t.Fatalf("unexpected parse options roots: %#v", got)
+	if len(result.DeletedFiles) != 1 || result.DeletedFiles[0] != "notes/gone.md" {
+		t.Fatalf("deleted files = %#v, want only notes/gone.md", result.DeletedFiles)
 	}
+
+	db, err := index.Open(vaultPath)
+	if err != nil {
+		t.Fatalf("failed to reopen index: %v", err)
+	}
+	t.Cleanup(func() { _ = db.Close() })
+
+	paths, err := db.AllIndexedFilePaths()
+	if err != nil {
+		t.Fatalf("AllIndexedFilePaths returned error: %v", err)
+	}
+	if containsString(paths, "notes/gone.md") || !containsString(paths, "notes/keep.md") {
+		t.Fatalf("indexed paths = %#v, want notes/keep.md without notes/gone.md", paths)
+	}
+	if !containsString(paths, "other.md") {
+		t.Fatalf("indexed paths = %#v, expected other.md outside the path to be left alone", paths)
+	}
+}
+
+func TestRunPathRejectsBadInput(t *testing.T) {
+	t.Parallel()
+
+	vaultPath := t.TempDir()
+	writeTestFile(t, vaultPath, "a.md", "# A\n")
+
+	_, err := Run(RunRequest{VaultPath: vaultPath, Path: "a.md", Full: true})
+	assertReindexCode(t, err, CodeInvalidInput)
+	_, err = Run(RunRequest{VaultPath: vaultPath, Path: "missing.md"})
+	assertReindexCode(t, err, CodeInvalidInput)
+	_, err = Run(RunRequest{VaultPath: vaultPath, Path: "../outside.md"})
+	assertReindexCode(t, err, CodeInvalidInput)
 }
 
 func writeTestFile(t *testing.T, vaultPath, relPath, content string) {
@@ -72,11 +72,18 @@ func New(objectIDs []string, opts Options) *Resolver {
 			addAssetShortNames(r.shortMap, id, shortName)
 		}
 
+		// Block IDs match exactly; slugging would drop the caret and let
+		// "file#intro" collide with the block "file#^intro".
+		if paths.IsBlockID(id) {
+			indexResolverSuffixes(r.suffixMap, id, false)
+			continue
+		}
+
 		// Build slugified map for fuzzy matching
 		sluggedID := pages.SlugifyPath(id)
 		r.slugMap[sluggedID] = id
 
-		indexResolverSuffixes(r.suffixMap, id)
+		indexResolverSuffixes(r.suffixMap, id, true)
 	}
 
 	// Copy aliases (skip empty ones)
@@ -189,6 +196,19 @@ type ResolveResult struct {
 
 	// Error message if resolution failed.
 	Error string
+
+	// Trace lists the rules tried, in order, and what each matched.
+	// Only populated by Explain.
+	Trace []TraceStep
+}
+
+// TraceStep records one resolution rule and the IDs it matched. Rules are
+// named after match sources (alias, name_field, object_id, suffix_match,
+// short_name, date); prefer_parent lists sections dropped because their
+// file also matched.
+type TraceStep struct {
+	Rule       string   `json:"rule"`
+	Candidates []string `json:"candidates"`
 }
 
 // Resolve resolves a reference to its target object ID.
@@ -204,6 +224,17 @@ type ResolveResult struct {
 //     ambiguous when they collide with explicit object/short-name matches.
 //  6. For unresolved path-like refs, leaf-based canonical fallback within the same path family
 func (r *Resolver) Resolve(ref string) ResolveResult {
+	return r.resolve(ref, false)
+}
+
+// Explain resolves ref like Resolve and also records the decision trace:
+// every rule that was tried and the candidates it matched, including
+// candidates an earlier rule had already claimed.
+func (r *Resolver) Explain(ref string) ResolveResult {
+	return r.resolve(ref, true)
+}
+
+func (r *Resolver) resolve(ref string, explain bool) ResolveResult {
 	ref = strings.TrimSpace(ref)
 	normalizedRef := normalizeRefForResolution(ref)
 	sluggedRef := pages.Slugify(ref)
@@ -212,6 +243,7 @@ func (r *Resolver) Resolve(ref string) ResolveResult {
 	normalizedLowerRef := strings.ToLower(normalizedRef)
 
 	c := newMatchCollector()
+	c.tracing = explain
 
 	addAliasMatches(r, c, ref, sluggedRef)
 	if normalizedRef != ref {
@@ -249,16 +281,53 @@ func (r *Resolver) Resolve(ref string) ResolveResult {
 	// over their sections. E.g., if we match both "companies/cursor" and
 	// "companies/cursor#cursor", prefer the parent "companies/cursor".
 	if len(matches) > 1 {
-		matches = preferParentOverSections(matches)
+		kept := preferParentOverSections(matches)
+		if explain && len(kept) < len(matches) {
+			c.checked("prefer_parent")
+			for _, id := range matches {
+				if !containsString(kept, id) {
+					c.traceCandidate("prefer_parent", id)
+				}
+			}
+		}
+		matches = kept
 	}
 
 	matchSources := filterMatchSources(c.sources, matches)
-	return buildResolveResult(matches, matchSources)
+	result := buildResolveResult(matches, matchSources)
+	result.Trace = c.trace
+	return result
 }
 
 type matchCollector struct {
 	matches []string
 	sources map[string]string // id -> source (for debugging)
+	tracing bool
+	trace   []TraceStep
+}
+
+// checked records that a rule was tried, so rules without matches still
+// appear in the trace.
+func (c *matchCollector) checked(rule string) {
+	if !c.tracing {
+		return
+	}
+	for _, step := range c.trace {
+		if step.Rule == rule {
+			return
+		}
+	}
+	c.trace = append(c.trace, TraceStep{Rule: rule, Candidates: []string{}})
+}
+
+func (c *matchCollector) traceCandidate(rule, id string) {
+	c.checked(rule)
+	for i := range c.trace {
+		if c.trace[i].Rule == rule {
+			c.trace[i].Candidates = appendUnique(c.trace[i].Candidates, id)
+			return
+		}
+	}
 }
 
 func newMatchCollector() *matchCollector {
@@ -271,6 +340,9 @@ func (c *matchCollector) add(id, source string) {
 	if id == "" {
 		return
 	}
+	if c.tracing {
+		c.traceCandidate(source, id)
+	}
 	if _, exists := c.sources[id]; exists {
 		return
 	}
@@ -279,6 +351,7 @@ func (c *matchCollector) add(id, source string) {
 }
 
 func addAliasMatches(r *Resolver, c *matchCollector, ref, sluggedRef string) {
+	c.checked("alias")
 	// Check aliases (exact and slugified)
 	if targetIDs, ok := r.aliasMap[ref]; ok {
 		for _, targetID := range targetIDs {
@@ -297,6 +370,7 @@ func addAliasMatches(r *Resolver, c *matchCollector, ref, sluggedRef string) {
 func addNameFieldMatches(r *Resolver, c *matchCollector, ref, sluggedRef, lowerRef string) {
 	// Check name_field values (semantic matching by display name)
 	// This allows [[The Prose Edda]] to resolve even if the file is the-prose-edda.md
+	c.checked("name_field")
 	var nameMatches []string
 	if m, ok := r.nameFieldMap[ref]; ok {
 		nameMatches = m
@@ -315,6 +389,7 @@ func addDateRefMatch(r *Resolver, c *matchCollector, ref string) {
 		return
 	}
 
+	c.checked("date")
 	c.add(path.Join(r.dailyDirectory, ref), "date")
 }
 
@@ -325,6 +400,7 @@ func isPathLikeRef(ref string) bool {
 }
 
 func addPathMatches(r *Resolver, c *matchCollector, ref string) {
+	c.checked("object_id")
 	// Check if it exists exactly
 	if _, ok := r.objectIDs[ref]; ok {
 		c.add(ref, "object_id")
@@ -339,15 +415,20 @@ func addPathMatches(r *Resolver, c *matchCollector, ref string) {
 		}
 	}
 
-	// Try slugified match: "people/Sif" -> "people/sif"
-	sluggedRefPath := pages.SlugifyPath(ref)
-	if originalID, ok := r.slugMap[sluggedRefPath]; ok {
-		c.add(originalID, "object_id")
+	// Block refs ("file#^id") are matched exactly, without slugging.
+	sluggedRefPath := ref
+	if !paths.IsBlockID(ref) {
+		// Try slugified match: "people/Sif" -> "people/sif"
+		sluggedRefPath = pages.SlugifyPath(ref)
+		if originalID, ok := r.slugMap[sluggedRefPath]; ok {
+			c.add(originalID, "object_id")
+		}
 	}
 
 	// Try suffix matching: "companies/cursor" -> "objects/companies/cursor"
 	// This handles cases where a directories.objects prefix is used
 	if len(c.matches) == 0 {
+		c.checked("suffix_match")
 		suffix := "/" + ref
 		sluggedSuffix := "/" + sluggedRefPath
 		for _, id := range r.suffixMap[suffix] {
@@ -425,6 +506,7 @@ func pathFamilyMatches(requestedDir, candidateID string) bool {
 
 func addShortMatches(r *Resolver, c *matchCollector, ref, sluggedRef string) {
 	// Short reference - search for matches
+	c.checked("short_name")
 	shortMatches := r.shortMap[ref]
 	if len(shortMatches) == 0 {
 		shortMatches = r.shortMap[sluggedRef]
@@ -443,15 +525,20 @@ func addShortMatches(r *Resolver, c *matchCollector, ref, sluggedRef string) {
 	}
 }
 
-func indexResolverSuffixes(suffixMap map[string][]string, id string) {
-	remaining := id
-	for {
-		slash := strings.IndexByte(remaining, '/')
-		if slash == -1 || slash+1 >= len(remaining) {
-			return
+func indexResolverSuffixes(suffixMap map[string][]string, id string, slugged bool) {
+	// Only slashes in the file path start a suffix. Heading-path section IDs
+	// ("notes/plan#roadmap/q1") contain slashes too, and splitting there would
+	// let "[[roadmap/q1]]" collide with a file at that path.
+	baseID, _, _ := paths.ParseSectionID(id)
+	for slash := 0; slash < len(baseID); slash++ {
+		if baseID[slash] != '/' || slash+1 >= len(id) {
+			continue
 		}
-		remaining = remaining[slash+1:]
+		remaining := id[slash+1:]
 		addResolverSuffixEntry(suffixMap, "/"+remaining, id)
+		if !slugged {
+			continue
+		}
 		sluggedSuffix := "/" + pages.SlugifyPath(remaining)
 		if sluggedSuffix != "/"+remaining {
 			addResolverSuffixEntry(suffixMap, sluggedSuffix, id)
@@ -1,6 +1,7 @@
 package resolver
 
 import (
+	"reflect"
 	"testing"
 )
 
@@ -106,6 +107,65 @@ func TestResolverAmbiguous(t *testing.T) {
 	}
 }
 
+func TestResolverExplainTrace(t *testing.T) {
+	t.Parallel()
+
+	t.Run("ambiguous short name lists both candidates", func(t *testing.T) {
+		r := New([]string{"people/freya", "clients/freya"}, Options{})
+
+		result := r.Explain("freya")
+		if !result.Ambiguous {
+			t.Fatalf("expected ambiguous, got %+v", result)
+		}
+		want := []TraceStep{
+			{Rule: "alias", Candidates: []string{}},
+			{Rule: "name_field", Candidates: []string{}},
+			{Rule: "short_name", Candidates: []string{"people/freya", "clients/freya"}},
+		}
+		if !reflect.DeepEqual(result.Trace, want) {
+			t.Errorf("trace = %#v, want %#v", result.Trace, want)
+		}
+	})
+
+	t.Run("records candidates an earlier rule claimed", func(t *testing.T) {
+		r := New([]string{"people/freya", "clients/freya"}, Options{
+			Aliases: map[string]string{"freya": "people/freya"},
+		})
+
+		result := r.Explain("freya")
+		if !result.Ambiguous || result.MatchSources["people/freya"] != "alias" {
+			t.Fatalf("expected ambiguous alias/short-name result, got %+v", result)
+		}
+		want := []TraceStep{
+			{Rule: "alias", Candidates: []string{"people/freya"}},
+			{Rule: "name_field", Candidates: []string{}},
+			{Rule: "short_name", Candidates: []string{"people/freya", "clients/freya"}},
+		}
+		if !reflect.DeepEqual(result.Trace, want) {
+			t.Errorf("trace = %#v, want %#v", result.Trace, want)
+		}
+	})
+
+	t.Run("path refs trace object_id", func(t *testing.T) {
+		r := New([]string{"companies/cursor", "companies/cursor#cursor"}, Options{})
+
+		result := r.Explain("companies/cursor")
+		if result.TargetID != "companies/cursor" {
+			t.Fatalf("target = %q, want companies/cursor", result.TargetID)
+		}
+		if len(result.Trace) < 3 || result.Trace[2].Rule != "object_id" {
+			t.Fatalf("expected alias, name_field, object_id steps, got %#v", result.Trace)
+		}
+	})
+
+	t.Run("resolve does not trace", func(t *testing.T) {
+		r := New([]string{"people/freya"}, Options{})
+		if trace := r.Resolve("freya").Trace; trace != nil {
+			t.Errorf("expected no trace from Resolve, got %#v", trace)
+		}
+	})
+}
+
 func TestResolverAssetShortNames(t *testing.T) {
 	t.Parallel()
 
@@ -435,6 +495,63 @@ func TestResolverPrefersParentOverSection(t *testing.T) {
 	})
 }
 
+func TestResolverBlockRefs(t *testing.T) {
+	t.Parallel()
+	r := New([]string{
+		"projects/plan",
+		"projects/plan#launch",
+		"projects/plan#^launch",
+		"projects/plan#^call-freya",
+	}, Options{})
+
+	tests := []struct {
+		ref  string
+		want string
+	}{
+		{"projects/plan#^launch", "projects/plan#^launch"},
+		{"projects/plan.md#^launch", "projects/plan#^launch"},
+		{"plan#^call-freya", "projects/plan#^call-freya"},
+		{"projects/plan#launch", "projects/plan#launch"},
+		{"plan#launch", "projects/plan#launch"},
+	}
+	for _, tt := range tests {
+		result := r.Resolve(tt.ref)
+		if result.Ambiguous || result.TargetID != tt.want {
+			t.Errorf("Resolve(%q) = %+v, want %q", tt.ref, result, tt.want)
+		}
+	}
+
+	if result := r.Resolve("projects/plan#^missing"); result.TargetID != "" {
+		t.Errorf("Resolve(projects/plan#^missing) = %q, want unresolved", result.TargetID)
+	}
+}
+
+func TestResolverHeadingPathSectionsDoNotCollideWithPaths(t *testing.T) {
+	t.Parallel()
+	r := New([]string{
+		"notes/plan",
+		"notes/plan#roadmap",
+		"notes/plan#roadmap/q1",
+		"work/roadmap/q1",
+	}, Options{})
+
+	tests := []struct {
+		ref  string
+		want string
+	}{
+		{"notes/plan#roadmap/q1", "notes/plan#roadmap/q1"},
+		{"plan#roadmap/q1", "notes/plan#roadmap/q1"},
+		{"roadmap/q1", "work/roadmap/q1"},
+		{"q1", "work/roadmap/q1"},
+	}
+	for _, tt := range tests {
+		result := r.Resolve(tt.ref)
+		if result.Ambiguous || result.TargetID != tt.want {
+			t.Errorf("Resolve(%q) = %+v, want %q", tt.ref, result, tt.want)
+		}
+	}
+}
+
 func TestResolverDateShorthand(t *testing.T) {
 	t.Parallel()
 	objectIDs := []string{
@@ -519,6 +636,7 @@ func TestResolverSlugifiedMatching(t *testing.T) {
 		"people/sif",
 		"people/freya",
 		"projects/my-awesome-project",
+		"people/alice-chen",
 	}
 
 	r := New(objectIDs, Options{})
@@ -552,6 +670,20 @@ func TestResolverSlugifiedMatching(t *testing.T) {
 		}
 	})
 
+	t.Run("display name short ref resolves to slugified file", func(t *testing.T) {
+		result := r.Resolve("Alice Chen")
+		if result.TargetID != "people/alice-chen" {
+			t.Errorf("got %q, want %q", result.TargetID, "people/alice-chen")
+		}
+	})
+
+	t.Run("case-only difference in short ref resolves", func(t *testing.T) {
+		result := r.Resolve("FREYA")
+		if result.TargetID != "people/freya" {
+			t.Errorf("got %q, want %q", result.TargetID, "people/freya")
+		}
+	})
+
 	t.Run("exact match still works", func(t *testing.T) {
 		result := r.Resolve("people/freya")
 		if result.TargetID != "people/freya" {
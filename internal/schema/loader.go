@@ -3,6 +3,7 @@ package schema
 import (
 	"fmt"
 	"os"
+	"sort"
 	"strings"
 
 	"gopkg.in/yaml.v3"
@@ -176,6 +177,9 @@ func LoadWithWarnings(vaultPath string) (*LoadResult, error) {
 			fieldDef.Type = normalizeFieldType(fieldDef.Type)
 		}
 	}
+	if err := resolveTypeInheritance(schema.Types); err != nil {
+		return nil, err
+	}
 	for traitName, traitDef := range schema.Traits {
 		if traitDef == nil {
 			return nil, fmt.Errorf("trait %q is null; expected an object definition", traitName)
@@ -187,6 +191,70 @@ func LoadWithWarnings(vaultPath string) (*LoadResult, error) {
 	return result, nil
 }
 
+// resolveTypeInheritance merges fields from each type's `extends` parent into
+// the type itself. Fields declared on the child win on name conflicts.
+// Unknown parents and inheritance cycles are load errors.
+func resolveTypeInheritance(types map[string]*TypeDefinition) error {
+	typeNames := make([]string, 0, len(types))
+	for name := range types {
+		typeNames = append(typeNames, name)
+	}
+	sort.Strings(typeNames)
+
+	resolved := make(map[string]bool, len(types))
+	var resolve func(name string, chain []string) error
+	resolve = func(name string, chain []string) error {
+		if resolved[name] {
+			return nil
+		}
+		typeDef := types[name]
+		parentName := strings.TrimSpace(typeDef.Extends)
+		if parentName == "" {
+			resolved[name] = true
+			return nil
+		}
+
+		chain = append(chain, name)
+		for _, seen := range chain {
+			if seen == parentName {
+				return fmt.Errorf("type inheritance cycle: %s -> %s", strings.Join(chain, " -> "), parentName)
+			}
+		}
+		parentDef, ok := types[parentName]
+		if !ok {
+			return fmt.Errorf("type %q extends unknown type %q", name, parentName)
+		}
+		if err := resolve(parentName, chain); err != nil {
+			return err
+		}
+
+		for fieldName, fieldDef := range parentDef.Fields {
+			if _, overridden := typeDef.Fields[fieldName]; overridden {
+				continue
+			}
+			inherited := *fieldDef
+			typeDef.Fields[fieldName] = &inherited
+			owner := parentName
+			if parentOwner, ok := parentDef.inheritedFrom[fieldName]; ok {
+				owner = parentOwner
+			}
+			if typeDef.inheritedFrom == nil {
+				typeDef.inheritedFrom = make(map[string]string)
+			}
+			typeDef.inheritedFrom[fieldName] = owner
+		}
+		resolved[name] = true
+		return nil
+	}
+
+	for _, name := range typeNames {
+		if err := resolve(name, nil); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
 func normalizeFieldType(fieldType FieldType) FieldType {
 	switch strings.ToLower(string(fieldType)) {
 	case "reference":
@@ -318,6 +386,11 @@ traits:
     type: enum
     values: [low, medium, high]
     default: medium
+
+  # Saved quotes: put @quote on its own line above a > blockquote
+  # to capture the quoted passage as the trait content
+  quote:
+    type: boolean
 `
 
 	if err := atomicfile.WriteFile(schemaPath, []byte(defaultSchema), 0o644); err != nil {
@@ -412,6 +412,90 @@ types:
 		}
 	})
 
+	t.Run("extends inherits and overrides parent fields", func(t *testing.T) {
+		tmpDir := t.TempDir()
+		schemaContent := `version: 1
+types:
+  person:
+    fields:
+      name: { type: string, required: true }
+      email: { type: string }
+  employee:
+    extends: person
+    fields:
+      email: { type: url }
+      team: { type: string }
+  manager:
+    extends: employee
+    fields:
+      reports: { type: "ref[]", target: employee }
+`
+		if err := os.WriteFile(filepath.Join(tmpDir, "schema.yaml"), []byte(schemaContent), 0o644); err != nil {
+			t.Fatalf("failed to write schema: %v", err)
+		}
+
+		sch, err := Load(tmpDir)
+		if err != nil {
+			t.Fatalf("unexpected error: %v", err)
+		}
+
+		employee := sch.Types["employee"]
+		if field := employee.Fields["name"]; field == nil || field.Type != FieldTypeString || !field.Required {
+			t.Fatalf("expected employee to inherit required name field, got %#v", employee.Fields["name"])
+		}
+		if field := employee.Fields["email"]; field == nil || field.Type != FieldTypeURL {
+			t.Fatalf("expected employee email override to win, got %#v", employee.Fields["email"])
+		}
+		manager := sch.Types["manager"]
+		for _, name := range []string{"name", "email", "team", "reports"} {
+			if _, ok := manager.Fields[name]; !ok {
+				t.Fatalf("expected manager to have field %q via inheritance, got %v", name, manager.Fields)
+			}
+		}
+		if got := sch.Types["person"].Fields["email"].Type; got != FieldTypeString {
+			t.Fatalf("expected parent field to be unchanged, got %q", got)
+		}
+		for field, want := range map[string]string{"name": "person", "email": "employee", "team": "employee"} {
+			if owner, ok := manager.InheritedFrom(field); !ok || owner != want {
+				t.Fatalf("manager.InheritedFrom(%q) = %q, %v; want %q", field, owner, ok, want)
+			}
+		}
+		if owner, ok := manager.InheritedFrom("reports"); ok {
+			t.Fatalf("expected manager's own field not to be inherited, got %q", owner)
+		}
+		if owner, ok := employee.InheritedFrom("email"); ok {
+			t.Fatalf("expected overridden field not to be inherited, got %q", owner)
+		}
+	})
+
+	t.Run("extends rejects unknown parent", func(t *testing.T) {
+		tmpDir := t.TempDir()
+		schemaContent := "version: 1\ntypes:\n  employee:\n    extends: person\n"
+		if err := os.WriteFile(filepath.Join(tmpDir, "schema.yaml"), []byte(schemaContent), 0o644); err != nil {
+			t.Fatalf("failed to write schema: %v", err)
+		}
+
+		if _, err := Load(tmpDir); err == nil {
+			t.Fatal("expected error for unknown parent type, got nil")
+		} else if !strings.Contains(err.Error(), `type "employee" extends unknown type "person"`) {
+			t.Fatalf("unexpected error: %v", err)
+		}
+	})
+
+	t.Run("extends rejects inheritance cycles", func(t *testing.T) {
+		tmpDir := t.TempDir()
+		schemaContent := "version: 1\ntypes:\n  a:\n    extends: b\n  b:\n    extends: c\n  c:\n    extends: a\n"
+		if err := os.WriteFile(filepath.Join(tmpDir, "schema.yaml"), []byte(schemaContent), 0o644); err != nil {
+			t.Fatalf("failed to write schema: %v", err)
+		}
+
+		if _, err := Load(tmpDir); err == nil {
+			t.Fatal("expected error for inheritance cycle, got nil")
+		} else if !strings.Contains(err.Error(), "type inheritance cycle: a -> b -> c -> a") {
+			t.Fatalf("unexpected error: %v", err)
+		}
+	})
+
 	t.Run("rejects null trait definition", func(t *testing.T) {
 		tmpDir := t.TempDir()
 		schemaContent := "version: 1\ntraits:\n  broken: null\n"
@@ -1,7 +1,12 @@
 // Package schema handles schema loading and validation.
 package schema
 
-import "encoding/json"
+import (
+	"encoding/json"
+	"sort"
+	"strconv"
+	"strings"
+)
 
 // CurrentSchemaVersion is the latest schema format version.
 const CurrentSchemaVersion = 1
@@ -74,6 +79,29 @@ func New() *Schema {
 	}
 }
 
+// TraitCaseVariant returns the declared trait that name differs from only by
+// letter case. It returns false when name is declared as written, or when no
+// single declared trait matches.
+func (s *Schema) TraitCaseVariant(name string) (string, bool) {
+	if s == nil {
+		return "", false
+	}
+	if _, declared := s.Traits[name]; declared {
+		return "", false
+	}
+	match := ""
+	for declared := range s.Traits {
+		if !strings.EqualFold(declared, name) {
+			continue
+		}
+		if match != "" {
+			return "", false
+		}
+		match = declared
+	}
+	return match, match != ""
+}
+
 // CoreTypeDefinition defines supported configuration for a core type.
 //
 // Core types are Raven-managed with fixed field definitions.
@@ -104,6 +132,23 @@ type TypeDefinition struct {
 	// DefaultTemplate selects the template ID from Templates that is applied by default.
 	// If empty, object creation proceeds without a template unless explicitly selected.
 	DefaultTemplate string `yaml:"default_template,omitempty"`
+	// Extends names a parent type whose fields this type inherits. Fields defined
+	// on this type override inherited fields with the same name. Resolved at load time.
+	Extends string `yaml:"extends,omitempty"`
+
+	// inheritedFrom maps each field copied in via Extends to the ancestor type
+	// that declares it. Populated by the loader.
+	inheritedFrom map[string]string
+}
+
+// InheritedFrom reports the ancestor type that declares fieldName when this
+// type only inherits it through Extends.
+func (t *TypeDefinition) InheritedFrom(fieldName string) (string, bool) {
+	if t == nil {
+		return "", false
+	}
+	owner, ok := t.inheritedFrom[fieldName]
+	return owner, ok
 }
 
 // TemplateDefinition defines a schema-level template that can be bound to one or more types.
@@ -126,6 +171,10 @@ type TraitDefinition struct {
 
 	// Default is the default value if none provided.
 	Default interface{} `yaml:"default,omitempty"`
+
+	// RequireContent makes check warn when the annotated line has no text
+	// besides the trait itself (e.g. a bare "- @todo").
+	RequireContent bool `yaml:"require_content,omitempty"`
 }
 
 // IsBoolean returns true if this trait is a boolean/marker trait.
@@ -140,10 +189,15 @@ type FieldDefinition struct {
 	Default  interface{} `yaml:"default,omitempty"`
 	Values   []string    `yaml:"values,omitempty"` // For enum types
 	Target   string      `yaml:"target,omitempty"` // For ref types
+	// Synonyms maps an enum value to alternative spellings that are accepted
+	// and indexed as that value, e.g. in_progress: [doing, wip].
+	Synonyms map[string][]string `yaml:"synonyms,omitempty"`
 	// Description provides optional context for humans/agents about this field.
 	Description string   `yaml:"description,omitempty"`
 	Min         *float64 `yaml:"min,omitempty"`        // For number types
 	Max         *float64 `yaml:"max,omitempty"`        // For number types
+	MinLength   *int     `yaml:"min_length,omitempty"` // For string types (in characters)
+	MaxLength   *int     `yaml:"max_length,omitempty"` // For string types (in characters)
 	Derived     string   `yaml:"derived,omitempty"`    // How to compute value
 	Positional  bool     `yaml:"positional,omitempty"` // For traits: positional argument
 }
@@ -215,6 +269,11 @@ func Array(items []FieldValue) FieldValue {
 	return FieldValue{value: items}
 }
 
+// Map creates a mapping FieldValue (e.g. an element of a YAML list-of-maps).
+func Map(entries map[string]FieldValue) FieldValue {
+	return FieldValue{value: entries}
+}
+
 // Null creates a null FieldValue.
 func Null() FieldValue {
 	return FieldValue{value: nil}
@@ -269,6 +328,14 @@ func (fv FieldValue) AsArray() ([]FieldValue, bool) {
 	return nil, false
 }
 
+// AsMap returns the value as a mapping, if possible.
+func (fv FieldValue) AsMap() (map[string]FieldValue, bool) {
+	if m, ok := fv.value.(map[string]FieldValue); ok {
+		return m, true
+	}
+	return nil, false
+}
+
 // AsRef returns the value as a reference path, if possible.
 func (fv FieldValue) AsRef() (string, bool) {
 	if r, ok := fv.value.(refValue); ok {
@@ -310,11 +377,62 @@ func (fv FieldValue) Raw() interface{} {
 			result[i] = item.Raw()
 		}
 		return result
+	case map[string]FieldValue:
+		result := make(map[string]interface{}, len(v))
+		for key, item := range v {
+			result[key] = item.Raw()
+		}
+		return result
 	default:
 		return v
 	}
 }
 
+// DisplayString renders the value for human-readable output. Refs render as
+// [[target]], arrays are comma-joined (nested arrays keep their brackets),
+// dates render as-is, and null renders as an empty string. FieldValue does
+// not implement fmt.Stringer, so %v formatting is unaffected.
+func (fv FieldValue) DisplayString() string {
+	return fv.displayString(false)
+}
+
+func (fv FieldValue) displayString(nested bool) string {
+	switch v := fv.value.(type) {
+	case nil:
+		return ""
+	case refValue:
+		return "[[" + v.s + "]]"
+	case float64:
+		return strconv.FormatFloat(v, 'f', -1, 64)
+	case bool:
+		return strconv.FormatBool(v)
+	case []FieldValue:
+		parts := make([]string, 0, len(v))
+		for _, item := range v {
+			parts = append(parts, item.displayString(true))
+		}
+		if nested {
+			return "[" + strings.Join(parts, ", ") + "]"
+		}
+		return strings.Join(parts, ", ")
+	case map[string]FieldValue:
+		keys := make([]string, 0, len(v))
+		for key := range v {
+			keys = append(keys, key)
+		}
+		sort.Strings(keys)
+		parts := make([]string, 0, len(keys))
+		for _, key := range keys {
+			parts = append(parts, key+": "+v[key].displayString(true))
+		}
+		return "{" + strings.Join(parts, ", ") + "}"
+	}
+	if s, ok := fv.AsString(); ok {
+		return s
+	}
+	return ""
+}
+
 // MarshalJSON implements json.Marshaler.
 func (fv FieldValue) MarshalJSON() ([]byte, error) {
 	return json.Marshal(fv.Raw())
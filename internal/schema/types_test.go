@@ -2,6 +2,7 @@ package schema
 
 import (
 	"encoding/json"
+	"fmt"
 	"testing"
 )
 
@@ -66,6 +67,33 @@ func TestTraitDefinitionIsBoolean(t *testing.T) {
 	}
 }
 
+func TestSchemaTraitCaseVariant(t *testing.T) {
+	t.Parallel()
+	sch := New()
+	sch.Traits["todo"] = &TraitDefinition{}
+	sch.Traits["due"] = &TraitDefinition{Type: FieldTypeDate}
+	sch.Traits["Ref"] = &TraitDefinition{}
+	sch.Traits["REF"] = &TraitDefinition{}
+
+	tests := []struct {
+		name   string
+		want   string
+		wantOK bool
+	}{
+		{"Todo", "todo", true},
+		{"DUE", "due", true},
+		{"todo", "", false},
+		{"todos", "", false},
+		{"ref", "", false},
+	}
+	for _, tt := range tests {
+		got, ok := sch.TraitCaseVariant(tt.name)
+		if got != tt.want || ok != tt.wantOK {
+			t.Errorf("TraitCaseVariant(%q) = %q, %v; want %q, %v", tt.name, got, ok, tt.want, tt.wantOK)
+		}
+	}
+}
+
 func TestFieldValueString(t *testing.T) {
 	t.Parallel()
 	t.Run("String value", func(t *testing.T) {
@@ -325,6 +353,40 @@ func TestFieldValueMarshalJSON(t *testing.T) {
 	})
 }
 
+func TestFieldValueDisplayString(t *testing.T) {
+	t.Parallel()
+
+	tests := []struct {
+		name  string
+		value FieldValue
+		want  string
+	}{
+		{"null", Null(), ""},
+		{"string", String("active"), "active"},
+		{"date", Date("2025-02-01"), "2025-02-01"},
+		{"datetime", Datetime("2025-02-01T09:30"), "2025-02-01T09:30"},
+		{"integer number", Number(3), "3"},
+		{"fractional number", Number(2.5), "2.5"},
+		{"bool", Bool(false), "false"},
+		{"ref", Ref("people/freya"), "[[people/freya]]"},
+		{"ref array", Array([]FieldValue{Ref("people/freya"), Ref("people/thor")}), "[[people/freya]], [[people/thor]]"},
+		{"nested array", Array([]FieldValue{String("a"), Array([]FieldValue{Number(1), Number(2)})}), "a, [1, 2]"},
+		{"map", Map(map[string]FieldValue{"role": String("host"), "name": Ref("people/alice")}), "{name: [[people/alice]], role: host}"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := tt.value.DisplayString(); got != tt.want {
+				t.Errorf("DisplayString() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+	// Existing %v/%s call sites (error messages, logs) must keep their output.
+	if _, ok := interface{}(Ref("people/freya")).(fmt.Stringer); ok {
+		t.Error("FieldValue should not implement fmt.Stringer; use DisplayString")
+	}
+}
+
 func TestNewFieldValue(t *testing.T) {
 	t.Parallel()
 	fv := NewFieldValue("test")
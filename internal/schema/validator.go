@@ -2,7 +2,11 @@ package schema
 
 import (
 	"fmt"
+	"sort"
+	"strconv"
 	"strings"
+	"time"
+	"unicode/utf8"
 
 	"github.com/aidanlsb/raven/internal/dates"
 )
@@ -48,7 +52,7 @@ func ValidateFields(fields map[string]FieldValue, fieldDefs map[string]*FieldDef
 	// Validate each provided field
 	for name, value := range fields {
 		// Skip reserved fields
-		if name == "id" || name == "type" || name == "alias" {
+		if name == "id" || name == "type" || name == "alias" || name == "aliases" {
 			continue
 		}
 
@@ -69,7 +73,8 @@ func ValidateFields(fields map[string]FieldValue, fieldDefs map[string]*FieldDef
 				})
 			}
 		}
-		// Note: Unknown fields are allowed (schema is not strict)
+		// Unknown fields are not reported here: rvn check flags them as
+		// unknown_frontmatter_key errors and field mutations reject them.
 	}
 
 	return errors
@@ -82,9 +87,17 @@ func validateFieldValue(name string, value FieldValue, def *FieldDefinition) err
 
 	switch def.Type {
 	case FieldTypeString:
-		if _, ok := value.AsString(); !ok {
+		s, ok := value.AsString()
+		if !ok {
 			return fmt.Errorf("expected string")
 		}
+		length := utf8.RuneCountInString(s)
+		if def.MinLength != nil && length < *def.MinLength {
+			return fmt.Errorf("length %d is below minimum %d", length, *def.MinLength)
+		}
+		if def.MaxLength != nil && length > *def.MaxLength {
+			return fmt.Errorf("length %d is above maximum %d", length, *def.MaxLength)
+		}
 
 	case FieldTypeStringArray:
 		arr, ok := value.AsArray()
@@ -194,14 +207,7 @@ func validateFieldValue(name string, value FieldValue, def *FieldDefinition) err
 		if def.Values == nil {
 			return fmt.Errorf("enum type missing 'values' definition")
 		}
-		found := false
-		for _, allowed := range def.Values {
-			if s == allowed {
-				found = true
-				break
-			}
-		}
-		if !found {
+		if !def.allowsEnumValue(s) {
 			return fmt.Errorf("invalid enum value '%s', expected one of: %v", s, def.Values)
 		}
 
@@ -218,14 +224,7 @@ func validateFieldValue(name string, value FieldValue, def *FieldDefinition) err
 			if !ok {
 				return fmt.Errorf("expected array of enum values")
 			}
-			found := false
-			for _, allowed := range def.Values {
-				if s == allowed {
-					found = true
-					break
-				}
-			}
-			if !found {
+			if !def.allowsEnumValue(s) {
 				return fmt.Errorf("invalid enum value '%s', expected one of: %v", s, def.Values)
 			}
 		}
@@ -423,8 +422,8 @@ func validateSchemaFieldDefinition(typeName, fieldName string, fieldDef *FieldDe
 	if !IsValidFieldType(fieldDef.Type) {
 		return append(issues, fmt.Sprintf("Type '%s' field '%s' has unknown field type '%s' (expected one of: %s)", typeName, fieldName, fieldDef.Type, validTypes))
 	}
-	if (fieldDef.Type == FieldTypeEnum || fieldDef.Type == FieldTypeEnumArray) && len(fieldDef.Values) == 0 {
-		issues = append(issues, fmt.Sprintf("Type '%s' field '%s' of type '%s' must define at least one allowed value", typeName, fieldName, fieldDef.Type))
+	for _, issue := range FieldDefinitionIssues(fieldDef) {
+		issues = append(issues, fmt.Sprintf("Type '%s' field '%s' %s", typeName, fieldName, issue))
 	}
 	if (fieldDef.Type == FieldTypeRef || fieldDef.Type == FieldTypeRefArray) && fieldDef.Target != "" {
 		if _, exists := sch.Types[fieldDef.Target]; !exists {
@@ -434,6 +433,175 @@ func validateSchemaFieldDefinition(typeName, fieldName string, fieldDef *FieldDe
 	return issues
 }
 
+// FieldDefinitionIssues reports problems with a single field definition that
+// can be detected without the rest of the schema: enums without values,
+// inverted min/max or length bounds, and defaults that do not fit the field type.
+// Messages are phrased to follow a "field 'x'" prefix.
+func FieldDefinitionIssues(fieldDef *FieldDefinition) []string {
+	if fieldDef == nil || !IsValidFieldType(fieldDef.Type) {
+		return nil
+	}
+
+	var issues []string
+	if (fieldDef.Type == FieldTypeEnum || fieldDef.Type == FieldTypeEnumArray) && len(fieldDef.Values) == 0 {
+		issues = append(issues, fmt.Sprintf("of type '%s' must define at least one allowed value", fieldDef.Type))
+	}
+	issues = append(issues, enumSynonymIssues(fieldDef)...)
+	if fieldDef.Min != nil && fieldDef.Max != nil && *fieldDef.Min > *fieldDef.Max {
+		issues = append(issues, fmt.Sprintf("has min %v greater than max %v", *fieldDef.Min, *fieldDef.Max))
+	}
+	if fieldDef.MinLength != nil && fieldDef.MaxLength != nil && *fieldDef.MinLength > *fieldDef.MaxLength {
+		issues = append(issues, fmt.Sprintf("has min_length %d greater than max_length %d", *fieldDef.MinLength, *fieldDef.MaxLength))
+	}
+	if fieldDef.Default != nil && len(issues) == 0 {
+		if err := validateFieldValue("default", defaultFieldValue(fieldDef.Default, fieldDef.Type), fieldDef); err != nil {
+			issues = append(issues, fmt.Sprintf("has invalid default %v: %v", fieldDef.Default, err))
+		}
+	}
+	return issues
+}
+
+// DefaultValue returns the field's schema default as a typed value, or false
+// when the field has no default.
+func (f *FieldDefinition) DefaultValue() (FieldValue, bool) {
+	if f == nil || f.Default == nil {
+		return Null(), false
+	}
+	return defaultFieldValue(f.Default, f.Type), true
+}
+
+// enumSynonymIssues reports synonyms on non-enum fields, synonyms for values
+// the enum does not list, and synonyms that are ambiguous or shadow a value.
+func enumSynonymIssues(fieldDef *FieldDefinition) []string {
+	if len(fieldDef.Synonyms) == 0 {
+		return nil
+	}
+	if fieldDef.Type != FieldTypeEnum && fieldDef.Type != FieldTypeEnumArray {
+		return []string{fmt.Sprintf("of type '%s' cannot define synonyms", fieldDef.Type)}
+	}
+
+	values := make(map[string]bool, len(fieldDef.Values))
+	for _, value := range fieldDef.Values {
+		values[value] = true
+	}
+	canonicals := make([]string, 0, len(fieldDef.Synonyms))
+	for canonical := range fieldDef.Synonyms {
+		canonicals = append(canonicals, canonical)
+	}
+	sort.Strings(canonicals)
+
+	var issues []string
+	seen := make(map[string]string)
+	for _, canonical := range canonicals {
+		if !values[canonical] {
+			issues = append(issues, fmt.Sprintf("defines synonyms for '%s', which is not one of its values", canonical))
+		}
+		for _, synonym := range fieldDef.Synonyms[canonical] {
+			switch {
+			case values[synonym]:
+				issues = append(issues, fmt.Sprintf("synonym '%s' is already one of its values", synonym))
+			case seen[synonym] != "" && seen[synonym] != canonical:
+				issues = append(issues, fmt.Sprintf("synonym '%s' is listed for both '%s' and '%s'", synonym, seen[synonym], canonical))
+			}
+			seen[synonym] = canonical
+		}
+	}
+	return issues
+}
+
+// allowsEnumValue reports whether s is one of the enum's values or synonyms.
+func (f *FieldDefinition) allowsEnumValue(s string) bool {
+	for _, allowed := range f.Values {
+		if s == allowed {
+			return true
+		}
+	}
+	return f.CanonicalEnumValue(s) != s
+}
+
+// CanonicalEnumValue returns the enum value that s is a synonym for, or s
+// unchanged when it is not a synonym.
+func (f *FieldDefinition) CanonicalEnumValue(s string) string {
+	if f == nil {
+		return s
+	}
+	for canonical, synonyms := range f.Synonyms {
+		for _, synonym := range synonyms {
+			if s == synonym {
+				return canonical
+			}
+		}
+	}
+	return s
+}
+
+// NormalizeEnumSynonyms rewrites synonyms in an enum or enum[] value to the
+// values they stand for. Other values are returned unchanged.
+func (f *FieldDefinition) NormalizeEnumSynonyms(value FieldValue) FieldValue {
+	if f == nil || len(f.Synonyms) == 0 {
+		return value
+	}
+	switch f.Type {
+	case FieldTypeEnum:
+		if s, ok := value.AsString(); ok {
+			return String(f.CanonicalEnumValue(s))
+		}
+	case FieldTypeEnumArray:
+		if items, ok := value.AsArray(); ok {
+			normalized := make([]FieldValue, len(items))
+			for i, item := range items {
+				normalized[i] = item
+				if s, ok := item.AsString(); ok {
+					normalized[i] = String(f.CanonicalEnumValue(s))
+				}
+			}
+			return Array(normalized)
+		}
+	}
+	return value
+}
+
+// defaultFieldValue converts a YAML-decoded default into a FieldValue.
+// String scalars are coerced for number and bool fields because
+// `rvn schema add field --default` writes defaults as strings.
+func defaultFieldValue(raw interface{}, fieldType FieldType) FieldValue {
+	switch v := raw.(type) {
+	case string:
+		switch fieldType {
+		case FieldTypeNumber:
+			if n, err := strconv.ParseFloat(strings.TrimSpace(v), 64); err == nil {
+				return Number(n)
+			}
+		case FieldTypeBool:
+			if b, err := strconv.ParseBool(strings.TrimSpace(v)); err == nil {
+				return Bool(b)
+			}
+		}
+		return String(v)
+	case int:
+		return Number(float64(v))
+	case int64:
+		return Number(float64(v))
+	case uint64:
+		return Number(float64(v))
+	case float64:
+		return Number(v)
+	case time.Time:
+		if v.Hour() == 0 && v.Minute() == 0 && v.Second() == 0 && v.Nanosecond() == 0 {
+			return Date(v.Format(dates.DateLayout))
+		}
+		return Datetime(v.Format(dates.DatetimeSecondsLayout))
+	case []interface{}:
+		items := make([]FieldValue, 0, len(v))
+		for _, item := range v {
+			items = append(items, defaultFieldValue(item, FieldType(strings.TrimSuffix(string(fieldType), "[]"))))
+		}
+		return Array(items)
+	default:
+		return NewFieldValue(raw)
+	}
+}
+
 func validateSchemaTraitDefinition(traitName string, traitDef *TraitDefinition) []string {
 	if traitDef == nil {
 		return []string{fmt.Sprintf("Trait '%s' must be an object", traitName)}
@@ -255,6 +255,43 @@ func TestValidateFieldValueNumber(t *testing.T) {
 	})
 }
 
+func TestValidateFieldValueStringLength(t *testing.T) {
+	t.Parallel()
+	minLength, maxLength := 1, 10
+	defs := map[string]*FieldDefinition{
+		"summary": {Type: FieldTypeString, MinLength: &minLength, MaxLength: &maxLength},
+		"count":   {Type: FieldTypeNumber, MinLength: &minLength, MaxLength: &maxLength},
+	}
+
+	t.Run("too short", func(t *testing.T) {
+		errors := ValidateFields(map[string]FieldValue{"summary": String("")}, defs, nil)
+		if len(errors) != 1 || !strings.Contains(errors[0].Message, "length 0 is below minimum 1") {
+			t.Fatalf("expected min_length error, got %v", errors)
+		}
+	})
+
+	t.Run("too long", func(t *testing.T) {
+		errors := ValidateFields(map[string]FieldValue{"summary": String("much too long")}, defs, nil)
+		if len(errors) != 1 || !strings.Contains(errors[0].Message, "length 13 is above maximum 10") {
+			t.Fatalf("expected max_length error, got %v", errors)
+		}
+	})
+
+	t.Run("counts characters not bytes", func(t *testing.T) {
+		errors := ValidateFields(map[string]FieldValue{"summary": String("ÄÖÜäöüßéèê")}, defs, nil)
+		if len(errors) != 0 {
+			t.Fatalf("expected 10 characters to fit max_length 10, got %v", errors)
+		}
+	})
+
+	t.Run("non-string fields ignore length constraints", func(t *testing.T) {
+		errors := ValidateFields(map[string]FieldValue{"count": Number(12345678901)}, defs, nil)
+		if len(errors) != 0 {
+			t.Fatalf("expected no errors for number field, got %v", errors)
+		}
+	})
+}
+
 func TestValidateFieldValueNumberArray(t *testing.T) {
 	t.Parallel()
 	defs := map[string]*FieldDefinition{
@@ -606,6 +643,84 @@ func TestValidateFieldValueEnum(t *testing.T) {
 	})
 }
 
+func TestEnumSynonyms(t *testing.T) {
+	t.Parallel()
+	status := &FieldDefinition{
+		Type:     FieldTypeEnum,
+		Values:   []string{"todo", "in_progress", "done"},
+		Synonyms: map[string][]string{"in_progress": {"doing", "wip"}},
+	}
+	labels := &FieldDefinition{
+		Type:     FieldTypeEnumArray,
+		Values:   []string{"todo", "in_progress", "done"},
+		Synonyms: status.Synonyms,
+	}
+	defs := map[string]*FieldDefinition{"status": status, "labels": labels}
+
+	fields := map[string]FieldValue{
+		"status": String("wip"),
+		"labels": Array([]FieldValue{String("doing"), String("done")}),
+	}
+	if errors := ValidateFields(fields, defs, nil); len(errors) != 0 {
+		t.Fatalf("expected synonyms to validate, got %v", errors)
+	}
+	if errors := ValidateFields(map[string]FieldValue{"status": String("started")}, defs, nil); len(errors) != 1 {
+		t.Fatalf("expected 1 error for unknown value, got %v", errors)
+	}
+
+	if got, _ := status.NormalizeEnumSynonyms(String("doing")).AsString(); got != "in_progress" {
+		t.Errorf("normalized status = %q, want in_progress", got)
+	}
+	if got, _ := status.NormalizeEnumSynonyms(String("done")).AsString(); got != "done" {
+		t.Errorf("normalized status = %q, want done unchanged", got)
+	}
+	items, _ := labels.NormalizeEnumSynonyms(fields["labels"]).AsArray()
+	if len(items) != 2 {
+		t.Fatalf("normalized labels = %v, want 2 items", items)
+	}
+	if first, _ := items[0].AsString(); first != "in_progress" {
+		t.Errorf("normalized labels[0] = %q, want in_progress", first)
+	}
+
+	t.Run("definition issues", func(t *testing.T) {
+		tests := []struct {
+			name string
+			def  *FieldDefinition
+			want string
+		}{
+			{
+				name: "non-enum field",
+				def:  &FieldDefinition{Type: FieldTypeString, Synonyms: map[string][]string{"a": {"b"}}},
+				want: "cannot define synonyms",
+			},
+			{
+				name: "unknown canonical value",
+				def:  &FieldDefinition{Type: FieldTypeEnum, Values: []string{"done"}, Synonyms: map[string][]string{"doing": {"wip"}}},
+				want: "not one of its values",
+			},
+			{
+				name: "synonym shadows a value",
+				def:  &FieldDefinition{Type: FieldTypeEnum, Values: []string{"todo", "done"}, Synonyms: map[string][]string{"done": {"todo"}}},
+				want: "already one of its values",
+			},
+			{
+				name: "ambiguous synonym",
+				def:  &FieldDefinition{Type: FieldTypeEnum, Values: []string{"todo", "done"}, Synonyms: map[string][]string{"todo": {"x"}, "done": {"x"}}},
+				want: "listed for both",
+			},
+		}
+		for _, tt := range tests {
+			issues := FieldDefinitionIssues(tt.def)
+			if len(issues) != 1 || !strings.Contains(issues[0], tt.want) {
+				t.Errorf("%s: issues = %v, want one containing %q", tt.name, issues, tt.want)
+			}
+		}
+		if issues := FieldDefinitionIssues(status); len(issues) != 0 {
+			t.Errorf("valid synonyms reported issues: %v", issues)
+		}
+	})
+}
+
 func TestValidateFieldValueEnumArray(t *testing.T) {
 	t.Parallel()
 	defs := map[string]*FieldDefinition{
@@ -997,6 +1112,70 @@ func TestValidateSchema(t *testing.T) {
 		}
 	})
 
+	t.Run("min must not exceed max", func(t *testing.T) {
+		minValue, maxValue := 10.0, 1.0
+		sch := &Schema{
+			Types: map[string]*TypeDefinition{
+				"book": {
+					Fields: map[string]*FieldDefinition{
+						"rating": {Type: FieldTypeNumber, Min: &minValue, Max: &maxValue},
+					},
+				},
+			},
+		}
+		issues := ValidateSchema(sch)
+		want := "Type 'book' field 'rating' has min 10 greater than max 1"
+		if !containsIssueSubstring(issues, want) {
+			t.Fatalf("expected issue containing %q, got %v", want, issues)
+		}
+	})
+
+	t.Run("defaults must match field type", func(t *testing.T) {
+		maxValue := 5.0
+		sch := &Schema{
+			Types: map[string]*TypeDefinition{
+				"book": {
+					Fields: map[string]*FieldDefinition{
+						"status":   {Type: FieldTypeEnum, Values: []string{"reading", "done"}, Default: "shelved"},
+						"rating":   {Type: FieldTypeNumber, Max: &maxValue, Default: 9},
+						"pages":    {Type: FieldTypeNumber, Default: "lots"},
+						"finished": {Type: FieldTypeDate, Default: "someday"},
+					},
+				},
+			},
+		}
+		issues := ValidateSchema(sch)
+		for _, want := range []string{
+			"Type 'book' field 'status' has invalid default shelved",
+			"Type 'book' field 'rating' has invalid default 9",
+			"Type 'book' field 'pages' has invalid default lots",
+			"Type 'book' field 'finished' has invalid default someday",
+		} {
+			if !containsIssueSubstring(issues, want) {
+				t.Fatalf("expected issue containing %q, got %v", want, issues)
+			}
+		}
+	})
+
+	t.Run("valid and CLI-written string defaults pass", func(t *testing.T) {
+		sch := &Schema{
+			Types: map[string]*TypeDefinition{
+				"book": {
+					Fields: map[string]*FieldDefinition{
+						"status":   {Type: FieldTypeEnum, Values: []string{"reading", "done"}, Default: "reading"},
+						"rating":   {Type: FieldTypeNumber, Default: "3"},
+						"owned":    {Type: FieldTypeBool, Default: "true"},
+						"archived": {Type: FieldTypeBool, Default: false},
+						"tags":     {Type: FieldTypeStringArray, Default: []interface{}{"fiction"}},
+					},
+				},
+			},
+		}
+		if issues := ValidateSchema(sch); len(issues) != 0 {
+			t.Fatalf("ValidateSchema() issues = %v, want none", issues)
+		}
+	})
+
 	t.Run("null type definition reports issue instead of panicking", func(t *testing.T) {
 		sch := &Schema{
 			Types: map[string]*TypeDefinition{
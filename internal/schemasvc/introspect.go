@@ -26,6 +26,7 @@ type TypeSchema struct {
 	Template        string                 `json:"template,omitempty"`
 	Templates       []string               `json:"templates,omitempty"`
 	DefaultTemplate string                 `json:"default_template,omitempty"`
+	Extends         string                 `json:"extends,omitempty"`
 	Fields          map[string]FieldSchema `json:"fields,omitempty"`
 }
 
@@ -42,19 +43,21 @@ type TemplateSchema struct {
 }
 
 type FieldSchema struct {
-	Type        string   `json:"type"`
-	Required    bool     `json:"required"`
-	Default     string   `json:"default,omitempty"`
-	Values      []string `json:"values,omitempty"`
-	Target      string   `json:"target,omitempty"`
-	Description string   `json:"description,omitempty"`
+	Type        string              `json:"type"`
+	Required    bool                `json:"required"`
+	Default     string              `json:"default,omitempty"`
+	Values      []string            `json:"values,omitempty"`
+	Synonyms    map[string][]string `json:"synonyms,omitempty"`
+	Target      string              `json:"target,omitempty"`
+	Description string              `json:"description,omitempty"`
 }
 
 type TraitSchema struct {
-	Name    string   `json:"name"`
-	Type    string   `json:"type"`
-	Values  []string `json:"values,omitempty"`
-	Default string   `json:"default,omitempty"`
+	Name           string   `json:"name"`
+	Type           string   `json:"type"`
+	Values         []string `json:"values,omitempty"`
+	Default        string   `json:"default,omitempty"`
+	RequireContent bool     `json:"require_content,omitempty"`
 }
 
 type SavedQueryInfo struct {
@@ -299,6 +302,7 @@ func buildTypeSchema(name string, typeDef *schema.TypeDefinition, builtin bool)
 	result.Template = typeDef.Template
 	result.Templates = append([]string(nil), typeDef.Templates...)
 	result.DefaultTemplate = typeDef.DefaultTemplate
+	result.Extends = typeDef.Extends
 
 	if len(typeDef.Fields) > 0 {
 		result.Fields = make(map[string]FieldSchema)
@@ -315,6 +319,7 @@ func buildTypeSchema(name string, typeDef *schema.TypeDefinition, builtin bool)
 				Required:    fieldDef.Required,
 				Default:     defaultStr,
 				Values:      fieldDef.Values,
+				Synonyms:    fieldDef.Synonyms,
 				Target:      fieldDef.Target,
 				Description: fieldDef.Description,
 			}
@@ -344,5 +349,6 @@ func buildTraitSchema(name string, traitDef *schema.TraitDefinition) TraitSchema
 	if traitDef.Default != nil {
 		result.Default = fmt.Sprintf("%v", traitDef.Default)
 	}
+	result.RequireContent = traitDef.RequireContent
 	return result
 }
@@ -1,9 +1,11 @@
 package schemasvc
 
 import (
+	"encoding/json"
 	"testing"
 
 	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/testutil"
 )
 
 func TestBuildTypeSchemaIncludesDescriptions(t *testing.T) {
@@ -48,3 +50,55 @@ func TestBuildTypeSchemaIncludesTemplateBindings(t *testing.T) {
 		t.Fatalf("expected default template %q, got %q", "interview_technical", result.DefaultTemplate)
 	}
 }
+
+func TestBuildTraitSchemaJSONIncludesEnumValues(t *testing.T) {
+	traitDef := &schema.TraitDefinition{
+		Type:    schema.FieldTypeEnum,
+		Values:  []string{"todo", "doing", "done"},
+		Default: "todo",
+	}
+
+	encoded, err := json.Marshal(buildTraitSchema("todo", traitDef))
+	if err != nil {
+		t.Fatalf("marshal trait schema: %v", err)
+	}
+
+	want := `{"name":"todo","type":"enum","values":["todo","doing","done"],"default":"todo"}`
+	if string(encoded) != want {
+		t.Fatalf("trait schema JSON = %s, want %s", encoded, want)
+	}
+}
+
+func TestFullSchemaIncludesBuiltinsAndRoundTripsJSON(t *testing.T) {
+	t.Parallel()
+	vault := testutil.NewTestVault(t).WithSchema(testutil.PersonProjectSchema()).Build()
+
+	result, err := FullSchema(vault.Path)
+	if err != nil {
+		t.Fatalf("FullSchema returned error: %v", err)
+	}
+
+	encoded, err := json.Marshal(result)
+	if err != nil {
+		t.Fatalf("marshal schema result: %v", err)
+	}
+	var decoded SchemaResult
+	if err := json.Unmarshal(encoded, &decoded); err != nil {
+		t.Fatalf("unmarshal schema result: %v", err)
+	}
+
+	for _, name := range []string{"person", "project", "page", "section", "date"} {
+		if _, ok := decoded.Types[name]; !ok {
+			t.Fatalf("expected type %q in schema dump, got %v", name, decoded.Types)
+		}
+	}
+	if !decoded.Types["page"].Builtin || decoded.Types["person"].Builtin {
+		t.Fatalf("expected builtin flag only on core types, got page=%v person=%v", decoded.Types["page"].Builtin, decoded.Types["person"].Builtin)
+	}
+	if field, ok := decoded.Types["project"].Fields["owner"]; !ok || field.Target != "person" {
+		t.Fatalf("expected project.owner ref field targeting person, got %#v", decoded.Types["project"].Fields)
+	}
+	if trait, ok := decoded.Traits["priority"]; !ok || len(trait.Values) != 3 {
+		t.Fatalf("expected priority trait with enum values, got %#v", decoded.Traits)
+	}
+}
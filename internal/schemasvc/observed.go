@@ -0,0 +1,64 @@
+package schemasvc
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/schema"
+)
+
+// ObservedField is a frontmatter key found on indexed objects of a type.
+type ObservedField struct {
+	Name     string `json:"name"`
+	Count    int    `json:"count"`
+	InSchema bool   `json:"in_schema"`
+}
+
+// ObservedFieldsResult lists observed fields for a type, most common first.
+type ObservedFieldsResult struct {
+	Type   string          `json:"type"`
+	Fields []ObservedField `json:"fields"`
+}
+
+// ObservedFields reports which frontmatter fields objects of typeName actually
+// use, with counts, including keys the schema does not declare yet.
+func ObservedFields(vaultPath, typeName string) (*ObservedFieldsResult, error) {
+	typeName = strings.TrimSpace(typeName)
+	if typeName == "" {
+		return nil, newError(ErrorInvalidInput, "type name is required", "Usage: rvn fields <type>", nil, nil)
+	}
+
+	sch, err := loadSchema(vaultPath, "Run 'rvn init' to create a schema")
+	if err != nil {
+		return nil, err
+	}
+	var typeDef *schema.TypeDefinition
+	if !schema.IsBuiltinType(typeName) {
+		typeDef = sch.Types[typeName]
+	}
+
+	db, err := index.Open(vaultPath)
+	if err != nil {
+		return nil, newError(ErrorDatabase, "failed to open database", "Run 'rvn reindex' to rebuild the database", nil, err)
+	}
+	defer db.Close()
+
+	counts, err := db.ObservedFields(typeName)
+	if err != nil {
+		return nil, newError(ErrorDatabase, fmt.Sprintf("failed to query fields for type '%s'", typeName), "", nil, err)
+	}
+	if len(counts) == 0 && typeDef == nil && !schema.IsBuiltinType(typeName) {
+		return nil, newError(ErrorTypeNotFound, fmt.Sprintf("type '%s' not found", typeName), "Run 'rvn schema types' to see available types", nil, nil)
+	}
+
+	result := &ObservedFieldsResult{Type: typeName, Fields: make([]ObservedField, 0, len(counts))}
+	for _, count := range counts {
+		inSchema := false
+		if typeDef != nil {
+			_, inSchema = typeDef.Fields[count.Name]
+		}
+		result.Fields = append(result.Fields, ObservedField{Name: count.Name, Count: count.Count, InSchema: inSchema})
+	}
+	return result, nil
+}
@@ -141,6 +141,9 @@ func RenameField(req RenameFieldRequest) (*RenameFieldResult, error) {
 	if _, ok := typeDef.Fields[oldField]; !ok {
 		return nil, newError(ErrorFieldNotFound, fmt.Sprintf("field '%s' not found on type '%s'", oldField, typeName), "", nil, nil)
 	}
+	if err := checkFieldNotInherited(typeDef, typeName, oldField); err != nil {
+		return nil, err
+	}
 	if _, ok := typeDef.Fields[newField]; ok {
 		return nil, newError(ErrorObjectExists, fmt.Sprintf("field '%s' already exists on type '%s'", newField, typeName), "", nil, nil)
 	}
@@ -299,13 +302,24 @@ func RenameType(req RenameTypeRequest) (*RenameTypeResult, error) {
 	}
 
 	for typeName, typeDef := range sch.Types {
-		if typeDef == nil || typeDef.Fields == nil {
+		if typeDef == nil {
 			continue
 		}
+		if strings.TrimSpace(typeDef.Extends) == oldName {
+			changes = append(changes, TypeRenameChange{
+				FilePath:    "schema.yaml",
+				ChangeType:  "schema_extends",
+				Description: fmt.Sprintf("update type '%s' extends from '%s' to '%s'", typeName, oldName, newName),
+			})
+		}
 		for fieldName, fieldDef := range typeDef.Fields {
 			if fieldDef == nil {
 				continue
 			}
+			// Inherited fields are rewritten on the type that declares them.
+			if _, inherited := typeDef.InheritedFrom(fieldName); inherited {
+				continue
+			}
 			if fieldDef.Target == oldName {
 				changes = append(changes, TypeRenameChange{
 					FilePath:    "schema.yaml",
@@ -455,6 +469,10 @@ func RenameType(req RenameTypeRequest) (*RenameTypeResult, error) {
 		if !ok {
 			continue
 		}
+		if extends, ok := typeMap["extends"].(string); ok && strings.TrimSpace(extends) == oldName {
+			typeMap["extends"] = newName
+			appliedChanges++
+		}
 		fields, ok := typeMap["fields"].(map[string]interface{})
 		if !ok {
 			continue
@@ -0,0 +1,222 @@
+package schemasvc
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+
+	"gopkg.in/yaml.v3"
+
+	"github.com/aidanlsb/raven/internal/dates"
+	"github.com/aidanlsb/raven/internal/index"
+	"github.com/aidanlsb/raven/internal/schema"
+)
+
+// SuggestedField is a field inferred from values found in existing notes.
+type SuggestedField struct {
+	Name  string           `json:"name"`
+	Type  schema.FieldType `json:"type"`
+	Count int              `json:"count"`
+}
+
+// SuggestedType groups the inferred fields for one object type. Only fields
+// the schema does not declare yet are included.
+type SuggestedType struct {
+	Name    string           `json:"name"`
+	Objects int              `json:"objects"`
+	Core    bool             `json:"core,omitempty"`
+	Fields  []SuggestedField `json:"fields"`
+}
+
+// SuggestSchemaResult holds the inferred types and a schema.yaml snippet
+// that declares them.
+type SuggestSchemaResult struct {
+	Types []SuggestedType `json:"types"`
+	YAML  string          `json:"yaml"`
+}
+
+// fieldKinds tallies the value kinds seen for one field.
+type fieldKinds struct {
+	kinds map[schema.FieldType]int
+	array bool
+}
+
+// SuggestSchema infers field types from the frontmatter values indexed for
+// each object type (including the fallback 'page' type). Field counts match
+// 'rvn fields'. Nothing is written; the result is a candidate snippet to
+// review and paste into schema.yaml.
+func SuggestSchema(vaultPath string) (*SuggestSchemaResult, error) {
+	sch, err := loadSchema(vaultPath, "Run 'rvn init' to create a schema")
+	if err != nil {
+		return nil, err
+	}
+
+	db, err := index.Open(vaultPath)
+	if err != nil {
+		return nil, newError(ErrorDatabase, "failed to open database", "Run 'rvn reindex' to rebuild the database", nil, err)
+	}
+	defer db.Close()
+
+	typeCounts, err := db.ObjectCountsByType()
+	if err != nil {
+		return nil, newError(ErrorDatabase, "failed to query object types", "", nil, err)
+	}
+
+	result := &SuggestSchemaResult{Types: []SuggestedType{}}
+	for _, typeCount := range typeCounts {
+		typeName := typeCount.Type
+		var typeDef *schema.TypeDefinition
+		if !schema.IsBuiltinType(typeName) {
+			typeDef = sch.Types[typeName]
+		}
+
+		counts, err := db.ObservedFields(typeName)
+		if err != nil {
+			return nil, newError(ErrorDatabase, fmt.Sprintf("failed to query fields for type '%s'", typeName), "", nil, err)
+		}
+		objects, err := db.QueryObjects(typeName)
+		if err != nil {
+			return nil, newError(ErrorDatabase, fmt.Sprintf("failed to query objects of type '%s'", typeName), "", nil, err)
+		}
+		refTargets, err := db.RefTargetsByObject(typeName)
+		if err != nil {
+			return nil, newError(ErrorDatabase, fmt.Sprintf("failed to query refs for type '%s'", typeName), "", nil, err)
+		}
+
+		observed := make(map[string]*fieldKinds)
+		for _, obj := range objects {
+			for name, value := range obj.Fields {
+				kinds := observed[name]
+				if kinds == nil {
+					kinds = &fieldKinds{kinds: make(map[schema.FieldType]int)}
+					observed[name] = kinds
+				}
+				kinds.observe(value, refTargets[obj.ID])
+			}
+		}
+
+		suggested := SuggestedType{
+			Name:    typeName,
+			Objects: typeCount.Count,
+			Core:    schema.IsBuiltinType(typeName),
+		}
+		// counts is ordered by count (descending), then name.
+		for _, count := range counts {
+			if typeDef != nil {
+				if _, declared := typeDef.Fields[count.Name]; declared {
+					continue
+				}
+			}
+			fieldType := schema.FieldTypeString
+			if kinds := observed[count.Name]; kinds != nil {
+				fieldType = kinds.inferredType()
+			}
+			suggested.Fields = append(suggested.Fields, SuggestedField{Name: count.Name, Type: fieldType, Count: count.Count})
+		}
+		if len(suggested.Fields) == 0 {
+			continue
+		}
+		result.Types = append(result.Types, suggested)
+	}
+	sort.Slice(result.Types, func(i, j int) bool {
+		return result.Types[i].Name < result.Types[j].Name
+	})
+
+	result.YAML = renderSuggestedSchemaYAML(result.Types)
+	return result, nil
+}
+
+// observe records the kind of an indexed field value. refTargets holds the
+// wikilink targets written in the same object.
+func (k *fieldKinds) observe(value interface{}, refTargets map[string]struct{}) {
+	switch v := value.(type) {
+	case nil:
+		return
+	case []interface{}:
+		k.array = true
+		for _, item := range v {
+			if item != nil {
+				k.kinds[scalarFieldType(item, refTargets)]++
+			}
+		}
+		return
+	}
+	k.kinds[scalarFieldType(value, refTargets)]++
+}
+
+// inferredType picks a single field type for the observed values. Dates mixed
+// with datetimes widen to datetime; any other mix falls back to string.
+func (k *fieldKinds) inferredType() schema.FieldType {
+	fieldType := schema.FieldTypeString
+	switch len(k.kinds) {
+	case 1:
+		for kind := range k.kinds {
+			fieldType = kind
+		}
+	case 2:
+		if k.kinds[schema.FieldTypeDate] > 0 && k.kinds[schema.FieldTypeDatetime] > 0 {
+			fieldType = schema.FieldTypeDatetime
+		}
+	}
+	if k.array {
+		return fieldType + "[]"
+	}
+	return fieldType
+}
+
+func scalarFieldType(value interface{}, refTargets map[string]struct{}) schema.FieldType {
+	switch v := value.(type) {
+	case bool:
+		return schema.FieldTypeBool
+	case float64:
+		return schema.FieldTypeNumber
+	case string:
+		if _, ok := refTargets[v]; ok {
+			return schema.FieldTypeRef
+		}
+		// Dates are indexed as strings, whether or not they were quoted.
+		if dates.IsValidDate(v) {
+			return schema.FieldTypeDate
+		}
+		if dates.IsValidDatetime(v) {
+			return schema.FieldTypeDatetime
+		}
+	}
+	return schema.FieldTypeString
+}
+
+// renderSuggestedSchemaYAML writes the suggestion as a schema.yaml fragment,
+// noting how many objects set each field. Core types cannot declare fields, so
+// their suggestions are commented out as a starting point for a new type.
+func renderSuggestedSchemaYAML(types []SuggestedType) string {
+	if len(types) == 0 {
+		return ""
+	}
+
+	var b strings.Builder
+	b.WriteString("types:\n")
+	for _, typeDef := range types {
+		prefix := ""
+		if typeDef.Core {
+			prefix = "# "
+			fmt.Fprintf(&b, "  # '%s' is a core type and cannot declare fields.\n", typeDef.Name)
+			b.WriteString("  # Rename this block and set `type:` on those notes to adopt it.\n")
+		}
+		fmt.Fprintf(&b, "  %s%s:\n", prefix, yamlScalar(typeDef.Name))
+		fmt.Fprintf(&b, "  %s  fields:\n", prefix)
+		for _, field := range typeDef.Fields {
+			fmt.Fprintf(&b, "  %s    %s:  # set on %d of %d\n", prefix, yamlScalar(field.Name), field.Count, typeDef.Objects)
+			fmt.Fprintf(&b, "  %s      type: %s\n", prefix, field.Type)
+		}
+	}
+	return b.String()
+}
+
+// yamlScalar quotes a key only when YAML would otherwise misread it.
+func yamlScalar(s string) string {
+	out, err := yaml.Marshal(s)
+	if err != nil {
+		return fmt.Sprintf("%q", s)
+	}
+	return strings.TrimSpace(string(out))
+}
@@ -0,0 +1,97 @@
+package schemasvc
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/aidanlsb/raven/internal/schema"
+	"github.com/aidanlsb/raven/internal/testutil"
+)
+
+func TestSuggestSchemaInfersFieldTypes(t *testing.T) {
+	t.Parallel()
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.PersonProjectSchema()).
+		WithFile("books/dune.md", "---\ntype: book\npages: 412\nrating: 4.5\nfinished: 2024-03-01\nfavorite: true\nauthor: \"[[people/frank]]\"\ntags: [scifi, classic]\n---\n").
+		WithFile("books/emma.md", "---\ntype: book\npages: 474\nfinished: \"2023-11-20\"\nauthor: \"[[people/jane]]\"\nnote: 12\n---\n").
+		WithFile("books/notes.md", "---\ntype: book\nnote: see chapter 3\n---\n").
+		WithFile("people/frank.md", "---\ntype: person\nname: Frank\nborn: 1920-10-08\n---\n").
+		WithFile("ideas.md", "---\ncreated: 2024-01-02T09:30\n---\n# Ideas\n").
+		Build()
+	vault.RunCLI("reindex").MustSucceed(t)
+
+	result, err := SuggestSchema(vault.Path)
+	if err != nil {
+		t.Fatalf("SuggestSchema returned error: %v", err)
+	}
+
+	got := make(map[string]map[string]SuggestedField)
+	for _, typeDef := range result.Types {
+		got[typeDef.Name] = make(map[string]SuggestedField)
+		for _, field := range typeDef.Fields {
+			got[typeDef.Name][field.Name] = field
+		}
+	}
+
+	want := map[string]map[string]schema.FieldType{
+		"book": {
+			"pages":    schema.FieldTypeNumber,
+			"rating":   schema.FieldTypeNumber,
+			"finished": schema.FieldTypeDate,
+			"favorite": schema.FieldTypeBool,
+			"author":   schema.FieldTypeRef,
+			"tags":     schema.FieldTypeStringArray,
+			"note":     schema.FieldTypeString,
+		},
+		"person": {"born": schema.FieldTypeDate},
+		"page":   {"created": schema.FieldTypeDatetime},
+	}
+	for typeName, fields := range want {
+		for name, fieldType := range fields {
+			if got[typeName][name].Type != fieldType {
+				t.Errorf("%s.%s = %q, want %q", typeName, name, got[typeName][name].Type, fieldType)
+			}
+		}
+		if len(got[typeName]) != len(fields) {
+			t.Errorf("%s fields = %v, want only %v", typeName, got[typeName], fields)
+		}
+	}
+	if _, ok := got["project"]; ok {
+		t.Errorf("project has no notes and should not be suggested: %v", got["project"])
+	}
+	if got["book"]["pages"].Count != 2 {
+		t.Errorf("book.pages count = %d, want 2", got["book"]["pages"].Count)
+	}
+
+	if !strings.Contains(result.YAML, "    pages:  # set on 2 of 3\n      type: number\n") {
+		t.Errorf("snippet missing pages field:\n%s", result.YAML)
+	}
+	if !strings.Contains(result.YAML, "  # page:\n") {
+		t.Errorf("expected core page type to be commented out:\n%s", result.YAML)
+	}
+}
+
+func TestSuggestSchemaSnippetLoads(t *testing.T) {
+	t.Parallel()
+	vault := testutil.NewTestVault(t).
+		WithSchema(testutil.MinimalSchema()).
+		WithFile("books/dune.md", "---\ntype: book\npages: 412\nfinished: 2024-03-01\n\"read on\": [2024-01-01, 2024-02-01]\n---\n").
+		WithFile("ideas.md", "---\ntitle: Ideas\n---\n").
+		Build()
+	vault.RunCLI("reindex").MustSucceed(t)
+
+	result, err := SuggestSchema(vault.Path)
+	if err != nil {
+		t.Fatalf("SuggestSchema returned error: %v", err)
+	}
+
+	vault.WriteFile("schema.yaml", "version: 2\n"+result.YAML)
+	sch, err := schema.Load(vault.Path)
+	if err != nil {
+		t.Fatalf("suggested snippet does not load: %v\n%s", err, result.YAML)
+	}
+	book := sch.Types["book"]
+	if book == nil || book.Fields["read on"] == nil || book.Fields["read on"].Type != schema.FieldTypeDateArray {
+		t.Fatalf("expected book with date[] 'read on' field, got %#v\n%s", book, result.YAML)
+	}
+}
@@ -2,6 +2,7 @@ package schemasvc
 
 import (
 	"fmt"
+	"sort"
 	"strings"
 
 	"github.com/aidanlsb/raven/internal/codes"
@@ -341,6 +342,9 @@ func UpdateField(req UpdateFieldRequest) (*UpdateResult, error) {
 			nil,
 		)
 	}
+	if err := checkFieldNotInherited(typeDef, typeName, fieldName); err != nil {
+		return nil, err
+	}
 	requestedBaseType := ""
 	if strings.TrimSpace(req.FieldType) != "" {
 		requestedBaseType = normalizeFieldTypeAlias(strings.TrimSuffix(strings.TrimSpace(req.FieldType), "[]"))
@@ -540,6 +544,26 @@ func currentFieldType(def *schema.FieldDefinition) string {
 	return strings.TrimSpace(string(def.Type))
 }
 
+// checkFieldNotInherited rejects edits to a field the type only inherits via
+// extends, since the schema entry lives on the ancestor that declares it.
+func checkFieldNotInherited(typeDef *schema.TypeDefinition, typeName, fieldName string) error {
+	owner, inherited := typeDef.InheritedFrom(fieldName)
+	if !inherited {
+		return nil
+	}
+	return newError(
+		ErrorInvalidInput,
+		fmt.Sprintf("field '%s' on type '%s' is inherited from '%s'", fieldName, typeName, owner),
+		fmt.Sprintf("Change it on type '%s' instead", owner),
+		map[string]interface{}{
+			"type":           typeName,
+			"field":          fieldName,
+			"inherited_from": owner,
+		},
+		nil,
+	)
+}
+
 func RemoveType(req RemoveTypeRequest) (*RemoveResult, error) {
 	typeName := strings.TrimSpace(req.TypeName)
 	if typeName == "" {
@@ -562,6 +586,25 @@ func RemoveType(req RemoveTypeRequest) (*RemoveResult, error) {
 	if _, exists := sch.Types[typeName]; !exists {
 		return nil, newError(ErrorTypeNotFound, fmt.Sprintf("type '%s' not found", typeName), "", nil, nil)
 	}
+	children := make([]string, 0)
+	for name, typeDef := range sch.Types {
+		if typeDef != nil && strings.TrimSpace(typeDef.Extends) == typeName {
+			children = append(children, name)
+		}
+	}
+	if len(children) > 0 {
+		sort.Strings(children)
+		return nil, newError(
+			ErrorDataIntegrity,
+			fmt.Sprintf("cannot remove type '%s': extended by %s", typeName, strings.Join(children, ", ")),
+			"Remove or change 'extends' on those types first",
+			map[string]interface{}{
+				"type":        typeName,
+				"extended_by": children,
+			},
+			nil,
+		)
+	}
 
 	warnings := make([]Warning, 0)
 	if db, err := index.Open(req.VaultPath); err == nil {
@@ -702,6 +745,9 @@ func RemoveField(req RemoveFieldRequest) (*RemoveResult, error) {
 	if !exists {
 		return nil, newError(ErrorFieldNotFound, fmt.Sprintf("field '%s' not found on type '%s'", fieldName, typeName), "", nil, nil)
 	}
+	if err := checkFieldNotInherited(typeDef, typeName, fieldName); err != nil {
+		return nil, err
+	}
 
 	if fieldDef != nil && fieldDef.Required {
 		if db, err := index.Open(req.VaultPath); err == nil {
@@ -140,3 +140,87 @@ traits:
 		t.Fatalf("done default = %#v, want bool(true)", loaded.Traits["done"].Default)
 	}
 }
+
+const inheritanceSchema = `version: 2
+types:
+  person:
+    fields:
+      name: { type: string, required: true }
+      email: { type: string }
+  employee:
+    extends: person
+    fields:
+      team: { type: string }
+traits: {}
+`
+
+func TestRemoveType_RejectsTypeWithChildren(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).WithSchema(inheritanceSchema).Build()
+
+	_, err := RemoveType(RemoveTypeRequest{VaultPath: vault.Path, TypeName: "person", Force: true})
+	var svcErr *Error
+	if !errors.As(err, &svcErr) {
+		t.Fatalf("expected schemasvc error, got %T: %v", err, err)
+	}
+	if svcErr.Code != ErrorDataIntegrity {
+		t.Fatalf("error code = %q, want %q", svcErr.Code, ErrorDataIntegrity)
+	}
+	if !strings.Contains(svcErr.Message, "extended by employee") {
+		t.Fatalf("unexpected error message: %q", svcErr.Message)
+	}
+	if _, err := schema.Load(vault.Path); err != nil {
+		t.Fatalf("expected schema to still load, got %v", err)
+	}
+
+	if _, err := RemoveType(RemoveTypeRequest{VaultPath: vault.Path, TypeName: "employee", Force: true}); err != nil {
+		t.Fatalf("RemoveType child returned error: %v", err)
+	}
+	if _, err := RemoveType(RemoveTypeRequest{VaultPath: vault.Path, TypeName: "person", Force: true}); err != nil {
+		t.Fatalf("RemoveType parent after child returned error: %v", err)
+	}
+}
+
+func TestFieldEdits_RejectInheritedFields(t *testing.T) {
+	t.Parallel()
+
+	vault := testutil.NewTestVault(t).WithSchema(inheritanceSchema).Build()
+	before := vault.ReadFile("schema.yaml")
+
+	edits := map[string]func() error{
+		"update": func() error {
+			_, err := UpdateField(UpdateFieldRequest{VaultPath: vault.Path, TypeName: "employee", FieldName: "email", Required: "true"})
+			return err
+		},
+		"remove": func() error {
+			_, err := RemoveField(RemoveFieldRequest{VaultPath: vault.Path, TypeName: "employee", FieldName: "email"})
+			return err
+		},
+		"rename": func() error {
+			_, err := RenameField(RenameFieldRequest{VaultPath: vault.Path, TypeName: "employee", OldField: "email", NewField: "mail", Confirm: true})
+			return err
+		},
+	}
+	for name, edit := range edits {
+		err := edit()
+		var svcErr *Error
+		if !errors.As(err, &svcErr) {
+			t.Fatalf("%s: expected schemasvc error, got %T: %v", name, err, err)
+		}
+		if svcErr.Code != ErrorInvalidInput {
+			t.Fatalf("%s: error code = %q, want %q", name, svcErr.Code, ErrorInvalidInput)
+		}
+		if !strings.Contains(svcErr.Message, "inherited from 'person'") || !strings.Contains(svcErr.Suggestion, "'person'") {
+			t.Fatalf("%s: expected pointer to parent type, got %q / %q", name, svcErr.Message, svcErr.Suggestion)
+		}
+	}
+	if got := vault.ReadFile("schema.yaml"); got != before {
+		t.Fatalf("expected schema.yaml unchanged, got:\n%s", got)
+	}
+
+	// Fields declared on the child itself remain editable.
+	if _, err := RemoveField(RemoveFieldRequest{VaultPath: vault.Path, TypeName: "employee", FieldName: "team"}); err != nil {
+		t.Fatalf("RemoveField own field returned error: %v", err)
+	}
+}
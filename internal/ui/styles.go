@@ -6,6 +6,14 @@ import (
 	"strings"
 
 	"github.com/charmbracelet/lipgloss"
+	"github.com/muesli/termenv"
+)
+
+// Color modes accepted by the global --color flag.
+const (
+	ColorAuto   = "auto"   // Color when stdout is a terminal and NO_COLOR is unset
+	ColorAlways = "always" // Color even when piped; overrides NO_COLOR
+	ColorNever  = "never"  // No ANSI styling at all
 )
 
 // Minimal color palette with focused semantic accents.
@@ -36,6 +44,12 @@ var (
 	SyntaxSubtle = lipgloss.NewStyle().Foreground(lipgloss.Color("6"))
 
 	accentColor string
+
+	colorMode = ColorAuto
+
+	// autoColorProfile is the profile lipgloss detected for stdout at startup,
+	// restored when switching back to auto.
+	autoColorProfile = lipgloss.ColorProfile()
 )
 
 // ConfigureTheme configures optional UI theme colors from config.
@@ -81,8 +95,35 @@ func AccentColor() (string, bool) {
 	return accentColor, true
 }
 
+// SetColorMode applies the --color flag. It must run before ConfigureTheme.
+// Unrecognized modes are rejected and leave the current mode unchanged.
+func SetColorMode(mode string) bool {
+	switch strings.ToLower(strings.TrimSpace(mode)) {
+	case "", ColorAuto:
+		colorMode = ColorAuto
+		lipgloss.SetColorProfile(autoColorProfile)
+	case ColorAlways:
+		colorMode = ColorAlways
+		lipgloss.SetColorProfile(termenv.ANSI256)
+	case ColorNever:
+		colorMode = ColorNever
+		lipgloss.SetColorProfile(termenv.Ascii)
+	default:
+		return false
+	}
+	return true
+}
+
 // NoColorEnabled returns true when terminal color output should be suppressed.
+// An explicit --color mode wins over the NO_COLOR environment variable; in auto
+// mode, lipgloss additionally drops styling when stdout is not a terminal.
 func NoColorEnabled() bool {
+	switch colorMode {
+	case ColorNever:
+		return true
+	case ColorAlways:
+		return false
+	}
 	return os.Getenv("NO_COLOR") != ""
 }
 
@@ -1,6 +1,11 @@
 package ui
 
-import "testing"
+import (
+	"strings"
+	"testing"
+
+	"github.com/charmbracelet/lipgloss"
+)
 
 func TestNormalizeAccentColor(t *testing.T) {
 	tests := []struct {
@@ -106,3 +111,76 @@ func TestConfigureThemeHonorsNoColor(t *testing.T) {
 		t.Fatalf("expected configured accent color to be ignored when NO_COLOR is set")
 	}
 }
+
+func TestSetColorModeNeverStripsStyling(t *testing.T) {
+	t.Setenv("NO_COLOR", "")
+	restoreThemeAfterTest(t)
+
+	if !SetColorMode(ColorNever) {
+		t.Fatalf("expected %q to be accepted", ColorNever)
+	}
+	ConfigureTheme("39")
+
+	for name, style := range map[string]lipgloss.Style{
+		"accent": Accent,
+		"bold":   Bold,
+		"muted":  Muted,
+		"syntax": Syntax,
+		"ad hoc": lipgloss.NewStyle().Foreground(lipgloss.Color("1")).Bold(true),
+	} {
+		if got := style.Render("value"); got != "value" {
+			t.Errorf("%s style rendered %q with --color never, want plain text", name, got)
+		}
+	}
+}
+
+func TestSetColorModeAlwaysOverridesNoColor(t *testing.T) {
+	t.Setenv("NO_COLOR", "1")
+	restoreThemeAfterTest(t)
+
+	if !SetColorMode(ColorAlways) {
+		t.Fatalf("expected %q to be accepted", ColorAlways)
+	}
+	ConfigureTheme("")
+
+	if got := Bold.Render("value"); !strings.Contains(got, "\x1b[") {
+		t.Fatalf("expected ANSI escapes with --color always, got %q", got)
+	}
+
+	SetColorMode(ColorAuto)
+	ConfigureTheme("")
+	if got := Bold.Render("value"); got != "value" {
+		t.Fatalf("expected NO_COLOR to apply again in auto mode, got %q", got)
+	}
+}
+
+func TestSetColorModeRejectsUnknownMode(t *testing.T) {
+	restoreThemeAfterTest(t)
+
+	if SetColorMode("sometimes") {
+		t.Fatalf("expected unknown color mode to be rejected")
+	}
+	if colorMode != ColorAuto {
+		t.Fatalf("expected mode to stay %q, got %q", ColorAuto, colorMode)
+	}
+}
+
+func restoreThemeAfterTest(t *testing.T) {
+	t.Helper()
+
+	origAccent := Accent
+	origBold := Bold
+	origMuted := Muted
+	origSyntax := Syntax
+	origSyntaxSubtle := SyntaxSubtle
+	origAccentColor := accentColor
+	t.Cleanup(func() {
+		SetColorMode(ColorAuto)
+		Accent = origAccent
+		Bold = origBold
+		Muted = origMuted
+		Syntax = origSyntax
+		SyntaxSubtle = origSyntaxSubtle
+		accentColor = origAccentColor
+	})
+}
@@ -36,7 +36,7 @@ func OpenInEditorAtLine(cfg *config.Config, filePath string, line int) bool {
 	}
 
 	var cmd *exec.Cmd
-	args := editorOpenArgs(editor, filePath, line)
+	args := editorOpenArgs(cfg.EditorProfile, editor, filePath, line)
 
 	// If editor contains spaces, it's a compound command like "open -a Cursor"
 	// Execute via shell to handle this correctly
@@ -64,24 +64,46 @@ func OpenInEditorAtLine(cfg *config.Config, filePath string, line int) bool {
 	return true
 }
 
-func editorOpenArgs(editor, filePath string, line int) []string {
+// editorOpenArgs builds the arguments that open filePath at line. The
+// configured profile wins; otherwise it is detected from the editor command.
+func editorOpenArgs(profile, editor, filePath string, line int) []string {
 	if line <= 0 {
 		return []string{filePath}
 	}
 
+	profile = strings.ToLower(strings.TrimSpace(profile))
+	if profile == "" {
+		profile = detectEditorProfile(editor)
+	}
+
 	lineTarget := fmt.Sprintf("%s:%d", filePath, line)
-	switch editorCommandName(editor) {
-	case "code", "code-insiders", "codium", "cursor":
+	switch profile {
+	case "vscode":
 		return []string{"-g", lineTarget}
-	case "vi", "vim", "vimdiff", "nvim", "nvimdiff", "nano", "micro":
+	case "vim", "emacs":
 		return []string{fmt.Sprintf("+%d", line), filePath}
-	case "hx", "helix", "kak", "kakoune":
+	case "helix":
 		return []string{lineTarget}
 	default:
 		return []string{filePath}
 	}
 }
 
+func detectEditorProfile(editor string) string {
+	switch editorCommandName(editor) {
+	case "code", "code-insiders", "codium", "cursor", "windsurf":
+		return "vscode"
+	case "vi", "vim", "vimdiff", "nvim", "nvimdiff", "nano", "micro":
+		return "vim"
+	case "emacs", "emacsclient":
+		return "emacs"
+	case "hx", "helix", "kak", "kakoune", "subl", "zed":
+		return "helix"
+	default:
+		return "none"
+	}
+}
+
 func quoteShellArgs(args []string) string {
 	quoted := make([]string, len(args))
 	for i, arg := range args {
@@ -70,12 +70,14 @@ func TestEditorOpenArgsAtLine(t *testing.T) {
 		{name: "code with args", editor: "code --reuse-window", path: "/tmp/note.md", line: 42, want: []string{"-g", "/tmp/note.md:42"}},
 		{name: "vim", editor: "vim", path: "/tmp/note.md", line: 42, want: []string{"+42", "/tmp/note.md"}},
 		{name: "helix", editor: "hx", path: "/tmp/note.md", line: 42, want: []string{"/tmp/note.md:42"}},
+		{name: "emacsclient", editor: "emacsclient -n", path: "/tmp/note.md", line: 42, want: []string{"+42", "/tmp/note.md"}},
+		{name: "zed", editor: "zed", path: "/tmp/note.md", line: 42, want: []string{"/tmp/note.md:42"}},
 		{name: "unknown", editor: "unknown", path: "/tmp/note.md", line: 42, want: []string{"/tmp/note.md"}},
 	}
 
 	for _, tt := range tests {
 		t.Run(tt.name, func(t *testing.T) {
-			got := editorOpenArgs(tt.editor, tt.path, tt.line)
+			got := editorOpenArgs("", tt.editor, tt.path, tt.line)
 			if strings.Join(got, "|") != strings.Join(tt.want, "|") {
 				t.Fatalf("editorOpenArgs() = %#v, want %#v", got, tt.want)
 			}
@@ -83,6 +85,32 @@ func TestEditorOpenArgsAtLine(t *testing.T) {
 	}
 }
 
+func TestEditorOpenArgsWithProfile(t *testing.T) {
+	t.Parallel()
+	tests := []struct {
+		name    string
+		profile string
+		editor  string
+		want    []string
+	}{
+		{name: "vscode wrapper", profile: "vscode", editor: "open -a Cursor", want: []string{"-g", "/tmp/note.md:7"}},
+		{name: "vim wrapper", profile: "vim", editor: "kitty nvim", want: []string{"+7", "/tmp/note.md"}},
+		{name: "helix", profile: "Helix", editor: "my-editor", want: []string{"/tmp/note.md:7"}},
+		{name: "emacs", profile: "emacs", editor: "my-editor", want: []string{"+7", "/tmp/note.md"}},
+		{name: "none overrides detection", profile: "none", editor: "code", want: []string{"/tmp/note.md"}},
+		{name: "unknown profile", profile: "sublime", editor: "code", want: []string{"/tmp/note.md"}},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := editorOpenArgs(tt.profile, tt.editor, "/tmp/note.md", 7)
+			if strings.Join(got, "|") != strings.Join(tt.want, "|") {
+				t.Fatalf("editorOpenArgs(%q, %q) = %#v, want %#v", tt.profile, tt.editor, got, tt.want)
+			}
+		})
+	}
+}
+
 func TestParseEditorMode(t *testing.T) {
 	t.Parallel()
 	tests := []struct {
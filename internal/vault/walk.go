@@ -30,11 +30,43 @@ type WalkOptions struct {
 	ParseOptions *parser.ParseOptions
 	// ExcludeMatcher skips paths that are not managed by Raven.
 	ExcludeMatcher *ravenignore.Matcher
+	// FollowSymlinks reads symlinked files and descends into symlinked
+	// directories, even when they point outside the vault. Otherwise only
+	// symlinked files that resolve inside the vault are read.
+	FollowSymlinks bool
+	// Root limits the walk to a file or directory inside the vault. Relative
+	// paths in results stay vault-relative. Empty walks the whole vault.
+	Root string
+	// SkipParse reports each file's path and mtime without reading or
+	// parsing it. Document is nil in every result.
+	SkipParse bool
+}
+
+// ParseOptionsFromConfig builds parse options from raven.yaml settings. It
+// returns nil when the config leaves every option at its default.
+func ParseOptionsFromConfig(vaultCfg *config.VaultConfig) *parser.ParseOptions {
+	if vaultCfg == nil {
+		return nil
+	}
+	opts := &parser.ParseOptions{
+		ObjectsRoot:   vaultCfg.GetObjectsRoot(),
+		PagesRoot:     vaultCfg.GetPagesRoot(),
+		CheckboxTasks: vaultCfg.CheckboxTasks,
+	}
+	if style := vaultCfg.GetSectionIDStyle(); style != parser.SectionIDStyleSlug {
+		opts.SectionIDStyle = style
+	}
+	if *opts == (parser.ParseOptions{}) {
+		return nil
+	}
+	return opts
 }
 
 // WalkMarkdownFiles walks all markdown files in a vault and calls the handler for each.
 // It automatically:
 // - Skips the .raven directory
+// - Follows symlinked directories and links leaving the vault only when
+//   WalkOptions.FollowSymlinks is set
 // - Only processes .md files
 // - Verifies files are within the vault (security check)
 // - Parses each document
@@ -45,11 +77,35 @@ func WalkMarkdownFiles(vaultPath string, handler func(result WalkResult) error)
 // WalkMarkdownFilesWithOptions walks all markdown files with custom options.
 func WalkMarkdownFilesWithOptions(vaultPath string, opts *WalkOptions, handler func(result WalkResult) error) error {
 	var parseOpts *parser.ParseOptions
+	followSymlinks := false
+	root := vaultPath
 	if opts != nil {
 		parseOpts = opts.ParseOptions
+		followSymlinks = opts.FollowSymlinks
+		if opts.Root != "" {
+			root = opts.Root
+		}
+	}
+
+	// Resolved directories already walked, so a symlink back to an ancestor
+	// cannot loop forever when following symlinks.
+	visitedDirs := make(map[string]bool)
+
+	var visit fs.WalkDirFunc
+
+	// walkLinkedDir walks the target of a symlinked directory, reporting paths
+	// under the link's location in the vault.
+	walkLinkedDir := func(linkPath, realDir string) error {
+		return filepath.WalkDir(realDir, func(path string, d fs.DirEntry, err error) error {
+			rel, relErr := filepath.Rel(realDir, path)
+			if relErr != nil {
+				return relErr
+			}
+			return visit(filepath.Join(linkPath, rel), d, err)
+		})
 	}
 
-	return filepath.WalkDir(vaultPath, func(path string, d fs.DirEntry, err error) error {
+	visit = func(path string, d fs.DirEntry, err error) error {
 		if err != nil {
 			relativePath, _ := filepath.Rel(vaultPath, path)
 			return handler(WalkResult{
@@ -62,15 +118,55 @@ func WalkMarkdownFilesWithOptions(vaultPath string, opts *WalkOptions, handler f
 		relativePath, _ := filepath.Rel(vaultPath, path)
 		relativePath = filepath.ToSlash(relativePath)
 
+		// Without FollowSymlinks, a symlink falls through as a plain file: links
+		// to directories fail the .md check and links leaving the vault fail
+		// the within-vault check below.
+		if d.Type()&fs.ModeSymlink != 0 && followSymlinks {
+			realPath, err := filepath.EvalSymlinks(path)
+			if err != nil {
+				// Only broken links that look like notes are worth reporting.
+				if !strings.HasSuffix(path, ".md") {
+					return nil
+				}
+				return handler(WalkResult{
+					Path:         path,
+					RelativePath: relativePath,
+					Error:        err,
+				})
+			}
+			info, err := os.Stat(realPath)
+			if err != nil {
+				return handler(WalkResult{
+					Path:         path,
+					RelativePath: relativePath,
+					Error:        err,
+				})
+			}
+			if info.IsDir() {
+				if visitedDirs[realPath] {
+					return nil
+				}
+				return walkLinkedDir(path, realPath)
+			}
+		}
+
 		// Skip directories, but skip .raven, .trash, and excluded directories entirely
 		if d.IsDir() {
-			name := d.Name()
+			name := filepath.Base(path)
 			if name == ".raven" || name == ".trash" || name == ".git" {
 				return filepath.SkipDir
 			}
 			if relativePath != "." && opts != nil && opts.ExcludeMatcher.Match(relativePath, true) {
 				return filepath.SkipDir
 			}
+			if followSymlinks {
+				if realPath, err := filepath.EvalSymlinks(path); err == nil {
+					if visitedDirs[realPath] {
+						return filepath.SkipDir
+					}
+					visitedDirs[realPath] = true
+				}
+			}
 			return nil
 		}
 
@@ -79,8 +175,9 @@ func WalkMarkdownFilesWithOptions(vaultPath string, opts *WalkOptions, handler f
 			return nil
 		}
 
-		// Security: verify file is within vault
-		if err := paths.ValidateWithinVault(vaultPath, path); err != nil {
+		// Security: verify file is within vault. Followed symlinks may
+		// deliberately point outside it.
+		if err := paths.ValidateNotePath(vaultPath, path, followSymlinks); err != nil {
 			if errors.Is(err, paths.ErrPathOutsideVault) {
 				return nil
 			}
@@ -96,8 +193,11 @@ func WalkMarkdownFilesWithOptions(vaultPath string, opts *WalkOptions, handler f
 			return nil
 		}
 
-		// Get file mtime
+		// Get file mtime (of the link target for symlinks)
 		info, err := d.Info()
+		if err == nil && d.Type()&fs.ModeSymlink != 0 {
+			info, err = os.Stat(path)
+		}
 		if err != nil {
 			return handler(WalkResult{
 				Path:         path,
@@ -106,6 +206,13 @@ func WalkMarkdownFilesWithOptions(vaultPath string, opts *WalkOptions, handler f
 			})
 		}
 		fileMtime := info.ModTime().Unix()
+		if opts != nil && opts.SkipParse {
+			return handler(WalkResult{
+				Path:         path,
+				RelativePath: relativePath,
+				FileMtime:    fileMtime,
+			})
+		}
 
 		// Read file
 		content, err := os.ReadFile(path)
@@ -133,7 +240,9 @@ func WalkMarkdownFilesWithOptions(vaultPath string, opts *WalkOptions, handler f
 			Document:     doc,
 			FileMtime:    fileMtime,
 		})
-	})
+	}
+
+	return filepath.WalkDir(root, visit)
 }
 
 // CollectDocuments walks all markdown files and returns parsed documents.
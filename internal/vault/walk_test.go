@@ -5,7 +5,9 @@ import (
 	"path/filepath"
 	"testing"
 
+	"github.com/aidanlsb/raven/internal/config"
 	ravenignore "github.com/aidanlsb/raven/internal/ignore"
+	"github.com/aidanlsb/raven/internal/parser"
 )
 
 func TestWalkMarkdownFiles(t *testing.T) {
@@ -219,6 +221,98 @@ func TestWalkMarkdownFilesWithOptionsExcludesPaths(t *testing.T) {
 	}
 }
 
+func TestWalkMarkdownFilesWithOptionsFollowSymlinks(t *testing.T) {
+	t.Parallel()
+
+	vaultPath := t.TempDir()
+	outside := t.TempDir()
+	if err := os.WriteFile(filepath.Join(vaultPath, "keep.md"), []byte("# Keep\n"), 0o644); err != nil {
+		t.Fatalf("write keep.md: %v", err)
+	}
+	if err := os.WriteFile(filepath.Join(outside, "linked.md"), []byte("# Linked\n"), 0o644); err != nil {
+		t.Fatalf("write linked.md: %v", err)
+	}
+	if err := os.MkdirAll(filepath.Join(outside, "shared"), 0o755); err != nil {
+		t.Fatalf("mkdir shared: %v", err)
+	}
+	if err := os.WriteFile(filepath.Join(outside, "shared", "note.md"), []byte("# Note\n"), 0o644); err != nil {
+		t.Fatalf("write shared/note.md: %v", err)
+	}
+	if err := os.Symlink(filepath.Join(outside, "linked.md"), filepath.Join(vaultPath, "linked.md")); err != nil {
+		t.Skipf("symlinks not supported: %v", err)
+	}
+	if err := os.Symlink(filepath.Join(outside, "shared"), filepath.Join(vaultPath, "shared")); err != nil {
+		t.Fatalf("symlink shared: %v", err)
+	}
+	// A link back to an ancestor must not loop.
+	if err := os.Symlink(outside, filepath.Join(outside, "shared", "loop")); err != nil {
+		t.Fatalf("symlink loop: %v", err)
+	}
+
+	walk := func(follow bool) map[string]bool {
+		found := make(map[string]bool)
+		err := WalkMarkdownFilesWithOptions(vaultPath, &WalkOptions{FollowSymlinks: follow}, func(result WalkResult) error {
+			if result.Error != nil {
+				t.Fatalf("unexpected walk error for %s: %v", result.RelativePath, result.Error)
+			}
+			found[result.RelativePath] = true
+			return nil
+		})
+		if err != nil {
+			t.Fatalf("WalkMarkdownFilesWithOptions returned error: %v", err)
+		}
+		return found
+	}
+
+	disabled := walk(false)
+	if len(disabled) != 1 || !disabled["keep.md"] {
+		t.Fatalf("found files without following = %#v, want only keep.md", disabled)
+	}
+
+	enabled := walk(true)
+	for _, want := range []string{"keep.md", "linked.md", "shared/note.md", "shared/loop/linked.md"} {
+		if !enabled[want] {
+			t.Errorf("found files when following = %#v, missing %s", enabled, want)
+		}
+	}
+	if enabled["shared/loop/shared/note.md"] {
+		t.Errorf("found files when following = %#v, symlink loop was walked twice", enabled)
+	}
+}
+
+func TestWalkMarkdownFilesWithOptionsKeepsInVaultSymlinkedFiles(t *testing.T) {
+	t.Parallel()
+
+	vaultPath := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(vaultPath, "notes"), 0o755); err != nil {
+		t.Fatalf("mkdir notes: %v", err)
+	}
+	if err := os.WriteFile(filepath.Join(vaultPath, "notes", "real.md"), []byte("# Real\n"), 0o644); err != nil {
+		t.Fatalf("write notes/real.md: %v", err)
+	}
+	if err := os.Symlink(filepath.Join(vaultPath, "notes", "real.md"), filepath.Join(vaultPath, "alias.md")); err != nil {
+		t.Skipf("symlinks not supported: %v", err)
+	}
+	if err := os.Symlink(filepath.Join(vaultPath, "notes"), filepath.Join(vaultPath, "mirror")); err != nil {
+		t.Fatalf("symlink mirror: %v", err)
+	}
+
+	found := make(map[string]bool)
+	err := WalkMarkdownFilesWithOptions(vaultPath, &WalkOptions{}, func(result WalkResult) error {
+		if result.Error != nil {
+			t.Fatalf("unexpected walk error for %s: %v", result.RelativePath, result.Error)
+		}
+		found[result.RelativePath] = true
+		return nil
+	})
+	if err != nil {
+		t.Fatalf("WalkMarkdownFilesWithOptions returned error: %v", err)
+	}
+	if len(found) != 2 || !found["notes/real.md"] || !found["alias.md"] {
+		t.Fatalf("found files = %#v, want notes/real.md and alias.md without descending into mirror", found)
+	}
+}
+
 func TestCollectDocuments(t *testing.T) {
 	t.Parallel()
 	// Create a temp directory with test files
@@ -254,3 +348,33 @@ type: page
 		t.Errorf("Got %d errors, want 0", len(errors))
 	}
 }
+
+func TestParseOptionsFromConfig(t *testing.T) {
+	t.Parallel()
+	if got := ParseOptionsFromConfig(nil); got != nil {
+		t.Fatalf("expected nil parse options for nil config, got %#v", got)
+	}
+
+	if got := ParseOptionsFromConfig(&config.VaultConfig{}); got != nil {
+		t.Fatalf("expected nil parse options for default config, got %#v", got)
+	}
+
+	cfg := &config.VaultConfig{
+		Directories: &config.DirectoriesConfig{
+			Object: "objects",
+			Page:   "pages",
+		},
+	}
+	got := ParseOptionsFromConfig(cfg)
+	if got == nil {
+		t.Fatal("expected parse options when directories are configured")
+	}
+	if got.ObjectsRoot != "objects/" || got.PagesRoot != "pages/" {
+		t.Fatalf("unexpected parse options roots: %#v", got)
+	}
+
+	got = ParseOptionsFromConfig(&config.VaultConfig{SectionIDStyle: parser.SectionIDStyleHeadingPath, CheckboxTasks: true})
+	if got == nil || got.SectionIDStyle != parser.SectionIDStyleHeadingPath || !got.CheckboxTasks {
+		t.Fatalf("unexpected parse options: %#v", got)
+	}
+}
@@ -6,16 +6,15 @@
 //	[[target|display text]]
 //
 // Notes:
+//   - The target ends at the first unescaped '|'; display text may contain balanced brackets.
 //   - The target is trimmed of surrounding whitespace.
 //   - The display text (if present) is also trimmed.
+//   - A backslash before the opening brackets (\[[literal]]) escapes the link.
 //   - This package intentionally does NOT understand markdown code fences; higher-level
 //     parsers decide whether scanning is enabled for a given region.
 package wikilink
 
-import (
-	"regexp"
-	"strings"
-)
+import "strings"
 
 // Match represents a wikilink found in a string (typically a single line).
 type Match struct {
@@ -26,9 +25,70 @@ type Match struct {
 	Literal     string
 }
 
-// re matches [[target]] or [[target|display]].
-// The target cannot contain [ or ] to avoid matching array syntax like [[[ref]]].
-var re = regexp.MustCompile(`\[\[([^\]\[|]+)(?:\|([^\]]+))?\]\]`)
+// scan reads a wikilink whose opening "[[" starts at start. The target runs to
+// the first unescaped '|' or to "]]" and cannot contain '[' or ']', which keeps
+// array syntax like [[[ref]]] out of the target. Display text may contain
+// balanced brackets, so [[page|See [note]]] closes on the final "]]"; when the
+// brackets never balance, the first "]]" after the '|' closes the link.
+// Offsets are returned for the whole literal and, when present, the display.
+func scan(s string, start int) (end, targetEnd, displayStart, displayEnd int, ok bool) {
+	if start < 0 || start+1 >= len(s) || s[start] != '[' || s[start+1] != '[' {
+		return 0, 0, 0, 0, false
+	}
+
+	i := start + 2
+	for i < len(s) && s[i] != '|' {
+		switch s[i] {
+		case '\\':
+			if i+1 < len(s) && s[i+1] == '|' {
+				i++
+			}
+		case '[':
+			return 0, 0, 0, 0, false
+		case ']':
+			if i > start+2 && i+1 < len(s) && s[i+1] == ']' {
+				return i + 2, i, -1, -1, true
+			}
+			return 0, 0, 0, 0, false
+		}
+		i++
+	}
+	if i >= len(s) || i == start+2 {
+		return 0, 0, 0, 0, false
+	}
+
+	targetEnd = i
+	displayStart = i + 1
+	depth := 0
+	firstClose := -1
+	for j := displayStart; j < len(s); j++ {
+		switch s[j] {
+		case '\\':
+			j++
+		case '[':
+			depth++
+		case ']':
+			if j+1 < len(s) && s[j+1] == ']' {
+				if firstClose == -1 {
+					firstClose = j
+				}
+				if depth == 0 {
+					if j == displayStart {
+						return 0, 0, 0, 0, false
+					}
+					return j + 2, targetEnd, displayStart, j, true
+				}
+			}
+			if depth > 0 {
+				depth--
+			}
+		}
+	}
+	if firstClose > displayStart {
+		return firstClose + 2, targetEnd, displayStart, firstClose, true
+	}
+	return 0, 0, 0, 0, false
+}
 
 // ParseExact parses a string that is exactly a wikilink literal, returning its target and optional display text.
 func ParseExact(s string) (target string, display *string, ok bool) {
@@ -37,44 +97,76 @@ func ParseExact(s string) (target string, display *string, ok bool) {
 		return "", nil, false
 	}
 	inner := strings.TrimSuffix(strings.TrimPrefix(s, "[["), "]]")
-	parts := strings.SplitN(inner, "|", 2)
-	target = strings.TrimSpace(parts[0])
+	target = inner
+	pipe := unescapedPipe(inner)
+	if pipe >= 0 {
+		target = inner[:pipe]
+	}
+	target = strings.TrimSpace(target)
 	if target == "" {
 		return "", nil, false
 	}
-	if len(parts) == 2 {
-		d := strings.TrimSpace(parts[1])
+	if pipe >= 0 {
+		d := strings.TrimSpace(inner[pipe+1:])
 		display = &d
 	}
 	return target, display, true
 }
 
+// unescapedPipe returns the index of the first '|' not preceded by a backslash.
+func unescapedPipe(s string) int {
+	for i := 0; i < len(s); i++ {
+		switch s[i] {
+		case '\\':
+			i++
+		case '|':
+			return i
+		}
+	}
+	return -1
+}
+
 // FindAllInLine finds wikilinks in a single line.
 //
 // If allowTriple is false, matches preceded by '[' are skipped to avoid array syntax like [[[ref]]].
+// Matches preceded by a backslash (\[[literal]]) are always skipped.
 func FindAllInLine(line string, allowTriple bool) []Match {
 	var out []Match
 
-	matches := re.FindAllStringSubmatchIndex(line, -1)
-	for _, m := range matches {
-		if len(m) < 4 {
+	for start := 0; start+1 < len(line); {
+		idx := strings.Index(line[start:], "[[")
+		if idx == -1 {
+			break
+		}
+		start += idx
+
+		end, targetEnd, displayStart, displayEnd, ok := scan(line, start)
+		if !ok {
+			start++
+			continue
+		}
+
+		// Skip escaped links like \[[not a link]].
+		if start > 0 && line[start-1] == '\\' {
+			start = end
 			continue
 		}
-		start, end := m[0], m[1]
 
 		// Skip if preceded by '[' (array syntax like [[[ref]]]) unless allowTriple is enabled.
 		if !allowTriple && start > 0 && line[start-1] == '[' {
+			start = end
 			continue
 		}
 
-		target := strings.TrimSpace(line[m[2]:m[3]])
+		target := strings.TrimSpace(line[start+2 : targetEnd])
 		if target == "" {
+			start = end
 			continue
 		}
 
 		var display *string
-		if len(m) >= 6 && m[4] >= 0 && m[5] >= 0 {
-			d := strings.TrimSpace(line[m[4]:m[5]])
+		if displayStart >= 0 {
+			d := strings.TrimSpace(line[displayStart:displayEnd])
 			display = &d
 		}
 
@@ -85,6 +177,7 @@ func FindAllInLine(line string, allowTriple bool) []Match {
 			End:         end,
 			Literal:     line[start:end],
 		})
+		start = end
 	}
 
 	return out
@@ -94,26 +187,15 @@ func FindAllInLine(line string, allowTriple bool) []Match {
 // `start` must point at the first '[' of a "[[" sequence.
 // Returns the end offset (exclusive), target, literal, and ok.
 func ScanAt(input string, start int) (end int, target string, literal string, ok bool) {
-	if start < 0 || start+1 >= len(input) {
+	end, _, _, _, ok = scan(input, start)
+	if !ok {
 		return 0, "", "", false
 	}
-	if input[start] != '[' || input[start+1] != '[' {
+	literal = input[start:end]
+	t, _, parsed := ParseExact(literal)
+	if !parsed {
+		// If parsing fails (e.g., empty target), still return ok=false.
 		return 0, "", "", false
 	}
-
-	i := start + 2
-	for i+1 < len(input) {
-		if input[i] == ']' && input[i+1] == ']' {
-			end = i + 2
-			literal = input[start:end]
-			t, _, parsed := ParseExact(literal)
-			if !parsed {
-				// If parsing fails (e.g., empty target), still return ok=false.
-				return 0, "", "", false
-			}
-			return end, t, literal, true
-		}
-		i++
-	}
-	return 0, "", "", false
+	return end, t, literal, true
 }
@@ -67,6 +67,17 @@ func TestFindAllInLine(t *testing.T) {
 	}
 }
 
+func TestFindAllInLine_SkipsEscapedLinks(t *testing.T) {
+	t.Parallel()
+	line := `Write \[[not a link]] next to [[real]]`
+	for _, allowTriple := range []bool{false, true} {
+		m := FindAllInLine(line, allowTriple)
+		if len(m) != 1 || m[0].Target != "real" {
+			t.Fatalf("allowTriple=%v: expected only [[real]], got %#v", allowTriple, m)
+		}
+	}
+}
+
 func TestScanAt(t *testing.T) {
 	t.Parallel()
 	input := `x [[people/freya|Freya]] y`
@@ -84,3 +95,65 @@ func TestScanAt(t *testing.T) {
 		t.Fatalf("end=%d, want %d", end, 2+len(literal))
 	}
 }
+
+func TestFindAllInLine_DisplayWithBrackets(t *testing.T) {
+	t.Parallel()
+	tests := []struct {
+		line        string
+		wantTarget  string
+		wantDisplay string
+		wantLiteral string
+	}{
+		{line: "[[page|See [note]]] after", wantTarget: "page", wantDisplay: "See [note]", wantLiteral: "[[page|See [note]]]"},
+		{line: "x [[page|a [b] and [c]]] y", wantTarget: "page", wantDisplay: "a [b] and [c]", wantLiteral: "[[page|a [b] and [c]]]"},
+		{line: "[[page|[[nested]] text]]", wantTarget: "page", wantDisplay: "[[nested]] text", wantLiteral: "[[page|[[nested]] text]]"},
+		{line: "[[page|stray ] close]]", wantTarget: "page", wantDisplay: "stray ] close", wantLiteral: "[[page|stray ] close]]"},
+		{line: "[[page|open [ only]] and more", wantTarget: "page", wantDisplay: "open [ only", wantLiteral: "[[page|open [ only]]"},
+		{line: "[[page|a | b]]", wantTarget: "page", wantDisplay: "a | b", wantLiteral: "[[page|a | b]]"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.line, func(t *testing.T) {
+			m := FindAllInLine(tt.line, false)
+			if len(m) != 1 {
+				t.Fatalf("expected 1 match, got %#v", m)
+			}
+			if m[0].Target != tt.wantTarget {
+				t.Fatalf("target=%q, want %q", m[0].Target, tt.wantTarget)
+			}
+			if m[0].DisplayText == nil || *m[0].DisplayText != tt.wantDisplay {
+				t.Fatalf("display=%v, want %q", m[0].DisplayText, tt.wantDisplay)
+			}
+			if m[0].Literal != tt.wantLiteral || tt.line[m[0].Start:m[0].End] != tt.wantLiteral {
+				t.Fatalf("literal=%q (%d:%d), want %q", m[0].Literal, m[0].Start, m[0].End, tt.wantLiteral)
+			}
+		})
+	}
+}
+
+func TestFindAllInLine_DisplayWithBracketsKeepsFollowingLinks(t *testing.T) {
+	t.Parallel()
+	m := FindAllInLine("[[a|See [x]]] then [[b]] and [[[c|C]]]", false)
+	if len(m) != 2 || m[0].Target != "a" || m[1].Target != "b" {
+		t.Fatalf("unexpected matches: %#v", m)
+	}
+
+	m = FindAllInLine("[[[a|A [1]]], [[b|B]]]", true)
+	if len(m) != 2 || m[0].Target != "a" || *m[0].DisplayText != "A [1]" || m[1].Target != "b" {
+		t.Fatalf("unexpected array matches: %#v", m)
+	}
+}
+
+func TestScanAt_DisplayWithBrackets(t *testing.T) {
+	t.Parallel()
+	input := `[[page|See [note]]] rest`
+	end, target, literal, ok := ScanAt(input, 0)
+	if !ok || target != "page" || literal != "[[page|See [note]]]" || end != len(literal) {
+		t.Fatalf("ScanAt = %d %q %q %v", end, target, literal, ok)
+	}
+
+	_, display, ok := ParseExact(literal)
+	if !ok || display == nil || *display != "See [note]" {
+		t.Fatalf("ParseExact display=%v ok=%v, want %q", display, ok, "See [note]")
+	}
+}